@@ -1,4 +1,4 @@
-use std::net::IpAddr;
+use std::{fmt, net::IpAddr};
 
 use crate::{extensions::{self, ip_addr_extensions::IpAddrExt}, net::{address_type::AddressType, network_type::NetworkType}, parsing};
 use extensions::{array_extensions::ArrayExt, utf8_array_extensions::U8ArrayExt};
@@ -38,6 +38,12 @@ impl PayloadParser for ConnectionAddresses {
                 ttl = Some(ttl_slice.utf8_to_number::<u8>()?);
                 count = count_slice.utf8_to_number::<u8>()?;
             }
+            else if addr_type == AddressType::Ipv4 {
+                ttl = Some(other.utf8_to_number::<u8>()?);
+            }
+            else {
+                count = other.utf8_to_number::<u8>()?;
+            }
             IpAddr::parse(addr_slice, &addr_type)?
         }
         else {
@@ -76,4 +82,28 @@ impl ConnectionAddresses {
     pub fn addresses(&self) -> &[IpAddr] {
         &self.addresses
     }
+
+    pub fn ttl(&self) -> Option<u8> {
+        self.ttl
+    }
+}
+
+/// Writes the value of the `c=` field, e.g. `IN IP4 224.2.1.1/127/3`.
+///
+/// IPv4 multicast addresses carry the TTL before the address count, IPv6 addresses
+/// only carry the count unless a TTL was parsed.
+impl fmt::Display for ConnectionAddresses {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.net_type.as_str(), self.addr_type.as_str())?;
+        if let Some(address) = self.addresses.first() {
+            write!(f, " {}", address)?;
+        }
+        let count = self.addresses.len();
+        match self.ttl {
+            Some(ttl) if count > 1 => write!(f, "/{}/{}", ttl, count),
+            Some(ttl) => write!(f, "/{}", ttl),
+            None if count > 1 && self.addr_type == AddressType::Ipv6 => write!(f, "/{}", count),
+            None => Ok(()),
+        }
+    }
 }
@@ -47,4 +47,16 @@ fn test_parse_connection_addresses(
 
     let result = ConnectionAddresses::parse(data).map_err(|_| ());
     assert_eq!(result, expected);
-}
\ No newline at end of file
+}
+#[rstest]
+#[case(b"IN IP4 224.2.17.12/127", Some(127), 1)]
+#[case(b"IN IP4 224.2.1.1/127/3", Some(127), 3)]
+#[case(b"IN IP6 ff15::101/3", None, 3)]
+#[case(b"IN IP4 192.0.2.10", None, 1)]
+fn test_connection_addresses_round_trip(#[case] data: &[u8], #[case] ttl: Option<u8>, #[case] count: usize) {
+    let addresses = ConnectionAddresses::parse(data).unwrap();
+
+    assert_eq!(addresses.ttl(), ttl);
+    assert_eq!(addresses.addresses().len(), count);
+    assert_eq!(addresses.to_string().as_bytes(), data);
+}
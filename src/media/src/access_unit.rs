@@ -0,0 +1,74 @@
+/// Represents a complete frame reassembled from RTP payloads.
+///
+/// # Fields
+///
+/// * `timestamp` - The RTP timestamp shared by all packets of the frame.
+/// * `decode_timestamp` - The time at which the frame has to be decoded, equal to `timestamp`
+///   unless the payload format signals a decoding order.
+/// * `keyframe` - Whether the frame can be decoded without previous frames.
+/// * `data` - The frame in the codec's elementary stream format.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AccessUnit {
+    timestamp: u32,
+    decode_timestamp: u32,
+    keyframe: bool,
+    data: Vec<u8>,
+}
+
+impl AccessUnit {
+    pub fn new(timestamp: u32, keyframe: bool, data: Vec<u8>) -> Self {
+        Self { timestamp, decode_timestamp: timestamp, keyframe, data }
+    }
+
+    /// Sets the decoding timestamp of an access unit whose decoding order differs from its presentation order.
+    pub(crate) fn with_decode_timestamp(mut self, decode_timestamp: u32) -> Self {
+        self.decode_timestamp = decode_timestamp;
+        self
+    }
+
+    pub fn timestamp(&self) -> u32 {
+        self.timestamp
+    }
+
+    pub fn decode_timestamp(&self) -> u32 {
+        self.decode_timestamp
+    }
+
+    pub fn keyframe(&self) -> bool {
+        self.keyframe
+    }
+
+    pub fn data(&self) -> &[u8] {
+        &self.data
+    }
+
+    pub fn into_data(self) -> Vec<u8> {
+        self.data
+    }
+}
+
+/// Derives the decoding timestamp of an access unit released from a decoding order number reorder buffer.
+///
+/// A frame has to be decoded before any frame that follows it in decoding order is presented, so the
+/// decoding timestamp is the earliest presentation timestamp among the access unit and the NAL units
+/// still waiting behind it, kept monotonic and never later than the access unit's own timestamp.
+///
+/// # Arguments
+///
+/// * `timestamp` - The presentation timestamp of the access unit.
+/// * `following` - The timestamps of the NAL units that follow it in decoding order.
+/// * `previous` - The decoding timestamp of the previous access unit, if any.
+pub(crate) fn decode_timestamp(timestamp: u32, following: impl Iterator<Item = u32>, previous: Option<u32>) -> u32 {
+    let earliest = following.fold(timestamp, |earliest, next| if is_before(next, earliest) { next } else { earliest });
+    match previous {
+        Some(previous) if is_before(earliest, previous) => {
+            if is_before(timestamp, previous) { timestamp } else { previous }
+        }
+        _ => earliest,
+    }
+}
+
+/// Compares RTP timestamps modulo 2^32.
+fn is_before(timestamp: u32, other: u32) -> bool {
+    (timestamp.wrapping_sub(other) as i32) < 0
+}
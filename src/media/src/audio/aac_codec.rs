@@ -1,4 +1,6 @@
+use crate::audio::audio_specific_config::AudioSpecificConfig;
 use crate::codec::{Codec, CodecHelper};
+use crate::config_error::ConfigError;
 use abstractions::extensions::array_extensions::ArrayExt;
 use abstractions::extensions::utf8_array_extensions::U8ArrayExt;
 use abstractions::extensions::EMPTY_BYTE_SLICE;
@@ -9,8 +11,19 @@ const SIZE_LENGTH_START_STRING: &[u8] = b"sizeLength=";
 const INDEX_LENGTH_START_STRING: &[u8] = b"indexLength=";
 const INDEX_DELTA_LENGTH_START_STRING: &[u8] = b"indexDeltaLength=";
 const CONFIG_START_STRING: &[u8] = b"config=";
+const STREAM_TYPE_START_STRING: &[u8] = b"streamtype=";
+const PROFILE_LEVEL_ID_START_STRING: &[u8] = b"profile-level-id=";
+const MODE_START_STRING: &[u8] = b"mode=";
 
-pub(crate) const NAME: &'static str = "MPEG4-GENERIC";
+/// `streamtype` of audio streams, written when the codec was not parsed from an `a=fmtp` line.
+const AUDIO_STREAM_TYPE: u8 = 5;
+/// `profile-level-id` written when the codec was not parsed from an `a=fmtp` line.
+const DEFAULT_PROFILE_LEVEL_ID: u32 = 1;
+
+/// `sizeLength` of the low bit-rate mode of RFC 3640, section 3.3.5.
+const AAC_LBR_SIZE_LENGTH: i32 = 6;
+
+pub(crate) const NAME: &str = "MPEG4-GENERIC";
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct AacCodec {
@@ -21,11 +34,25 @@ pub struct AacCodec {
     index_length: i32,
     index_delta_length: i32,
     config_bytes: Option<Vec<u8>>,
+    stream_type: Option<u8>,
+    profile_level_id: Option<u32>,
+    mode: Option<String>,
 }
 
 impl AacCodec {
     pub fn new(format: u16, clock_rate: u32, channel_count: Option<u8>, size_length: i32, index_length: i32, index_delta_length: i32, config_bytes: Option<Vec<u8>>) -> Self {
-        Self { format, clock_rate, channel_count, size_length, index_length, index_delta_length, config_bytes }
+        Self {
+            format,
+            clock_rate,
+            channel_count,
+            size_length,
+            index_length,
+            index_delta_length,
+            config_bytes,
+            stream_type: None,
+            profile_level_id: None,
+            mode: None,
+        }
     }
     
     pub fn size_length(&self) -> i32 {
@@ -43,6 +70,45 @@ impl AacCodec {
     pub fn config_bytes(&self) -> Option<&Vec<u8>> {
         self.config_bytes.as_ref()
     }
+
+    /// Sets the `streamtype`, `profile-level-id` and `mode` parameters written by `fmtp`.
+    ///
+    /// Without them, `fmtp` writes an audio stream type, profile-level-id 1 and the mode
+    /// matching the AU-header lengths.
+    pub fn with_stream_parameters(mut self, stream_type: u8, profile_level_id: u32, mode: &str) -> Self {
+        self.stream_type = Some(stream_type);
+        self.profile_level_id = Some(profile_level_id);
+        self.mode = Some(mode.to_string());
+        self
+    }
+
+    /// Returns the `streamtype` parameter, if the codec was parsed from an `a=fmtp` line that has one.
+    pub fn stream_type(&self) -> Option<u8> {
+        self.stream_type
+    }
+
+    /// Returns the `profile-level-id` parameter, if the codec was parsed from an `a=fmtp` line that has one.
+    pub fn profile_level_id(&self) -> Option<u32> {
+        self.profile_level_id
+    }
+
+    /// Returns the `mode` parameter, if the codec was parsed from an `a=fmtp` line that has one.
+    pub fn mode(&self) -> Option<&str> {
+        self.mode.as_deref()
+    }
+
+    /// Decodes the `config` parameter and checks it against the `a=rtpmap` line.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the decoded configuration, or a `ConfigError` if it is malformed
+    /// or its sampling frequency or channel count differs from the `a=rtpmap` line.
+    pub fn audio_specific_config(&self) -> Result<AudioSpecificConfig, ConfigError> {
+        let config_bytes = self.config_bytes.as_deref().unwrap_or_default();
+        let config = AudioSpecificConfig::try_from(config_bytes)?;
+        config.validate(self.clock_rate, self.channel_count)?;
+        Ok(config)
+    }
 }
 
 impl CodecHelper for AacCodec {
@@ -53,6 +119,9 @@ impl CodecHelper for AacCodec {
         let mut size_length_parameter: &[u8] = EMPTY_BYTE_SLICE;
         let mut index_length_parameter: &[u8] = EMPTY_BYTE_SLICE;
         let mut index_delta_length_parameter: &[u8] = EMPTY_BYTE_SLICE;
+        let mut stream_type = None;
+        let mut profile_level_id = None;
+        let mut mode = None;
 
         while let Some((left, right)) = current.while_separate_trimmed(SEMICOLON, WHITESPACE) {
             if left.starts_with(SIZE_LENGTH_START_STRING) {
@@ -67,6 +136,15 @@ impl CodecHelper for AacCodec {
             else if left.starts_with(CONFIG_START_STRING) {
                 config_parameter = &left[CONFIG_START_STRING.len()..]
             }
+            else if left.starts_with(STREAM_TYPE_START_STRING) {
+                stream_type = Some(left[STREAM_TYPE_START_STRING.len()..].utf8_to_number::<u8>()?);
+            }
+            else if left.starts_with(PROFILE_LEVEL_ID_START_STRING) {
+                profile_level_id = Some(left[PROFILE_LEVEL_ID_START_STRING.len()..].utf8_to_number::<u32>()?);
+            }
+            else if left.starts_with(MODE_START_STRING) {
+                mode = Some(left[MODE_START_STRING.len()..].utf8_to_str()?.to_string());
+            }
             current = right;
         }
         if size_length_parameter != EMPTY_BYTE_SLICE
@@ -95,10 +173,13 @@ impl CodecHelper for AacCodec {
                     index_length,
                     index_delta_length,
                     config_bytes,
+                    stream_type,
+                    profile_level_id,
+                    mode,
                 });
             }
         }
-        return Err(ParsingError::from_bytes(data));
+        Err(ParsingError::from_bytes(data))
     }
 }
 
@@ -120,6 +201,28 @@ impl Codec for AacCodec {
         NAME
     }
 
+    fn fmtp(&self) -> Option<String> {
+        // Parameters that were not parsed are derived from the AU-header lengths.
+        let mode = match &self.mode {
+            Some(mode) => mode.as_str(),
+            None if self.size_length == AAC_LBR_SIZE_LENGTH => "AAC-lbr",
+            None => "AAC-hbr",
+        };
+        let mut fmtp = format!(
+            "streamtype={}; profile-level-id={}; mode={}; sizeLength={}; indexLength={}; indexDeltaLength={}",
+            self.stream_type.unwrap_or(AUDIO_STREAM_TYPE),
+            self.profile_level_id.unwrap_or(DEFAULT_PROFILE_LEVEL_ID),
+            mode,
+            self.size_length,
+            self.index_length,
+            self.index_delta_length
+        );
+        if let Some(config_bytes) = &self.config_bytes {
+            fmtp.push_str(&format!("; config={}", hex::encode(config_bytes)));
+        }
+        Some(fmtp)
+    }
+
     fn parse(clock_rate: u32, channel_count: Option<u8>, data: &[u8]) -> Result<Self, ParsingError>
     where
         Self: Sized,
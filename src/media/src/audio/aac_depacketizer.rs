@@ -0,0 +1,219 @@
+use std::collections::VecDeque;
+
+use byteorder::{BigEndian, ByteOrder};
+use rtp::rtp_packet::RtpPacket;
+
+use crate::{
+    access_unit::AccessUnit,
+    audio::aac_codec::{self, AacCodec},
+    bit_reader::BitReader,
+    codec::Codec,
+    depacketizer::Depacketizer,
+    depacketizer_error::DepacketizerError,
+    media_frame::MediaFrame,
+    packet_sequence::{PacketOrder, PacketSequence},
+};
+
+/// Number of samples in an AAC frame when the `config` parameter cannot be decoded.
+const DEFAULT_FRAME_LENGTH: u32 = 1024;
+
+/// Size of the AU-headers-length field.
+const AU_HEADERS_LENGTH_SIZE: usize = 2;
+
+/// Represents an access unit being reassembled from fragments.
+#[derive(Debug)]
+struct Fragment {
+    timestamp: u32,
+    size: usize,
+    data: Vec<u8>,
+}
+
+/// Splits MPEG4-GENERIC RTP payloads into raw AAC frames as defined in RFC 3640.
+///
+/// Every payload starts with an AU-headers section whose headers carry the size and the
+/// index of each access unit, as configured by `sizeLength`, `indexLength` and `indexDeltaLength`.
+/// The timestamp of every access unit is derived from the RTP timestamp and its index.
+/// Access units larger than one packet are reassembled from the fragments that share a
+/// timestamp, and fragmented access units that lost a packet are dropped.
+///
+/// Access units are pulled in transmission order, so interleaved streams have to be
+/// reordered by timestamp by the caller.
+#[derive(Debug)]
+pub struct AacDepacketizer {
+    size_length: usize,
+    index_length: usize,
+    index_delta_length: usize,
+    frame_length: u32,
+    fragment: Option<Fragment>,
+    synchronized: bool,
+    sequence: PacketSequence,
+    last_timestamp: Option<u32>,
+    ready: VecDeque<AccessUnit>,
+}
+
+impl AacDepacketizer {
+    /// Creates a new `AacDepacketizer` for the stream described by the codec.
+    ///
+    /// # Arguments
+    ///
+    /// * `codec` - The codec negotiated in SDP, providing the AU-header field lengths and the frame length.
+    pub fn new(codec: &AacCodec) -> Self {
+        // The timestamp increment between access units is the frame length at the RTP clock rate,
+        // which is twice the core sampling frequency with implicit SBR.
+        let frame_length = match codec.audio_specific_config() {
+            Ok(config) => (config.frame_length() as u64 * codec.clock_rate() as u64 / config.sampling_frequency() as u64) as u32,
+            Err(_) => DEFAULT_FRAME_LENGTH,
+        };
+        Self {
+            size_length: codec.size_length().max(0) as usize,
+            index_length: codec.index_length().max(0) as usize,
+            index_delta_length: codec.index_delta_length().max(0) as usize,
+            frame_length,
+            fragment: None,
+            synchronized: true,
+            sequence: PacketSequence::default(),
+            last_timestamp: None,
+            ready: VecDeque::new(),
+        }
+    }
+
+    /// Returns the number of packets detected as missing from the sequence.
+    pub fn lost_packets(&self) -> u64 {
+        self.sequence.lost_packets()
+    }
+
+    /// Pushes the next RTP packet of the stream.
+    ///
+    /// # Arguments
+    ///
+    /// * `packet` - The RTP packet carrying an MPEG4-GENERIC payload.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` that is an error if the payload is malformed; its access units are dropped.
+    pub fn push(&mut self, packet: &RtpPacket) -> Result<(), DepacketizerError> {
+        let header = packet.header();
+        match self.sequence.push(header.sequence_number()) {
+            PacketOrder::Late => return Ok(()),
+            PacketOrder::Next => {}
+            PacketOrder::AfterGap => {
+                self.fragment = None;
+                self.synchronized = false;
+            }
+        }
+
+        // A new timestamp starts a new access unit, even if the end of the previous one was lost.
+        if self.last_timestamp != Some(header.timestamp()) {
+            self.synchronized = true;
+        }
+        self.last_timestamp = Some(header.timestamp());
+
+        let result = self.push_payload(header.timestamp(), packet.payload());
+        if result.is_err() {
+            self.fragment = None;
+            self.synchronized = false;
+        }
+        if header.marker() {
+            self.synchronized = true;
+        }
+        result
+    }
+
+    /// Pulls the next complete access unit.
+    ///
+    /// # Returns
+    ///
+    /// An `Option` containing the raw AAC frame, or `None` if none is complete.
+    pub fn pull(&mut self) -> Option<AccessUnit> {
+        self.ready.pop_front()
+    }
+
+    fn push_payload(&mut self, timestamp: u32, payload: &[u8]) -> Result<(), DepacketizerError> {
+        if self.size_length == 0 {
+            // Without AU headers every payload is exactly one access unit.
+            self.ready.push_back(AccessUnit::new(timestamp, true, payload.to_vec()));
+            return Ok(());
+        }
+
+        if payload.len() < AU_HEADERS_LENGTH_SIZE {
+            return Err(DepacketizerError::Truncated("AU-headers-length"));
+        }
+        let headers_length = BigEndian::read_u16(payload) as usize;
+        let headers_size = headers_length.div_ceil(8);
+        let data_start = AU_HEADERS_LENGTH_SIZE + headers_size;
+        if payload.len() < data_start {
+            return Err(DepacketizerError::Truncated("AU headers"));
+        }
+
+        let mut reader = BitReader::new(&payload[AU_HEADERS_LENGTH_SIZE..data_start]);
+        let padding = headers_size * 8 - headers_length;
+        let mut headers = Vec::new();
+        let mut index = 0;
+        while reader.remaining() > padding {
+            let size = reader.read_bits(self.size_length).ok_or(DepacketizerError::Truncated("AU size"))? as usize;
+            index = if headers.is_empty() {
+                reader.read_bits(self.index_length).ok_or(DepacketizerError::Truncated("AU index"))?
+            }
+            else {
+                let delta = reader.read_bits(self.index_delta_length).ok_or(DepacketizerError::Truncated("AU index delta"))?;
+                index.wrapping_add(delta).wrapping_add(1)
+            };
+            headers.push((size, index));
+        }
+
+        let mut data = &payload[data_start..];
+        if let [(size, _)] = headers[..] {
+            if size > data.len() || self.fragment.as_ref().is_some_and(|fragment| fragment.timestamp == timestamp) {
+                return self.push_fragment(timestamp, size, data);
+            }
+        }
+        self.fragment = None;
+
+        let first_index = headers.first().map_or(0, |&(_, index)| index);
+        for (size, index) in headers {
+            if data.len() < size {
+                return Err(DepacketizerError::Truncated("access unit"));
+            }
+            let offset = index.wrapping_sub(first_index).wrapping_mul(self.frame_length);
+            self.ready.push_back(AccessUnit::new(timestamp.wrapping_add(offset), true, data[..size].to_vec()));
+            data = &data[size..];
+        }
+        Ok(())
+    }
+
+    fn push_fragment(&mut self, timestamp: u32, size: usize, data: &[u8]) -> Result<(), DepacketizerError> {
+        let fragment = match self.fragment.take() {
+            Some(mut fragment) if fragment.timestamp == timestamp && fragment.size == size => {
+                fragment.data.extend_from_slice(data);
+                fragment
+            }
+            _ if self.synchronized => Fragment { timestamp, size, data: data.to_vec() },
+            _ => return Ok(()),
+        };
+
+        if fragment.data.len() > size {
+            return Err(DepacketizerError::Truncated("fragmented access unit"));
+        }
+        if fragment.data.len() == size {
+            self.ready.push_back(AccessUnit::new(timestamp, true, fragment.data));
+        }
+        else {
+            self.fragment = Some(fragment);
+        }
+        Ok(())
+    }
+}
+
+impl Depacketizer for AacDepacketizer {
+    fn push(&mut self, packet: &RtpPacket) -> Result<(), DepacketizerError> {
+        AacDepacketizer::push(self, packet)
+    }
+
+    fn pull(&mut self) -> Option<MediaFrame> {
+        AacDepacketizer::pull(self).map(|access_unit| MediaFrame::from_access_unit(access_unit, aac_codec::NAME))
+    }
+
+    fn lost_packets(&self) -> u64 {
+        AacDepacketizer::lost_packets(self)
+    }
+}
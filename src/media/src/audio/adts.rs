@@ -0,0 +1,73 @@
+use crate::{
+    audio::audio_specific_config::{AudioSpecificConfig, AAC_LTP, AAC_MAIN, EXPLICIT_FREQUENCY_INDEX},
+    config_error::ConfigError,
+};
+
+/// Size of an ADTS header without CRC.
+pub const ADTS_HEADER_SIZE: usize = 7;
+
+/// Largest frame, header included, that the 13-bit `aac_frame_length` field can describe.
+const MAX_FRAME_LENGTH: usize = 0x1FFF;
+
+/// `adts_buffer_fullness` value signaling a variable bit rate stream.
+const VARIABLE_BIT_RATE: u16 = 0x7FF;
+
+/// Writes the ADTS header of a raw AAC frame.
+///
+/// ```text
+/// AAAAAAAA AAAABCCD EEFFFFGH HHIJKLMM MMMMMMMM MMMOOOOO OOOOOOPP
+/// A: syncword, B: MPEG version, C: layer, D: protection absent, E: profile,
+/// F: sampling frequency index, G: private bit, H: channel configuration,
+/// I-L: originality and copyright bits, M: frame length, O: buffer fullness,
+/// P: number of raw data blocks minus one
+/// ```
+///
+/// # Arguments
+///
+/// * `config` - The configuration of the stream.
+/// * `frame_size` - The size of the raw AAC frame.
+///
+/// # Returns
+///
+/// A `Result` containing the header, or a `ConfigError` if the configuration or the size cannot be expressed in ADTS.
+pub fn write_header(config: &AudioSpecificConfig, frame_size: usize) -> Result<[u8; ADTS_HEADER_SIZE], ConfigError> {
+    let object_type = config.object_type();
+    if !(AAC_MAIN..=AAC_LTP).contains(&object_type) {
+        return Err(ConfigError::Unsupported("ADTS audio object type", object_type as u32));
+    }
+    let frequency_index = config.sampling_frequency_index();
+    if frequency_index == EXPLICIT_FREQUENCY_INDEX {
+        return Err(ConfigError::Unsupported("ADTS sampling frequency", config.sampling_frequency()));
+    }
+    let frame_length = frame_size + ADTS_HEADER_SIZE;
+    if frame_length > MAX_FRAME_LENGTH {
+        return Err(ConfigError::FrameTooLarge(frame_size));
+    }
+
+    let profile = object_type - 1;
+    let channel_configuration = config.channel_configuration();
+    Ok([
+        0xFF,
+        0xF1,
+        (profile << 6) | (frequency_index << 2) | (channel_configuration >> 2),
+        ((channel_configuration & 0b11) << 6) | (frame_length >> 11) as u8,
+        (frame_length >> 3) as u8,
+        ((frame_length & 0b111) << 5) as u8 | (VARIABLE_BIT_RATE >> 6) as u8,
+        ((VARIABLE_BIT_RATE & 0b11_1111) << 2) as u8,
+    ])
+}
+
+/// Wraps a raw AAC frame into an ADTS frame.
+///
+/// # Arguments
+///
+/// * `config` - The configuration of the stream.
+/// * `frame` - The raw AAC frame, such as an access unit pulled from an `AacDepacketizer`.
+///
+/// # Returns
+///
+/// A `Result` containing the ADTS header followed by the frame, or a `ConfigError` if the frame cannot be expressed in ADTS.
+pub fn write_frame(config: &AudioSpecificConfig, frame: &[u8]) -> Result<Vec<u8>, ConfigError> {
+    let header = write_header(config, frame.len())?;
+    Ok([header.as_slice(), frame].concat())
+}
@@ -0,0 +1,261 @@
+use crate::{bit_reader::BitReader, config_error::ConfigError};
+
+/// Sampling frequencies addressed by `samplingFrequencyIndex`, ISO/IEC 14496-3 table 1.18.
+pub(crate) const SAMPLING_FREQUENCIES: [u32; 13] = [96000, 88200, 64000, 48000, 44100, 32000, 24000, 22050, 16000, 12000, 11025, 8000, 7350];
+
+/// `samplingFrequencyIndex` escape value followed by an explicit 24-bit frequency.
+pub(crate) const EXPLICIT_FREQUENCY_INDEX: u8 = 0x0F;
+
+pub(crate) const AAC_MAIN: u8 = 1;
+pub(crate) const AAC_LTP: u8 = 4;
+const SBR: u8 = 5;
+const AAC_SCALABLE: u8 = 6;
+const TWIN_VQ: u8 = 7;
+const ER_AAC_LC: u8 = 17;
+const ER_AAC_LTP: u8 = 19;
+const ER_AAC_SCALABLE: u8 = 20;
+const ER_BSAC: u8 = 22;
+const ER_AAC_LD: u8 = 23;
+const PS: u8 = 29;
+/// `audioObjectType` escape value followed by a 6-bit extension.
+const ESCAPE_OBJECT_TYPE: u8 = 31;
+
+const SBR_SYNC_EXTENSION: u32 = 0x2B7;
+const PS_SYNC_EXTENSION: u32 = 0x548;
+
+/// Represents a decoded MPEG-4 `AudioSpecificConfig` as carried in the `config` parameter of RFC 3640.
+///
+/// # Fields
+///
+/// * `object_type` - The audio object type of the core codec, 2 for AAC LC.
+/// * `sampling_frequency_index` - The index of the core sampling frequency, or 15 if it is explicit.
+/// * `sampling_frequency` - The core sampling frequency in Hz.
+/// * `extension_sampling_frequency` - The output sampling frequency of SBR, if present.
+/// * `channel_configuration` - The channel configuration, 0 if defined by a program config element.
+/// * `frame_length` - The number of samples per frame at the core sampling frequency.
+/// * `sbr` - Whether spectral band replication is signaled.
+/// * `ps` - Whether parametric stereo is signaled.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AudioSpecificConfig {
+    object_type: u8,
+    sampling_frequency_index: u8,
+    sampling_frequency: u32,
+    extension_sampling_frequency: Option<u32>,
+    channel_configuration: u8,
+    frame_length: u16,
+    sbr: bool,
+    ps: bool,
+}
+
+impl AudioSpecificConfig {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        object_type: u8,
+        sampling_frequency_index: u8,
+        sampling_frequency: u32,
+        extension_sampling_frequency: Option<u32>,
+        channel_configuration: u8,
+        frame_length: u16,
+        sbr: bool,
+        ps: bool,
+    ) -> Self {
+        Self {
+            object_type,
+            sampling_frequency_index,
+            sampling_frequency,
+            extension_sampling_frequency,
+            channel_configuration,
+            frame_length,
+            sbr,
+            ps,
+        }
+    }
+
+    pub fn object_type(&self) -> u8 {
+        self.object_type
+    }
+
+    pub fn sampling_frequency_index(&self) -> u8 {
+        self.sampling_frequency_index
+    }
+
+    pub fn sampling_frequency(&self) -> u32 {
+        self.sampling_frequency
+    }
+
+    pub fn extension_sampling_frequency(&self) -> Option<u32> {
+        self.extension_sampling_frequency
+    }
+
+    pub fn channel_configuration(&self) -> u8 {
+        self.channel_configuration
+    }
+
+    pub fn frame_length(&self) -> u16 {
+        self.frame_length
+    }
+
+    pub fn sbr(&self) -> bool {
+        self.sbr
+    }
+
+    pub fn ps(&self) -> bool {
+        self.ps
+    }
+
+    /// Returns the sampling frequency of the decoded output, which SBR may double.
+    pub fn output_sampling_frequency(&self) -> u32 {
+        self.extension_sampling_frequency.unwrap_or(self.sampling_frequency)
+    }
+
+    /// Returns the number of output channels, or `None` if defined by a program config element.
+    pub fn channel_count(&self) -> Option<u8> {
+        match self.channel_configuration {
+            0 => None,
+            1..=6 => Some(self.channel_configuration),
+            7 => Some(8),
+            _ => None,
+        }
+    }
+
+    /// Checks the configuration against the `a=rtpmap` line of the stream.
+    ///
+    /// The clock rate may be either the core or the SBR sampling frequency, and a mono
+    /// configuration with parametric stereo may be announced with two channels.
+    ///
+    /// # Arguments
+    ///
+    /// * `clock_rate` - The clock rate of the `a=rtpmap` line.
+    /// * `channel_count` - The channel count of the `a=rtpmap` line, which defaults to one.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` that is a `ConfigError` describing the first mismatch.
+    pub fn validate(&self, clock_rate: u32, channel_count: Option<u8>) -> Result<(), ConfigError> {
+        if clock_rate != self.sampling_frequency && Some(clock_rate) != self.extension_sampling_frequency {
+            return Err(ConfigError::ClockRateMismatch { rtpmap: clock_rate, config: self.output_sampling_frequency() });
+        }
+        let rtpmap_channels = channel_count.unwrap_or(1);
+        if let Some(config_channels) = self.channel_count() {
+            let parametric_stereo = self.ps && config_channels == 1 && rtpmap_channels == 2;
+            if rtpmap_channels != config_channels && !parametric_stereo {
+                return Err(ConfigError::ChannelCountMismatch { rtpmap: rtpmap_channels, config: config_channels });
+            }
+        }
+        Ok(())
+    }
+}
+
+impl TryFrom<&[u8]> for AudioSpecificConfig {
+    type Error = ConfigError;
+
+    fn try_from(data: &[u8]) -> Result<Self, Self::Error> {
+        let mut reader = BitReader::new(data);
+        let mut object_type = read_object_type(&mut reader)?;
+        let (sampling_frequency_index, sampling_frequency) = read_sampling_frequency(&mut reader)?;
+        let channel_configuration = reader.field(4, "channelConfiguration")? as u8;
+
+        let mut sbr = false;
+        let mut ps = false;
+        let mut extension_sampling_frequency = None;
+        if object_type == SBR || object_type == PS {
+            sbr = true;
+            ps = object_type == PS;
+            extension_sampling_frequency = Some(read_sampling_frequency(&mut reader)?.1);
+            object_type = read_object_type(&mut reader)?;
+            if object_type == ER_BSAC {
+                reader.field(4, "extensionChannelConfiguration")?;
+            }
+        }
+
+        let frame_length_flag = is_general_audio(object_type) && reader.read_bit() == Some(true);
+        let frame_length = match (object_type, frame_length_flag) {
+            (ER_AAC_LD, true) => 480,
+            (ER_AAC_LD, false) => 512,
+            (_, true) => 960,
+            (_, false) => 1024,
+        };
+
+        // Explicit backward compatible signaling of SBR and PS follows the GASpecificConfig,
+        // which cannot be skipped when it contains a program config element.
+        if is_general_audio(object_type) && channel_configuration != 0 && !sbr {
+            if let Some((extension_sbr, extension_ps, frequency)) = read_sync_extension(&mut reader, object_type) {
+                sbr = extension_sbr;
+                ps = extension_ps;
+                extension_sampling_frequency = frequency;
+            }
+        }
+
+        Ok(Self::new(
+            object_type,
+            sampling_frequency_index,
+            sampling_frequency,
+            extension_sampling_frequency,
+            channel_configuration,
+            frame_length,
+            sbr,
+            ps,
+        ))
+    }
+}
+
+fn read_object_type(reader: &mut BitReader) -> Result<u8, ConfigError> {
+    let object_type = reader.field(5, "audioObjectType")? as u8;
+    if object_type == ESCAPE_OBJECT_TYPE {
+        return Ok(32 + reader.field(6, "audioObjectTypeExt")? as u8);
+    }
+    Ok(object_type)
+}
+
+fn read_sampling_frequency(reader: &mut BitReader) -> Result<(u8, u32), ConfigError> {
+    let index = reader.field(4, "samplingFrequencyIndex")? as u8;
+    if index == EXPLICIT_FREQUENCY_INDEX {
+        return match reader.field(24, "samplingFrequency")? {
+            0 => Err(ConfigError::Unsupported("samplingFrequency", 0)),
+            frequency => Ok((index, frequency)),
+        };
+    }
+    SAMPLING_FREQUENCIES
+        .get(index as usize)
+        .map(|&frequency| (index, frequency))
+        .ok_or(ConfigError::Unsupported("samplingFrequencyIndex", index as u32))
+}
+
+fn is_general_audio(object_type: u8) -> bool {
+    matches!(object_type, AAC_MAIN..=AAC_LTP | AAC_SCALABLE | TWIN_VQ | ER_AAC_LC | ER_AAC_LTP..=ER_AAC_LD)
+}
+
+/// Reads the rest of the GASpecificConfig and the sync extension that may follow it.
+///
+/// # Returns
+///
+/// An `Option` containing the SBR and PS flags and the SBR sampling frequency, or `None` if no extension is present.
+fn read_sync_extension(reader: &mut BitReader, object_type: u8) -> Option<(bool, bool, Option<u32>)> {
+    if reader.read_bit()? {
+        // dependsOnCoreCoder is followed by coreCoderDelay.
+        reader.skip_bits(14)?;
+    }
+    let extension_flag = reader.read_bit()?;
+    if object_type == AAC_SCALABLE || object_type == ER_AAC_SCALABLE {
+        reader.skip_bits(3)?;
+    }
+    if extension_flag {
+        if object_type == ER_BSAC {
+            reader.skip_bits(16)?;
+        }
+        if matches!(object_type, ER_AAC_LC | ER_AAC_LTP | ER_AAC_SCALABLE | ER_AAC_LD) {
+            reader.skip_bits(3)?;
+        }
+        reader.skip_bits(1)?;
+    }
+
+    if reader.remaining() < 16 || reader.read_bits(11)? != SBR_SYNC_EXTENSION {
+        return None;
+    }
+    if read_object_type(reader).ok()? != SBR || !reader.read_bit()? {
+        return None;
+    }
+    let frequency = read_sampling_frequency(reader).ok()?.1;
+    let ps = reader.remaining() >= 12 && reader.read_bits(11)? == PS_SYNC_EXTENSION && reader.read_bit()?;
+    Some((true, ps, Some(frequency)))
+}
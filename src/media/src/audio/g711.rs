@@ -0,0 +1,84 @@
+/// Sign bit of a G.711 code word.
+const SIGN_BIT: u8 = 0x80;
+/// Mask of the quantization bits of a G.711 code word.
+const QUANT_MASK: u8 = 0x0F;
+/// Mask of the segment bits of a G.711 code word.
+const SEG_MASK: u8 = 0x70;
+/// Shift of the segment bits of a G.711 code word.
+const SEG_SHIFT: u8 = 4;
+
+/// Bias added to μ-law magnitudes so that every segment starts at a power of two.
+const MU_LAW_BIAS: i32 = 0x84;
+/// Largest 14-bit magnitude that μ-law can encode before adding the bias.
+const MU_LAW_CLIP: i32 = 8159;
+/// Even bits inverted in every A-law code word.
+const A_LAW_TOGGLE: u8 = 0x55;
+
+/// Upper bounds of the μ-law segments, on 14-bit biased magnitudes.
+const MU_LAW_SEGMENT_ENDS: [i32; 8] = [0x3F, 0x7F, 0xFF, 0x1FF, 0x3FF, 0x7FF, 0xFFF, 0x1FFF];
+/// Upper bounds of the A-law segments, on 13-bit magnitudes.
+const A_LAW_SEGMENT_ENDS: [i32; 8] = [0x1F, 0x3F, 0x7F, 0xFF, 0x1FF, 0x3FF, 0x7FF, 0xFFF];
+
+/// Converts a μ-law code word to a 16-bit linear sample, ITU-T G.711.
+pub fn mu_law_to_linear(code: u8) -> i16 {
+    let code = !code;
+    let mut magnitude = (((code & QUANT_MASK) as i32) << 3) + MU_LAW_BIAS;
+    magnitude <<= (code & SEG_MASK) >> SEG_SHIFT;
+    let sample = if code & SIGN_BIT != 0 { MU_LAW_BIAS - magnitude } else { magnitude - MU_LAW_BIAS };
+    sample as i16
+}
+
+/// Converts a 16-bit linear sample to a μ-law code word, ITU-T G.711.
+pub fn linear_to_mu_law(sample: i16) -> u8 {
+    let mut value = sample as i32 >> 2;
+    let mask = if value < 0 {
+        value = -value;
+        0x7F
+    }
+    else {
+        0xFF
+    };
+    value = value.min(MU_LAW_CLIP) + (MU_LAW_BIAS >> 2);
+    let code = match segment(value, &MU_LAW_SEGMENT_ENDS) {
+        Some(segment) => (segment << SEG_SHIFT) | ((value >> (segment + 1)) as u8 & QUANT_MASK),
+        None => 0x7F,
+    };
+    code ^ mask
+}
+
+/// Converts an A-law code word to a 16-bit linear sample, ITU-T G.711.
+pub fn a_law_to_linear(code: u8) -> i16 {
+    let code = code ^ A_LAW_TOGGLE;
+    let mut magnitude = ((code & QUANT_MASK) as i32) << 4;
+    let segment = (code & SEG_MASK) >> SEG_SHIFT;
+    match segment {
+        0 => magnitude += 8,
+        1 => magnitude += 0x108,
+        _ => magnitude = (magnitude + 0x108) << (segment - 1),
+    }
+    let sample = if code & SIGN_BIT != 0 { magnitude } else { -magnitude };
+    sample as i16
+}
+
+/// Converts a 16-bit linear sample to an A-law code word, ITU-T G.711.
+pub fn linear_to_a_law(sample: i16) -> u8 {
+    let mut value = sample as i32 >> 3;
+    let mask = if value >= 0 {
+        0xD5
+    }
+    else {
+        value = -value - 1;
+        A_LAW_TOGGLE
+    };
+    let code = match segment(value, &A_LAW_SEGMENT_ENDS) {
+        Some(segment @ 0..=1) => (segment << SEG_SHIFT) | ((value >> 1) as u8 & QUANT_MASK),
+        Some(segment) => (segment << SEG_SHIFT) | ((value >> segment) as u8 & QUANT_MASK),
+        None => 0x7F,
+    };
+    code ^ mask
+}
+
+/// Returns the segment of a magnitude, or `None` if it exceeds the last segment.
+fn segment(value: i32, segment_ends: &[i32; 8]) -> Option<u8> {
+    segment_ends.iter().position(|&end| value <= end).map(|segment| segment as u8)
+}
@@ -0,0 +1,127 @@
+use abstractions::parsing::parsing_error::ParsingError;
+
+use crate::{
+    audio::g711::{a_law_to_linear, linear_to_a_law, linear_to_mu_law, mu_law_to_linear},
+    codec::{Codec, CodecHelper},
+};
+
+pub(crate) const PCMU_NAME: &str = "PCMU";
+pub(crate) const PCMA_NAME: &str = "PCMA";
+
+/// Represents a G.711 μ-law stream, `PCMU` in RFC 3551, with the static payload type 0.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PcmuCodec {
+    format: u16,
+    clock_rate: u32,
+    channel_count: Option<u8>,
+}
+
+impl PcmuCodec {
+    pub fn new(format: u16, clock_rate: u32, channel_count: Option<u8>) -> Self {
+        Self { format, clock_rate, channel_count }
+    }
+
+    /// Decodes a payload of μ-law code words into 16-bit linear samples.
+    pub fn decode(&self, payload: &[u8]) -> Vec<i16> {
+        payload.iter().map(|&code| mu_law_to_linear(code)).collect()
+    }
+
+    /// Encodes 16-bit linear samples into a payload of μ-law code words.
+    pub fn encode(&self, samples: &[i16]) -> Vec<u8> {
+        samples.iter().map(|&sample| linear_to_mu_law(sample)).collect()
+    }
+}
+
+impl CodecHelper for PcmuCodec {
+    fn from_fmtp_internal(format: u16, clock_rate: u32, channel_count: Option<u8>, _data: &[u8]) -> Result<Self, ParsingError> {
+        Ok(Self::new(format, clock_rate, channel_count))
+    }
+}
+
+impl Codec for PcmuCodec {
+    fn channel_count(&self) -> &Option<u8> {
+        &self.channel_count
+    }
+
+    fn clock_rate(&self) -> u32 {
+        self.clock_rate
+    }
+
+    fn format(&self) -> u16 {
+        self.format
+    }
+
+    fn name(&self) -> &'static str {
+        PCMU_NAME
+    }
+
+    fn fmtp(&self) -> Option<String> {
+        None
+    }
+
+    fn parse(clock_rate: u32, channel_count: Option<u8>, data: &[u8]) -> Result<Self, ParsingError>
+    where
+        Self: Sized,
+    {
+        Self::from_fmtp(clock_rate, channel_count, data)
+    }
+}
+
+/// Represents a G.711 A-law stream, `PCMA` in RFC 3551, with the static payload type 8.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PcmaCodec {
+    format: u16,
+    clock_rate: u32,
+    channel_count: Option<u8>,
+}
+
+impl PcmaCodec {
+    pub fn new(format: u16, clock_rate: u32, channel_count: Option<u8>) -> Self {
+        Self { format, clock_rate, channel_count }
+    }
+
+    /// Decodes a payload of A-law code words into 16-bit linear samples.
+    pub fn decode(&self, payload: &[u8]) -> Vec<i16> {
+        payload.iter().map(|&code| a_law_to_linear(code)).collect()
+    }
+
+    /// Encodes 16-bit linear samples into a payload of A-law code words.
+    pub fn encode(&self, samples: &[i16]) -> Vec<u8> {
+        samples.iter().map(|&sample| linear_to_a_law(sample)).collect()
+    }
+}
+
+impl CodecHelper for PcmaCodec {
+    fn from_fmtp_internal(format: u16, clock_rate: u32, channel_count: Option<u8>, _data: &[u8]) -> Result<Self, ParsingError> {
+        Ok(Self::new(format, clock_rate, channel_count))
+    }
+}
+
+impl Codec for PcmaCodec {
+    fn channel_count(&self) -> &Option<u8> {
+        &self.channel_count
+    }
+
+    fn clock_rate(&self) -> u32 {
+        self.clock_rate
+    }
+
+    fn format(&self) -> u16 {
+        self.format
+    }
+
+    fn name(&self) -> &'static str {
+        PCMA_NAME
+    }
+
+    fn fmtp(&self) -> Option<String> {
+        None
+    }
+
+    fn parse(clock_rate: u32, channel_count: Option<u8>, data: &[u8]) -> Result<Self, ParsingError>
+    where
+        Self: Sized,
+    {
+        Self::from_fmtp(clock_rate, channel_count, data)
+    }
+}
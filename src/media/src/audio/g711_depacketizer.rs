@@ -0,0 +1,81 @@
+use std::collections::VecDeque;
+
+use rtp::rtp_packet::RtpPacket;
+
+use crate::{
+    access_unit::AccessUnit, codec::Codec, depacketizer::Depacketizer, depacketizer_error::DepacketizerError, media_frame::MediaFrame,
+    packet_sequence::{PacketOrder, PacketSequence},
+};
+
+/// Extracts G.711 frames from `PCMU` and `PCMA` RTP payloads as defined in RFC 3551.
+///
+/// Every payload is a sequence of 8-bit code words, one per sample and channel, so every
+/// packet is a frame that can be decoded on its own.
+#[derive(Debug)]
+pub struct G711Depacketizer {
+    name: &'static str,
+    sequence: PacketSequence,
+    ready: VecDeque<AccessUnit>,
+}
+
+impl G711Depacketizer {
+    /// Creates a new `G711Depacketizer` for the stream described by the codec.
+    ///
+    /// # Arguments
+    ///
+    /// * `codec` - The `PCMU` or `PCMA` codec negotiated in SDP, providing the encoding name.
+    pub fn new(codec: &dyn Codec) -> Self {
+        Self { name: codec.name(), sequence: PacketSequence::default(), ready: VecDeque::new() }
+    }
+
+    /// Returns the number of packets detected as missing from the sequence.
+    pub fn lost_packets(&self) -> u64 {
+        self.sequence.lost_packets()
+    }
+
+    /// Pushes the next RTP packet of the stream.
+    ///
+    /// # Arguments
+    ///
+    /// * `packet` - The RTP packet carrying G.711 code words.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` that is an error if the payload is empty; it is dropped.
+    pub fn push(&mut self, packet: &RtpPacket) -> Result<(), DepacketizerError> {
+        let header = packet.header();
+        if self.sequence.push(header.sequence_number()) == PacketOrder::Late {
+            return Ok(());
+        }
+
+        let payload = packet.payload();
+        if payload.is_empty() {
+            return Err(DepacketizerError::Truncated("G.711 payload"));
+        }
+        self.ready.push_back(AccessUnit::new(header.timestamp(), true, payload.to_vec()));
+        Ok(())
+    }
+
+    /// Pulls the next G.711 frame.
+    ///
+    /// # Returns
+    ///
+    /// An `Option` containing the code words of the frame, or `None` if none is ready.
+    pub fn pull(&mut self) -> Option<AccessUnit> {
+        self.ready.pop_front()
+    }
+}
+
+impl Depacketizer for G711Depacketizer {
+    fn push(&mut self, packet: &RtpPacket) -> Result<(), DepacketizerError> {
+        G711Depacketizer::push(self, packet)
+    }
+
+    fn pull(&mut self) -> Option<MediaFrame> {
+        G711Depacketizer::pull(self).map(|access_unit| MediaFrame::from_access_unit(access_unit, self.name))
+    }
+
+    fn lost_packets(&self) -> u64 {
+        G711Depacketizer::lost_packets(self)
+    }
+}
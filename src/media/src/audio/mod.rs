@@ -1 +1,10 @@
 pub mod aac_codec;
+pub mod aac_depacketizer;
+pub mod adts;
+pub mod audio_specific_config;
+pub mod g711;
+pub mod g711_codec;
+pub mod g711_depacketizer;
+pub mod opus_codec;
+pub mod opus_depacketizer;
+pub mod opus_packet;
@@ -0,0 +1,198 @@
+use abstractions::{
+    extensions::{array_extensions::ArrayExt, utf8_array_extensions::U8ArrayExt},
+    parsing::{parsing_error::ParsingError, SEMICOLON, WHITESPACE},
+};
+
+use crate::codec::{Codec, CodecHelper, FMTP_KEY};
+
+const STEREO_START: &[u8] = b"stereo=";
+const SPROP_STEREO_START: &[u8] = b"sprop-stereo=";
+const MAX_PLAYBACK_RATE_START: &[u8] = b"maxplaybackrate=";
+const USE_INBAND_FEC_START: &[u8] = b"useinbandfec=";
+const USE_DTX_START: &[u8] = b"usedtx=";
+const PTIME_START: &[u8] = b"ptime=";
+
+pub(crate) const NAME: &str = "opus";
+/// The encoding name as matched against the upper-cased `a=rtpmap` line; RFC 7587 writes it in lower case.
+pub(crate) const RTPMAP_NAME: &str = "OPUS";
+const CLOCK_RATE: u32 = 48000;
+const CHANNEL_COUNT: u8 = 2;
+
+/// Represents an Opus stream as described in RFC 7587.
+///
+/// The `a=rtpmap` line is always `opus/48000/2`; the number of channels actually sent and
+/// the preferences of the receiver are carried by the format parameters, which all have defaults.
+///
+/// # Fields
+///
+/// * `stereo` - Whether the receiver prefers to receive stereo.
+/// * `sprop_stereo` - Whether the sender is likely to send stereo.
+/// * `max_playback_rate` - The highest audio bandwidth the receiver can render, in Hz.
+/// * `use_inband_fec` - Whether the receiver can use in-band forward error correction.
+/// * `use_dtx` - Whether the receiver prefers discontinuous transmission.
+/// * `ptime` - The preferred duration of a packet, in milliseconds.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OpusCodec {
+    format: u16,
+    clock_rate: u32,
+    channel_count: Option<u8>,
+    stereo: bool,
+    sprop_stereo: bool,
+    max_playback_rate: Option<u32>,
+    use_inband_fec: bool,
+    use_dtx: bool,
+    ptime: Option<u32>,
+}
+
+impl OpusCodec {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        format: u16,
+        clock_rate: u32,
+        channel_count: Option<u8>,
+        stereo: bool,
+        sprop_stereo: bool,
+        max_playback_rate: Option<u32>,
+        use_inband_fec: bool,
+        use_dtx: bool,
+        ptime: Option<u32>,
+    ) -> Self {
+        Self { format, clock_rate, channel_count, stereo, sprop_stereo, max_playback_rate, use_inband_fec, use_dtx, ptime }
+    }
+
+    pub fn stereo(&self) -> bool {
+        self.stereo
+    }
+
+    pub fn sprop_stereo(&self) -> bool {
+        self.sprop_stereo
+    }
+
+    pub fn max_playback_rate(&self) -> Option<u32> {
+        self.max_playback_rate
+    }
+
+    pub fn use_inband_fec(&self) -> bool {
+        self.use_inband_fec
+    }
+
+    pub fn use_dtx(&self) -> bool {
+        self.use_dtx
+    }
+
+    pub fn ptime(&self) -> Option<u32> {
+        self.ptime
+    }
+
+    /// Parses the codec from the lines that follow its `a=rtpmap` line.
+    ///
+    /// Unlike `Codec::parse`, the `a=fmtp` line may be missing since every format parameter has a default.
+    /// RFC 7587 fixes the `a=rtpmap` line to `opus/48000/2`, so any other clock rate or channel count is rejected.
+    ///
+    /// # Arguments
+    ///
+    /// * `format` - The payload format number of the `a=rtpmap` line.
+    /// * `clock_rate` - The clock rate of the codec.
+    /// * `channel_count` - The optional channel count.
+    /// * `data` - A byte slice that may contain the `a=fmtp` line of the codec.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the codec, or a `ParsingError` if the `a=rtpmap` or `a=fmtp` line is malformed.
+    pub fn from_rtpmap(format: u16, clock_rate: u32, channel_count: Option<u8>, data: &[u8]) -> Result<Self, ParsingError> {
+        if clock_rate != CLOCK_RATE || channel_count != Some(CHANNEL_COUNT) {
+            return Err(ParsingError::from_str("Invalid Opus rtpmap, expected opus/48000/2"));
+        }
+        if data.separate(FMTP_KEY).is_none() {
+            return Ok(OpusCodec::new(format, clock_rate, channel_count, false, false, None, false, false, None));
+        }
+        Self::from_fmtp(clock_rate, channel_count, data)
+    }
+}
+
+impl CodecHelper for OpusCodec {
+    fn from_fmtp_internal(format: u16, clock_rate: u32, channel_count: Option<u8>, data: &[u8]) -> Result<Self, ParsingError> {
+        let mut codec = OpusCodec::new(format, clock_rate, channel_count, false, false, None, false, false, None);
+
+        let mut current = data;
+        while let Some((left, right)) = current.while_separate_trimmed(SEMICOLON, WHITESPACE) {
+            if let Some(value) = left.strip_prefix(SPROP_STEREO_START) {
+                codec.sprop_stereo = parse_flag(value)?;
+            }
+            else if let Some(value) = left.strip_prefix(STEREO_START) {
+                codec.stereo = parse_flag(value)?;
+            }
+            else if let Some(value) = left.strip_prefix(MAX_PLAYBACK_RATE_START) {
+                codec.max_playback_rate = Some(value.utf8_to_number::<u32>()?);
+            }
+            else if let Some(value) = left.strip_prefix(USE_INBAND_FEC_START) {
+                codec.use_inband_fec = parse_flag(value)?;
+            }
+            else if let Some(value) = left.strip_prefix(USE_DTX_START) {
+                codec.use_dtx = parse_flag(value)?;
+            }
+            else if let Some(value) = left.strip_prefix(PTIME_START) {
+                codec.ptime = Some(value.utf8_to_number::<u32>()?);
+            }
+            current = right;
+        }
+        Ok(codec)
+    }
+}
+
+impl Codec for OpusCodec {
+    fn channel_count(&self) -> &Option<u8> {
+        &self.channel_count
+    }
+
+    fn clock_rate(&self) -> u32 {
+        self.clock_rate
+    }
+
+    fn format(&self) -> u16 {
+        self.format
+    }
+
+    fn name(&self) -> &'static str {
+        NAME
+    }
+
+    fn fmtp(&self) -> Option<String> {
+        let mut parameters = Vec::new();
+        if let Some(max_playback_rate) = self.max_playback_rate {
+            parameters.push(format!("maxplaybackrate={}", max_playback_rate));
+        }
+        if self.stereo {
+            parameters.push("stereo=1".to_string());
+        }
+        if self.sprop_stereo {
+            parameters.push("sprop-stereo=1".to_string());
+        }
+        if self.use_inband_fec {
+            parameters.push("useinbandfec=1".to_string());
+        }
+        if self.use_dtx {
+            parameters.push("usedtx=1".to_string());
+        }
+        if let Some(ptime) = self.ptime {
+            parameters.push(format!("ptime={}", ptime));
+        }
+        (!parameters.is_empty()).then(|| parameters.join("; "))
+    }
+
+    fn parse(clock_rate: u32, channel_count: Option<u8>, data: &[u8]) -> Result<Self, ParsingError>
+    where
+        Self: Sized,
+    {
+        Self::from_fmtp(clock_rate, channel_count, data)
+    }
+}
+
+/// Parses a `0` or `1` parameter value.
+fn parse_flag(value: &[u8]) -> Result<bool, ParsingError> {
+    match value.utf8_to_number::<u8>()? {
+        0 => Ok(false),
+        1 => Ok(true),
+        _ => Err(ParsingError::from_bytes(value)),
+    }
+}
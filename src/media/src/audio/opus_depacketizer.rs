@@ -0,0 +1,96 @@
+use std::collections::VecDeque;
+
+use rtp::rtp_packet::RtpPacket;
+
+use crate::{
+    access_unit::AccessUnit,
+    audio::{
+        opus_codec::{self, OpusCodec},
+        opus_packet::packet_duration,
+    },
+    depacketizer::Depacketizer,
+    depacketizer_error::DepacketizerError,
+    media_frame::MediaFrame,
+    packet_sequence::{PacketOrder, PacketSequence},
+};
+
+/// Extracts Opus packets from RTP payloads as defined in RFC 7587.
+///
+/// Every payload is exactly one Opus packet and the RTP clock always runs at 48 kHz,
+/// so the RTP timestamp is the presentation time of the packet at 48 kHz.
+/// Packets carry no inter-frame dependencies that prevent decoding after a loss,
+/// so every packet is a keyframe.
+#[derive(Debug)]
+pub struct OpusDepacketizer {
+    sequence: PacketSequence,
+    next_timestamp: Option<u32>,
+    ready: VecDeque<AccessUnit>,
+}
+
+impl OpusDepacketizer {
+    /// Creates a new `OpusDepacketizer` for the stream described by the codec.
+    ///
+    /// # Arguments
+    ///
+    /// * `_codec` - The codec negotiated in SDP, whose parameters do not affect the payload format.
+    pub fn new(_codec: &OpusCodec) -> Self {
+        Self { sequence: PacketSequence::default(), next_timestamp: None, ready: VecDeque::new() }
+    }
+
+    /// Returns the number of packets detected as missing from the sequence.
+    pub fn lost_packets(&self) -> u64 {
+        self.sequence.lost_packets()
+    }
+
+    /// Returns the timestamp expected for the next packet, at the end of the last pushed packet.
+    ///
+    /// A later timestamp on the next packet means that the sender skipped silence with DTX or that packets were lost.
+    pub fn next_timestamp(&self) -> Option<u32> {
+        self.next_timestamp
+    }
+
+    /// Pushes the next RTP packet of the stream.
+    ///
+    /// # Arguments
+    ///
+    /// * `packet` - The RTP packet carrying an Opus payload.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` that is an error if the payload is not a valid Opus packet; it is dropped.
+    pub fn push(&mut self, packet: &RtpPacket) -> Result<(), DepacketizerError> {
+        let header = packet.header();
+        if self.sequence.push(header.sequence_number()) == PacketOrder::Late {
+            return Ok(());
+        }
+
+        let payload = packet.payload();
+        let duration = packet_duration(payload)?;
+        self.next_timestamp = Some(header.timestamp().wrapping_add(duration));
+        self.ready.push_back(AccessUnit::new(header.timestamp(), true, payload.to_vec()));
+        Ok(())
+    }
+
+    /// Pulls the next Opus packet.
+    ///
+    /// # Returns
+    ///
+    /// An `Option` containing the Opus packet, or `None` if none is ready.
+    pub fn pull(&mut self) -> Option<AccessUnit> {
+        self.ready.pop_front()
+    }
+}
+
+impl Depacketizer for OpusDepacketizer {
+    fn push(&mut self, packet: &RtpPacket) -> Result<(), DepacketizerError> {
+        OpusDepacketizer::push(self, packet)
+    }
+
+    fn pull(&mut self) -> Option<MediaFrame> {
+        OpusDepacketizer::pull(self).map(|access_unit| MediaFrame::from_access_unit(access_unit, opus_codec::NAME))
+    }
+
+    fn lost_packets(&self) -> u64 {
+        OpusDepacketizer::lost_packets(self)
+    }
+}
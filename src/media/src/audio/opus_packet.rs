@@ -0,0 +1,76 @@
+use crate::depacketizer_error::DepacketizerError;
+
+/// Mask of the frame count code in the TOC byte.
+const FRAME_COUNT_CODE_MASK: u8 = 0b0000_0011;
+/// Stereo flag of the TOC byte.
+const STEREO_MASK: u8 = 0b0000_0100;
+/// Mask of the frame count in the byte that follows the TOC byte of a code 3 packet.
+const FRAME_COUNT_MASK: u8 = 0b0011_1111;
+
+/// Longest duration of a packet, 120 ms at 48 kHz.
+pub const MAX_PACKET_DURATION: u32 = 5760;
+
+/// Frame durations at 48 kHz of the SILK-only configurations 0 to 11, by bandwidth.
+const SILK_DURATIONS: [u32; 4] = [480, 960, 1920, 2880];
+/// Frame durations at 48 kHz of the hybrid configurations 12 to 15.
+const HYBRID_DURATIONS: [u32; 2] = [480, 960];
+/// Frame durations at 48 kHz of the CELT-only configurations 16 to 31.
+const CELT_DURATIONS: [u32; 4] = [120, 240, 480, 960];
+
+/// Returns the duration of every frame of a packet, in samples at 48 kHz.
+///
+/// ```text
+/// 0 1 2 3 4 5 6 7
+/// +-+-+-+-+-+-+-+-+
+/// | config  |s| c |
+/// +-+-+-+-+-+-+-+-+
+/// ```
+///
+/// # Arguments
+///
+/// * `toc` - The TOC byte that starts the packet, RFC 6716 section 3.1.
+pub fn frame_duration(toc: u8) -> u32 {
+    let config = (toc >> 3) as usize;
+    match config {
+        0..=11 => SILK_DURATIONS[config % 4],
+        12..=15 => HYBRID_DURATIONS[config % 2],
+        _ => CELT_DURATIONS[config % 4],
+    }
+}
+
+/// Returns whether the frames of a packet are coded in stereo.
+pub fn is_stereo(toc: u8) -> bool {
+    toc & STEREO_MASK != 0
+}
+
+/// Returns the number of frames of a packet, RFC 6716 section 3.2.
+///
+/// # Returns
+///
+/// A `Result` containing the number of frames, or a `DepacketizerError` if the packet has no TOC byte or frame count.
+pub fn frame_count(packet: &[u8]) -> Result<u32, DepacketizerError> {
+    let toc = *packet.first().ok_or(DepacketizerError::Truncated("TOC"))?;
+    match toc & FRAME_COUNT_CODE_MASK {
+        0 => Ok(1),
+        1 | 2 => Ok(2),
+        _ => packet
+            .get(1)
+            .map(|count| (count & FRAME_COUNT_MASK) as u32)
+            .ok_or(DepacketizerError::Truncated("frame count")),
+    }
+}
+
+/// Returns the duration of a packet, in samples at 48 kHz.
+///
+/// # Returns
+///
+/// A `Result` containing the duration, or a `DepacketizerError` if the packet is truncated,
+/// has no frame or lasts longer than 120 ms.
+pub fn packet_duration(packet: &[u8]) -> Result<u32, DepacketizerError> {
+    let count = frame_count(packet)?;
+    let duration = count * frame_duration(packet[0]);
+    if count == 0 || duration > MAX_PACKET_DURATION {
+        return Err(DepacketizerError::Invalid("frame count"));
+    }
+    Ok(duration)
+}
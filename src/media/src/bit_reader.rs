@@ -0,0 +1,154 @@
+use std::ops::RangeInclusive;
+
+use crate::config_error::ConfigError;
+
+/// Reads big-endian bit fields from a byte slice, most significant bit first.
+#[derive(Debug)]
+pub(crate) struct BitReader<'a> {
+    data: &'a [u8],
+    position: usize,
+}
+
+impl<'a> BitReader<'a> {
+    pub(crate) fn new(data: &'a [u8]) -> Self {
+        Self { data, position: 0 }
+    }
+
+    /// Returns the number of bits left to read.
+    pub(crate) fn remaining(&self) -> usize {
+        (self.data.len() * 8).saturating_sub(self.position)
+    }
+
+    /// Reads a single bit.
+    ///
+    /// # Returns
+    ///
+    /// An `Option` containing the bit, or `None` if the data is exhausted.
+    pub(crate) fn read_bit(&mut self) -> Option<bool> {
+        self.read_bits(1).map(|bit| bit == 1)
+    }
+
+    /// Reads up to 32 bits as an unsigned number.
+    ///
+    /// # Arguments
+    ///
+    /// * `count` - The number of bits to read, at most 32.
+    ///
+    /// # Returns
+    ///
+    /// An `Option` containing the number, or `None` if fewer than `count` bits are left.
+    pub(crate) fn read_bits(&mut self, count: usize) -> Option<u32> {
+        if count > 32 || count > self.remaining() {
+            return None;
+        }
+        let mut value: u64 = 0;
+        for _ in 0..count {
+            let byte = self.data[self.position / 8];
+            let bit = (byte >> (7 - self.position % 8)) & 1;
+            value = (value << 1) | bit as u64;
+            self.position += 1;
+        }
+        Some(value as u32)
+    }
+
+    /// Skips the given number of bits.
+    ///
+    /// # Returns
+    ///
+    /// An `Option` that is `None` if fewer than `count` bits are left.
+    pub(crate) fn skip_bits(&mut self, count: usize) -> Option<()> {
+        if count > self.remaining() {
+            return None;
+        }
+        self.position += count;
+        Some(())
+    }
+
+    /// Reads an unsigned Exp-Golomb code, `ue(v)` in the H.264 and H.265 syntax tables.
+    ///
+    /// # Returns
+    ///
+    /// An `Option` containing the number, or `None` if the data is exhausted or the code exceeds 32 bits.
+    pub(crate) fn read_ue(&mut self) -> Option<u32> {
+        let mut leading_zeros = 0;
+        while !self.read_bit()? {
+            leading_zeros += 1;
+            if leading_zeros > 31 {
+                return None;
+            }
+        }
+        let suffix = self.read_bits(leading_zeros)? as u64;
+        u32::try_from((1u64 << leading_zeros) - 1 + suffix).ok()
+    }
+
+    /// Reads a signed Exp-Golomb code, `se(v)` in the H.264 and H.265 syntax tables.
+    ///
+    /// # Returns
+    ///
+    /// An `Option` containing the number, or `None` if the data is exhausted or the code exceeds 32 bits.
+    pub(crate) fn read_se(&mut self) -> Option<i32> {
+        let code = self.read_ue()? as i64;
+        let value = if code % 2 == 1 { (code + 1) / 2 } else { -(code / 2) };
+        Some(value as i32)
+    }
+
+    /// Returns whether data is left before the `rbsp_trailing_bits`, `more_rbsp_data()` in the syntax tables.
+    pub(crate) fn more_rbsp_data(&self) -> bool {
+        let Some(last) = self.data.iter().rposition(|byte| *byte != 0) else {
+            return false;
+        };
+        let stop_bit = last * 8 + 7 - self.data[last].trailing_zeros() as usize;
+        self.position < stop_bit
+    }
+
+    /// Reads a fixed-length field, `u(n)` in the syntax tables.
+    ///
+    /// # Arguments
+    ///
+    /// * `count` - The number of bits to read, at most 32.
+    /// * `name` - The name of the field, reported if the data is exhausted.
+    pub(crate) fn field(&mut self, count: usize, name: &'static str) -> Result<u32, ConfigError> {
+        self.read_bits(count).ok_or(ConfigError::Truncated(name))
+    }
+
+    /// Reads a one-bit flag, `u(1)` in the syntax tables.
+    pub(crate) fn flag(&mut self, name: &'static str) -> Result<bool, ConfigError> {
+        self.read_bit().ok_or(ConfigError::Truncated(name))
+    }
+
+    /// Reads an unsigned Exp-Golomb field, `ue(v)` in the syntax tables.
+    pub(crate) fn ue_field(&mut self, name: &'static str) -> Result<u32, ConfigError> {
+        self.read_ue().ok_or(ConfigError::Truncated(name))
+    }
+
+    /// Reads a signed Exp-Golomb field, `se(v)` in the syntax tables.
+    pub(crate) fn se_field(&mut self, name: &'static str) -> Result<i32, ConfigError> {
+        self.read_se().ok_or(ConfigError::Truncated(name))
+    }
+
+    /// Reads an unsigned Exp-Golomb field whose value is restricted by the specification.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the value, or a `ConfigError::Invalid` naming the field if it exceeds `max`.
+    pub(crate) fn ue_field_max(&mut self, name: &'static str, max: u32) -> Result<u32, ConfigError> {
+        let value = self.ue_field(name)?;
+        if value > max {
+            return Err(ConfigError::Invalid(name));
+        }
+        Ok(value)
+    }
+
+    /// Reads a signed Exp-Golomb field whose value is restricted by the specification.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the value, or a `ConfigError::Invalid` naming the field if it is out of `range`.
+    pub(crate) fn se_field_in(&mut self, name: &'static str, range: RangeInclusive<i32>) -> Result<i32, ConfigError> {
+        let value = self.se_field(name)?;
+        if !range.contains(&value) {
+            return Err(ConfigError::Invalid(name));
+        }
+        Ok(value)
+    }
+}
@@ -25,7 +25,7 @@ pub(crate) trait CodecHelper where Self: Sized + Codec {
                 .ok_or_else(|| ParsingError::from_bytes(data))?;
             fmtp = right;
             
-            return Ok(Self::from_fmtp_internal(format.utf8_to_number::<u16>()?, clock_rate, channel_count, fmtp)?);
+            return Self::from_fmtp_internal(format.utf8_to_number::<u16>()?, clock_rate, channel_count, fmtp);
         }
         Err(ParsingError::from_bytes(data))
     }
@@ -66,6 +66,13 @@ pub trait Codec {
     /// A `&str` representing the name of codec.
     fn name(&self) -> &'static str;
 
+    /// Returns the format parameters written to the `a=fmtp` line.
+    ///
+    /// # Returns
+    ///
+    /// An `Option` containing the `;`-separated parameters, or `None` if the codec has none.
+    fn fmtp(&self) -> Option<String>;
+
     /// Parses codec-specific data from a byte slice.
     ///
     /// # Arguments
@@ -1,3 +1,5 @@
+use std::fmt;
+
 use abstractions::{
     extensions::{array_extensions::ArrayExt, utf8_array_extensions::U8ArrayExt, EMPTY_BYTE_SLICE},
     parsing::{
@@ -5,44 +7,181 @@ use abstractions::{
     },
 };
 
+use rtp::rtp_tracker::RtpTracker;
+
 use crate::{
-    audio::aac_codec::{self, AacCodec},
+    audio::{
+        aac_codec::{self, AacCodec},
+        aac_depacketizer::AacDepacketizer,
+        g711_codec::{PcmaCodec, PcmuCodec, PCMA_NAME, PCMU_NAME},
+        g711_depacketizer::G711Depacketizer,
+        opus_codec::{self, OpusCodec},
+        opus_depacketizer::OpusDepacketizer,
+    },
     codec::Codec,
-    video::{h264_codec::{self, H264Codec}, h265_codec::{self, H265Codec}},
+    depacketizer::Depacketizer,
+    static_payload_type::StaticPayloadType,
+    unsupported_codec::UnsupportedCodec,
+    video::{
+        av1_codec::{self, Av1Codec},
+        av1_depacketizer::Av1Depacketizer,
+        h264_codec::{self, H264Codec},
+        h264_depacketizer::H264Depacketizer,
+        h265_codec::{self, H265Codec},
+        h265_depacketizer::H265Depacketizer,
+        jpeg_codec::{self, JpegCodec},
+        jpeg_depacketizer::JpegDepacketizer,
+        vp8_codec::{self, Vp8Codec},
+        vp8_depacketizer::Vp8Depacketizer,
+        vp9_codec::{self, Vp9Codec},
+        vp9_depacketizer::Vp9Depacketizer,
+    },
 };
 
 #[derive(Debug, PartialEq, Eq)]
 pub enum CodecType {
     Aac(AacCodec),
+    Opus(OpusCodec),
+    Pcmu(PcmuCodec),
+    Pcma(PcmaCodec),
     H265(H265Codec),
     H264(H264Codec),
-    Unsupported(String),
+    Jpeg(JpegCodec),
+    Vp8(Vp8Codec),
+    Vp9(Vp9Codec),
+    Av1(Av1Codec),
+    Unsupported(UnsupportedCodec),
 }
 
 impl CodecType {
     pub fn get_codec(&self) -> Option<&dyn Codec> {
         match self {
             CodecType::Aac(codec) => Some(codec),
+            CodecType::Opus(codec) => Some(codec),
+            CodecType::Pcmu(codec) => Some(codec),
+            CodecType::Pcma(codec) => Some(codec),
             CodecType::H265(codec) => Some(codec),
             CodecType::H264(codec) => Some(codec),
+            CodecType::Jpeg(codec) => Some(codec),
+            CodecType::Vp8(codec) => Some(codec),
+            CodecType::Vp9(codec) => Some(codec),
+            CodecType::Av1(codec) => Some(codec),
             CodecType::Unsupported(_) => None,
         }
     }
 
-    pub fn is_supported(&self) -> bool {
+    /// Resolves a payload type listed without an `a=rtpmap` line from the static mappings of RFC 3551.
+    ///
+    /// # Arguments
+    ///
+    /// * `format` - The payload type number from the `m=` line.
+    ///
+    /// # Returns
+    ///
+    /// An `Option` containing the codec, or `None` if the payload type has no static mapping.
+    pub fn from_static_payload_type(format: u16) -> Option<CodecType> {
+        let payload_type = StaticPayloadType::find(format)?;
+        get_codec(payload_type.name(), format, payload_type.clock_rate(), payload_type.channel_count(), EMPTY_BYTE_SLICE).ok()
+    }
+
+    /// Creates a depacketizer for a stream of the codec.
+    ///
+    /// # Returns
+    ///
+    /// An `Option` containing the depacketizer, or `None` if the codec is not supported.
+    pub fn depacketizer(&self) -> Option<Box<dyn Depacketizer>> {
         match self {
-            CodecType::Unsupported(_) => false,
-            _ => true,
+            CodecType::Aac(codec) => Some(Box::new(AacDepacketizer::new(codec))),
+            CodecType::Opus(codec) => Some(Box::new(OpusDepacketizer::new(codec))),
+            CodecType::Pcmu(codec) => Some(Box::new(G711Depacketizer::new(codec))),
+            CodecType::Pcma(codec) => Some(Box::new(G711Depacketizer::new(codec))),
+            CodecType::H265(codec) => Some(Box::new(H265Depacketizer::new(codec))),
+            CodecType::H264(codec) => Some(Box::new(H264Depacketizer::new(codec))),
+            CodecType::Jpeg(codec) => Some(Box::new(JpegDepacketizer::new(codec))),
+            CodecType::Vp8(codec) => Some(Box::new(Vp8Depacketizer::new(codec))),
+            CodecType::Vp9(codec) => Some(Box::new(Vp9Depacketizer::new(codec))),
+            CodecType::Av1(codec) => Some(Box::new(Av1Depacketizer::new(codec))),
+            CodecType::Unsupported(_) => None,
         }
     }
 
+    /// Creates a tracker that extends the sequence numbers and timestamps of a stream of the codec
+    /// and converts its timestamps to presentation times at the clock rate of the codec.
+    pub fn rtp_tracker(&self) -> RtpTracker {
+        RtpTracker::new(self.clock_rate())
+    }
+
+    pub fn is_supported(&self) -> bool {
+        !matches!(self, CodecType::Unsupported(_))
+    }
+
     pub fn get_name(&self) -> &str {
         match self {
             CodecType::Aac(_) => aac_codec::NAME,
+            CodecType::Opus(_) => opus_codec::NAME,
+            CodecType::Pcmu(_) => PCMU_NAME,
+            CodecType::Pcma(_) => PCMA_NAME,
             CodecType::H265(_) => h265_codec::NAME,
             CodecType::H264(_) => h264_codec::NAME,
-            CodecType::Unsupported(name) => name,
+            CodecType::Jpeg(_) => jpeg_codec::NAME,
+            CodecType::Vp8(_) => vp8_codec::NAME,
+            CodecType::Vp9(_) => vp9_codec::NAME,
+            CodecType::Av1(_) => av1_codec::NAME,
+            CodecType::Unsupported(codec) => codec.name(),
+        }
+    }
+
+    /// Returns the payload format number of the codec.
+    pub fn format(&self) -> u16 {
+        match self {
+            CodecType::Unsupported(codec) => codec.format(),
+            _ => self.get_codec().map_or(0, |codec| codec.format()),
+        }
+    }
+
+    /// Returns the clock rate of the codec.
+    pub fn clock_rate(&self) -> u32 {
+        match self {
+            CodecType::Unsupported(codec) => codec.clock_rate(),
+            _ => self.get_codec().map_or(0, |codec| codec.clock_rate()),
+        }
+    }
+
+    /// Returns the channel count of the codec, if any.
+    pub fn channel_count(&self) -> Option<u8> {
+        match self {
+            CodecType::Unsupported(codec) => *codec.channel_count(),
+            _ => self.get_codec().and_then(|codec| *codec.channel_count()),
+        }
+    }
+
+    /// Returns the parameters of the `a=fmtp` line of the codec, if any.
+    pub fn fmtp(&self) -> Option<String> {
+        match self {
+            CodecType::Unsupported(codec) => codec.fmtp().map(str::to_string),
+            _ => self.get_codec().and_then(|codec| codec.fmtp()),
+        }
+    }
+}
+
+/// Writes the `a=rtpmap` line and, if the codec has parameters, the `a=fmtp` line.
+///
+/// ```text
+/// a=rtpmap:<payload type> <encoding name>/<clock rate>[/<encoding parameters>]
+/// a=fmtp:<format> <format specific parameters>
+/// ```
+impl fmt::Display for CodecType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let format = self.format();
+        write!(f, "a=rtpmap:{} {}/{}", format, self.get_name(), self.clock_rate())?;
+        if let Some(channel_count) = self.channel_count() {
+            write!(f, "/{}", channel_count)?;
+        }
+        f.write_str("\r\n")?;
+        if let Some(fmtp) = self.fmtp() {
+            write!(f, "a=fmtp:{} {}\r\n", format, fmtp)?;
         }
+        Ok(())
     }
 }
 
@@ -58,18 +197,20 @@ impl PayloadParser for CodecType {
             (data, EMPTY_BYTE_SLICE)
         };
 
-        let (_, codec_info) = rtpmap
+        let (format, codec_info) = rtpmap
             .separate_trimmed(WHITESPACE, TRIM_NEW_LINE)
             .ok_or_else(|| ParsingError::from_bytes(data))?;
+        let format = format.utf8_to_number::<u16>()?;
 
         let (name, clock_rate, chanel_count) = get_codec_info(codec_info)?;
 
-        Ok(get_codec(
+        get_codec(
             name,
+            format,
             clock_rate,
             chanel_count,
             bot,
-        )?)
+        )
     }
 }
 
@@ -87,12 +228,19 @@ fn get_codec_info(data: &[u8]) -> Result<(&str, u32, Option<u8>), ParsingError>
     Ok((name, other.utf8_to_number::<u32>()?, None))
 }
 
-fn get_codec(name: &str, clock_rate: u32, chanel_count: Option<u8>,data: &[u8]) -> Result<CodecType, ParsingError> {
+fn get_codec(name: &str, format: u16, clock_rate: u32, chanel_count: Option<u8>,data: &[u8]) -> Result<CodecType, ParsingError> {
     let codec_type = match name.to_uppercase().as_str() {
         aac_codec::NAME => CodecType::Aac(AacCodec::parse(clock_rate, chanel_count, data)?),
+        PCMU_NAME => CodecType::Pcmu(PcmuCodec::new(format, clock_rate, chanel_count)),
+        PCMA_NAME => CodecType::Pcma(PcmaCodec::new(format, clock_rate, chanel_count)),
+        opus_codec::RTPMAP_NAME => CodecType::Opus(OpusCodec::from_rtpmap(format, clock_rate, chanel_count, data)?),
         h265_codec::NAME => CodecType::H265(H265Codec::parse(clock_rate, chanel_count, data)?),
         h264_codec::NAME => CodecType::H264(H264Codec::parse(clock_rate, chanel_count, data)?),
-        _ => CodecType::Unsupported(name.to_string()),
+        jpeg_codec::NAME => CodecType::Jpeg(JpegCodec::new(format, clock_rate, chanel_count)),
+        vp8_codec::NAME => CodecType::Vp8(Vp8Codec::from_rtpmap(format, clock_rate, chanel_count, data)?),
+        vp9_codec::NAME => CodecType::Vp9(Vp9Codec::from_rtpmap(format, clock_rate, chanel_count, data)?),
+        av1_codec::NAME => CodecType::Av1(Av1Codec::from_rtpmap(format, clock_rate, chanel_count, data)?),
+        _ => CodecType::Unsupported(UnsupportedCodec::parse(name, format, clock_rate, chanel_count, data)?),
     };
     Ok(codec_type)
 }
@@ -0,0 +1,37 @@
+use thiserror::Error;
+
+/// Represents errors that can occur while decoding or applying codec configuration.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum ConfigError {
+    /// Represents configuration data that ends before a field it announces.
+    #[error("Truncated configuration: {0}")]
+    Truncated(&'static str),
+
+    /// Represents a parameter set or configuration that is not present.
+    #[error("Missing {0}")]
+    Missing(&'static str),
+
+    /// Represents a field value that is not valid.
+    #[error("Invalid {0}")]
+    Invalid(&'static str),
+
+    /// Represents a field value that is not supported.
+    #[error("Unsupported {0}: {1}")]
+    Unsupported(&'static str, u32),
+
+    /// Represents a sampling frequency that differs from the clock rate of the `a=rtpmap` line.
+    #[error("Clock rate {rtpmap} does not match the configured sampling frequency {config}")]
+    ClockRateMismatch { rtpmap: u32, config: u32 },
+
+    /// Represents a channel configuration that differs from the channel count of the `a=rtpmap` line.
+    #[error("Channel count {rtpmap} does not match the configured channel count {config}")]
+    ChannelCountMismatch { rtpmap: u8, config: u8 },
+
+    /// Represents an SDP parameter that differs from the value found in the bitstream.
+    #[error("{parameter} {fmtp} does not match {bitstream} in the bitstream")]
+    ParameterMismatch { parameter: &'static str, fmtp: u32, bitstream: u32 },
+
+    /// Represents a frame too large to be described by the header.
+    #[error("Frame of {0} bytes is too large")]
+    FrameTooLarge(usize),
+}
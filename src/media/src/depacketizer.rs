@@ -0,0 +1,41 @@
+use std::fmt::Debug;
+
+use rtp::rtp_packet::RtpPacket;
+
+use crate::{depacketizer_error::DepacketizerError, media_frame::MediaFrame};
+
+/// Reassembles the frames of one RTP stream, whatever its codec.
+///
+/// Implemented by the depacketizer of every supported codec and created from the negotiated
+/// codec with `CodecType::depacketizer`, so that a client can handle any track without matching
+/// on codec names. Packets are pushed in sequence number order and complete frames are pulled
+/// as `MediaFrame`s.
+pub trait Depacketizer: Debug + Send {
+    /// Pushes the next RTP packet of the stream.
+    ///
+    /// Packets older than the last pushed one are ignored as duplicates or late arrivals.
+    ///
+    /// # Arguments
+    ///
+    /// * `packet` - The RTP packet carrying a payload of the codec.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` that is an error if the payload is malformed; the frame it belongs to is dropped.
+    fn push(&mut self, packet: &RtpPacket) -> Result<(), DepacketizerError>;
+
+    /// Pulls the next complete frame.
+    ///
+    /// # Returns
+    ///
+    /// An `Option` containing the frame, or `None` if none is complete.
+    fn pull(&mut self) -> Option<MediaFrame>;
+
+    /// Returns the number of packets detected as missing from the sequence.
+    fn lost_packets(&self) -> u64;
+
+    /// Closes the pending frame, for instance at the end of the stream.
+    ///
+    /// Depacketizers that only emit a frame once its last packet arrived have nothing to flush.
+    fn flush(&mut self) {}
+}
@@ -0,0 +1,17 @@
+use thiserror::Error;
+
+/// Represents errors that can occur while reassembling frames from RTP payloads.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum DepacketizerError {
+    /// Represents a payload that ends before a structure it announces.
+    #[error("Truncated payload: {0}")]
+    Truncated(&'static str),
+
+    /// Represents a payload field whose value is not valid.
+    #[error("Invalid payload: {0}")]
+    Invalid(&'static str),
+
+    /// Represents a payload structure that is not valid for the negotiated mode.
+    #[error("Unexpected packet type {0}")]
+    UnexpectedPacketType(u8),
+}
@@ -6,4 +6,14 @@
 pub mod codec;
 pub mod audio;
 pub mod video;
-pub mod codec_type;
\ No newline at end of file
+pub mod codec_type;
+pub mod unsupported_codec;
+pub mod static_payload_type;
+pub mod access_unit;
+pub mod media_frame;
+pub mod depacketizer;
+pub mod depacketizer_error;
+pub mod packetizer_error;
+pub mod config_error;
+pub(crate) mod bit_reader;
+pub(crate) mod packet_sequence;
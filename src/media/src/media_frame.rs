@@ -0,0 +1,64 @@
+use crate::access_unit::AccessUnit;
+
+/// Represents a frame pulled from a `Depacketizer`, independent of the codec that produced it.
+///
+/// RTP only carries presentation times, so the decoding timestamp equals the presentation timestamp
+/// unless the payload format signals a decoding order, as the H.264 and H.265 decoding order numbers do.
+/// Frames are pulled in decoding order.
+///
+/// # Fields
+///
+/// * `pts` - The presentation timestamp, in units of the RTP clock rate.
+/// * `dts` - The decoding timestamp, in units of the RTP clock rate.
+/// * `keyframe` - Whether the frame can be decoded without previous frames.
+/// * `data` - The frame in the codec's elementary stream format.
+/// * `codec` - The encoding name of the codec, as in the `a=rtpmap` line.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MediaFrame {
+    pts: u32,
+    dts: u32,
+    keyframe: bool,
+    data: Vec<u8>,
+    codec: &'static str,
+}
+
+impl MediaFrame {
+    pub fn new(pts: u32, dts: u32, keyframe: bool, data: Vec<u8>, codec: &'static str) -> Self {
+        Self { pts, dts, keyframe, data, codec }
+    }
+
+    /// Creates a `MediaFrame` from an access unit.
+    ///
+    /// # Arguments
+    ///
+    /// * `access_unit` - The access unit reassembled by a depacketizer.
+    /// * `codec` - The encoding name of the codec.
+    pub fn from_access_unit(access_unit: AccessUnit, codec: &'static str) -> Self {
+        let keyframe = access_unit.keyframe();
+        Self::new(access_unit.timestamp(), access_unit.decode_timestamp(), keyframe, access_unit.into_data(), codec)
+    }
+
+    pub fn pts(&self) -> u32 {
+        self.pts
+    }
+
+    pub fn dts(&self) -> u32 {
+        self.dts
+    }
+
+    pub fn keyframe(&self) -> bool {
+        self.keyframe
+    }
+
+    pub fn data(&self) -> &[u8] {
+        &self.data
+    }
+
+    pub fn codec(&self) -> &'static str {
+        self.codec
+    }
+
+    pub fn into_data(self) -> Vec<u8> {
+        self.data
+    }
+}
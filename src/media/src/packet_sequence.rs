@@ -0,0 +1,52 @@
+use rtp::rtp_tracker::SequenceNumberTracker;
+
+/// Position of a pushed packet relative to the packets pushed before it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum PacketOrder {
+    /// The packet is not newer than the last one, as a duplicate or late arrival.
+    Late,
+    /// The packet directly follows the last one, or is the first one.
+    Next,
+    /// Packets between the last one and this one were lost.
+    AfterGap,
+}
+
+/// Tracks the sequence numbers pushed to a depacketizer and counts the packets lost between them.
+///
+/// Depacketizers expect packets in sequence number order, so a late packet is dropped rather
+/// than inserted into a frame that may already be complete; a gap means that the frame in
+/// progress is incomplete, and its loss is counted once here.
+#[derive(Debug, Default)]
+pub(crate) struct PacketSequence {
+    sequence_numbers: SequenceNumberTracker,
+    lost_packets: u64,
+}
+
+impl PacketSequence {
+    /// Returns the number of packets detected as missing from the sequence.
+    pub fn lost_packets(&self) -> u64 {
+        self.lost_packets
+    }
+
+    /// Records the sequence number of a pushed packet.
+    ///
+    /// # Arguments
+    ///
+    /// * `sequence_number` - The sequence number of the packet.
+    ///
+    /// # Returns
+    ///
+    /// The position of the packet relative to the last one; the packet should be dropped if it is `Late`.
+    pub fn push(&mut self, sequence_number: u16) -> PacketOrder {
+        let highest = self.sequence_numbers.highest();
+        let extended = self.sequence_numbers.extend(sequence_number);
+        match highest {
+            Some(highest) if extended <= highest => PacketOrder::Late,
+            Some(highest) if extended > highest + 1 => {
+                self.lost_packets += extended - highest - 1;
+                PacketOrder::AfterGap
+            }
+            _ => PacketOrder::Next,
+        }
+    }
+}
@@ -0,0 +1,21 @@
+use thiserror::Error;
+
+/// Represents errors that can occur while splitting frames into RTP payloads.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum PacketizerError {
+    /// Represents input that ends before a length it announces.
+    #[error("Truncated input: {0}")]
+    Truncated(&'static str),
+
+    /// Represents a length prefix size other than 1, 2, 3 or 4 bytes.
+    #[error("Invalid length prefix size {0}")]
+    InvalidLengthSize(usize),
+
+    /// Represents a unit that does not fit into one packet when fragmentation is not allowed.
+    #[error("Unit of {size} bytes exceeds the maximum payload size of {max} bytes")]
+    UnitTooLarge { size: usize, max: usize },
+
+    /// Represents an MTU too small to carry the payload headers.
+    #[error("MTU of {0} bytes is too small")]
+    MtuTooSmall(usize),
+}
@@ -0,0 +1,77 @@
+/// Represents a payload type with a static mapping to an encoding, RFC 3551 section 6.
+///
+/// A media description may list these payload types without an `a=rtpmap` line.
+///
+/// # Fields
+///
+/// * `format` - The payload type number.
+/// * `name` - The encoding name.
+/// * `clock_rate` - The clock rate in Hz.
+/// * `channel_count` - The channel count written in `a=rtpmap`, `None` for mono audio and video.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StaticPayloadType {
+    format: u16,
+    name: &'static str,
+    clock_rate: u32,
+    channel_count: Option<u8>,
+}
+
+/// Payload types of RFC 3551 tables 4 and 5, without the reserved and unassigned ones.
+const STATIC_PAYLOAD_TYPES: [StaticPayloadType; 24] = [
+    StaticPayloadType::new(0, "PCMU", 8000, None),
+    StaticPayloadType::new(3, "GSM", 8000, None),
+    StaticPayloadType::new(4, "G723", 8000, None),
+    StaticPayloadType::new(5, "DVI4", 8000, None),
+    StaticPayloadType::new(6, "DVI4", 16000, None),
+    StaticPayloadType::new(7, "LPC", 8000, None),
+    StaticPayloadType::new(8, "PCMA", 8000, None),
+    // The clock rate of G.722 is 8000 Hz for historical reasons although it samples at 16000 Hz.
+    StaticPayloadType::new(9, "G722", 8000, None),
+    StaticPayloadType::new(10, "L16", 44100, Some(2)),
+    StaticPayloadType::new(11, "L16", 44100, None),
+    StaticPayloadType::new(12, "QCELP", 8000, None),
+    StaticPayloadType::new(13, "CN", 8000, None),
+    StaticPayloadType::new(14, "MPA", 90000, None),
+    StaticPayloadType::new(15, "G728", 8000, None),
+    StaticPayloadType::new(16, "DVI4", 11025, None),
+    StaticPayloadType::new(17, "DVI4", 22050, None),
+    StaticPayloadType::new(18, "G729", 8000, None),
+    StaticPayloadType::new(25, "CelB", 90000, None),
+    StaticPayloadType::new(26, "JPEG", 90000, None),
+    StaticPayloadType::new(28, "nv", 90000, None),
+    StaticPayloadType::new(31, "H261", 90000, None),
+    StaticPayloadType::new(32, "MPV", 90000, None),
+    StaticPayloadType::new(33, "MP2T", 90000, None),
+    StaticPayloadType::new(34, "H263", 90000, None),
+];
+
+impl StaticPayloadType {
+    pub const fn new(format: u16, name: &'static str, clock_rate: u32, channel_count: Option<u8>) -> Self {
+        Self { format, name, clock_rate, channel_count }
+    }
+
+    /// Looks up the static mapping of a payload type.
+    ///
+    /// # Returns
+    ///
+    /// An `Option` containing the mapping, or `None` if the payload type is dynamic, reserved or unassigned.
+    pub fn find(format: u16) -> Option<&'static StaticPayloadType> {
+        STATIC_PAYLOAD_TYPES.iter().find(|payload_type| payload_type.format == format)
+    }
+
+    pub fn format(&self) -> u16 {
+        self.format
+    }
+
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    pub fn clock_rate(&self) -> u32 {
+        self.clock_rate
+    }
+
+    pub fn channel_count(&self) -> Option<u8> {
+        self.channel_count
+    }
+}
@@ -0,0 +1,84 @@
+use abstractions::{
+    extensions::{array_extensions::ArrayExt, utf8_array_extensions::U8ArrayExt},
+    parsing::{parsing_error::ParsingError, NEW_LINE, TRIM_NEW_LINE, WHITESPACE},
+};
+
+use crate::codec::FMTP_KEY;
+
+/// Represents a codec that is not handled by this crate.
+///
+/// The `a=rtpmap` information and the raw `a=fmtp` parameters are kept so that the
+/// media description can be written back out without losing the format.
+///
+/// # Fields
+///
+/// * `name` - The encoding name from the `a=rtpmap` line, in its original case.
+/// * `format` - The payload format number.
+/// * `clock_rate` - The clock rate of the codec.
+/// * `channel_count` - The optional channel count.
+/// * `fmtp` - The raw parameters of the `a=fmtp` line, if present.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnsupportedCodec {
+    name: String,
+    format: u16,
+    clock_rate: u32,
+    channel_count: Option<u8>,
+    fmtp: Option<String>,
+}
+
+impl UnsupportedCodec {
+    pub fn new(name: String, format: u16, clock_rate: u32, channel_count: Option<u8>, fmtp: Option<String>) -> Self {
+        Self { name, format, clock_rate, channel_count, fmtp }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn format(&self) -> u16 {
+        self.format
+    }
+
+    pub fn clock_rate(&self) -> u32 {
+        self.clock_rate
+    }
+
+    pub fn channel_count(&self) -> &Option<u8> {
+        &self.channel_count
+    }
+
+    pub fn fmtp(&self) -> Option<&str> {
+        self.fmtp.as_deref()
+    }
+
+    /// Parses the codec from the lines that follow its `a=rtpmap` line.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The encoding name.
+    /// * `format` - The payload format number.
+    /// * `clock_rate` - The clock rate of the codec.
+    /// * `channel_count` - The optional channel count.
+    /// * `data` - A byte slice that may contain the `a=fmtp` line of the codec.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the codec, or a `ParsingError` if the `a=fmtp` line is malformed.
+    pub fn parse(name: &str, format: u16, clock_rate: u32, channel_count: Option<u8>, data: &[u8]) -> Result<Self, ParsingError> {
+        let fmtp = match data.separate_trimmed(FMTP_KEY, WHITESPACE) {
+            Some((_, bot)) => {
+                let line = match bot.separate_trimmed(NEW_LINE, TRIM_NEW_LINE) {
+                    Some((top, _)) => top,
+                    None => bot,
+                };
+                let (_, parameters) = line
+                    .separate_trimmed(WHITESPACE, WHITESPACE)
+                    .ok_or_else(|| ParsingError::from_bytes(data))?;
+                Some(parameters.trim(TRIM_NEW_LINE).utf8_to_str()?.to_string())
+            }
+            None => None,
+        };
+
+        Ok(Self::new(name.to_string(), format, clock_rate, channel_count, fmtp))
+    }
+}
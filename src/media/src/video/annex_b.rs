@@ -0,0 +1,92 @@
+use std::{iter, mem};
+
+/// Start code written before every NAL unit of an Annex-B byte stream.
+pub const START_CODE: [u8; 4] = [0, 0, 0, 1];
+
+/// Three-byte form of the start code, which every start code ends with.
+const SHORT_START_CODE: [u8; 3] = [0, 0, 1];
+
+/// Byte inserted after two zero bytes to prevent start code emulation.
+const EMULATION_PREVENTION_BYTE: u8 = 0x03;
+
+/// Splits an Annex-B byte stream into NAL units.
+///
+/// Both three- and four-byte start codes are accepted, and the zero bytes that pad
+/// the stream between NAL units are dropped. Data without any start code is returned
+/// as a single NAL unit.
+///
+/// # Arguments
+///
+/// * `data` - A byte slice containing the byte stream.
+///
+/// # Returns
+///
+/// An iterator over the NAL units without their start codes.
+pub fn split_nal_units(data: &[u8]) -> impl Iterator<Item = &[u8]> {
+    let mut rest = match find_start_code(data) {
+        Some((_, end)) => &data[end..],
+        None => data,
+    };
+    iter::from_fn(move || {
+        if rest.is_empty() {
+            return None;
+        }
+        match find_start_code(rest) {
+            Some((start, end)) => {
+                let nal_unit = trim_trailing_zeros(&rest[..start]);
+                rest = &rest[end..];
+                Some(nal_unit)
+            }
+            None => Some(mem::take(&mut rest)),
+        }
+    })
+    .filter(|nal_unit| !nal_unit.is_empty())
+}
+
+/// Appends a NAL unit prefixed with a four-byte start code.
+///
+/// # Arguments
+///
+/// * `buffer` - The byte stream to append to.
+/// * `nal_unit` - The NAL unit without a start code.
+pub fn push_nal_unit(buffer: &mut Vec<u8>, nal_unit: &[u8]) {
+    buffer.extend_from_slice(&START_CODE);
+    buffer.extend_from_slice(nal_unit);
+}
+
+/// Removes the emulation prevention bytes of a NAL unit.
+///
+/// Every `0x03` that follows two zero bytes was inserted by the encoder so that the
+/// payload cannot contain a start code, and is dropped to restore the raw byte sequence payload.
+///
+/// # Arguments
+///
+/// * `nal_unit` - The NAL unit without a start code.
+///
+/// # Returns
+///
+/// The raw byte sequence payload, header included.
+pub fn to_rbsp(nal_unit: &[u8]) -> Vec<u8> {
+    let mut rbsp = Vec::with_capacity(nal_unit.len());
+    let mut zeros = 0;
+    for &byte in nal_unit {
+        if zeros >= 2 && byte == EMULATION_PREVENTION_BYTE {
+            zeros = 0;
+            continue;
+        }
+        zeros = if byte == 0 { zeros + 1 } else { 0 };
+        rbsp.push(byte);
+    }
+    rbsp
+}
+
+fn find_start_code(data: &[u8]) -> Option<(usize, usize)> {
+    data.windows(SHORT_START_CODE.len())
+        .position(|window| window == SHORT_START_CODE)
+        .map(|position| (position, position + SHORT_START_CODE.len()))
+}
+
+fn trim_trailing_zeros(data: &[u8]) -> &[u8] {
+    let end = data.iter().rposition(|byte| *byte != 0).map_or(0, |position| position + 1);
+    &data[..end]
+}
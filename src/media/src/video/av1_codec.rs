@@ -0,0 +1,147 @@
+use abstractions::{
+    extensions::{array_extensions::ArrayExt, utf8_array_extensions::U8ArrayExt},
+    parsing::{parsing_error::ParsingError, SEMICOLON, WHITESPACE},
+};
+
+use crate::codec::{Codec, CodecHelper, FMTP_KEY};
+
+const PROFILE_START: &[u8] = b"profile=";
+const LEVEL_IDX_START: &[u8] = b"level-idx=";
+const TIER_START: &[u8] = b"tier=";
+
+const MAX_PROFILE: u8 = 2;
+const MAX_LEVEL_IDX: u8 = 31;
+const MAX_TIER: u8 = 1;
+
+pub(crate) const NAME: &str = "AV1";
+
+/// Represents an AV1 stream as described in the RTP Payload Format For AV1, section 7.2.
+///
+/// # Fields
+///
+/// * `profile` - The `seq_profile` of the stream, 0 (Main) when absent.
+/// * `level_idx` - The `seq_level_idx` of the stream, 5 (level 3.1) when absent.
+/// * `tier` - The `seq_tier` of the stream, 0 (Main tier) when absent.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Av1Codec {
+    format: u16,
+    clock_rate: u32,
+    channel_count: Option<u8>,
+    profile: Option<u8>,
+    level_idx: Option<u8>,
+    tier: Option<u8>,
+}
+
+impl Av1Codec {
+    pub fn new(
+        format: u16,
+        clock_rate: u32,
+        channel_count: Option<u8>,
+        profile: Option<u8>,
+        level_idx: Option<u8>,
+        tier: Option<u8>,
+    ) -> Self {
+        Self { format, clock_rate, channel_count, profile, level_idx, tier }
+    }
+
+    pub fn profile(&self) -> Option<u8> {
+        self.profile
+    }
+
+    pub fn level_idx(&self) -> Option<u8> {
+        self.level_idx
+    }
+
+    pub fn tier(&self) -> Option<u8> {
+        self.tier
+    }
+
+    /// Parses the codec from the lines that follow its `a=rtpmap` line.
+    ///
+    /// Unlike `Codec::parse`, the `a=fmtp` line may be missing since every format parameter has a default.
+    ///
+    /// # Arguments
+    ///
+    /// * `format` - The payload format number of the `a=rtpmap` line.
+    /// * `clock_rate` - The clock rate of the codec.
+    /// * `channel_count` - The optional channel count.
+    /// * `data` - A byte slice that may contain the `a=fmtp` line of the codec.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the codec, or a `ParsingError` if the `a=fmtp` line is malformed.
+    pub fn from_rtpmap(format: u16, clock_rate: u32, channel_count: Option<u8>, data: &[u8]) -> Result<Self, ParsingError> {
+        if data.separate(FMTP_KEY).is_none() {
+            return Ok(Av1Codec::new(format, clock_rate, channel_count, None, None, None));
+        }
+        Self::from_fmtp(clock_rate, channel_count, data)
+    }
+}
+
+impl CodecHelper for Av1Codec {
+    fn from_fmtp_internal(format: u16, clock_rate: u32, channel_count: Option<u8>, data: &[u8]) -> Result<Self, ParsingError> {
+        let mut codec = Av1Codec::new(format, clock_rate, channel_count, None, None, None);
+
+        let mut current = data;
+        while let Some((left, right)) = current.while_separate_trimmed(SEMICOLON, WHITESPACE) {
+            if let Some(value) = left.strip_prefix(PROFILE_START) {
+                codec.profile = Some(parse_bounded(value, MAX_PROFILE)?);
+            }
+            else if let Some(value) = left.strip_prefix(LEVEL_IDX_START) {
+                codec.level_idx = Some(parse_bounded(value, MAX_LEVEL_IDX)?);
+            }
+            else if let Some(value) = left.strip_prefix(TIER_START) {
+                codec.tier = Some(parse_bounded(value, MAX_TIER)?);
+            }
+            current = right;
+        }
+        Ok(codec)
+    }
+}
+
+impl Codec for Av1Codec {
+    fn channel_count(&self) -> &Option<u8> {
+        &self.channel_count
+    }
+
+    fn clock_rate(&self) -> u32 {
+        self.clock_rate
+    }
+
+    fn format(&self) -> u16 {
+        self.format
+    }
+
+    fn name(&self) -> &'static str {
+        NAME
+    }
+
+    fn fmtp(&self) -> Option<String> {
+        let mut parameters = Vec::new();
+        if let Some(profile) = self.profile {
+            parameters.push(format!("profile={}", profile));
+        }
+        if let Some(level_idx) = self.level_idx {
+            parameters.push(format!("level-idx={}", level_idx));
+        }
+        if let Some(tier) = self.tier {
+            parameters.push(format!("tier={}", tier));
+        }
+        (!parameters.is_empty()).then(|| parameters.join("; "))
+    }
+
+    fn parse(clock_rate: u32, channel_count: Option<u8>, data: &[u8]) -> Result<Self, ParsingError>
+    where
+        Self: Sized,
+    {
+        Self::from_fmtp(clock_rate, channel_count, data)
+    }
+}
+
+/// Parses a parameter value that cannot exceed `max`.
+fn parse_bounded(value: &[u8], max: u8) -> Result<u8, ParsingError> {
+    match value.utf8_to_number::<u8>()? {
+        number if number <= max => Ok(number),
+        _ => Err(ParsingError::from_bytes(value)),
+    }
+}
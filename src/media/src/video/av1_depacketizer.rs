@@ -0,0 +1,224 @@
+use std::collections::VecDeque;
+
+use rtp::rtp_packet::RtpPacket;
+
+use crate::{
+    access_unit::AccessUnit,
+    depacketizer::Depacketizer,
+    depacketizer_error::DepacketizerError,
+    media_frame::MediaFrame,
+    packet_sequence::{PacketOrder, PacketSequence},
+    video::{
+        av1_codec::{self, Av1Codec},
+        av1_obu::{read_leb128, split_obus, starts_coded_video_sequence, Obu, OBU_TEMPORAL_DELIMITER, OBU_TILE_LIST},
+    },
+};
+
+/// Size of the aggregation header.
+pub(crate) const AGGREGATION_HEADER_SIZE: usize = 1;
+/// Marks that the first OBU element continues the fragment that ended the previous packet.
+pub(crate) const Z_MASK: u8 = 0x80;
+/// Marks that the last OBU element is a fragment continued in the next packet.
+pub(crate) const Y_MASK: u8 = 0x40;
+/// Number of OBU elements, or zero when every element has a length field.
+pub(crate) const W_SHIFT: u8 = 4;
+pub(crate) const W_MASK: u8 = 0x03;
+/// Marks the first packet of a coded video sequence.
+pub(crate) const N_MASK: u8 = 0x08;
+
+/// Temporal delimiter OBU with an empty payload that starts every temporal unit.
+const TEMPORAL_DELIMITER: [u8; 2] = [0x12, 0x00];
+
+/// Reassembles AV1 temporal units from RTP payloads as defined in the RTP Payload Format For AV1.
+///
+/// Packets are pushed in sequence number order and complete temporal units are pulled in the
+/// low-overhead bitstream format: a temporal delimiter followed by OBUs with `obu_size` fields.
+/// A temporal unit is closed by the marker bit or by a packet with a different timestamp,
+/// and temporal units that lost a packet are dropped.
+///
+/// Every payload starts with an aggregation header:
+///
+/// ```text
+///  0 1 2 3 4 5 6 7
+/// +-+-+-+-+-+-+-+-+
+/// |Z|Y| W |N|-|-|-|
+/// +-+-+-+-+-+-+-+-+
+/// ```
+///
+/// A temporal unit is a keyframe when its first packet has the N bit set or when it starts with
+/// a sequence header and a key frame.
+#[derive(Debug)]
+pub struct Av1Depacketizer {
+    temporal_unit: Vec<u8>,
+    timestamp: Option<u32>,
+    keyframe: bool,
+    corrupted: bool,
+    fragment: Option<Vec<u8>>,
+    sequence: PacketSequence,
+    ready: VecDeque<AccessUnit>,
+}
+
+impl Av1Depacketizer {
+    /// Creates a new `Av1Depacketizer` for the stream described by the codec.
+    ///
+    /// # Arguments
+    ///
+    /// * `_codec` - The codec negotiated in SDP, whose parameters do not affect the payload format.
+    pub fn new(_codec: &Av1Codec) -> Self {
+        Self {
+            temporal_unit: Vec::new(),
+            timestamp: None,
+            keyframe: false,
+            corrupted: false,
+            fragment: None,
+            sequence: PacketSequence::default(),
+            ready: VecDeque::new(),
+        }
+    }
+
+    /// Returns the number of packets detected as missing from the sequence.
+    pub fn lost_packets(&self) -> u64 {
+        self.sequence.lost_packets()
+    }
+
+    /// Pushes the next RTP packet of the stream.
+    ///
+    /// # Arguments
+    ///
+    /// * `packet` - The RTP packet carrying an AV1 payload.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` that is an error if the payload is malformed; the temporal unit it belongs to is dropped.
+    pub fn push(&mut self, packet: &RtpPacket) -> Result<(), DepacketizerError> {
+        let header = packet.header();
+        let gap = match self.sequence.push(header.sequence_number()) {
+            PacketOrder::Late => return Ok(()),
+            PacketOrder::Next => false,
+            PacketOrder::AfterGap => {
+                self.fragment = None;
+                true
+            }
+        };
+
+        if gap {
+            self.corrupted = true;
+        }
+        if self.timestamp.is_some_and(|timestamp| timestamp != header.timestamp()) {
+            self.finish_temporal_unit();
+            self.corrupted = gap;
+        }
+        self.timestamp = Some(header.timestamp());
+
+        let result = self.push_payload(packet.payload());
+        if result.is_err() {
+            self.fragment = None;
+            self.corrupted = true;
+        }
+        if header.marker() {
+            self.finish_temporal_unit();
+        }
+        result
+    }
+
+    /// Pulls the next complete temporal unit.
+    ///
+    /// # Returns
+    ///
+    /// An `Option` containing the temporal unit in the low-overhead bitstream format, or `None` if none is complete.
+    pub fn pull(&mut self) -> Option<AccessUnit> {
+        self.ready.pop_front()
+    }
+
+    fn push_payload(&mut self, payload: &[u8]) -> Result<(), DepacketizerError> {
+        let aggregation_header = *payload.first().ok_or(DepacketizerError::Truncated("aggregation header"))?;
+        let continuation = aggregation_header & Z_MASK != 0;
+        let fragmented = aggregation_header & Y_MASK != 0;
+        let element_count = ((aggregation_header >> W_SHIFT) & W_MASK) as usize;
+        if aggregation_header & N_MASK != 0 {
+            self.keyframe = true;
+        }
+
+        let elements = split_elements(&payload[AGGREGATION_HEADER_SIZE..], element_count)?;
+        if !continuation && self.fragment.is_some() {
+            return Err(DepacketizerError::Invalid("OBU fragment"));
+        }
+
+        let last = elements.len().saturating_sub(1);
+        for (index, element) in elements.into_iter().enumerate() {
+            let mut obu = element.to_vec();
+            if index == 0 && continuation {
+                let Some(mut fragment) = self.fragment.take() else {
+                    continue;
+                };
+                fragment.extend_from_slice(element);
+                obu = fragment;
+            }
+            if index == last && fragmented {
+                self.fragment = Some(obu);
+            }
+            else {
+                self.push_obu(&obu)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn push_obu(&mut self, element: &[u8]) -> Result<(), DepacketizerError> {
+        let (obu, _) = Obu::parse(element).map_err(DepacketizerError::Truncated)?;
+        if !matches!(obu.obu_type(), OBU_TEMPORAL_DELIMITER | OBU_TILE_LIST) {
+            obu.write_with_size(&mut self.temporal_unit);
+        }
+        Ok(())
+    }
+
+    fn finish_temporal_unit(&mut self) {
+        let temporal_unit = std::mem::take(&mut self.temporal_unit);
+        if !temporal_unit.is_empty() && !self.corrupted {
+            let keyframe = self.keyframe || split_obus(&temporal_unit).is_ok_and(|obus| starts_coded_video_sequence(&obus));
+            let data = [TEMPORAL_DELIMITER.as_slice(), temporal_unit.as_slice()].concat();
+            self.ready.push_back(AccessUnit::new(self.timestamp.unwrap_or_default(), keyframe, data));
+        }
+        self.fragment = None;
+        self.keyframe = false;
+        self.corrupted = false;
+    }
+}
+
+/// Splits the OBU elements that follow the aggregation header.
+///
+/// # Arguments
+///
+/// * `data` - The payload without its aggregation header.
+/// * `element_count` - The W field: the number of elements, the last one without a length field, or zero if every element has one.
+fn split_elements(mut data: &[u8], element_count: usize) -> Result<Vec<&[u8]>, DepacketizerError> {
+    let mut elements = Vec::new();
+    while !data.is_empty() {
+        if element_count != 0 && elements.len() == element_count - 1 {
+            elements.push(data);
+            break;
+        }
+        let (length, length_size) = read_leb128(data).ok_or(DepacketizerError::Truncated("OBU element length"))?;
+        let element = data
+            .get(length_size..)
+            .and_then(|rest| rest.get(..usize::try_from(length).ok()?))
+            .ok_or(DepacketizerError::Truncated("OBU element"))?;
+        elements.push(element);
+        data = &data[length_size + element.len()..];
+    }
+    Ok(elements)
+}
+
+impl Depacketizer for Av1Depacketizer {
+    fn push(&mut self, packet: &RtpPacket) -> Result<(), DepacketizerError> {
+        Av1Depacketizer::push(self, packet)
+    }
+
+    fn pull(&mut self) -> Option<MediaFrame> {
+        Av1Depacketizer::pull(self).map(|access_unit| MediaFrame::from_access_unit(access_unit, av1_codec::NAME))
+    }
+
+    fn lost_packets(&self) -> u64 {
+        Av1Depacketizer::lost_packets(self)
+    }
+}
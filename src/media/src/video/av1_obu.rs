@@ -0,0 +1,167 @@
+pub const OBU_SEQUENCE_HEADER: u8 = 1;
+pub const OBU_TEMPORAL_DELIMITER: u8 = 2;
+pub const OBU_FRAME_HEADER: u8 = 3;
+pub const OBU_FRAME: u8 = 6;
+pub const OBU_TILE_LIST: u8 = 8;
+
+const TYPE_SHIFT: u8 = 3;
+const TYPE_MASK: u8 = 0x0F;
+const EXTENSION_MASK: u8 = 0x04;
+const HAS_SIZE_MASK: u8 = 0x02;
+
+/// Highest number of bytes of a `leb128` value, AV1 specification section 4.10.5.
+const MAX_LEB128_SIZE: usize = 8;
+
+/// `reduced_still_picture_header` in the first byte of a sequence header.
+const REDUCED_STILL_PICTURE_HEADER_MASK: u8 = 0x08;
+/// `show_existing_frame` and `frame_type` in the first byte of an uncompressed frame header.
+const SHOW_EXISTING_FRAME_AND_FRAME_TYPE_MASK: u8 = 0xE0;
+
+/// Represents an open bitstream unit, AV1 specification section 5.3.
+///
+/// ```text
+/// +-+-+-+-+-+-+-+-+
+/// |F| type  |X|S|-| (REQUIRED)
+/// +-+-+-+-+-+-+-+-+
+/// |  T  | S |-|-|-| (if X)
+/// +-+-+-+-+-+-+-+-+
+/// |   obu_size    | (if S, leb128)
+/// +-+-+-+-+-+-+-+-+
+/// |  OBU payload  |
+/// |     ...       |
+/// ```
+///
+/// # Fields
+///
+/// * `header` - The OBU header, with its extension if present.
+/// * `payload` - The OBU payload, without the size field.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Obu<'a> {
+    header: &'a [u8],
+    payload: &'a [u8],
+}
+
+impl<'a> Obu<'a> {
+    /// Parses an OBU from the start of the data.
+    ///
+    /// An OBU without the `obu_has_size_field` flag extends to the end of the data.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the OBU and the data that follows it, or the name of the field the data ends in.
+    pub fn parse(data: &'a [u8]) -> Result<(Self, &'a [u8]), &'static str> {
+        let first = *data.first().ok_or("OBU header")?;
+        let header_size = if first & EXTENSION_MASK != 0 { 2 } else { 1 };
+        let header = data.get(..header_size).ok_or("OBU extension header")?;
+        let rest = &data[header_size..];
+        if first & HAS_SIZE_MASK == 0 {
+            return Ok((Self { header, payload: rest }, &[]));
+        }
+
+        let (size, size_length) = read_leb128(rest).ok_or("obu_size")?;
+        let payload = rest
+            .get(size_length..)
+            .and_then(|rest| rest.get(..usize::try_from(size).ok()?))
+            .ok_or("OBU payload")?;
+        Ok((Self { header, payload }, &rest[size_length + payload.len()..]))
+    }
+
+    pub fn header(&self) -> &'a [u8] {
+        self.header
+    }
+
+    pub fn payload(&self) -> &'a [u8] {
+        self.payload
+    }
+
+    pub fn obu_type(&self) -> u8 {
+        (self.header[0] >> TYPE_SHIFT) & TYPE_MASK
+    }
+
+    /// Writes the OBU with an `obu_size` field, as in the low-overhead bitstream format.
+    pub fn write_with_size(&self, output: &mut Vec<u8>) {
+        output.push(self.header[0] | HAS_SIZE_MASK);
+        output.extend_from_slice(&self.header[1..]);
+        write_leb128(output, self.payload.len() as u64);
+        output.extend_from_slice(self.payload);
+    }
+
+    /// Writes the OBU without an `obu_size` field, as in RTP payloads.
+    pub fn write_without_size(&self, output: &mut Vec<u8>) {
+        output.push(self.header[0] & !HAS_SIZE_MASK);
+        output.extend_from_slice(&self.header[1..]);
+        output.extend_from_slice(self.payload);
+    }
+
+    /// Returns the size of the OBU once written without an `obu_size` field.
+    pub fn size_without_size_field(&self) -> usize {
+        self.header.len() + self.payload.len()
+    }
+}
+
+/// Splits a temporal unit in the low-overhead bitstream format into OBUs.
+///
+/// # Returns
+///
+/// A `Result` containing the OBUs, or the name of the field the data ends in.
+pub fn split_obus(mut data: &[u8]) -> Result<Vec<Obu<'_>>, &'static str> {
+    let mut obus = Vec::new();
+    while !data.is_empty() {
+        let (obu, rest) = Obu::parse(data)?;
+        obus.push(obu);
+        data = rest;
+    }
+    Ok(obus)
+}
+
+/// Returns whether the OBUs start a coded video sequence: a sequence header followed by a key frame.
+pub fn starts_coded_video_sequence(obus: &[Obu]) -> bool {
+    let mut reduced_still_picture_header = None;
+    for obu in obus {
+        match obu.obu_type() {
+            OBU_SEQUENCE_HEADER => {
+                reduced_still_picture_header = obu.payload().first().map(|byte| byte & REDUCED_STILL_PICTURE_HEADER_MASK != 0);
+            }
+            OBU_FRAME_HEADER | OBU_FRAME => {
+                return match reduced_still_picture_header {
+                    None => false,
+                    // A reduced still picture header implies a single shown key frame.
+                    Some(true) => true,
+                    Some(false) => obu.payload().first().is_some_and(|byte| byte & SHOW_EXISTING_FRAME_AND_FRAME_TYPE_MASK == 0),
+                };
+            }
+            _ => {}
+        }
+    }
+    false
+}
+
+/// Reads an unsigned `leb128` value.
+///
+/// # Returns
+///
+/// An `Option` containing the value and its size in bytes, or `None` if the data ends before the value.
+pub fn read_leb128(data: &[u8]) -> Option<(u64, usize)> {
+    let mut value = 0;
+    for (index, byte) in data.iter().take(MAX_LEB128_SIZE).enumerate() {
+        value |= ((byte & 0x7F) as u64) << (index * 7);
+        if byte & 0x80 == 0 {
+            return Some((value, index + 1));
+        }
+    }
+    None
+}
+
+/// Writes an unsigned `leb128` value in as few bytes as possible.
+pub fn write_leb128(output: &mut Vec<u8>, mut value: u64) {
+    while value >= 0x80 {
+        output.push((value & 0x7F) as u8 | 0x80);
+        value >>= 7;
+    }
+    output.push(value as u8);
+}
+
+/// Returns the number of bytes `write_leb128` uses for the value.
+pub fn leb128_size(value: u64) -> usize {
+    (64 - value.leading_zeros() as usize).div_ceil(7).max(1)
+}
@@ -0,0 +1,197 @@
+use std::{
+    collections::hash_map::RandomState,
+    hash::{BuildHasher, Hasher},
+};
+
+use rtp::{rtp_header::RtpHeader, rtp_packet::RtpPacket};
+
+use crate::{
+    codec::Codec,
+    packetizer_error::PacketizerError,
+    video::{
+        av1_codec::Av1Codec,
+        av1_depacketizer::{AGGREGATION_HEADER_SIZE, N_MASK, W_SHIFT, Y_MASK, Z_MASK},
+        av1_obu::{leb128_size, split_obus, starts_coded_video_sequence, write_leb128, OBU_TEMPORAL_DELIMITER, OBU_TILE_LIST},
+    },
+};
+
+/// Size of an RTP header without CSRCs or extensions.
+const RTP_HEADER_SIZE: usize = 12;
+/// Smallest payload that leaves room for data after the aggregation header.
+const MIN_PAYLOAD_SIZE: usize = 16;
+/// Highest number of OBU elements that the W field can count.
+const MAX_COUNTED_ELEMENTS: usize = 3;
+
+/// Represents the OBU elements of one packet.
+#[derive(Debug, Default)]
+struct Payload {
+    continuation: bool,
+    fragmented: bool,
+    elements: Vec<Vec<u8>>,
+    size: usize,
+}
+
+/// Splits AV1 temporal units into RTP packets as defined in the RTP Payload Format For AV1.
+///
+/// The OBUs of a temporal unit are written without their `obu_size` fields and aggregated into
+/// as few packets as the MTU allows, an OBU that does not fit into the rest of a packet being
+/// fragmented across packets. Temporal delimiters and tile lists are not sent. The N bit is set
+/// on the first packet of a temporal unit that starts a coded video sequence, and the marker bit
+/// on the last packet of every temporal unit.
+#[derive(Debug)]
+pub struct Av1Packetizer {
+    payload_type: u8,
+    ssrc: u32,
+    max_payload_size: usize,
+    sequence_number: u16,
+    payloads: Vec<Vec<u8>>,
+}
+
+impl Av1Packetizer {
+    /// Creates a new `Av1Packetizer` starting at a random sequence number.
+    ///
+    /// # Arguments
+    ///
+    /// * `codec` - The codec advertised in SDP, providing the payload type.
+    /// * `ssrc` - The synchronization source of the stream.
+    /// * `mtu` - The maximum size of an RTP packet, header included.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the packetizer, or a `PacketizerError` if the MTU cannot fit an OBU fragment.
+    pub fn new(codec: &Av1Codec, ssrc: u32, mtu: usize) -> Result<Self, PacketizerError> {
+        if mtu < RTP_HEADER_SIZE + MIN_PAYLOAD_SIZE {
+            return Err(PacketizerError::MtuTooSmall(mtu));
+        }
+        Ok(Self {
+            payload_type: codec.format() as u8,
+            ssrc,
+            max_payload_size: mtu - RTP_HEADER_SIZE,
+            sequence_number: RandomState::new().build_hasher().finish() as u16,
+            payloads: Vec::new(),
+        })
+    }
+
+    /// Returns the sequence number of the next packet.
+    pub fn sequence_number(&self) -> u16 {
+        self.sequence_number
+    }
+
+    /// Packetizes a temporal unit in the low-overhead bitstream format.
+    ///
+    /// # Arguments
+    ///
+    /// * `timestamp` - The RTP timestamp of the temporal unit.
+    /// * `temporal_unit` - The OBUs of the temporal unit, each with an `obu_size` field except possibly the last one.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the packets of the temporal unit, or a `PacketizerError` if an OBU is truncated.
+    pub fn packetize(&mut self, timestamp: u32, temporal_unit: &[u8]) -> Result<Vec<RtpPacket<'_>>, PacketizerError> {
+        let obus = split_obus(temporal_unit).map_err(PacketizerError::Truncated)?;
+        let new_coded_video_sequence = starts_coded_video_sequence(&obus);
+        let elements = obus
+            .iter()
+            .filter(|obu| !matches!(obu.obu_type(), OBU_TEMPORAL_DELIMITER | OBU_TILE_LIST))
+            .map(|obu| {
+                let mut element = Vec::with_capacity(obu.size_without_size_field());
+                obu.write_without_size(&mut element);
+                element
+            })
+            .collect::<Vec<_>>();
+
+        self.payloads.clear();
+        for (index, payload) in self.aggregate(&elements).into_iter().enumerate() {
+            let first = index == 0 && new_coded_video_sequence;
+            self.payloads.push(write_payload(payload, first));
+        }
+
+        let sequence_number = self.sequence_number;
+        self.sequence_number = sequence_number.wrapping_add(self.payloads.len() as u16);
+        let last = self.payloads.len().saturating_sub(1);
+        Ok(self
+            .payloads
+            .iter()
+            .enumerate()
+            .map(|(index, payload)| {
+                let header = RtpHeader::new(
+                    2,
+                    false,
+                    false,
+                    0,
+                    index == last,
+                    self.payload_type,
+                    sequence_number.wrapping_add(index as u16),
+                    timestamp,
+                    self.ssrc,
+                    &[],
+                );
+                RtpPacket::new(header, payload)
+            })
+            .collect())
+    }
+
+    /// Distributes the OBU elements over payloads, assuming every element has a length field.
+    fn aggregate(&self, elements: &[Vec<u8>]) -> Vec<Payload> {
+        let mut payloads = Vec::new();
+        let mut current = Payload { size: AGGREGATION_HEADER_SIZE, ..Default::default() };
+
+        for element in elements {
+            let mut data = element.as_slice();
+            loop {
+                let available = self.max_payload_size - current.size;
+                let needed = leb128_size(data.len() as u64) + data.len();
+                if needed <= available {
+                    current.elements.push(data.to_vec());
+                    current.size += needed;
+                    break;
+                }
+
+                let fragment_size = available.saturating_sub(leb128_size(available as u64));
+                let continuation = fragment_size > 0;
+                if continuation {
+                    current.elements.push(data[..fragment_size].to_vec());
+                    current.fragmented = true;
+                    data = &data[fragment_size..];
+                }
+                payloads.push(current);
+                current = Payload { continuation, size: AGGREGATION_HEADER_SIZE, ..Default::default() };
+            }
+        }
+        if !current.elements.is_empty() {
+            payloads.push(current);
+        }
+        payloads
+    }
+}
+
+/// Writes the aggregation header and the OBU elements of a payload.
+///
+/// Up to three elements are counted in the W field so that the last one needs no length field.
+fn write_payload(payload: Payload, new_coded_video_sequence: bool) -> Vec<u8> {
+    let counted = payload.elements.len() <= MAX_COUNTED_ELEMENTS;
+    let mut aggregation_header = 0;
+    if payload.continuation {
+        aggregation_header |= Z_MASK;
+    }
+    if payload.fragmented {
+        aggregation_header |= Y_MASK;
+    }
+    if counted {
+        aggregation_header |= (payload.elements.len() as u8) << W_SHIFT;
+    }
+    if new_coded_video_sequence {
+        aggregation_header |= N_MASK;
+    }
+
+    let mut output = Vec::with_capacity(payload.size);
+    output.push(aggregation_header);
+    let last = payload.elements.len() - 1;
+    for (index, element) in payload.elements.iter().enumerate() {
+        if !(counted && index == last) {
+            write_leb128(&mut output, element.len() as u64);
+        }
+        output.extend_from_slice(element);
+    }
+    output
+}
@@ -0,0 +1,36 @@
+use byteorder::{BigEndian, ByteOrder};
+
+use crate::packetizer_error::PacketizerError;
+
+/// Splits a length-prefixed (AVCC/HVCC) sample into NAL units.
+///
+/// # Arguments
+///
+/// * `data` - A byte slice containing the sample.
+/// * `length_size` - The size of each length prefix in bytes, `lengthSizeMinusOne + 1` of the decoder configuration.
+///
+/// # Returns
+///
+/// A `Result` containing the NAL units, or a `PacketizerError` if a length exceeds the sample.
+pub fn split_nal_units(data: &[u8], length_size: usize) -> Result<Vec<&[u8]>, PacketizerError> {
+    if !(1..=4).contains(&length_size) {
+        return Err(PacketizerError::InvalidLengthSize(length_size));
+    }
+    let mut nal_units = Vec::new();
+    let mut rest = data;
+    while !rest.is_empty() {
+        if rest.len() < length_size {
+            return Err(PacketizerError::Truncated("NAL unit length"));
+        }
+        let length = BigEndian::read_uint(rest, length_size) as usize;
+        let end = length_size + length;
+        if rest.len() < end {
+            return Err(PacketizerError::Truncated("NAL unit"));
+        }
+        if length > 0 {
+            nal_units.push(&rest[length_size..end]);
+        }
+        rest = &rest[end..];
+    }
+    Ok(nal_units)
+}
@@ -5,17 +5,24 @@ use abstractions::{
 };
 use base64::{prelude::BASE64_STANDARD, Engine};
 
-use crate::codec::{CodecHelper, Codec};
+use crate::{
+    codec::{CodecHelper, Codec},
+    config_error::ConfigError,
+    video::{
+        annex_b::{split_nal_units, START_CODE},
+        h264_nal::{nal_unit_type, PPS, SPS},
+        h264_pps::H264Pps,
+        h264_sps::H264Sps,
+    },
+};
 
 const SPROP_KEY: &[u8] = b"sprop-parameter-sets=";
 const PACKETIZATION_MODE_KEY: &[u8] = b"packetization-mode=";
 const PROFILE_LEVEL_ID_KEY: &[u8] = b"profile-level-id=";
 
-const START_MARKER: [u8; 4] = [0, 0, 0, 1];
-
-pub(crate) const NAME: &'static str = "H264";
+pub(crate) const NAME: &str = "H264";
 
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum PackatizationMode {
     SingleNalUnit = 0,
     NonInterleaved = 1,
@@ -31,6 +38,10 @@ impl PackatizationMode {
             _ => None,
         }
     }
+
+    pub fn as_u8(self) -> u8 {
+        self as u8
+    }
 }
 
 #[derive(Debug, PartialEq, Eq)]
@@ -59,6 +70,59 @@ impl H264Codec {
     pub fn sps_pps_bytes(&self) -> &[u8] {
         &self.sps_pps_bytes
     }
+
+    /// Parses the sequence parameter set from `sprop-parameter-sets`.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the SPS, or a `ConfigError` if it is missing or malformed.
+    pub fn sps(&self) -> Result<H264Sps, ConfigError> {
+        let nal_unit = self.parameter_set(SPS).ok_or(ConfigError::Missing("SPS"))?;
+        H264Sps::try_from(nal_unit)
+    }
+
+    /// Parses the picture parameter set from `sprop-parameter-sets`.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the PPS, or a `ConfigError` if it is missing or malformed.
+    pub fn pps(&self) -> Result<H264Pps, ConfigError> {
+        let nal_unit = self.parameter_set(PPS).ok_or(ConfigError::Missing("PPS"))?;
+        H264Pps::try_from(nal_unit)
+    }
+
+    /// Checks `profile-level-id` against the profile and level of the sequence parameter set.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the SPS, or a `ConfigError` if `profile-level-id` is malformed
+    /// or its `profile_idc` or `level_idc` differs from the SPS.
+    pub fn check_profile_level_id(&self) -> Result<H264Sps, ConfigError> {
+        let profile_level_id = hex::decode(&self.profile_level_id)
+            .ok()
+            .filter(|bytes| bytes.len() == 3)
+            .ok_or(ConfigError::Invalid("profile-level-id"))?;
+        let sps = self.sps()?;
+        if profile_level_id[0] != sps.profile_idc() {
+            return Err(ConfigError::ParameterMismatch {
+                parameter: "profile_idc",
+                fmtp: profile_level_id[0] as u32,
+                bitstream: sps.profile_idc() as u32,
+            });
+        }
+        if profile_level_id[2] != sps.level_idc() {
+            return Err(ConfigError::ParameterMismatch {
+                parameter: "level_idc",
+                fmtp: profile_level_id[2] as u32,
+                bitstream: sps.level_idc() as u32,
+            });
+        }
+        Ok(sps)
+    }
+
+    fn parameter_set(&self, parameter_set_type: u8) -> Option<&[u8]> {
+        split_nal_units(&self.sps_pps_bytes).find(|nal_unit| nal_unit_type(nal_unit[0]) == parameter_set_type)
+    }
 }
 
 impl CodecHelper for H264Codec {
@@ -107,13 +171,13 @@ impl CodecHelper for H264Codec {
 }
 
 fn get_sps_pps_bytes(data: &[u8]) -> Result<Vec<u8>, ParsingError> {
-    let mut result = START_MARKER.to_vec();
+    let mut result = START_CODE.to_vec();
     match data.separate_trimmed(COMMA, WHITESPACE) {
         Some((sps, pps)) => {
-            let mut pps_start = START_MARKER.to_vec();
+            let mut pps_start = START_CODE.to_vec();
             result.extend(BASE64_STANDARD.decode(sps)
                     .map_err(|_| ParsingError::from_bytes(sps))?);
-            pps_start.extend(BASE64_STANDARD.decode(sps)
+            pps_start.extend(BASE64_STANDARD.decode(pps)
                     .map_err(|_| ParsingError::from_bytes(pps))?);
             result.extend(pps_start);
         }
@@ -139,7 +203,18 @@ impl Codec for H264Codec {
     }
 
     fn name(&self) -> &'static str {
-        &NAME
+        NAME
+    }
+
+    fn fmtp(&self) -> Option<String> {
+        let parameter_sets = split_nal_units(&self.sps_pps_bytes)
+            .map(|nal_unit| BASE64_STANDARD.encode(nal_unit))
+            .collect::<Vec<_>>()
+            .join(",");
+        Some(format!(
+            "packetization-mode={}; profile-level-id={}; sprop-parameter-sets={}",
+            self.packetization_mode.as_u8(), self.profile_level_id, parameter_sets
+        ))
     }
 
     fn parse(clock_rate: u32, channel_count: Option<u8>, data: &[u8]) -> Result<Self, ParsingError>
@@ -0,0 +1,377 @@
+use std::collections::{BTreeMap, HashSet, VecDeque};
+
+use byteorder::{BigEndian, ByteOrder};
+use rtp::rtp_packet::RtpPacket;
+
+use crate::{
+    access_unit::{decode_timestamp, AccessUnit},
+    depacketizer::Depacketizer,
+    depacketizer_error::DepacketizerError,
+    media_frame::MediaFrame,
+    packet_sequence::{PacketOrder, PacketSequence},
+    video::{
+        annex_b::push_nal_unit,
+        h264_codec::{self, H264Codec, PackatizationMode},
+        h264_nal::{
+            nal_unit_type, FORBIDDEN_NRI_MASK, FU_A, FU_B, FU_END_MASK, FU_START_MASK, IDR_SLICE, MTAP16, MTAP24,
+            SPS, STAP_A, STAP_B,
+        },
+    },
+};
+
+/// Number of NAL units held back in interleaved mode to restore the decoding order.
+const INTERLEAVING_DEPTH: usize = 64;
+
+/// Offset of the first extended decoding order number, so that unwrapping never goes below zero.
+const DON_BASE: i64 = 1 << 32;
+
+/// Represents a NAL unit being reassembled from fragmentation units.
+#[derive(Debug)]
+struct Fragment {
+    timestamp: u32,
+    don: Option<u16>,
+    nal_unit: Vec<u8>,
+}
+
+/// Reassembles H.264 access units from RTP payloads as defined in RFC 6184.
+///
+/// Packets are pushed in sequence number order and complete access units are pulled
+/// as Annex-B byte streams. An access unit is closed by the marker bit or by a packet
+/// with a different timestamp. Access units that lost a packet are dropped, and the
+/// SPS and PPS from the `sprop-parameter-sets` are prepended to IDR access units that
+/// do not carry their own.
+///
+/// In interleaved mode, NAL units are reordered by their decoding order number and
+/// access units are only closed once a later access unit leaves the reorder window,
+/// so `flush` has to be called at the end of the stream.
+/// A lost packet drops the access units of the packets on both sides of the gap, as the
+/// access unit it belonged to is unknown.
+#[derive(Debug)]
+pub struct H264Depacketizer {
+    parameter_sets: Vec<u8>,
+    interleaved: bool,
+    access_unit: Vec<u8>,
+    timestamp: Option<u32>,
+    keyframe: bool,
+    has_parameter_sets: bool,
+    corrupted: bool,
+    fragment: Option<Fragment>,
+    sequence: PacketSequence,
+    last_don: Option<i64>,
+    reorder_buffer: BTreeMap<i64, (u32, Vec<u8>)>,
+    last_decode_timestamp: Option<u32>,
+    last_packet_timestamp: Option<u32>,
+    corrupted_timestamps: HashSet<u32>,
+    ready: VecDeque<AccessUnit>,
+}
+
+impl H264Depacketizer {
+    /// Creates a new `H264Depacketizer` for the stream described by the codec.
+    ///
+    /// # Arguments
+    ///
+    /// * `codec` - The codec negotiated in SDP, providing the parameter sets and the packetization mode.
+    pub fn new(codec: &H264Codec) -> Self {
+        Self {
+            parameter_sets: codec.sps_pps_bytes().to_vec(),
+            interleaved: *codec.packetization_mode() == PackatizationMode::Interleaved,
+            access_unit: Vec::new(),
+            timestamp: None,
+            keyframe: false,
+            has_parameter_sets: false,
+            corrupted: false,
+            fragment: None,
+            sequence: PacketSequence::default(),
+            last_don: None,
+            reorder_buffer: BTreeMap::new(),
+            last_decode_timestamp: None,
+            last_packet_timestamp: None,
+            corrupted_timestamps: HashSet::new(),
+            ready: VecDeque::new(),
+        }
+    }
+
+    /// Returns the number of packets detected as missing from the sequence.
+    pub fn lost_packets(&self) -> u64 {
+        self.sequence.lost_packets()
+    }
+
+    /// Pushes the next RTP packet of the stream.
+    ///
+    /// # Arguments
+    ///
+    /// * `packet` - The RTP packet carrying an H.264 payload.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` that is an error if the payload is malformed; the access unit it belongs to is dropped.
+    pub fn push(&mut self, packet: &RtpPacket) -> Result<(), DepacketizerError> {
+        let header = packet.header();
+        let gap = match self.sequence.push(header.sequence_number()) {
+            PacketOrder::Late => return Ok(()),
+            PacketOrder::Next => false,
+            PacketOrder::AfterGap => {
+                self.fragment = None;
+                true
+            }
+        };
+
+        if !self.interleaved {
+            if gap {
+                self.corrupted = true;
+            }
+            if self.timestamp.is_some_and(|timestamp| timestamp != header.timestamp()) {
+                self.finish_access_unit(None);
+                self.corrupted = gap;
+            }
+            self.timestamp = Some(header.timestamp());
+        }
+
+        let result = self.push_payload(header.timestamp(), packet.payload());
+        if result.is_err() {
+            self.fragment = None;
+            if !self.interleaved {
+                self.corrupted = true;
+            }
+        }
+        if self.interleaved {
+            // The lost packets are not known to belong to the access unit before or after the gap,
+            // so both are dropped once they are reordered.
+            if gap {
+                if let Some(last_packet_timestamp) = self.last_packet_timestamp {
+                    self.mark_corrupted(last_packet_timestamp);
+                }
+            }
+            if gap || result.is_err() {
+                self.mark_corrupted(header.timestamp());
+            }
+            self.last_packet_timestamp = Some(header.timestamp());
+        }
+        if header.marker() && !self.interleaved {
+            self.finish_access_unit(None);
+        }
+        result
+    }
+
+    /// Pulls the next complete access unit.
+    ///
+    /// # Returns
+    ///
+    /// An `Option` containing the access unit as an Annex-B byte stream, or `None` if none is complete.
+    pub fn pull(&mut self) -> Option<AccessUnit> {
+        self.ready.pop_front()
+    }
+
+    /// Closes the pending access unit, releasing every NAL unit held back for reordering.
+    pub fn flush(&mut self) {
+        while let Some((_, (timestamp, nal_unit))) = self.reorder_buffer.pop_first() {
+            self.push_ordered(timestamp, &nal_unit);
+        }
+        self.finish_access_unit(None);
+        self.corrupted_timestamps.clear();
+    }
+
+    fn push_payload(&mut self, timestamp: u32, payload: &[u8]) -> Result<(), DepacketizerError> {
+        let Some(&indicator) = payload.first() else {
+            return Err(DepacketizerError::Truncated("empty payload"));
+        };
+
+        match (nal_unit_type(indicator), self.interleaved) {
+            (1..=23, false) => self.push_nal_unit(timestamp, None, payload),
+            (STAP_A, false) => {
+                for nal_unit in aggregated_units(&payload[1..], 0) {
+                    self.push_nal_unit(timestamp, None, nal_unit?);
+                }
+            }
+            (STAP_B, true) => {
+                let mut don = read_don(payload)?;
+                for nal_unit in aggregated_units(&payload[3..], 0) {
+                    self.push_nal_unit(timestamp, Some(don), nal_unit?);
+                    don = don.wrapping_add(1);
+                }
+            }
+            (packet_type @ (MTAP16 | MTAP24), true) => {
+                let offset_size = if packet_type == MTAP16 { 2 } else { 3 };
+                let base_don = read_don(payload)?;
+                for unit in aggregated_units(&payload[3..], 1 + offset_size) {
+                    let unit = unit?;
+                    let don = base_don.wrapping_add(unit[0] as u16);
+                    let offset = BigEndian::read_uint(&unit[1..1 + offset_size], offset_size) as u32;
+                    self.push_nal_unit(timestamp.wrapping_add(offset), Some(don), &unit[1 + offset_size..]);
+                }
+            }
+            (FU_A, _) => self.push_fragment(timestamp, payload, false)?,
+            (FU_B, true) => self.push_fragment(timestamp, payload, true)?,
+            (packet_type, _) => return Err(DepacketizerError::UnexpectedPacketType(packet_type)),
+        }
+        Ok(())
+    }
+
+    fn push_fragment(&mut self, timestamp: u32, payload: &[u8], has_don: bool) -> Result<(), DepacketizerError> {
+        let data_start = if has_don { 4 } else { 2 };
+        if payload.len() <= data_start {
+            return Err(DepacketizerError::Truncated("FU"));
+        }
+        let fu_header = payload[1];
+        let data = &payload[data_start..];
+
+        if fu_header & FU_START_MASK != 0 {
+            let don = if has_don { Some(BigEndian::read_u16(&payload[2..4])) } else { None };
+            if self.interleaved && don.is_none() {
+                return Err(DepacketizerError::UnexpectedPacketType(FU_A));
+            }
+            let mut nal_unit = Vec::with_capacity(data.len() + 1);
+            nal_unit.push((payload[0] & FORBIDDEN_NRI_MASK) | nal_unit_type(fu_header));
+            nal_unit.extend_from_slice(data);
+            self.fragment = Some(Fragment { timestamp, don, nal_unit });
+        }
+        else if let Some(fragment) = self.fragment.as_mut() {
+            fragment.nal_unit.extend_from_slice(data);
+        }
+        else {
+            return Ok(());
+        }
+
+        if fu_header & FU_END_MASK != 0 {
+            if let Some(fragment) = self.fragment.take() {
+                self.push_nal_unit(fragment.timestamp, fragment.don, &fragment.nal_unit);
+            }
+        }
+        Ok(())
+    }
+
+    fn push_nal_unit(&mut self, timestamp: u32, don: Option<u16>, nal_unit: &[u8]) {
+        let Some(don) = don else {
+            self.append(nal_unit);
+            return;
+        };
+
+        let don = self.extend_don(don);
+        self.reorder_buffer.insert(don, (timestamp, nal_unit.to_vec()));
+        while self.reorder_buffer.len() > INTERLEAVING_DEPTH {
+            if let Some((_, (timestamp, nal_unit))) = self.reorder_buffer.pop_first() {
+                self.push_ordered(timestamp, &nal_unit);
+            }
+        }
+    }
+
+    fn push_ordered(&mut self, timestamp: u32, nal_unit: &[u8]) {
+        if self.timestamp.is_some_and(|current| current != timestamp) {
+            self.finish_access_unit(Some(timestamp));
+        }
+        self.timestamp = Some(timestamp);
+        if self.corrupted_timestamps.remove(&timestamp) {
+            self.corrupted = true;
+        }
+        self.append(nal_unit);
+    }
+
+    /// Drops the access unit with the given timestamp, whether it is being assembled or still reordered.
+    fn mark_corrupted(&mut self, timestamp: u32) {
+        if self.timestamp == Some(timestamp) {
+            self.corrupted = true;
+        }
+        else if self.reorder_buffer.values().any(|(buffered, _)| *buffered == timestamp) {
+            self.corrupted_timestamps.insert(timestamp);
+        }
+    }
+
+    fn append(&mut self, nal_unit: &[u8]) {
+        match nal_unit_type(nal_unit[0]) {
+            IDR_SLICE => self.keyframe = true,
+            SPS => self.has_parameter_sets = true,
+            _ => {}
+        }
+        push_nal_unit(&mut self.access_unit, nal_unit);
+    }
+
+    /// Closes the access unit being assembled.
+    ///
+    /// # Arguments
+    ///
+    /// * `next_timestamp` - The timestamp of the reordered NAL unit that starts the next access unit, if any.
+    fn finish_access_unit(&mut self, next_timestamp: Option<u32>) {
+        let access_unit = std::mem::take(&mut self.access_unit);
+        if !access_unit.is_empty() && !self.corrupted {
+            let data = if self.keyframe && !self.has_parameter_sets {
+                [self.parameter_sets.as_slice(), access_unit.as_slice()].concat()
+            }
+            else {
+                access_unit
+            };
+            let timestamp = self.timestamp.unwrap_or_default();
+            let mut access_unit = AccessUnit::new(timestamp, self.keyframe, data);
+            if self.interleaved {
+                let following = next_timestamp.into_iter().chain(self.reorder_buffer.values().map(|(timestamp, _)| *timestamp));
+                let dts = decode_timestamp(timestamp, following, self.last_decode_timestamp);
+                self.last_decode_timestamp = Some(dts);
+                access_unit = access_unit.with_decode_timestamp(dts);
+            }
+            self.ready.push_back(access_unit);
+        }
+        self.keyframe = false;
+        self.has_parameter_sets = false;
+        self.corrupted = false;
+    }
+
+    /// Unwraps a 16-bit decoding order number relative to the last one seen.
+    fn extend_don(&mut self, don: u16) -> i64 {
+        let extended = match self.last_don {
+            Some(last) => last + don.wrapping_sub(last as u16) as i16 as i64,
+            None => DON_BASE + don as i64,
+        };
+        self.last_don = Some(self.last_don.map_or(extended, |last| last.max(extended)));
+        extended
+    }
+}
+
+fn read_don(payload: &[u8]) -> Result<u16, DepacketizerError> {
+    if payload.len() < 3 {
+        return Err(DepacketizerError::Truncated("decoding order number"));
+    }
+    Ok(BigEndian::read_u16(&payload[1..3]))
+}
+
+/// Iterates over the size-prefixed units of an aggregation packet.
+///
+/// # Arguments
+///
+/// * `data` - The aggregation units following the payload header.
+/// * `min_size` - The size of the unit fields that precede the NAL unit.
+fn aggregated_units(mut data: &[u8], min_size: usize) -> impl Iterator<Item = Result<&[u8], DepacketizerError>> {
+    std::iter::from_fn(move || {
+        if data.is_empty() {
+            return None;
+        }
+        if data.len() < 2 {
+            data = &[];
+            return Some(Err(DepacketizerError::Truncated("aggregation unit size")));
+        }
+        let size = BigEndian::read_u16(&data[..2]) as usize;
+        if size <= min_size || data.len() < 2 + size {
+            data = &[];
+            return Some(Err(DepacketizerError::Truncated("aggregation unit")));
+        }
+        let unit = &data[2..2 + size];
+        data = &data[2 + size..];
+        Some(Ok(unit))
+    })
+}
+
+impl Depacketizer for H264Depacketizer {
+    fn push(&mut self, packet: &RtpPacket) -> Result<(), DepacketizerError> {
+        H264Depacketizer::push(self, packet)
+    }
+
+    fn pull(&mut self) -> Option<MediaFrame> {
+        H264Depacketizer::pull(self).map(|access_unit| MediaFrame::from_access_unit(access_unit, h264_codec::NAME))
+    }
+
+    fn lost_packets(&self) -> u64 {
+        H264Depacketizer::lost_packets(self)
+    }
+
+    fn flush(&mut self) {
+        H264Depacketizer::flush(self)
+    }
+}
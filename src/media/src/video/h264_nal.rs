@@ -0,0 +1,40 @@
+/// Mask of the `F` and `NRI` bits of the NAL unit header.
+pub(crate) const FORBIDDEN_NRI_MASK: u8 = 0b1110_0000;
+/// Mask of the forbidden zero bit of the NAL unit header.
+pub(crate) const FORBIDDEN_MASK: u8 = 0b1000_0000;
+/// Mask of the `NRI` bits of the NAL unit header.
+pub(crate) const NRI_MASK: u8 = 0b0110_0000;
+/// Mask of the `Type` field of the NAL unit header.
+pub(crate) const NAL_UNIT_TYPE_MASK: u8 = 0b0001_1111;
+
+pub(crate) const IDR_SLICE: u8 = 5;
+pub(crate) const SPS: u8 = 7;
+pub(crate) const PPS: u8 = 8;
+
+/// Single-time aggregation packet without decoding order numbers.
+pub(crate) const STAP_A: u8 = 24;
+/// Single-time aggregation packet with a decoding order number.
+pub(crate) const STAP_B: u8 = 25;
+/// Multi-time aggregation packet with 16-bit timestamp offsets.
+pub(crate) const MTAP16: u8 = 26;
+/// Multi-time aggregation packet with 24-bit timestamp offsets.
+pub(crate) const MTAP24: u8 = 27;
+/// Fragmentation unit without a decoding order number.
+pub(crate) const FU_A: u8 = 28;
+/// Fragmentation unit with a decoding order number.
+pub(crate) const FU_B: u8 = 29;
+
+/// Start bit of the FU header.
+pub(crate) const FU_START_MASK: u8 = 0b1000_0000;
+/// End bit of the FU header.
+pub(crate) const FU_END_MASK: u8 = 0b0100_0000;
+
+/// Returns the `Type` field of a NAL unit header.
+pub(crate) fn nal_unit_type(header: u8) -> u8 {
+    header & NAL_UNIT_TYPE_MASK
+}
+
+/// Returns the `NRI` field of a NAL unit header, still in place.
+pub(crate) fn nal_ref_idc(header: u8) -> u8 {
+    header & NRI_MASK
+}
@@ -0,0 +1,263 @@
+use std::{
+    collections::hash_map::RandomState,
+    hash::{BuildHasher, Hasher},
+};
+
+use rtp::{rtp_header::RtpHeader, rtp_packet::RtpPacket};
+
+use crate::{
+    codec::Codec,
+    packetizer_error::PacketizerError,
+    video::{
+        annex_b, avcc,
+        h264_codec::{H264Codec, PackatizationMode},
+        h264_nal::{
+            nal_ref_idc, nal_unit_type, FORBIDDEN_MASK, FORBIDDEN_NRI_MASK, FU_A, FU_B, FU_END_MASK, FU_START_MASK,
+            STAP_A, STAP_B,
+        },
+    },
+};
+
+/// Size of an RTP header without CSRCs or extensions.
+const RTP_HEADER_SIZE: usize = 12;
+/// Size of the FU indicator and FU header.
+const FU_HEADER_SIZE: usize = 2;
+/// Size of a decoding order number.
+const DON_SIZE: usize = 2;
+/// Size of the NAL unit size field of an aggregation unit.
+const AGGREGATION_UNIT_SIZE: usize = 2;
+/// Smallest payload that leaves room for data after every payload header.
+const MIN_PAYLOAD_SIZE: usize = 16;
+
+/// Splits H.264 access units into RTP packets as defined in RFC 6184.
+///
+/// In single NAL unit mode every NAL unit is sent in its own packet. In non-interleaved mode
+/// consecutive NAL units that fit into one packet are aggregated into STAP-A packets and
+/// NAL units larger than the MTU are split into FU-A packets. In interleaved mode STAP-B
+/// and FU-B packets carry decoding order numbers, which are assigned in transmission order.
+/// The marker bit is set on the last packet of every access unit.
+#[derive(Debug)]
+pub struct H264Packetizer {
+    payload_type: u8,
+    packetization_mode: PackatizationMode,
+    ssrc: u32,
+    max_payload_size: usize,
+    sequence_number: u16,
+    don: u16,
+    payloads: Vec<Vec<u8>>,
+}
+
+impl H264Packetizer {
+    /// Creates a new `H264Packetizer` starting at a random sequence number.
+    ///
+    /// # Arguments
+    ///
+    /// * `codec` - The codec advertised in SDP, providing the payload type and the packetization mode.
+    /// * `ssrc` - The synchronization source of the stream.
+    /// * `mtu` - The maximum size of an RTP packet, header included.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the packetizer, or a `PacketizerError` if the MTU cannot fit a fragmentation unit.
+    pub fn new(codec: &H264Codec, ssrc: u32, mtu: usize) -> Result<Self, PacketizerError> {
+        if mtu < RTP_HEADER_SIZE + MIN_PAYLOAD_SIZE {
+            return Err(PacketizerError::MtuTooSmall(mtu));
+        }
+        Ok(Self {
+            payload_type: codec.format() as u8,
+            packetization_mode: *codec.packetization_mode(),
+            ssrc,
+            max_payload_size: mtu - RTP_HEADER_SIZE,
+            sequence_number: RandomState::new().build_hasher().finish() as u16,
+            don: 0,
+            payloads: Vec::new(),
+        })
+    }
+
+    /// Returns the sequence number of the next packet.
+    pub fn sequence_number(&self) -> u16 {
+        self.sequence_number
+    }
+
+    /// Packetizes an access unit in Annex-B format.
+    ///
+    /// # Arguments
+    ///
+    /// * `timestamp` - The RTP timestamp of the access unit.
+    /// * `access_unit` - The NAL units of the access unit, each preceded by a start code.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the packets of the access unit, or a `PacketizerError` if a NAL unit
+    /// does not fit into a packet in single NAL unit mode.
+    pub fn packetize_annex_b(&mut self, timestamp: u32, access_unit: &[u8]) -> Result<Vec<RtpPacket<'_>>, PacketizerError> {
+        let nal_units = annex_b::split_nal_units(access_unit).collect::<Vec<_>>();
+        self.packetize(timestamp, &nal_units)
+    }
+
+    /// Packetizes an access unit in AVCC format.
+    ///
+    /// # Arguments
+    ///
+    /// * `timestamp` - The RTP timestamp of the access unit.
+    /// * `access_unit` - The NAL units of the access unit, each preceded by its length.
+    /// * `length_size` - The size of each length prefix in bytes.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the packets of the access unit, or a `PacketizerError` if the sample
+    /// is malformed or a NAL unit does not fit into a packet in single NAL unit mode.
+    pub fn packetize_avcc(&mut self, timestamp: u32, access_unit: &[u8], length_size: usize) -> Result<Vec<RtpPacket<'_>>, PacketizerError> {
+        let nal_units = avcc::split_nal_units(access_unit, length_size)?;
+        self.packetize(timestamp, &nal_units)
+    }
+
+    /// Packetizes the NAL units of one access unit.
+    ///
+    /// # Arguments
+    ///
+    /// * `timestamp` - The RTP timestamp of the access unit.
+    /// * `nal_units` - The NAL units without start codes or length prefixes.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the packets of the access unit, or a `PacketizerError` if a NAL unit
+    /// does not fit into a packet in single NAL unit mode.
+    pub fn packetize(&mut self, timestamp: u32, nal_units: &[&[u8]]) -> Result<Vec<RtpPacket<'_>>, PacketizerError> {
+        self.payloads.clear();
+        match self.packetization_mode {
+            PackatizationMode::SingleNalUnit => {
+                for nal_unit in nal_units {
+                    if nal_unit.len() > self.max_payload_size {
+                        return Err(PacketizerError::UnitTooLarge { size: nal_unit.len(), max: self.max_payload_size });
+                    }
+                    self.payloads.push(nal_unit.to_vec());
+                }
+            }
+            PackatizationMode::NonInterleaved | PackatizationMode::Interleaved => self.aggregate(nal_units),
+        }
+
+        let sequence_number = self.sequence_number;
+        self.sequence_number = sequence_number.wrapping_add(self.payloads.len() as u16);
+        let last = self.payloads.len().saturating_sub(1);
+        Ok(self
+            .payloads
+            .iter()
+            .enumerate()
+            .map(|(index, payload)| {
+                let header = RtpHeader::new(
+                    2,
+                    false,
+                    false,
+                    0,
+                    index == last,
+                    self.payload_type,
+                    sequence_number.wrapping_add(index as u16),
+                    timestamp,
+                    self.ssrc,
+                    &[],
+                );
+                RtpPacket::new(header, payload)
+            })
+            .collect())
+    }
+
+    fn interleaved(&self) -> bool {
+        self.packetization_mode == PackatizationMode::Interleaved
+    }
+
+    fn aggregate(&mut self, nal_units: &[&[u8]]) {
+        let header_size = if self.interleaved() { 1 + DON_SIZE } else { 1 };
+        let mut pending: Vec<&[u8]> = Vec::new();
+        let mut pending_size = header_size;
+
+        for nal_unit in nal_units {
+            let unit_size = AGGREGATION_UNIT_SIZE + nal_unit.len();
+            if pending_size + unit_size <= self.max_payload_size {
+                pending.push(nal_unit);
+                pending_size += unit_size;
+                continue;
+            }
+            self.push_aggregate(&pending);
+            pending.clear();
+            pending_size = header_size;
+
+            if header_size + unit_size <= self.max_payload_size {
+                pending.push(nal_unit);
+                pending_size += unit_size;
+            }
+            else if !self.interleaved() && nal_unit.len() <= self.max_payload_size {
+                self.payloads.push(nal_unit.to_vec());
+            }
+            else {
+                self.push_fragments(nal_unit);
+            }
+        }
+        self.push_aggregate(&pending);
+    }
+
+    /// Pushes the NAL units as a single NAL unit packet or as an aggregation packet.
+    fn push_aggregate(&mut self, nal_units: &[&[u8]]) {
+        match nal_units {
+            [] => {}
+            [nal_unit] if !self.interleaved() => self.payloads.push(nal_unit.to_vec()),
+            _ => {
+                let forbidden = nal_units.iter().fold(0, |bits, nal_unit| bits | (nal_unit[0] & FORBIDDEN_MASK));
+                let nri = nal_units.iter().map(|nal_unit| nal_ref_idc(nal_unit[0])).max().unwrap_or_default();
+                let mut payload = Vec::new();
+                if self.interleaved() {
+                    payload.push(forbidden | nri | STAP_B);
+                    payload.extend_from_slice(&self.don.to_be_bytes());
+                    self.don = self.don.wrapping_add(nal_units.len() as u16);
+                }
+                else {
+                    payload.push(forbidden | nri | STAP_A);
+                }
+                for nal_unit in nal_units {
+                    payload.extend_from_slice(&(nal_unit.len() as u16).to_be_bytes());
+                    payload.extend_from_slice(nal_unit);
+                }
+                self.payloads.push(payload);
+            }
+        }
+    }
+
+    /// Pushes a NAL unit split into fragmentation units.
+    fn push_fragments(&mut self, nal_unit: &[u8]) {
+        let indicator = nal_unit[0] & FORBIDDEN_NRI_MASK;
+        let fu_type = nal_unit_type(nal_unit[0]);
+        let mut data = &nal_unit[1..];
+        let mut start = true;
+
+        while !data.is_empty() {
+            let mut payload = Vec::with_capacity(self.max_payload_size);
+            let has_don = start && self.interleaved();
+            let header_size = if has_don { FU_HEADER_SIZE + DON_SIZE } else { FU_HEADER_SIZE };
+            let mut size = data.len().min(self.max_payload_size - header_size);
+            if start && size == data.len() {
+                // A fragmentation unit must not have both the start and the end bit set.
+                size -= 1;
+            }
+            let end = size == data.len();
+
+            let mut fu_header = fu_type;
+            if start {
+                fu_header |= FU_START_MASK;
+            }
+            if end {
+                fu_header |= FU_END_MASK;
+            }
+            payload.push(indicator | if has_don { FU_B } else { FU_A });
+            payload.push(fu_header);
+            if has_don {
+                payload.extend_from_slice(&self.don.to_be_bytes());
+                self.don = self.don.wrapping_add(1);
+            }
+            payload.extend_from_slice(&data[..size]);
+            self.payloads.push(payload);
+
+            data = &data[size..];
+            start = false;
+        }
+    }
+}
@@ -0,0 +1,211 @@
+use std::ops::RangeInclusive;
+
+use crate::{
+    bit_reader::BitReader,
+    config_error::ConfigError,
+    video::{
+        annex_b::to_rbsp,
+        h264_nal::{nal_unit_type, PPS},
+    },
+};
+
+/// Slice group map type with explicit run lengths.
+const INTERLEAVED_SLICE_GROUPS: u32 = 0;
+/// Slice group map type with foreground rectangles.
+const FOREGROUND_SLICE_GROUPS: u32 = 2;
+/// First slice group map type that evolves over time.
+const CHANGING_SLICE_GROUPS_FIRST: u32 = 3;
+/// Last slice group map type that evolves over time.
+const CHANGING_SLICE_GROUPS_LAST: u32 = 5;
+/// Slice group map type with an explicit slice group for every map unit.
+const EXPLICIT_SLICE_GROUPS: u32 = 6;
+
+/// Highest `pic_parameter_set_id`.
+const MAX_PIC_PARAMETER_SET_ID: u32 = 255;
+/// Highest `seq_parameter_set_id`.
+const MAX_SEQ_PARAMETER_SET_ID: u32 = 31;
+/// Highest `num_slice_groups_minus1`.
+const MAX_SLICE_GROUPS_MINUS1: u32 = 7;
+/// Highest `num_ref_idx_l0_default_active_minus1` and `num_ref_idx_l1_default_active_minus1`.
+const MAX_REF_IDX_DEFAULT_ACTIVE_MINUS1: u32 = 31;
+/// Range of `pic_init_qp_minus26`, down to the lowest QP of 14-bit samples.
+const PIC_INIT_QP_MINUS26: RangeInclusive<i32> = -62..=25;
+/// Range of `pic_init_qs_minus26`.
+const PIC_INIT_QS_MINUS26: RangeInclusive<i32> = -26..=25;
+/// Range of `chroma_qp_index_offset`.
+const CHROMA_QP_INDEX_OFFSET: RangeInclusive<i32> = -12..=12;
+
+/// Represents an H.264 picture parameter set, ITU-T H.264 section 7.3.2.2.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct H264Pps {
+    pic_parameter_set_id: u32,
+    seq_parameter_set_id: u32,
+    entropy_coding_mode: bool,
+    bottom_field_pic_order_in_frame_present: bool,
+    num_slice_groups: u32,
+    num_ref_idx_l0_default_active: u32,
+    num_ref_idx_l1_default_active: u32,
+    weighted_pred: bool,
+    weighted_bipred_idc: u32,
+    pic_init_qp: i32,
+    pic_init_qs: i32,
+    chroma_qp_index_offset: i32,
+    deblocking_filter_control_present: bool,
+    constrained_intra_pred: bool,
+    redundant_pic_cnt_present: bool,
+    transform_8x8_mode: bool,
+}
+
+impl H264Pps {
+    pub fn pic_parameter_set_id(&self) -> u32 {
+        self.pic_parameter_set_id
+    }
+
+    pub fn seq_parameter_set_id(&self) -> u32 {
+        self.seq_parameter_set_id
+    }
+
+    /// Returns whether CABAC is used, as opposed to CAVLC.
+    pub fn entropy_coding_mode(&self) -> bool {
+        self.entropy_coding_mode
+    }
+
+    pub fn bottom_field_pic_order_in_frame_present(&self) -> bool {
+        self.bottom_field_pic_order_in_frame_present
+    }
+
+    pub fn num_slice_groups(&self) -> u32 {
+        self.num_slice_groups
+    }
+
+    pub fn num_ref_idx_l0_default_active(&self) -> u32 {
+        self.num_ref_idx_l0_default_active
+    }
+
+    pub fn num_ref_idx_l1_default_active(&self) -> u32 {
+        self.num_ref_idx_l1_default_active
+    }
+
+    pub fn weighted_pred(&self) -> bool {
+        self.weighted_pred
+    }
+
+    pub fn weighted_bipred_idc(&self) -> u32 {
+        self.weighted_bipred_idc
+    }
+
+    pub fn pic_init_qp(&self) -> i32 {
+        self.pic_init_qp
+    }
+
+    pub fn pic_init_qs(&self) -> i32 {
+        self.pic_init_qs
+    }
+
+    pub fn chroma_qp_index_offset(&self) -> i32 {
+        self.chroma_qp_index_offset
+    }
+
+    pub fn deblocking_filter_control_present(&self) -> bool {
+        self.deblocking_filter_control_present
+    }
+
+    pub fn constrained_intra_pred(&self) -> bool {
+        self.constrained_intra_pred
+    }
+
+    pub fn redundant_pic_cnt_present(&self) -> bool {
+        self.redundant_pic_cnt_present
+    }
+
+    /// Returns whether 8x8 transforms may be used, which is only signaled by the high profiles.
+    pub fn transform_8x8_mode(&self) -> bool {
+        self.transform_8x8_mode
+    }
+}
+
+impl TryFrom<&[u8]> for H264Pps {
+    type Error = ConfigError;
+
+    /// Parses a picture parameter set NAL unit, header included.
+    fn try_from(nal_unit: &[u8]) -> Result<Self, Self::Error> {
+        match nal_unit.first() {
+            Some(&header) if nal_unit_type(header) == PPS => {}
+            Some(&header) => return Err(ConfigError::Unsupported("PPS NAL unit type", nal_unit_type(header) as u32)),
+            None => return Err(ConfigError::Truncated("NAL unit header")),
+        }
+        let rbsp = to_rbsp(&nal_unit[1..]);
+        let mut reader = BitReader::new(&rbsp);
+
+        let pic_parameter_set_id = reader.ue_field_max("pic_parameter_set_id", MAX_PIC_PARAMETER_SET_ID)?;
+        let seq_parameter_set_id = reader.ue_field_max("seq_parameter_set_id", MAX_SEQ_PARAMETER_SET_ID)?;
+        let entropy_coding_mode = reader.flag("entropy_coding_mode_flag")?;
+        let bottom_field_pic_order_in_frame_present = reader.flag("bottom_field_pic_order_in_frame_present_flag")?;
+        let num_slice_groups = reader.ue_field_max("num_slice_groups_minus1", MAX_SLICE_GROUPS_MINUS1)? + 1;
+        if num_slice_groups > 1 {
+            skip_slice_group_map(&mut reader, num_slice_groups)?;
+        }
+        let num_ref_idx_l0_default_active =
+            reader.ue_field_max("num_ref_idx_l0_default_active_minus1", MAX_REF_IDX_DEFAULT_ACTIVE_MINUS1)? + 1;
+        let num_ref_idx_l1_default_active =
+            reader.ue_field_max("num_ref_idx_l1_default_active_minus1", MAX_REF_IDX_DEFAULT_ACTIVE_MINUS1)? + 1;
+        let weighted_pred = reader.flag("weighted_pred_flag")?;
+        let weighted_bipred_idc = reader.field(2, "weighted_bipred_idc")?;
+        let pic_init_qp = 26 + reader.se_field_in("pic_init_qp_minus26", PIC_INIT_QP_MINUS26)?;
+        let pic_init_qs = 26 + reader.se_field_in("pic_init_qs_minus26", PIC_INIT_QS_MINUS26)?;
+        let chroma_qp_index_offset = reader.se_field_in("chroma_qp_index_offset", CHROMA_QP_INDEX_OFFSET)?;
+        let deblocking_filter_control_present = reader.flag("deblocking_filter_control_present_flag")?;
+        let constrained_intra_pred = reader.flag("constrained_intra_pred_flag")?;
+        let redundant_pic_cnt_present = reader.flag("redundant_pic_cnt_present_flag")?;
+        let transform_8x8_mode = reader.more_rbsp_data() && reader.flag("transform_8x8_mode_flag")?;
+
+        Ok(Self {
+            pic_parameter_set_id,
+            seq_parameter_set_id,
+            entropy_coding_mode,
+            bottom_field_pic_order_in_frame_present,
+            num_slice_groups,
+            num_ref_idx_l0_default_active,
+            num_ref_idx_l1_default_active,
+            weighted_pred,
+            weighted_bipred_idc,
+            pic_init_qp,
+            pic_init_qs,
+            chroma_qp_index_offset,
+            deblocking_filter_control_present,
+            constrained_intra_pred,
+            redundant_pic_cnt_present,
+            transform_8x8_mode,
+        })
+    }
+}
+
+fn skip_slice_group_map(reader: &mut BitReader, num_slice_groups: u32) -> Result<(), ConfigError> {
+    let map_type = reader.ue_field("slice_group_map_type")?;
+    match map_type {
+        INTERLEAVED_SLICE_GROUPS => {
+            for _ in 0..num_slice_groups {
+                reader.ue_field("run_length_minus1")?;
+            }
+        }
+        FOREGROUND_SLICE_GROUPS => {
+            for _ in 1..num_slice_groups {
+                reader.ue_field("top_left")?;
+                reader.ue_field("bottom_right")?;
+            }
+        }
+        CHANGING_SLICE_GROUPS_FIRST..=CHANGING_SLICE_GROUPS_LAST => {
+            reader.flag("slice_group_change_direction_flag")?;
+            reader.ue_field("slice_group_change_rate_minus1")?;
+        }
+        EXPLICIT_SLICE_GROUPS => {
+            let map_units = reader.ue_field("pic_size_in_map_units_minus1")? + 1;
+            let id_size = (u32::BITS - (num_slice_groups - 1).leading_zeros()) as usize;
+            for _ in 0..map_units {
+                reader.field(id_size, "slice_group_id")?;
+            }
+        }
+        _ => {}
+    }
+    Ok(())
+}
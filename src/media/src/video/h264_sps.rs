@@ -0,0 +1,357 @@
+use crate::{
+    bit_reader::BitReader,
+    config_error::ConfigError,
+    video::{
+        annex_b::to_rbsp,
+        h264_nal::{nal_unit_type, SPS},
+        vui_timing::VuiTiming,
+    },
+};
+
+/// Profiles whose sequence parameter set carries the chroma format, the bit depths and the scaling matrices.
+const HIGH_PROFILES: [u8; 13] = [100, 110, 122, 244, 44, 83, 86, 118, 128, 138, 139, 134, 135];
+
+/// Constrained baseline and baseline profile, which have no B slices.
+const BASELINE_PROFILE: u8 = 66;
+
+/// `aspect_ratio_idc` followed by an explicit sample aspect ratio.
+const EXTENDED_SAR: u32 = 255;
+
+/// Picture order count type derived from `frame_num`, which rules out B frames used as reference.
+const POC_TYPE_FRAME_NUM: u32 = 2;
+
+/// Highest `seq_parameter_set_id`.
+const MAX_SEQ_PARAMETER_SET_ID: u32 = 31;
+
+/// Highest `bit_depth_luma_minus8` and `bit_depth_chroma_minus8`, for 14-bit samples.
+const MAX_BIT_DEPTH_MINUS8: u32 = 6;
+
+/// Highest `log2_max_frame_num_minus4` and `log2_max_pic_order_cnt_lsb_minus4`.
+const MAX_LOG2_MINUS4: u32 = 12;
+
+/// Highest `num_ref_frames_in_pic_order_cnt_cycle`.
+const MAX_REF_FRAMES_IN_POC_CYCLE: u32 = 255;
+
+/// Size of a macroblock in luma samples.
+const MACROBLOCK_SIZE: u32 = 16;
+
+/// Represents an H.264 sequence parameter set, ITU-T H.264 section 7.3.2.1.1.
+///
+/// Only the fields needed to describe the stream are kept; the resolution is
+/// computed from the macroblock counts and the cropping rectangle.
+#[derive(Debug, Clone, PartialEq)]
+pub struct H264Sps {
+    profile_idc: u8,
+    constraint_flags: u8,
+    level_idc: u8,
+    seq_parameter_set_id: u32,
+    chroma_format_idc: u32,
+    bit_depth_luma: u32,
+    bit_depth_chroma: u32,
+    pic_order_cnt_type: u32,
+    max_num_ref_frames: u32,
+    frame_mbs_only: bool,
+    width: u32,
+    height: u32,
+    timing: Option<VuiTiming>,
+    fixed_frame_rate: bool,
+    max_num_reorder_frames: Option<u32>,
+}
+
+impl H264Sps {
+    pub fn profile_idc(&self) -> u8 {
+        self.profile_idc
+    }
+
+    /// Returns the `constraint_set0_flag` to `constraint_set5_flag` bits and the two reserved bits.
+    pub fn constraint_flags(&self) -> u8 {
+        self.constraint_flags
+    }
+
+    pub fn level_idc(&self) -> u8 {
+        self.level_idc
+    }
+
+    pub fn seq_parameter_set_id(&self) -> u32 {
+        self.seq_parameter_set_id
+    }
+
+    /// Returns the chroma format: 0 for monochrome, 1 for 4:2:0, 2 for 4:2:2 and 3 for 4:4:4.
+    pub fn chroma_format_idc(&self) -> u32 {
+        self.chroma_format_idc
+    }
+
+    pub fn bit_depth_luma(&self) -> u32 {
+        self.bit_depth_luma
+    }
+
+    pub fn bit_depth_chroma(&self) -> u32 {
+        self.bit_depth_chroma
+    }
+
+    pub fn pic_order_cnt_type(&self) -> u32 {
+        self.pic_order_cnt_type
+    }
+
+    pub fn max_num_ref_frames(&self) -> u32 {
+        self.max_num_ref_frames
+    }
+
+    /// Returns whether every picture is a frame, as opposed to possibly interlaced fields.
+    pub fn frame_mbs_only(&self) -> bool {
+        self.frame_mbs_only
+    }
+
+    /// Returns the width in luma samples after cropping.
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    /// Returns the height in luma samples after cropping.
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    pub fn timing(&self) -> Option<&VuiTiming> {
+        self.timing.as_ref()
+    }
+
+    pub fn fixed_frame_rate(&self) -> bool {
+        self.fixed_frame_rate
+    }
+
+    /// Returns `max_num_reorder_frames` of the VUI bitstream restrictions, if present.
+    pub fn max_num_reorder_frames(&self) -> Option<u32> {
+        self.max_num_reorder_frames
+    }
+
+    /// Returns the frame rate signaled in the VUI, where a frame lasts two clock ticks.
+    pub fn frame_rate(&self) -> Option<f64> {
+        self.timing.and_then(|timing| timing.tick_rate()).map(|tick_rate| tick_rate / 2.0)
+    }
+
+    /// Returns whether frames may be decoded in a different order than they are displayed.
+    ///
+    /// The baseline profile, picture order count type 2 and a VUI that allows no reordering
+    /// all rule out B frames; any other stream has to be assumed to use them.
+    pub fn may_use_b_frames(&self) -> bool {
+        self.profile_idc != BASELINE_PROFILE
+            && self.pic_order_cnt_type != POC_TYPE_FRAME_NUM
+            && self.max_num_reorder_frames != Some(0)
+    }
+}
+
+impl TryFrom<&[u8]> for H264Sps {
+    type Error = ConfigError;
+
+    /// Parses a sequence parameter set NAL unit, header included.
+    fn try_from(nal_unit: &[u8]) -> Result<Self, Self::Error> {
+        match nal_unit.first() {
+            Some(&header) if nal_unit_type(header) == SPS => {}
+            Some(&header) => return Err(ConfigError::Unsupported("SPS NAL unit type", nal_unit_type(header) as u32)),
+            None => return Err(ConfigError::Truncated("NAL unit header")),
+        }
+        let rbsp = to_rbsp(&nal_unit[1..]);
+        let mut reader = BitReader::new(&rbsp);
+
+        let profile_idc = reader.field(8, "profile_idc")? as u8;
+        let constraint_flags = reader.field(8, "constraint_set_flags")? as u8;
+        let level_idc = reader.field(8, "level_idc")? as u8;
+        let seq_parameter_set_id = reader.ue_field_max("seq_parameter_set_id", MAX_SEQ_PARAMETER_SET_ID)?;
+
+        let mut chroma_format_idc = 1;
+        let mut separate_colour_plane = false;
+        let mut bit_depth_luma = 8;
+        let mut bit_depth_chroma = 8;
+        if HIGH_PROFILES.contains(&profile_idc) {
+            chroma_format_idc = reader.ue_field("chroma_format_idc")?;
+            if chroma_format_idc > 3 {
+                return Err(ConfigError::Invalid("chroma_format_idc"));
+            }
+            if chroma_format_idc == 3 {
+                separate_colour_plane = reader.flag("separate_colour_plane_flag")?;
+            }
+            bit_depth_luma = 8 + reader.ue_field_max("bit_depth_luma_minus8", MAX_BIT_DEPTH_MINUS8)?;
+            bit_depth_chroma = 8 + reader.ue_field_max("bit_depth_chroma_minus8", MAX_BIT_DEPTH_MINUS8)?;
+            reader.flag("qpprime_y_zero_transform_bypass_flag")?;
+            if reader.flag("seq_scaling_matrix_present_flag")? {
+                let count = if chroma_format_idc == 3 { 12 } else { 8 };
+                for index in 0..count {
+                    if reader.flag("seq_scaling_list_present_flag")? {
+                        skip_scaling_list(&mut reader, if index < 6 { 16 } else { 64 })?;
+                    }
+                }
+            }
+        }
+
+        reader.ue_field_max("log2_max_frame_num_minus4", MAX_LOG2_MINUS4)?;
+        let pic_order_cnt_type = reader.ue_field("pic_order_cnt_type")?;
+        match pic_order_cnt_type {
+            0 => {
+                reader.ue_field_max("log2_max_pic_order_cnt_lsb_minus4", MAX_LOG2_MINUS4)?;
+            }
+            1 => {
+                reader.flag("delta_pic_order_always_zero_flag")?;
+                reader.se_field("offset_for_non_ref_pic")?;
+                reader.se_field("offset_for_top_to_bottom_field")?;
+                let cycle_length = reader.ue_field_max("num_ref_frames_in_pic_order_cnt_cycle", MAX_REF_FRAMES_IN_POC_CYCLE)?;
+                for _ in 0..cycle_length {
+                    reader.se_field("offset_for_ref_frame")?;
+                }
+            }
+            POC_TYPE_FRAME_NUM => {}
+            _ => return Err(ConfigError::Invalid("pic_order_cnt_type")),
+        }
+
+        let max_num_ref_frames = reader.ue_field("max_num_ref_frames")?;
+        reader.flag("gaps_in_frame_num_value_allowed_flag")?;
+        let width_in_mbs = reader.ue_field("pic_width_in_mbs_minus1")? + 1;
+        let height_in_map_units = reader.ue_field("pic_height_in_map_units_minus1")? + 1;
+        let frame_mbs_only = reader.flag("frame_mbs_only_flag")?;
+        if !frame_mbs_only {
+            reader.flag("mb_adaptive_frame_field_flag")?;
+        }
+        reader.flag("direct_8x8_inference_flag")?;
+
+        let field_factor = if frame_mbs_only { 1 } else { 2 };
+        let mut width = width_in_mbs
+            .checked_mul(MACROBLOCK_SIZE)
+            .ok_or(ConfigError::Invalid("pic_width_in_mbs_minus1"))?;
+        let mut height = height_in_map_units
+            .checked_mul(MACROBLOCK_SIZE * field_factor)
+            .ok_or(ConfigError::Invalid("pic_height_in_map_units_minus1"))?;
+        if reader.flag("frame_cropping_flag")? {
+            let left = reader.ue_field("frame_crop_left_offset")?;
+            let right = reader.ue_field("frame_crop_right_offset")?;
+            let top = reader.ue_field("frame_crop_top_offset")?;
+            let bottom = reader.ue_field("frame_crop_bottom_offset")?;
+            let (crop_unit_x, crop_unit_y) = match (chroma_format_idc, separate_colour_plane) {
+                (0, _) | (3, true) => (1, field_factor),
+                (1, _) => (2, 2 * field_factor),
+                (2, _) => (2, field_factor),
+                _ => (1, field_factor),
+            };
+            width = left
+                .checked_add(right)
+                .and_then(|offset| offset.checked_mul(crop_unit_x))
+                .and_then(|crop| width.checked_sub(crop))
+                .ok_or(ConfigError::Invalid("frame cropping"))?;
+            height = top
+                .checked_add(bottom)
+                .and_then(|offset| offset.checked_mul(crop_unit_y))
+                .and_then(|crop| height.checked_sub(crop))
+                .ok_or(ConfigError::Invalid("frame cropping"))?;
+        }
+
+        let mut vui = Vui::default();
+        if reader.flag("vui_parameters_present_flag")? {
+            vui = Vui::parse(&mut reader)?;
+        }
+
+        Ok(Self {
+            profile_idc,
+            constraint_flags,
+            level_idc,
+            seq_parameter_set_id,
+            chroma_format_idc,
+            bit_depth_luma,
+            bit_depth_chroma,
+            pic_order_cnt_type,
+            max_num_ref_frames,
+            frame_mbs_only,
+            width,
+            height,
+            timing: vui.timing,
+            fixed_frame_rate: vui.fixed_frame_rate,
+            max_num_reorder_frames: vui.max_num_reorder_frames,
+        })
+    }
+}
+
+/// Represents the fields kept from the VUI parameters, ITU-T H.264 section E.1.1.
+#[derive(Debug, Default)]
+struct Vui {
+    timing: Option<VuiTiming>,
+    fixed_frame_rate: bool,
+    max_num_reorder_frames: Option<u32>,
+}
+
+impl Vui {
+    fn parse(reader: &mut BitReader) -> Result<Self, ConfigError> {
+        let mut vui = Vui::default();
+        if reader.flag("aspect_ratio_info_present_flag")? && reader.field(8, "aspect_ratio_idc")? == EXTENDED_SAR {
+            reader.field(16, "sar_width")?;
+            reader.field(16, "sar_height")?;
+        }
+        if reader.flag("overscan_info_present_flag")? {
+            reader.flag("overscan_appropriate_flag")?;
+        }
+        if reader.flag("video_signal_type_present_flag")? {
+            reader.field(4, "video_format and video_full_range_flag")?;
+            if reader.flag("colour_description_present_flag")? {
+                reader.field(24, "colour_description")?;
+            }
+        }
+        if reader.flag("chroma_loc_info_present_flag")? {
+            reader.ue_field("chroma_sample_loc_type_top_field")?;
+            reader.ue_field("chroma_sample_loc_type_bottom_field")?;
+        }
+        if reader.flag("timing_info_present_flag")? {
+            let num_units_in_tick = reader.field(32, "num_units_in_tick")?;
+            let time_scale = reader.field(32, "time_scale")?;
+            vui.timing = Some(VuiTiming::new(num_units_in_tick, time_scale));
+            vui.fixed_frame_rate = reader.flag("fixed_frame_rate_flag")?;
+        }
+        let nal_hrd = reader.flag("nal_hrd_parameters_present_flag")?;
+        if nal_hrd {
+            skip_hrd_parameters(reader)?;
+        }
+        let vcl_hrd = reader.flag("vcl_hrd_parameters_present_flag")?;
+        if vcl_hrd {
+            skip_hrd_parameters(reader)?;
+        }
+        if nal_hrd || vcl_hrd {
+            reader.flag("low_delay_hrd_flag")?;
+        }
+        reader.flag("pic_struct_present_flag")?;
+        if reader.flag("bitstream_restriction_flag")? {
+            reader.flag("motion_vectors_over_pic_boundaries_flag")?;
+            reader.ue_field("max_bytes_per_pic_denom")?;
+            reader.ue_field("max_bits_per_mb_denom")?;
+            reader.ue_field("log2_max_mv_length_horizontal")?;
+            reader.ue_field("log2_max_mv_length_vertical")?;
+            vui.max_num_reorder_frames = Some(reader.ue_field("max_num_reorder_frames")?);
+            reader.ue_field("max_dec_frame_buffering")?;
+        }
+        Ok(vui)
+    }
+}
+
+/// Skips the HRD parameters, ITU-T H.264 section E.1.2.
+fn skip_hrd_parameters(reader: &mut BitReader) -> Result<(), ConfigError> {
+    let cpb_count = reader.ue_field("cpb_cnt_minus1")? + 1;
+    reader.field(8, "bit_rate_scale and cpb_size_scale")?;
+    for _ in 0..cpb_count {
+        reader.ue_field("bit_rate_value_minus1")?;
+        reader.ue_field("cpb_size_value_minus1")?;
+        reader.flag("cbr_flag")?;
+    }
+    reader.field(20, "hrd delay lengths")?;
+    Ok(())
+}
+
+/// Skips a scaling list, ITU-T H.264 section 7.3.2.1.1.1.
+fn skip_scaling_list(reader: &mut BitReader, size: usize) -> Result<(), ConfigError> {
+    let mut last_scale = 8;
+    let mut next_scale = 8;
+    for _ in 0..size {
+        if next_scale != 0 {
+            let delta_scale = reader.se_field_in("delta_scale", -128..=127)?;
+            next_scale = (last_scale + delta_scale + 256) % 256;
+        }
+        if next_scale != 0 {
+            last_scale = next_scale;
+        }
+    }
+    Ok(())
+}
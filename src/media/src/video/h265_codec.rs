@@ -4,39 +4,168 @@ use abstractions::{
 };
 use base64::{prelude::BASE64_STANDARD, Engine};
 
-use crate::codec::{Codec, CodecHelper};
+use crate::{
+    codec::{Codec, CodecHelper},
+    config_error::ConfigError,
+    video::{h265_pps::H265Pps, h265_sps::H265Sps, h265_vps::H265Vps},
+};
 
 const SPS_START: &[u8] = b"sprop-sps=";
 const VPS_START: &[u8] = b"sprop-vps=";
 const PPS_START: &[u8] = b"sprop-pps=";
 const PROFILE_ID_START: &[u8] = b"profile-id=";
+const MAX_DON_DIFF_START: &[u8] = b"sprop-max-don-diff=";
 
 
-pub(crate) const NAME: &'static str = "H265";
+pub(crate) const NAME: &str = "H265";
 
-#[derive(Debug, PartialEq, Eq)]
+/// Represents an H.265 stream as described in RFC 7798.
+///
+/// Two codecs are equal when they carry the same parameter sets in the same order, whether they
+/// were created from the concatenated bytes with `new` or from separate parameter sets.
+#[derive(Debug)]
 pub struct H265Codec {
     format: u16,
     clock_rate: u32,
     channel_count: Option<u8>,
     profile_id: u16,
+    vps: Vec<u8>,
+    sps: Vec<u8>,
+    pps: Vec<u8>,
+    max_don_diff: u16,
     sps_pps_bytes: Vec<u8>,
 }
 
 impl H265Codec {
+    /// Creates a codec from the SPS, PPS and VPS concatenated in this order.
+    ///
+    /// The parameter sets cannot be told apart in the concatenation, so `vps_bytes`, `sps_bytes` and
+    /// `pps_bytes` are empty and `fmtp` writes no `sprop-*` parameters; use `from_parameter_sets` for those.
     pub fn new(format: u16, clock_rate: u32, channel_count: Option<u8>, profile_id: u16, sps_pps_bytes: Vec<u8>) -> Self {
-        Self { format, clock_rate, channel_count, profile_id, sps_pps_bytes }
+        Self { format, clock_rate, channel_count, profile_id, vps: Vec::new(), sps: Vec::new(), pps: Vec::new(), max_don_diff: 0, sps_pps_bytes }
+    }
+
+    /// Creates a codec from the parameter sets of `sprop-vps`, `sprop-sps` and `sprop-pps`.
+    ///
+    /// # Arguments
+    ///
+    /// * `format` - The payload format number.
+    /// * `clock_rate` - The clock rate of the codec.
+    /// * `channel_count` - The optional channel count.
+    /// * `profile_id` - The `profile-id` parameter.
+    /// * `vps` - The video parameter set.
+    /// * `sps` - The sequence parameter set.
+    /// * `pps` - The picture parameter set.
+    #[allow(clippy::too_many_arguments)]
+    pub fn from_parameter_sets(
+        format: u16,
+        clock_rate: u32,
+        channel_count: Option<u8>,
+        profile_id: u16,
+        vps: Vec<u8>,
+        sps: Vec<u8>,
+        pps: Vec<u8>,
+    ) -> Self {
+        let sps_pps_bytes = [sps.as_slice(), pps.as_slice(), vps.as_slice()].concat();
+        Self { format, clock_rate, channel_count, profile_id, vps, sps, pps, max_don_diff: 0, sps_pps_bytes }
+    }
+
+    /// Sets the `sprop-max-don-diff` value, so that NAL units are sent with decoding order numbers.
+    pub fn with_max_don_diff(mut self, max_don_diff: u16) -> Self {
+        self.max_don_diff = max_don_diff;
+        self
     }
     
     pub fn profile_id(&self) -> u16 {
         self.profile_id
     }
 
+    /// Returns the video parameter set from `sprop-vps`.
+    pub fn vps_bytes(&self) -> &[u8] {
+        &self.vps
+    }
+
+    /// Returns the sequence parameter set from `sprop-sps`.
+    pub fn sps_bytes(&self) -> &[u8] {
+        &self.sps
+    }
+
+    /// Returns the picture parameter set from `sprop-pps`.
+    pub fn pps_bytes(&self) -> &[u8] {
+        &self.pps
+    }
+
+    /// Returns the `sprop-max-don-diff` value.
+    ///
+    /// A value greater than zero means that every NAL unit is sent with a decoding order number.
+    pub fn max_don_diff(&self) -> u16 {
+        self.max_don_diff
+    }
+
+    /// Returns the SPS, PPS and VPS concatenated in this order.
     pub fn sps_pps_vps_bytes(&self) -> &[u8] {
         &self.sps_pps_bytes
     }
+
+    /// Parses the video parameter set from `sprop-vps`.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the VPS, or a `ConfigError` if it is malformed.
+    pub fn vps(&self) -> Result<H265Vps, ConfigError> {
+        H265Vps::try_from(self.vps.as_slice())
+    }
+
+    /// Parses the sequence parameter set from `sprop-sps`.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the SPS, or a `ConfigError` if it is malformed.
+    pub fn sps(&self) -> Result<H265Sps, ConfigError> {
+        H265Sps::try_from(self.sps.as_slice())
+    }
+
+    /// Parses the picture parameter set from `sprop-pps`.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the PPS, or a `ConfigError` if it is malformed.
+    pub fn pps(&self) -> Result<H265Pps, ConfigError> {
+        H265Pps::try_from(self.pps.as_slice())
+    }
+
+    /// Checks `profile-id` against the general profile of the sequence parameter set.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the SPS, or a `ConfigError` if the SPS is malformed or its `general_profile_idc` differs.
+    pub fn check_profile_id(&self) -> Result<H265Sps, ConfigError> {
+        let sps = self.sps()?;
+        let profile_idc = sps.profile_tier_level().profile_idc() as u16;
+        if profile_idc != self.profile_id {
+            return Err(ConfigError::ParameterMismatch {
+                parameter: "profile-id",
+                fmtp: self.profile_id as u32,
+                bitstream: profile_idc as u32,
+            });
+        }
+        Ok(sps)
+    }
+}
+
+impl PartialEq for H265Codec {
+    fn eq(&self, other: &Self) -> bool {
+        self.format == other.format
+            && self.clock_rate == other.clock_rate
+            && self.channel_count == other.channel_count
+            && self.profile_id == other.profile_id
+            && self.sps_pps_bytes == other.sps_pps_bytes
+            && self.max_don_diff == other.max_don_diff
+    }
 }
 
+impl Eq for H265Codec {}
+
 impl Codec for H265Codec {
     
     fn channel_count(&self) -> &Option<u8> {
@@ -52,7 +181,21 @@ impl Codec for H265Codec {
     }
 
     fn name(&self) -> &'static str {
-        &NAME
+        NAME
+    }
+
+    fn fmtp(&self) -> Option<String> {
+        let mut fmtp = format!("profile-id={}", self.profile_id);
+        // A codec created from the concatenated bytes has no separate parameter sets to write.
+        for (key, parameter_set) in [("sprop-vps", &self.vps), ("sprop-sps", &self.sps), ("sprop-pps", &self.pps)] {
+            if !parameter_set.is_empty() {
+                fmtp.push_str(&format!("; {}={}", key, BASE64_STANDARD.encode(parameter_set)));
+            }
+        }
+        if self.max_don_diff > 0 {
+            fmtp.push_str(&format!("; sprop-max-don-diff={}", self.max_don_diff));
+        }
+        Some(fmtp)
     }
 
     fn parse(clock_rate: u32, channel_count: Option<u8>, data: &[u8]) -> Result<Self, ParsingError>
@@ -72,6 +215,7 @@ impl CodecHelper for H265Codec {
         let mut pps = EMPTY_BYTE_SLICE;
         let mut vps = EMPTY_BYTE_SLICE;
         let mut profile_id: Option<u16> = None;
+        let mut max_don_diff = 0;
     
         let mut current = data;
         while let Some((left, right)) = current.while_separate_trimmed(SEMICOLON, WHITESPACE) {
@@ -87,13 +231,16 @@ impl CodecHelper for H265Codec {
             else if let Some((_, profile_id_slice)) = left.separate_trimmed(PROFILE_ID_START, WHITESPACE) {
                 profile_id = Some(profile_id_slice.utf8_to_number::<u16>()?);
             }
+            else if let Some((_, max_don_diff_slice)) = left.separate_trimmed(MAX_DON_DIFF_START, WHITESPACE) {
+                max_don_diff = max_don_diff_slice.utf8_to_number::<u16>()?;
+            }
             current = right;
         }
         if sps == EMPTY_BYTE_SLICE || pps == EMPTY_BYTE_SLICE || vps == EMPTY_BYTE_SLICE {
             return Err(ParsingError::from_bytes(data));
         }
     
-        let mut sps_pps_bytes = BASE64_STANDARD
+        let sps = BASE64_STANDARD
             .decode(sps)
             .map_err(|_| ParsingError::from_bytes(sps))?;
     
@@ -105,16 +252,8 @@ impl CodecHelper for H265Codec {
             .decode(vps)
             .map_err(|_| ParsingError::from_bytes(vps))?;
     
-        sps_pps_bytes.extend(pps);
-        sps_pps_bytes.extend(vps);
         let profile_id = profile_id.ok_or_else(|| ParsingError::from_bytes(data))?;
 
-        return Ok(H265Codec {
-            format,
-            clock_rate,
-            channel_count,
-            profile_id,
-            sps_pps_bytes,
-        });
+        Ok(H265Codec::from_parameter_sets(format, clock_rate, channel_count, profile_id, vps, sps, pps).with_max_don_diff(max_don_diff))
     }
 }
\ No newline at end of file
@@ -0,0 +1,371 @@
+use std::collections::{BTreeMap, HashSet, VecDeque};
+
+use byteorder::{BigEndian, ByteOrder};
+use rtp::rtp_packet::RtpPacket;
+
+use crate::{
+    access_unit::{decode_timestamp, AccessUnit},
+    depacketizer::Depacketizer,
+    depacketizer_error::DepacketizerError,
+    media_frame::MediaFrame,
+    packet_sequence::{PacketOrder, PacketSequence},
+    video::{
+        annex_b::push_nal_unit,
+        h265_codec::{self, H265Codec},
+        h265_nal::{
+            is_irap, nal_unit_type, with_nal_unit_type, AP, FU, FU_END_MASK, FU_START_MASK, FU_TYPE_MASK,
+            NAL_HEADER_SIZE, SPS,
+        },
+    },
+};
+
+/// Offset of the first extended decoding order number, so that unwrapping never goes below zero.
+const DON_BASE: i64 = 1 << 32;
+
+/// Represents a NAL unit being reassembled from fragmentation units.
+#[derive(Debug)]
+struct Fragment {
+    timestamp: u32,
+    don: Option<u16>,
+    nal_unit: Vec<u8>,
+}
+
+/// Reassembles H.265 access units from RTP payloads as defined in RFC 7798.
+///
+/// Packets are pushed in sequence number order and complete access units are pulled
+/// as Annex-B byte streams. An access unit is closed by the marker bit or by a packet
+/// with a different timestamp. Access units that lost a packet are dropped, and the
+/// VPS, SPS and PPS from the `sprop-vps`, `sprop-sps` and `sprop-pps` parameters are
+/// prepended to IRAP access units that do not carry their own.
+///
+/// When `sprop-max-don-diff` is greater than zero, every NAL unit carries a decoding order
+/// number and is held back until `sprop-max-don-diff` later NAL units arrived, so `flush`
+/// has to be called at the end of the stream.
+/// A lost packet drops the access units of the packets on both sides of the gap, as the
+/// access unit it belonged to is unknown.
+#[derive(Debug)]
+pub struct H265Depacketizer {
+    parameter_sets: Vec<u8>,
+    max_don_diff: usize,
+    access_unit: Vec<u8>,
+    timestamp: Option<u32>,
+    keyframe: bool,
+    has_parameter_sets: bool,
+    corrupted: bool,
+    fragment: Option<Fragment>,
+    sequence: PacketSequence,
+    last_don: Option<i64>,
+    reorder_buffer: BTreeMap<i64, (u32, Vec<u8>)>,
+    last_decode_timestamp: Option<u32>,
+    last_packet_timestamp: Option<u32>,
+    corrupted_timestamps: HashSet<u32>,
+    ready: VecDeque<AccessUnit>,
+}
+
+impl H265Depacketizer {
+    /// Creates a new `H265Depacketizer` for the stream described by the codec.
+    ///
+    /// # Arguments
+    ///
+    /// * `codec` - The codec negotiated in SDP, providing the parameter sets and `sprop-max-don-diff`.
+    pub fn new(codec: &H265Codec) -> Self {
+        let mut parameter_sets = Vec::new();
+        for parameter_set in [codec.vps_bytes(), codec.sps_bytes(), codec.pps_bytes()] {
+            push_nal_unit(&mut parameter_sets, parameter_set);
+        }
+        Self {
+            parameter_sets,
+            max_don_diff: codec.max_don_diff() as usize,
+            access_unit: Vec::new(),
+            timestamp: None,
+            keyframe: false,
+            has_parameter_sets: false,
+            corrupted: false,
+            fragment: None,
+            sequence: PacketSequence::default(),
+            last_don: None,
+            reorder_buffer: BTreeMap::new(),
+            last_decode_timestamp: None,
+            last_packet_timestamp: None,
+            corrupted_timestamps: HashSet::new(),
+            ready: VecDeque::new(),
+        }
+    }
+
+    /// Returns the number of packets detected as missing from the sequence.
+    pub fn lost_packets(&self) -> u64 {
+        self.sequence.lost_packets()
+    }
+
+    /// Pushes the next RTP packet of the stream.
+    ///
+    /// # Arguments
+    ///
+    /// * `packet` - The RTP packet carrying an H.265 payload.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` that is an error if the payload is malformed; the access unit it belongs to is dropped.
+    pub fn push(&mut self, packet: &RtpPacket) -> Result<(), DepacketizerError> {
+        let header = packet.header();
+        let gap = match self.sequence.push(header.sequence_number()) {
+            PacketOrder::Late => return Ok(()),
+            PacketOrder::Next => false,
+            PacketOrder::AfterGap => {
+                self.fragment = None;
+                true
+            }
+        };
+
+        let has_don = self.has_don();
+        if !has_don {
+            if gap {
+                self.corrupted = true;
+            }
+            if self.timestamp.is_some_and(|timestamp| timestamp != header.timestamp()) {
+                self.finish_access_unit(None);
+                self.corrupted = gap;
+            }
+            self.timestamp = Some(header.timestamp());
+        }
+
+        let result = self.push_payload(header.timestamp(), packet.payload());
+        if result.is_err() {
+            self.fragment = None;
+            if !has_don {
+                self.corrupted = true;
+            }
+        }
+        if has_don {
+            // The lost packets are not known to belong to the access unit before or after the gap,
+            // so both are dropped once they are reordered.
+            if gap {
+                if let Some(last_packet_timestamp) = self.last_packet_timestamp {
+                    self.mark_corrupted(last_packet_timestamp);
+                }
+            }
+            if gap || result.is_err() {
+                self.mark_corrupted(header.timestamp());
+            }
+            self.last_packet_timestamp = Some(header.timestamp());
+        }
+        if header.marker() && !has_don {
+            self.finish_access_unit(None);
+        }
+        result
+    }
+
+    /// Pulls the next complete access unit.
+    ///
+    /// # Returns
+    ///
+    /// An `Option` containing the access unit as an Annex-B byte stream, or `None` if none is complete.
+    pub fn pull(&mut self) -> Option<AccessUnit> {
+        self.ready.pop_front()
+    }
+
+    /// Closes the pending access unit, releasing every NAL unit held back for reordering.
+    pub fn flush(&mut self) {
+        while let Some((_, (timestamp, nal_unit))) = self.reorder_buffer.pop_first() {
+            self.push_ordered(timestamp, &nal_unit);
+        }
+        self.finish_access_unit(None);
+        self.corrupted_timestamps.clear();
+    }
+
+    fn has_don(&self) -> bool {
+        self.max_don_diff > 0
+    }
+
+    fn push_payload(&mut self, timestamp: u32, payload: &[u8]) -> Result<(), DepacketizerError> {
+        if payload.len() <= NAL_HEADER_SIZE {
+            return Err(DepacketizerError::Truncated("payload header"));
+        }
+
+        match nal_unit_type(payload[0]) {
+            0..=47 if self.has_don() => {
+                let don = read_don(payload, NAL_HEADER_SIZE)?;
+                let nal_unit = [&payload[..NAL_HEADER_SIZE], &payload[NAL_HEADER_SIZE + 2..]].concat();
+                self.push_nal_unit(timestamp, Some(don), &nal_unit);
+            }
+            0..=47 => self.push_nal_unit(timestamp, None, payload),
+            AP => self.push_aggregation(timestamp, &payload[NAL_HEADER_SIZE..])?,
+            FU => self.push_fragment(timestamp, payload)?,
+            packet_type => return Err(DepacketizerError::UnexpectedPacketType(packet_type)),
+        }
+        Ok(())
+    }
+
+    fn push_aggregation(&mut self, timestamp: u32, mut data: &[u8]) -> Result<(), DepacketizerError> {
+        let mut don: Option<u16> = None;
+        while !data.is_empty() {
+            if self.has_don() {
+                don = Some(match don {
+                    None => {
+                        let first = read_don(data, 0)?;
+                        data = &data[2..];
+                        first
+                    }
+                    Some(previous) => {
+                        let difference = data[0];
+                        data = &data[1..];
+                        previous.wrapping_add(difference as u16).wrapping_add(1)
+                    }
+                });
+            }
+            if data.len() < 2 {
+                return Err(DepacketizerError::Truncated("aggregation unit size"));
+            }
+            let size = BigEndian::read_u16(&data[..2]) as usize;
+            if size < NAL_HEADER_SIZE || data.len() < 2 + size {
+                return Err(DepacketizerError::Truncated("aggregation unit"));
+            }
+            self.push_nal_unit(timestamp, don, &data[2..2 + size]);
+            data = &data[2 + size..];
+        }
+        Ok(())
+    }
+
+    fn push_fragment(&mut self, timestamp: u32, payload: &[u8]) -> Result<(), DepacketizerError> {
+        let fu_header = payload[NAL_HEADER_SIZE];
+        let start = fu_header & FU_START_MASK != 0;
+        let data_start = if start && self.has_don() { NAL_HEADER_SIZE + 3 } else { NAL_HEADER_SIZE + 1 };
+        if payload.len() <= data_start {
+            return Err(DepacketizerError::Truncated("FU"));
+        }
+        let data = &payload[data_start..];
+
+        if start {
+            let don = if self.has_don() { Some(read_don(payload, NAL_HEADER_SIZE + 1)?) } else { None };
+            let mut nal_unit = Vec::with_capacity(data.len() + NAL_HEADER_SIZE);
+            nal_unit.push(with_nal_unit_type(payload[0], fu_header & FU_TYPE_MASK));
+            nal_unit.push(payload[1]);
+            nal_unit.extend_from_slice(data);
+            self.fragment = Some(Fragment { timestamp, don, nal_unit });
+        }
+        else if let Some(fragment) = self.fragment.as_mut() {
+            fragment.nal_unit.extend_from_slice(data);
+        }
+        else {
+            return Ok(());
+        }
+
+        if fu_header & FU_END_MASK != 0 {
+            if let Some(fragment) = self.fragment.take() {
+                self.push_nal_unit(fragment.timestamp, fragment.don, &fragment.nal_unit);
+            }
+        }
+        Ok(())
+    }
+
+    fn push_nal_unit(&mut self, timestamp: u32, don: Option<u16>, nal_unit: &[u8]) {
+        let Some(don) = don else {
+            self.append(nal_unit);
+            return;
+        };
+
+        let don = self.extend_don(don);
+        self.reorder_buffer.insert(don, (timestamp, nal_unit.to_vec()));
+        while self.reorder_buffer.len() > self.max_don_diff {
+            if let Some((_, (timestamp, nal_unit))) = self.reorder_buffer.pop_first() {
+                self.push_ordered(timestamp, &nal_unit);
+            }
+        }
+    }
+
+    fn push_ordered(&mut self, timestamp: u32, nal_unit: &[u8]) {
+        if self.timestamp.is_some_and(|current| current != timestamp) {
+            self.finish_access_unit(Some(timestamp));
+        }
+        self.timestamp = Some(timestamp);
+        if self.corrupted_timestamps.remove(&timestamp) {
+            self.corrupted = true;
+        }
+        self.append(nal_unit);
+    }
+
+    /// Drops the access unit with the given timestamp, whether it is being assembled or still reordered.
+    fn mark_corrupted(&mut self, timestamp: u32) {
+        if self.timestamp == Some(timestamp) {
+            self.corrupted = true;
+        }
+        else if self.reorder_buffer.values().any(|(buffered, _)| *buffered == timestamp) {
+            self.corrupted_timestamps.insert(timestamp);
+        }
+    }
+
+    fn append(&mut self, nal_unit: &[u8]) {
+        let nal_unit_type = nal_unit_type(nal_unit[0]);
+        if is_irap(nal_unit_type) {
+            self.keyframe = true;
+        }
+        if nal_unit_type == SPS {
+            self.has_parameter_sets = true;
+        }
+        push_nal_unit(&mut self.access_unit, nal_unit);
+    }
+
+    /// Closes the access unit being assembled.
+    ///
+    /// # Arguments
+    ///
+    /// * `next_timestamp` - The timestamp of the reordered NAL unit that starts the next access unit, if any.
+    fn finish_access_unit(&mut self, next_timestamp: Option<u32>) {
+        let access_unit = std::mem::take(&mut self.access_unit);
+        if !access_unit.is_empty() && !self.corrupted {
+            let data = if self.keyframe && !self.has_parameter_sets {
+                [self.parameter_sets.as_slice(), access_unit.as_slice()].concat()
+            }
+            else {
+                access_unit
+            };
+            let timestamp = self.timestamp.unwrap_or_default();
+            let mut access_unit = AccessUnit::new(timestamp, self.keyframe, data);
+            if self.max_don_diff > 0 {
+                let following = next_timestamp.into_iter().chain(self.reorder_buffer.values().map(|(timestamp, _)| *timestamp));
+                let dts = decode_timestamp(timestamp, following, self.last_decode_timestamp);
+                self.last_decode_timestamp = Some(dts);
+                access_unit = access_unit.with_decode_timestamp(dts);
+            }
+            self.ready.push_back(access_unit);
+        }
+        self.keyframe = false;
+        self.has_parameter_sets = false;
+        self.corrupted = false;
+    }
+
+    /// Unwraps a 16-bit decoding order number relative to the last one seen.
+    fn extend_don(&mut self, don: u16) -> i64 {
+        let extended = match self.last_don {
+            Some(last) => last + don.wrapping_sub(last as u16) as i16 as i64,
+            None => DON_BASE + don as i64,
+        };
+        self.last_don = Some(self.last_don.map_or(extended, |last| last.max(extended)));
+        extended
+    }
+}
+
+fn read_don(data: &[u8], offset: usize) -> Result<u16, DepacketizerError> {
+    if data.len() < offset + 2 {
+        return Err(DepacketizerError::Truncated("decoding order number"));
+    }
+    Ok(BigEndian::read_u16(&data[offset..offset + 2]))
+}
+
+impl Depacketizer for H265Depacketizer {
+    fn push(&mut self, packet: &RtpPacket) -> Result<(), DepacketizerError> {
+        H265Depacketizer::push(self, packet)
+    }
+
+    fn pull(&mut self) -> Option<MediaFrame> {
+        H265Depacketizer::pull(self).map(|access_unit| MediaFrame::from_access_unit(access_unit, h265_codec::NAME))
+    }
+
+    fn lost_packets(&self) -> u64 {
+        H265Depacketizer::lost_packets(self)
+    }
+
+    fn flush(&mut self) {
+        H265Depacketizer::flush(self)
+    }
+}
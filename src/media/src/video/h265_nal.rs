@@ -0,0 +1,97 @@
+use crate::{config_error::ConfigError, video::annex_b::to_rbsp};
+
+/// Size of the NAL unit header and of the payload header.
+pub(crate) const NAL_HEADER_SIZE: usize = 2;
+
+/// Mask of the forbidden zero bit in the first byte of the NAL unit header.
+pub(crate) const FORBIDDEN_MASK: u8 = 0b1000_0000;
+/// Mask of the `Type` field in the first byte of the NAL unit header.
+pub(crate) const NAL_UNIT_TYPE_MASK: u8 = 0b0111_1110;
+/// Mask of the high bit of `LayerId` in the first byte of the NAL unit header.
+pub(crate) const LAYER_ID_HIGH_MASK: u8 = 0b0000_0001;
+/// Mask of the `TID` field in the second byte of the NAL unit header.
+pub(crate) const TID_MASK: u8 = 0b0000_0111;
+/// Number of `LayerId` bits in the second byte of the NAL unit header.
+const LAYER_ID_LOW_BITS: u8 = 5;
+
+/// First intra random access point picture type, a BLA picture.
+pub(crate) const IRAP_FIRST: u8 = 16;
+/// Last intra random access point picture type, reserved for future IRAP pictures.
+pub(crate) const IRAP_LAST: u8 = 23;
+pub(crate) const VPS: u8 = 32;
+pub(crate) const SPS: u8 = 33;
+pub(crate) const PPS: u8 = 34;
+
+/// Aggregation packet.
+pub(crate) const AP: u8 = 48;
+/// Fragmentation unit.
+pub(crate) const FU: u8 = 49;
+
+/// Start bit of the FU header.
+pub(crate) const FU_START_MASK: u8 = 0b1000_0000;
+/// End bit of the FU header.
+pub(crate) const FU_END_MASK: u8 = 0b0100_0000;
+/// Mask of the `FuType` field of the FU header.
+pub(crate) const FU_TYPE_MASK: u8 = 0b0011_1111;
+
+/// Returns the `Type` field of the first byte of a NAL unit header.
+pub(crate) fn nal_unit_type(header: u8) -> u8 {
+    (header & NAL_UNIT_TYPE_MASK) >> 1
+}
+
+/// Returns the first byte of a NAL unit header with its `Type` field replaced.
+pub(crate) fn with_nal_unit_type(header: u8, nal_unit_type: u8) -> u8 {
+    (header & !NAL_UNIT_TYPE_MASK) | (nal_unit_type << 1)
+}
+
+/// Returns whether the NAL unit type is an intra random access point picture.
+pub(crate) fn is_irap(nal_unit_type: u8) -> bool {
+    (IRAP_FIRST..=IRAP_LAST).contains(&nal_unit_type)
+}
+
+/// Returns the `LayerId` field of a NAL unit header.
+pub(crate) fn layer_id(header: &[u8]) -> u8 {
+    ((header[0] & LAYER_ID_HIGH_MASK) << LAYER_ID_LOW_BITS) | (header[1] >> 3)
+}
+
+/// Returns the `TID` field of a NAL unit header.
+pub(crate) fn temporal_id(header: &[u8]) -> u8 {
+    header[1] & TID_MASK
+}
+
+/// Builds a two-byte NAL unit header.
+///
+/// # Arguments
+///
+/// * `forbidden` - The forbidden zero bit, still in place.
+/// * `nal_unit_type` - The `Type` field.
+/// * `layer_id` - The `LayerId` field.
+/// * `temporal_id` - The `TID` field.
+pub(crate) fn nal_unit_header(forbidden: u8, nal_unit_type: u8, layer_id: u8, temporal_id: u8) -> [u8; 2] {
+    [
+        forbidden | (nal_unit_type << 1) | (layer_id >> LAYER_ID_LOW_BITS),
+        (layer_id << 3) | temporal_id,
+    ]
+}
+
+/// Checks the type of a parameter set NAL unit and removes its header and emulation prevention bytes.
+///
+/// # Arguments
+///
+/// * `nal_unit` - The NAL unit, header included.
+/// * `expected_type` - The NAL unit type of the parameter set.
+/// * `name` - The name of the NAL unit type, reported if it differs.
+///
+/// # Returns
+///
+/// A `Result` containing the raw byte sequence payload, or a `ConfigError` if the NAL unit is not of the expected type.
+pub(crate) fn parameter_set_rbsp(nal_unit: &[u8], expected_type: u8, name: &'static str) -> Result<Vec<u8>, ConfigError> {
+    if nal_unit.len() < NAL_HEADER_SIZE {
+        return Err(ConfigError::Truncated("NAL unit header"));
+    }
+    let nal_unit_type = nal_unit_type(nal_unit[0]);
+    if nal_unit_type != expected_type {
+        return Err(ConfigError::Unsupported(name, nal_unit_type as u32));
+    }
+    Ok(to_rbsp(&nal_unit[NAL_HEADER_SIZE..]))
+}
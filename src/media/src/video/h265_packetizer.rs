@@ -0,0 +1,282 @@
+use std::{
+    collections::hash_map::RandomState,
+    hash::{BuildHasher, Hasher},
+};
+
+use rtp::{rtp_header::RtpHeader, rtp_packet::RtpPacket};
+
+use crate::{
+    codec::Codec,
+    packetizer_error::PacketizerError,
+    video::{
+        annex_b, avcc,
+        h265_codec::H265Codec,
+        h265_nal::{
+            layer_id, nal_unit_header, nal_unit_type, temporal_id, with_nal_unit_type, AP, FORBIDDEN_MASK, FU,
+            FU_END_MASK, FU_START_MASK, NAL_HEADER_SIZE,
+        },
+    },
+};
+
+/// Size of an RTP header without CSRCs or extensions.
+const RTP_HEADER_SIZE: usize = 12;
+/// Size of the FU header.
+const FU_HEADER_SIZE: usize = 1;
+/// Size of a decoding order number.
+const DONL_SIZE: usize = 2;
+/// Size of a decoding order number difference.
+const DOND_SIZE: usize = 1;
+/// Size of the NAL unit size field of an aggregation unit.
+const AGGREGATION_UNIT_SIZE: usize = 2;
+/// Smallest payload that leaves room for data after every payload header.
+const MIN_PAYLOAD_SIZE: usize = 16;
+
+/// Splits H.265 access units into RTP packets as defined in RFC 7798.
+///
+/// Consecutive NAL units that fit into one packet are aggregated into aggregation packets,
+/// NAL units larger than the MTU are split into fragmentation units and all other NAL units
+/// are sent in single NAL unit packets. When `sprop-max-don-diff` is greater than zero, every
+/// NAL unit is sent with a decoding order number, which is assigned in transmission order.
+/// The marker bit is set on the last packet of every access unit.
+#[derive(Debug)]
+pub struct H265Packetizer {
+    payload_type: u8,
+    has_don: bool,
+    ssrc: u32,
+    max_payload_size: usize,
+    sequence_number: u16,
+    don: u16,
+    payloads: Vec<Vec<u8>>,
+}
+
+impl H265Packetizer {
+    /// Creates a new `H265Packetizer` starting at a random sequence number.
+    ///
+    /// # Arguments
+    ///
+    /// * `codec` - The codec advertised in SDP, providing the payload type and `sprop-max-don-diff`.
+    /// * `ssrc` - The synchronization source of the stream.
+    /// * `mtu` - The maximum size of an RTP packet, header included.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the packetizer, or a `PacketizerError` if the MTU cannot fit a fragmentation unit.
+    pub fn new(codec: &H265Codec, ssrc: u32, mtu: usize) -> Result<Self, PacketizerError> {
+        if mtu < RTP_HEADER_SIZE + MIN_PAYLOAD_SIZE {
+            return Err(PacketizerError::MtuTooSmall(mtu));
+        }
+        Ok(Self {
+            payload_type: codec.format() as u8,
+            has_don: codec.max_don_diff() > 0,
+            ssrc,
+            max_payload_size: mtu - RTP_HEADER_SIZE,
+            sequence_number: RandomState::new().build_hasher().finish() as u16,
+            don: 0,
+            payloads: Vec::new(),
+        })
+    }
+
+    /// Returns the sequence number of the next packet.
+    pub fn sequence_number(&self) -> u16 {
+        self.sequence_number
+    }
+
+    /// Packetizes an access unit in Annex-B format.
+    ///
+    /// # Arguments
+    ///
+    /// * `timestamp` - The RTP timestamp of the access unit.
+    /// * `access_unit` - The NAL units of the access unit, each preceded by a start code.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the packets of the access unit, or a `PacketizerError` if a NAL unit
+    /// is shorter than its header.
+    pub fn packetize_annex_b(&mut self, timestamp: u32, access_unit: &[u8]) -> Result<Vec<RtpPacket<'_>>, PacketizerError> {
+        let nal_units = annex_b::split_nal_units(access_unit).collect::<Vec<_>>();
+        self.packetize(timestamp, &nal_units)
+    }
+
+    /// Packetizes an access unit in HVCC format.
+    ///
+    /// # Arguments
+    ///
+    /// * `timestamp` - The RTP timestamp of the access unit.
+    /// * `access_unit` - The NAL units of the access unit, each preceded by its length.
+    /// * `length_size` - The size of each length prefix in bytes.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the packets of the access unit, or a `PacketizerError` if the sample
+    /// is malformed.
+    pub fn packetize_hvcc(&mut self, timestamp: u32, access_unit: &[u8], length_size: usize) -> Result<Vec<RtpPacket<'_>>, PacketizerError> {
+        let nal_units = avcc::split_nal_units(access_unit, length_size)?;
+        self.packetize(timestamp, &nal_units)
+    }
+
+    /// Packetizes the NAL units of one access unit.
+    ///
+    /// # Arguments
+    ///
+    /// * `timestamp` - The RTP timestamp of the access unit.
+    /// * `nal_units` - The NAL units without start codes or length prefixes.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the packets of the access unit, or a `PacketizerError` if a NAL unit
+    /// is shorter than its header.
+    pub fn packetize(&mut self, timestamp: u32, nal_units: &[&[u8]]) -> Result<Vec<RtpPacket<'_>>, PacketizerError> {
+        if nal_units.iter().any(|nal_unit| nal_unit.len() < NAL_HEADER_SIZE) {
+            return Err(PacketizerError::Truncated("NAL unit header"));
+        }
+        self.payloads.clear();
+        self.aggregate(nal_units);
+
+        let sequence_number = self.sequence_number;
+        self.sequence_number = sequence_number.wrapping_add(self.payloads.len() as u16);
+        let last = self.payloads.len().saturating_sub(1);
+        Ok(self
+            .payloads
+            .iter()
+            .enumerate()
+            .map(|(index, payload)| {
+                let header = RtpHeader::new(
+                    2,
+                    false,
+                    false,
+                    0,
+                    index == last,
+                    self.payload_type,
+                    sequence_number.wrapping_add(index as u16),
+                    timestamp,
+                    self.ssrc,
+                    &[],
+                );
+                RtpPacket::new(header, payload)
+            })
+            .collect())
+    }
+
+    /// Returns the size a NAL unit takes in an aggregation packet.
+    fn aggregation_unit_size(&self, nal_unit: &[u8], first: bool) -> usize {
+        let don_size = match (self.has_don, first) {
+            (false, _) => 0,
+            (true, true) => DONL_SIZE,
+            (true, false) => DOND_SIZE,
+        };
+        don_size + AGGREGATION_UNIT_SIZE + nal_unit.len()
+    }
+
+    fn aggregate(&mut self, nal_units: &[&[u8]]) {
+        let single_don_size = if self.has_don { DONL_SIZE } else { 0 };
+        let mut pending: Vec<&[u8]> = Vec::new();
+        let mut pending_size = NAL_HEADER_SIZE;
+
+        for nal_unit in nal_units {
+            let unit_size = self.aggregation_unit_size(nal_unit, pending.is_empty());
+            if pending_size + unit_size <= self.max_payload_size {
+                pending.push(nal_unit);
+                pending_size += unit_size;
+                continue;
+            }
+            self.push_aggregate(&pending);
+            pending.clear();
+            pending_size = NAL_HEADER_SIZE;
+
+            let unit_size = self.aggregation_unit_size(nal_unit, true);
+            if pending_size + unit_size <= self.max_payload_size {
+                pending.push(nal_unit);
+                pending_size += unit_size;
+            }
+            else if nal_unit.len() + single_don_size <= self.max_payload_size {
+                self.push_single(nal_unit);
+            }
+            else {
+                self.push_fragments(nal_unit);
+            }
+        }
+        self.push_aggregate(&pending);
+    }
+
+    /// Pushes the NAL units as a single NAL unit packet or as an aggregation packet.
+    fn push_aggregate(&mut self, nal_units: &[&[u8]]) {
+        match nal_units {
+            [] => {}
+            [nal_unit] => self.push_single(nal_unit),
+            _ => {
+                let forbidden = nal_units.iter().fold(0, |bits, nal_unit| bits | (nal_unit[0] & FORBIDDEN_MASK));
+                let layer_id = nal_units.iter().map(|nal_unit| layer_id(nal_unit)).min().unwrap_or_default();
+                let temporal_id = nal_units.iter().map(|nal_unit| temporal_id(nal_unit)).min().unwrap_or_default();
+                let mut payload = nal_unit_header(forbidden, AP, layer_id, temporal_id).to_vec();
+                for (index, nal_unit) in nal_units.iter().enumerate() {
+                    if self.has_don {
+                        if index == 0 {
+                            payload.extend_from_slice(&self.don.to_be_bytes());
+                        }
+                        else {
+                            // Decoding order numbers are consecutive, so the difference minus one is always zero.
+                            payload.push(0);
+                        }
+                        self.don = self.don.wrapping_add(1);
+                    }
+                    payload.extend_from_slice(&(nal_unit.len() as u16).to_be_bytes());
+                    payload.extend_from_slice(nal_unit);
+                }
+                self.payloads.push(payload);
+            }
+        }
+    }
+
+    /// Pushes a NAL unit as a single NAL unit packet.
+    fn push_single(&mut self, nal_unit: &[u8]) {
+        if !self.has_don {
+            self.payloads.push(nal_unit.to_vec());
+            return;
+        }
+        let mut payload = Vec::with_capacity(nal_unit.len() + DONL_SIZE);
+        payload.extend_from_slice(&nal_unit[..NAL_HEADER_SIZE]);
+        payload.extend_from_slice(&self.don.to_be_bytes());
+        payload.extend_from_slice(&nal_unit[NAL_HEADER_SIZE..]);
+        self.don = self.don.wrapping_add(1);
+        self.payloads.push(payload);
+    }
+
+    /// Pushes a NAL unit split into fragmentation units.
+    fn push_fragments(&mut self, nal_unit: &[u8]) {
+        let payload_header = [with_nal_unit_type(nal_unit[0], FU), nal_unit[1]];
+        let fu_type = nal_unit_type(nal_unit[0]);
+        let mut data = &nal_unit[NAL_HEADER_SIZE..];
+        let mut start = true;
+
+        while !data.is_empty() {
+            let mut payload = Vec::with_capacity(self.max_payload_size);
+            let has_don = start && self.has_don;
+            let header_size = NAL_HEADER_SIZE + FU_HEADER_SIZE + if has_don { DONL_SIZE } else { 0 };
+            let mut size = data.len().min(self.max_payload_size - header_size);
+            if start && size == data.len() {
+                // A fragmentation unit must not have both the start and the end bit set.
+                size -= 1;
+            }
+            let end = size == data.len();
+
+            let mut fu_header = fu_type;
+            if start {
+                fu_header |= FU_START_MASK;
+            }
+            if end {
+                fu_header |= FU_END_MASK;
+            }
+            payload.extend_from_slice(&payload_header);
+            payload.push(fu_header);
+            if has_don {
+                payload.extend_from_slice(&self.don.to_be_bytes());
+                self.don = self.don.wrapping_add(1);
+            }
+            payload.extend_from_slice(&data[..size]);
+            self.payloads.push(payload);
+
+            data = &data[size..];
+            start = false;
+        }
+    }
+}
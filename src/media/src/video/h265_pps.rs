@@ -0,0 +1,217 @@
+use std::ops::RangeInclusive;
+
+use crate::{
+    bit_reader::BitReader,
+    config_error::ConfigError,
+    video::h265_nal::{parameter_set_rbsp, PPS},
+};
+
+/// Highest `pps_pic_parameter_set_id`.
+const MAX_PIC_PARAMETER_SET_ID: u32 = 63;
+/// Highest `pps_seq_parameter_set_id`.
+const MAX_SEQ_PARAMETER_SET_ID: u32 = 15;
+/// Highest `num_ref_idx_l0_default_active_minus1` and `num_ref_idx_l1_default_active_minus1`.
+const MAX_REF_IDX_DEFAULT_ACTIVE_MINUS1: u32 = 14;
+/// Range of `init_qp_minus26`, down to the lowest QP of 16-bit samples.
+const INIT_QP_MINUS26: RangeInclusive<i32> = -74..=25;
+/// Range of `pps_cb_qp_offset` and `pps_cr_qp_offset`.
+const CHROMA_QP_OFFSET: RangeInclusive<i32> = -12..=12;
+
+/// Represents an H.265 picture parameter set, ITU-T H.265 section 7.3.2.3.
+///
+/// Parsing stops after the tile layout; the deblocking, scaling list and extension fields are not read.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct H265Pps {
+    pic_parameter_set_id: u32,
+    seq_parameter_set_id: u32,
+    dependent_slice_segments_enabled: bool,
+    output_flag_present: bool,
+    num_extra_slice_header_bits: u8,
+    sign_data_hiding_enabled: bool,
+    cabac_init_present: bool,
+    num_ref_idx_l0_default_active: u32,
+    num_ref_idx_l1_default_active: u32,
+    init_qp: i32,
+    constrained_intra_pred: bool,
+    transform_skip_enabled: bool,
+    cu_qp_delta_enabled: bool,
+    cb_qp_offset: i32,
+    cr_qp_offset: i32,
+    weighted_pred: bool,
+    weighted_bipred: bool,
+    transquant_bypass_enabled: bool,
+    tiles_enabled: bool,
+    entropy_coding_sync_enabled: bool,
+    num_tile_columns: u32,
+    num_tile_rows: u32,
+}
+
+impl H265Pps {
+    pub fn pic_parameter_set_id(&self) -> u32 {
+        self.pic_parameter_set_id
+    }
+
+    pub fn seq_parameter_set_id(&self) -> u32 {
+        self.seq_parameter_set_id
+    }
+
+    pub fn dependent_slice_segments_enabled(&self) -> bool {
+        self.dependent_slice_segments_enabled
+    }
+
+    pub fn output_flag_present(&self) -> bool {
+        self.output_flag_present
+    }
+
+    pub fn num_extra_slice_header_bits(&self) -> u8 {
+        self.num_extra_slice_header_bits
+    }
+
+    pub fn sign_data_hiding_enabled(&self) -> bool {
+        self.sign_data_hiding_enabled
+    }
+
+    pub fn cabac_init_present(&self) -> bool {
+        self.cabac_init_present
+    }
+
+    pub fn num_ref_idx_l0_default_active(&self) -> u32 {
+        self.num_ref_idx_l0_default_active
+    }
+
+    pub fn num_ref_idx_l1_default_active(&self) -> u32 {
+        self.num_ref_idx_l1_default_active
+    }
+
+    pub fn init_qp(&self) -> i32 {
+        self.init_qp
+    }
+
+    pub fn constrained_intra_pred(&self) -> bool {
+        self.constrained_intra_pred
+    }
+
+    pub fn transform_skip_enabled(&self) -> bool {
+        self.transform_skip_enabled
+    }
+
+    pub fn cu_qp_delta_enabled(&self) -> bool {
+        self.cu_qp_delta_enabled
+    }
+
+    pub fn cb_qp_offset(&self) -> i32 {
+        self.cb_qp_offset
+    }
+
+    pub fn cr_qp_offset(&self) -> i32 {
+        self.cr_qp_offset
+    }
+
+    pub fn weighted_pred(&self) -> bool {
+        self.weighted_pred
+    }
+
+    pub fn weighted_bipred(&self) -> bool {
+        self.weighted_bipred
+    }
+
+    pub fn transquant_bypass_enabled(&self) -> bool {
+        self.transquant_bypass_enabled
+    }
+
+    pub fn tiles_enabled(&self) -> bool {
+        self.tiles_enabled
+    }
+
+    /// Returns whether wavefront parallel processing may be used.
+    pub fn entropy_coding_sync_enabled(&self) -> bool {
+        self.entropy_coding_sync_enabled
+    }
+
+    /// Returns the number of tile columns, one when tiles are disabled.
+    pub fn num_tile_columns(&self) -> u32 {
+        self.num_tile_columns
+    }
+
+    /// Returns the number of tile rows, one when tiles are disabled.
+    pub fn num_tile_rows(&self) -> u32 {
+        self.num_tile_rows
+    }
+}
+
+impl TryFrom<&[u8]> for H265Pps {
+    type Error = ConfigError;
+
+    /// Parses a picture parameter set NAL unit, header included.
+    fn try_from(nal_unit: &[u8]) -> Result<Self, Self::Error> {
+        let rbsp = parameter_set_rbsp(nal_unit, PPS, "PPS NAL unit type")?;
+        let mut reader = BitReader::new(&rbsp);
+
+        let pic_parameter_set_id = reader.ue_field_max("pps_pic_parameter_set_id", MAX_PIC_PARAMETER_SET_ID)?;
+        let seq_parameter_set_id = reader.ue_field_max("pps_seq_parameter_set_id", MAX_SEQ_PARAMETER_SET_ID)?;
+        let dependent_slice_segments_enabled = reader.flag("dependent_slice_segments_enabled_flag")?;
+        let output_flag_present = reader.flag("output_flag_present_flag")?;
+        let num_extra_slice_header_bits = reader.field(3, "num_extra_slice_header_bits")? as u8;
+        let sign_data_hiding_enabled = reader.flag("sign_data_hiding_enabled_flag")?;
+        let cabac_init_present = reader.flag("cabac_init_present_flag")?;
+        let num_ref_idx_l0_default_active =
+            reader.ue_field_max("num_ref_idx_l0_default_active_minus1", MAX_REF_IDX_DEFAULT_ACTIVE_MINUS1)? + 1;
+        let num_ref_idx_l1_default_active =
+            reader.ue_field_max("num_ref_idx_l1_default_active_minus1", MAX_REF_IDX_DEFAULT_ACTIVE_MINUS1)? + 1;
+        let init_qp = 26 + reader.se_field_in("init_qp_minus26", INIT_QP_MINUS26)?;
+        let constrained_intra_pred = reader.flag("constrained_intra_pred_flag")?;
+        let transform_skip_enabled = reader.flag("transform_skip_enabled_flag")?;
+        let cu_qp_delta_enabled = reader.flag("cu_qp_delta_enabled_flag")?;
+        if cu_qp_delta_enabled {
+            reader.ue_field("diff_cu_qp_delta_depth")?;
+        }
+        let cb_qp_offset = reader.se_field_in("pps_cb_qp_offset", CHROMA_QP_OFFSET)?;
+        let cr_qp_offset = reader.se_field_in("pps_cr_qp_offset", CHROMA_QP_OFFSET)?;
+        reader.flag("pps_slice_chroma_qp_offsets_present_flag")?;
+        let weighted_pred = reader.flag("weighted_pred_flag")?;
+        let weighted_bipred = reader.flag("weighted_bipred_flag")?;
+        let transquant_bypass_enabled = reader.flag("transquant_bypass_enabled_flag")?;
+        let tiles_enabled = reader.flag("tiles_enabled_flag")?;
+        let entropy_coding_sync_enabled = reader.flag("entropy_coding_sync_enabled_flag")?;
+
+        let mut num_tile_columns = 1;
+        let mut num_tile_rows = 1;
+        if tiles_enabled {
+            num_tile_columns = reader.ue_field("num_tile_columns_minus1")? + 1;
+            num_tile_rows = reader.ue_field("num_tile_rows_minus1")? + 1;
+            if !reader.flag("uniform_spacing_flag")? {
+                for _ in 1..num_tile_columns {
+                    reader.ue_field("column_width_minus1")?;
+                }
+                for _ in 1..num_tile_rows {
+                    reader.ue_field("row_height_minus1")?;
+                }
+            }
+        }
+
+        Ok(Self {
+            pic_parameter_set_id,
+            seq_parameter_set_id,
+            dependent_slice_segments_enabled,
+            output_flag_present,
+            num_extra_slice_header_bits,
+            sign_data_hiding_enabled,
+            cabac_init_present,
+            num_ref_idx_l0_default_active,
+            num_ref_idx_l1_default_active,
+            init_qp,
+            constrained_intra_pred,
+            transform_skip_enabled,
+            cu_qp_delta_enabled,
+            cb_qp_offset,
+            cr_qp_offset,
+            weighted_pred,
+            weighted_bipred,
+            transquant_bypass_enabled,
+            tiles_enabled,
+            entropy_coding_sync_enabled,
+            num_tile_columns,
+            num_tile_rows,
+        })
+    }
+}
@@ -0,0 +1,117 @@
+use crate::{bit_reader::BitReader, config_error::ConfigError};
+
+/// Highest number of sub-layers, for which the sub-layer flags are padded.
+const MAX_SUB_LAYERS: u32 = 8;
+
+/// Number of bits of `sub_layer_profile_space` through `sub_layer_inbld_flag`.
+const SUB_LAYER_PROFILE_BITS: usize = 88;
+
+/// Represents the general profile, tier and level of an H.265 stream, ITU-T H.265 section 7.3.3.
+///
+/// # Fields
+///
+/// * `profile_space` - The `general_profile_space`, 0 for the profiles of ITU-T H.265.
+/// * `high_tier` - Whether the `general_tier_flag` selects the high tier.
+/// * `profile_idc` - The `general_profile_idc`, 1 for Main and 2 for Main 10.
+/// * `profile_compatibility_flags` - The 32 `general_profile_compatibility_flag` bits.
+/// * `progressive_source` - The `general_progressive_source_flag`.
+/// * `interlaced_source` - The `general_interlaced_source_flag`.
+/// * `level_idc` - The `general_level_idc`, thirty times the level number.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ProfileTierLevel {
+    profile_space: u8,
+    high_tier: bool,
+    profile_idc: u8,
+    profile_compatibility_flags: u32,
+    progressive_source: bool,
+    interlaced_source: bool,
+    level_idc: u8,
+}
+
+impl ProfileTierLevel {
+    pub fn new(
+        profile_space: u8,
+        high_tier: bool,
+        profile_idc: u8,
+        profile_compatibility_flags: u32,
+        progressive_source: bool,
+        interlaced_source: bool,
+        level_idc: u8,
+    ) -> Self {
+        Self { profile_space, high_tier, profile_idc, profile_compatibility_flags, progressive_source, interlaced_source, level_idc }
+    }
+
+    pub fn profile_space(&self) -> u8 {
+        self.profile_space
+    }
+
+    pub fn high_tier(&self) -> bool {
+        self.high_tier
+    }
+
+    pub fn profile_idc(&self) -> u8 {
+        self.profile_idc
+    }
+
+    pub fn profile_compatibility_flags(&self) -> u32 {
+        self.profile_compatibility_flags
+    }
+
+    pub fn progressive_source(&self) -> bool {
+        self.progressive_source
+    }
+
+    pub fn interlaced_source(&self) -> bool {
+        self.interlaced_source
+    }
+
+    pub fn level_idc(&self) -> u8 {
+        self.level_idc
+    }
+
+    /// Returns the level number, such as 4.1 for a `level_idc` of 123.
+    pub fn level(&self) -> f64 {
+        self.level_idc as f64 / 30.0
+    }
+
+    /// Reads a `profile_tier_level` structure with its profile present and skips the sub-layer entries.
+    ///
+    /// # Arguments
+    ///
+    /// * `reader` - The reader positioned at the structure.
+    /// * `max_sub_layers_minus1` - The `max_sub_layers_minus1` of the enclosing parameter set.
+    pub(crate) fn parse(reader: &mut BitReader, max_sub_layers_minus1: u32) -> Result<Self, ConfigError> {
+        let profile_space = reader.field(2, "general_profile_space")? as u8;
+        let high_tier = reader.flag("general_tier_flag")?;
+        let profile_idc = reader.field(5, "general_profile_idc")? as u8;
+        let profile_compatibility_flags = reader.field(32, "general_profile_compatibility_flags")?;
+        let progressive_source = reader.flag("general_progressive_source_flag")?;
+        let interlaced_source = reader.flag("general_interlaced_source_flag")?;
+        // general_non_packed_constraint_flag, general_frame_only_constraint_flag,
+        // the 43 reserved or constraint bits and general_inbld_flag.
+        reader.skip_bits(46).ok_or(ConfigError::Truncated("general_constraint_flags"))?;
+        let level_idc = reader.field(8, "general_level_idc")? as u8;
+
+        let mut sub_layers = Vec::with_capacity(max_sub_layers_minus1 as usize);
+        for _ in 0..max_sub_layers_minus1 {
+            let profile_present = reader.flag("sub_layer_profile_present_flag")?;
+            let level_present = reader.flag("sub_layer_level_present_flag")?;
+            sub_layers.push((profile_present, level_present));
+        }
+        if max_sub_layers_minus1 > 0 {
+            for _ in max_sub_layers_minus1..MAX_SUB_LAYERS {
+                reader.field(2, "reserved_zero_2bits")?;
+            }
+        }
+        for (profile_present, level_present) in sub_layers {
+            if profile_present {
+                reader.skip_bits(SUB_LAYER_PROFILE_BITS).ok_or(ConfigError::Truncated("sub_layer_profile"))?;
+            }
+            if level_present {
+                reader.field(8, "sub_layer_level_idc")?;
+            }
+        }
+
+        Ok(Self::new(profile_space, high_tier, profile_idc, profile_compatibility_flags, progressive_source, interlaced_source, level_idc))
+    }
+}
@@ -0,0 +1,383 @@
+use crate::{
+    bit_reader::BitReader,
+    config_error::ConfigError,
+    video::{
+        h265_nal::{parameter_set_rbsp, SPS},
+        h265_profile_tier_level::ProfileTierLevel,
+        h265_vps::read_sub_layer_ordering_info,
+        vui_timing::VuiTiming,
+    },
+};
+
+/// `aspect_ratio_idc` followed by an explicit sample aspect ratio.
+const EXTENDED_SAR: u32 = 255;
+
+/// Chroma format with a separate colour plane option.
+const CHROMA_444: u32 = 3;
+
+/// Highest `sps_seq_parameter_set_id`.
+const MAX_SEQ_PARAMETER_SET_ID: u32 = 15;
+
+/// Highest `bit_depth_luma_minus8` and `bit_depth_chroma_minus8`, for 16-bit samples.
+const MAX_BIT_DEPTH_MINUS8: u32 = 8;
+
+/// Highest `log2_max_pic_order_cnt_lsb_minus4`.
+const MAX_LOG2_MAX_PIC_ORDER_CNT_LSB_MINUS4: u32 = 12;
+
+/// Highest `num_long_term_ref_pics_sps`.
+const MAX_LONG_TERM_REF_PICS_SPS: u32 = 32;
+
+/// Number of scaling list sizes, from 4x4 to 32x32.
+const SCALING_LIST_SIZES: u32 = 4;
+
+/// Represents the conformance cropping window of an H.265 sequence parameter set.
+///
+/// # Fields
+///
+/// * `left` - The `conf_win_left_offset`, in chroma sample units.
+/// * `right` - The `conf_win_right_offset`, in chroma sample units.
+/// * `top` - The `conf_win_top_offset`, in chroma sample units.
+/// * `bottom` - The `conf_win_bottom_offset`, in chroma sample units.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ConformanceWindow {
+    left: u32,
+    right: u32,
+    top: u32,
+    bottom: u32,
+}
+
+impl ConformanceWindow {
+    pub fn new(left: u32, right: u32, top: u32, bottom: u32) -> Self {
+        Self { left, right, top, bottom }
+    }
+
+    pub fn left(&self) -> u32 {
+        self.left
+    }
+
+    pub fn right(&self) -> u32 {
+        self.right
+    }
+
+    pub fn top(&self) -> u32 {
+        self.top
+    }
+
+    pub fn bottom(&self) -> u32 {
+        self.bottom
+    }
+}
+
+/// Represents an H.265 sequence parameter set, ITU-T H.265 section 7.3.2.2.
+///
+/// Only the fields needed to describe the stream are kept; the displayed resolution is
+/// computed from the coded resolution and the conformance window.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct H265Sps {
+    video_parameter_set_id: u8,
+    max_sub_layers: u8,
+    profile_tier_level: ProfileTierLevel,
+    seq_parameter_set_id: u32,
+    chroma_format_idc: u32,
+    separate_colour_plane: bool,
+    pic_width: u32,
+    pic_height: u32,
+    conformance_window: Option<ConformanceWindow>,
+    width: u32,
+    height: u32,
+    bit_depth_luma: u32,
+    bit_depth_chroma: u32,
+    max_num_reorder_pics: u32,
+    field_seq: bool,
+    timing: Option<VuiTiming>,
+}
+
+impl H265Sps {
+    pub fn video_parameter_set_id(&self) -> u8 {
+        self.video_parameter_set_id
+    }
+
+    pub fn max_sub_layers(&self) -> u8 {
+        self.max_sub_layers
+    }
+
+    pub fn profile_tier_level(&self) -> &ProfileTierLevel {
+        &self.profile_tier_level
+    }
+
+    pub fn seq_parameter_set_id(&self) -> u32 {
+        self.seq_parameter_set_id
+    }
+
+    /// Returns the chroma format: 0 for monochrome, 1 for 4:2:0, 2 for 4:2:2 and 3 for 4:4:4.
+    pub fn chroma_format_idc(&self) -> u32 {
+        self.chroma_format_idc
+    }
+
+    pub fn separate_colour_plane(&self) -> bool {
+        self.separate_colour_plane
+    }
+
+    /// Returns the coded width in luma samples, `pic_width_in_luma_samples`.
+    pub fn pic_width(&self) -> u32 {
+        self.pic_width
+    }
+
+    /// Returns the coded height in luma samples, `pic_height_in_luma_samples`.
+    pub fn pic_height(&self) -> u32 {
+        self.pic_height
+    }
+
+    pub fn conformance_window(&self) -> Option<&ConformanceWindow> {
+        self.conformance_window.as_ref()
+    }
+
+    /// Returns the width in luma samples after applying the conformance window.
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    /// Returns the height in luma samples after applying the conformance window.
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    pub fn bit_depth_luma(&self) -> u32 {
+        self.bit_depth_luma
+    }
+
+    pub fn bit_depth_chroma(&self) -> u32 {
+        self.bit_depth_chroma
+    }
+
+    /// Returns `sps_max_num_reorder_pics` of the highest sub-layer.
+    pub fn max_num_reorder_pics(&self) -> u32 {
+        self.max_num_reorder_pics
+    }
+
+    /// Returns whether every picture is a field, as signaled by the VUI.
+    pub fn field_seq(&self) -> bool {
+        self.field_seq
+    }
+
+    pub fn timing(&self) -> Option<&VuiTiming> {
+        self.timing.as_ref()
+    }
+
+    /// Returns the picture rate signaled in the VUI, where a picture lasts one clock tick.
+    pub fn frame_rate(&self) -> Option<f64> {
+        self.timing.and_then(|timing| timing.tick_rate())
+    }
+}
+
+impl TryFrom<&[u8]> for H265Sps {
+    type Error = ConfigError;
+
+    /// Parses a sequence parameter set NAL unit, header included.
+    fn try_from(nal_unit: &[u8]) -> Result<Self, Self::Error> {
+        let rbsp = parameter_set_rbsp(nal_unit, SPS, "SPS NAL unit type")?;
+        let mut reader = BitReader::new(&rbsp);
+
+        let video_parameter_set_id = reader.field(4, "sps_video_parameter_set_id")? as u8;
+        let max_sub_layers_minus1 = reader.field(3, "sps_max_sub_layers_minus1")?;
+        reader.flag("sps_temporal_id_nesting_flag")?;
+        let profile_tier_level = ProfileTierLevel::parse(&mut reader, max_sub_layers_minus1)?;
+        let seq_parameter_set_id = reader.ue_field_max("sps_seq_parameter_set_id", MAX_SEQ_PARAMETER_SET_ID)?;
+        let chroma_format_idc = reader.ue_field("chroma_format_idc")?;
+        if chroma_format_idc > CHROMA_444 {
+            return Err(ConfigError::Invalid("chroma_format_idc"));
+        }
+        let separate_colour_plane = chroma_format_idc == CHROMA_444 && reader.flag("separate_colour_plane_flag")?;
+        let pic_width = reader.ue_field("pic_width_in_luma_samples")?;
+        let pic_height = reader.ue_field("pic_height_in_luma_samples")?;
+
+        let mut conformance_window = None;
+        let mut width = pic_width;
+        let mut height = pic_height;
+        if reader.flag("conformance_window_flag")? {
+            let window = ConformanceWindow::new(
+                reader.ue_field("conf_win_left_offset")?,
+                reader.ue_field("conf_win_right_offset")?,
+                reader.ue_field("conf_win_top_offset")?,
+                reader.ue_field("conf_win_bottom_offset")?,
+            );
+            let (sub_width, sub_height) = match (chroma_format_idc, separate_colour_plane) {
+                (1, _) => (2, 2),
+                (2, _) => (2, 1),
+                _ => (1, 1),
+            };
+            width = window
+                .left
+                .checked_add(window.right)
+                .and_then(|offset| offset.checked_mul(sub_width))
+                .and_then(|crop| pic_width.checked_sub(crop))
+                .ok_or(ConfigError::Invalid("conformance window"))?;
+            height = window
+                .top
+                .checked_add(window.bottom)
+                .and_then(|offset| offset.checked_mul(sub_height))
+                .and_then(|crop| pic_height.checked_sub(crop))
+                .ok_or(ConfigError::Invalid("conformance window"))?;
+            conformance_window = Some(window);
+        }
+
+        let bit_depth_luma = 8 + reader.ue_field_max("bit_depth_luma_minus8", MAX_BIT_DEPTH_MINUS8)?;
+        let bit_depth_chroma = 8 + reader.ue_field_max("bit_depth_chroma_minus8", MAX_BIT_DEPTH_MINUS8)?;
+        let log2_max_pic_order_cnt_lsb =
+            reader.ue_field_max("log2_max_pic_order_cnt_lsb_minus4", MAX_LOG2_MAX_PIC_ORDER_CNT_LSB_MINUS4)? + 4;
+        let max_num_reorder_pics = read_sub_layer_ordering_info(&mut reader, max_sub_layers_minus1)?;
+
+        reader.ue_field("log2_min_luma_coding_block_size_minus3")?;
+        reader.ue_field("log2_diff_max_min_luma_coding_block_size")?;
+        reader.ue_field("log2_min_luma_transform_block_size_minus2")?;
+        reader.ue_field("log2_diff_max_min_luma_transform_block_size")?;
+        reader.ue_field("max_transform_hierarchy_depth_inter")?;
+        reader.ue_field("max_transform_hierarchy_depth_intra")?;
+        if reader.flag("scaling_list_enabled_flag")? && reader.flag("sps_scaling_list_data_present_flag")? {
+            skip_scaling_list_data(&mut reader)?;
+        }
+        reader.flag("amp_enabled_flag")?;
+        reader.flag("sample_adaptive_offset_enabled_flag")?;
+        if reader.flag("pcm_enabled_flag")? {
+            reader.field(8, "pcm_sample_bit_depth_minus1")?;
+            reader.ue_field("log2_min_pcm_luma_coding_block_size_minus3")?;
+            reader.ue_field("log2_diff_max_min_pcm_luma_coding_block_size")?;
+            reader.flag("pcm_loop_filter_disabled_flag")?;
+        }
+        skip_short_term_ref_pic_sets(&mut reader)?;
+        if reader.flag("long_term_ref_pics_present_flag")? {
+            let count = reader.ue_field_max("num_long_term_ref_pics_sps", MAX_LONG_TERM_REF_PICS_SPS)?;
+            let entry_bits = log2_max_pic_order_cnt_lsb as usize + 1;
+            reader
+                .skip_bits(count as usize * entry_bits)
+                .ok_or(ConfigError::Truncated("lt_ref_pic_poc_lsb_sps"))?;
+        }
+        reader.flag("sps_temporal_mvp_enabled_flag")?;
+        reader.flag("strong_intra_smoothing_enabled_flag")?;
+
+        let mut vui = Vui::default();
+        if reader.flag("vui_parameters_present_flag")? {
+            vui = Vui::parse(&mut reader)?;
+        }
+
+        Ok(Self {
+            video_parameter_set_id,
+            max_sub_layers: max_sub_layers_minus1 as u8 + 1,
+            profile_tier_level,
+            seq_parameter_set_id,
+            chroma_format_idc,
+            separate_colour_plane,
+            pic_width,
+            pic_height,
+            conformance_window,
+            width,
+            height,
+            bit_depth_luma,
+            bit_depth_chroma,
+            max_num_reorder_pics,
+            field_seq: vui.field_seq,
+            timing: vui.timing,
+        })
+    }
+}
+
+/// Represents the fields kept from the VUI parameters, ITU-T H.265 section E.2.1.
+///
+/// Parsing stops after the timing information, before the HRD parameters.
+#[derive(Debug, Default)]
+struct Vui {
+    field_seq: bool,
+    timing: Option<VuiTiming>,
+}
+
+impl Vui {
+    fn parse(reader: &mut BitReader) -> Result<Self, ConfigError> {
+        let mut vui = Vui::default();
+        if reader.flag("aspect_ratio_info_present_flag")? && reader.field(8, "aspect_ratio_idc")? == EXTENDED_SAR {
+            reader.field(16, "sar_width")?;
+            reader.field(16, "sar_height")?;
+        }
+        if reader.flag("overscan_info_present_flag")? {
+            reader.flag("overscan_appropriate_flag")?;
+        }
+        if reader.flag("video_signal_type_present_flag")? {
+            reader.field(4, "video_format and video_full_range_flag")?;
+            if reader.flag("colour_description_present_flag")? {
+                reader.field(24, "colour_description")?;
+            }
+        }
+        if reader.flag("chroma_loc_info_present_flag")? {
+            reader.ue_field("chroma_sample_loc_type_top_field")?;
+            reader.ue_field("chroma_sample_loc_type_bottom_field")?;
+        }
+        reader.flag("neutral_chroma_indication_flag")?;
+        vui.field_seq = reader.flag("field_seq_flag")?;
+        reader.flag("frame_field_info_present_flag")?;
+        if reader.flag("default_display_window_flag")? {
+            reader.ue_field("def_disp_win_left_offset")?;
+            reader.ue_field("def_disp_win_right_offset")?;
+            reader.ue_field("def_disp_win_top_offset")?;
+            reader.ue_field("def_disp_win_bottom_offset")?;
+        }
+        if reader.flag("vui_timing_info_present_flag")? {
+            let num_units_in_tick = reader.field(32, "vui_num_units_in_tick")?;
+            let time_scale = reader.field(32, "vui_time_scale")?;
+            vui.timing = Some(VuiTiming::new(num_units_in_tick, time_scale));
+        }
+        Ok(vui)
+    }
+}
+
+/// Skips the scaling list data, ITU-T H.265 section 7.3.4.
+fn skip_scaling_list_data(reader: &mut BitReader) -> Result<(), ConfigError> {
+    for size_id in 0..SCALING_LIST_SIZES {
+        let matrix_step = if size_id == 3 { 3 } else { 1 };
+        for _ in (0..6).step_by(matrix_step) {
+            if !reader.flag("scaling_list_pred_mode_flag")? {
+                reader.ue_field("scaling_list_pred_matrix_id_delta")?;
+                continue;
+            }
+            let coefficient_count = 64.min(1 << (4 + (size_id << 1)));
+            if size_id > 1 {
+                reader.se_field("scaling_list_dc_coef_minus8")?;
+            }
+            for _ in 0..coefficient_count {
+                reader.se_field("scaling_list_delta_coef")?;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Skips the short-term reference picture sets, ITU-T H.265 section 7.3.7.
+///
+/// A set predicted from the previous one has one entry per delta picture of that set,
+/// so the number of delta pictures of every set is tracked.
+fn skip_short_term_ref_pic_sets(reader: &mut BitReader) -> Result<(), ConfigError> {
+    let count = reader.ue_field("num_short_term_ref_pic_sets")?;
+    let mut previous_delta_pocs = 0;
+    for index in 0..count {
+        let inter_ref_pic_set_prediction = index != 0 && reader.flag("inter_ref_pic_set_prediction_flag")?;
+        let mut delta_pocs = 0;
+        if inter_ref_pic_set_prediction {
+            reader.flag("delta_rps_sign")?;
+            reader.ue_field("abs_delta_rps_minus1")?;
+            for _ in 0..=previous_delta_pocs {
+                let used_by_curr_pic = reader.flag("used_by_curr_pic_flag")?;
+                if used_by_curr_pic || reader.flag("use_delta_flag")? {
+                    delta_pocs += 1;
+                }
+            }
+        } else {
+            let negative = reader.ue_field("num_negative_pics")?;
+            let positive = reader.ue_field("num_positive_pics")?;
+            delta_pocs = negative.checked_add(positive).ok_or(ConfigError::Invalid("num_delta_pocs"))?;
+            for _ in 0..delta_pocs {
+                reader.ue_field("delta_poc_minus1")?;
+                reader.flag("used_by_curr_pic_flag")?;
+            }
+        }
+        previous_delta_pocs = delta_pocs;
+    }
+    Ok(())
+}
@@ -0,0 +1,111 @@
+use crate::{
+    bit_reader::BitReader,
+    config_error::ConfigError,
+    video::{
+        h265_nal::{parameter_set_rbsp, VPS},
+        h265_profile_tier_level::ProfileTierLevel,
+        vui_timing::VuiTiming,
+    },
+};
+
+/// Represents an H.265 video parameter set, ITU-T H.265 section 7.3.2.1.
+///
+/// Parsing stops after the timing information; the layer sets are skipped and the HRD parameters are not read.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct H265Vps {
+    video_parameter_set_id: u8,
+    max_layers: u8,
+    max_sub_layers: u8,
+    temporal_id_nesting: bool,
+    profile_tier_level: ProfileTierLevel,
+    max_num_reorder_pics: u32,
+    timing: Option<VuiTiming>,
+}
+
+impl H265Vps {
+    pub fn video_parameter_set_id(&self) -> u8 {
+        self.video_parameter_set_id
+    }
+
+    pub fn max_layers(&self) -> u8 {
+        self.max_layers
+    }
+
+    pub fn max_sub_layers(&self) -> u8 {
+        self.max_sub_layers
+    }
+
+    pub fn temporal_id_nesting(&self) -> bool {
+        self.temporal_id_nesting
+    }
+
+    pub fn profile_tier_level(&self) -> &ProfileTierLevel {
+        &self.profile_tier_level
+    }
+
+    /// Returns `vps_max_num_reorder_pics` of the highest sub-layer.
+    pub fn max_num_reorder_pics(&self) -> u32 {
+        self.max_num_reorder_pics
+    }
+
+    pub fn timing(&self) -> Option<&VuiTiming> {
+        self.timing.as_ref()
+    }
+}
+
+impl TryFrom<&[u8]> for H265Vps {
+    type Error = ConfigError;
+
+    /// Parses a video parameter set NAL unit, header included.
+    fn try_from(nal_unit: &[u8]) -> Result<Self, Self::Error> {
+        let rbsp = parameter_set_rbsp(nal_unit, VPS, "VPS NAL unit type")?;
+        let mut reader = BitReader::new(&rbsp);
+
+        let video_parameter_set_id = reader.field(4, "vps_video_parameter_set_id")? as u8;
+        reader.field(2, "vps_base_layer_internal_flag and vps_base_layer_available_flag")?;
+        let max_layers = reader.field(6, "vps_max_layers_minus1")? as u8 + 1;
+        let max_sub_layers_minus1 = reader.field(3, "vps_max_sub_layers_minus1")?;
+        let temporal_id_nesting = reader.flag("vps_temporal_id_nesting_flag")?;
+        reader.field(16, "vps_reserved_0xffff_16bits")?;
+        let profile_tier_level = ProfileTierLevel::parse(&mut reader, max_sub_layers_minus1)?;
+        let max_num_reorder_pics = read_sub_layer_ordering_info(&mut reader, max_sub_layers_minus1)?;
+
+        let max_layer_id = reader.field(6, "vps_max_layer_id")?;
+        let num_layer_sets = reader.ue_field("vps_num_layer_sets_minus1")? + 1;
+        let layer_id_included_bits = (num_layer_sets as usize - 1) * (max_layer_id as usize + 1);
+        reader.skip_bits(layer_id_included_bits).ok_or(ConfigError::Truncated("layer_id_included_flag"))?;
+
+        let mut timing = None;
+        if reader.flag("vps_timing_info_present_flag")? {
+            let num_units_in_tick = reader.field(32, "vps_num_units_in_tick")?;
+            let time_scale = reader.field(32, "vps_time_scale")?;
+            timing = Some(VuiTiming::new(num_units_in_tick, time_scale));
+        }
+
+        Ok(Self {
+            video_parameter_set_id,
+            max_layers,
+            max_sub_layers: max_sub_layers_minus1 as u8 + 1,
+            temporal_id_nesting,
+            profile_tier_level,
+            max_num_reorder_pics,
+            timing,
+        })
+    }
+}
+
+/// Reads the sub-layer ordering information shared by the VPS and the SPS.
+///
+/// # Returns
+///
+/// A `Result` containing `max_num_reorder_pics` of the highest sub-layer.
+pub(crate) fn read_sub_layer_ordering_info(reader: &mut BitReader, max_sub_layers_minus1: u32) -> Result<u32, ConfigError> {
+    let first = if reader.flag("sub_layer_ordering_info_present_flag")? { 0 } else { max_sub_layers_minus1 };
+    let mut max_num_reorder_pics = 0;
+    for _ in first..=max_sub_layers_minus1 {
+        reader.ue_field("max_dec_pic_buffering_minus1")?;
+        max_num_reorder_pics = reader.ue_field("max_num_reorder_pics")?;
+        reader.ue_field("max_latency_increase_plus1")?;
+    }
+    Ok(max_num_reorder_pics)
+}
@@ -0,0 +1,168 @@
+use crate::depacketizer_error::DepacketizerError;
+
+/// Luminance quantization table of ITU-T T.81 Annex K, in zig-zag order.
+const LUMA_QUANTIZER: [u8; 64] = [
+    16, 11, 12, 14, 12, 10, 16, 14, 13, 14, 18, 17, 16, 19, 24, 40,
+    26, 24, 22, 22, 24, 49, 35, 37, 29, 40, 58, 51, 61, 60, 57, 51,
+    56, 55, 64, 72, 92, 78, 64, 68, 87, 69, 55, 56, 80, 109, 81, 87,
+    95, 98, 103, 104, 103, 62, 77, 113, 121, 112, 100, 120, 92, 101, 103, 99,
+];
+
+/// Chrominance quantization table of ITU-T T.81 Annex K, in zig-zag order.
+const CHROMA_QUANTIZER: [u8; 64] = [
+    17, 18, 18, 24, 21, 24, 47, 26, 26, 47, 99, 66, 56, 66, 99, 99,
+    99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99,
+    99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99,
+    99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99, 99,
+];
+
+const LUMA_DC_CODE_LENGTHS: [u8; 16] = [0, 1, 5, 1, 1, 1, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0];
+const LUMA_DC_SYMBOLS: [u8; 12] = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11];
+
+const LUMA_AC_CODE_LENGTHS: [u8; 16] = [0, 2, 1, 3, 3, 2, 4, 3, 5, 5, 4, 4, 0, 0, 1, 0x7D];
+const LUMA_AC_SYMBOLS: [u8; 162] = [
+    0x01, 0x02, 0x03, 0x00, 0x04, 0x11, 0x05, 0x12, 0x21, 0x31, 0x41, 0x06, 0x13, 0x51, 0x61, 0x07,
+    0x22, 0x71, 0x14, 0x32, 0x81, 0x91, 0xA1, 0x08, 0x23, 0x42, 0xB1, 0xC1, 0x15, 0x52, 0xD1, 0xF0,
+    0x24, 0x33, 0x62, 0x72, 0x82, 0x09, 0x0A, 0x16, 0x17, 0x18, 0x19, 0x1A, 0x25, 0x26, 0x27, 0x28,
+    0x29, 0x2A, 0x34, 0x35, 0x36, 0x37, 0x38, 0x39, 0x3A, 0x43, 0x44, 0x45, 0x46, 0x47, 0x48, 0x49,
+    0x4A, 0x53, 0x54, 0x55, 0x56, 0x57, 0x58, 0x59, 0x5A, 0x63, 0x64, 0x65, 0x66, 0x67, 0x68, 0x69,
+    0x6A, 0x73, 0x74, 0x75, 0x76, 0x77, 0x78, 0x79, 0x7A, 0x83, 0x84, 0x85, 0x86, 0x87, 0x88, 0x89,
+    0x8A, 0x92, 0x93, 0x94, 0x95, 0x96, 0x97, 0x98, 0x99, 0x9A, 0xA2, 0xA3, 0xA4, 0xA5, 0xA6, 0xA7,
+    0xA8, 0xA9, 0xAA, 0xB2, 0xB3, 0xB4, 0xB5, 0xB6, 0xB7, 0xB8, 0xB9, 0xBA, 0xC2, 0xC3, 0xC4, 0xC5,
+    0xC6, 0xC7, 0xC8, 0xC9, 0xCA, 0xD2, 0xD3, 0xD4, 0xD5, 0xD6, 0xD7, 0xD8, 0xD9, 0xDA, 0xE1, 0xE2,
+    0xE3, 0xE4, 0xE5, 0xE6, 0xE7, 0xE8, 0xE9, 0xEA, 0xF1, 0xF2, 0xF3, 0xF4, 0xF5, 0xF6, 0xF7, 0xF8,
+    0xF9, 0xFA,
+];
+
+const CHROMA_DC_CODE_LENGTHS: [u8; 16] = [0, 3, 1, 1, 1, 1, 1, 1, 1, 1, 1, 0, 0, 0, 0, 0];
+const CHROMA_DC_SYMBOLS: [u8; 12] = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11];
+
+const CHROMA_AC_CODE_LENGTHS: [u8; 16] = [0, 2, 1, 2, 4, 4, 3, 4, 7, 5, 4, 4, 0, 1, 2, 0x77];
+const CHROMA_AC_SYMBOLS: [u8; 162] = [
+    0x00, 0x01, 0x02, 0x03, 0x11, 0x04, 0x05, 0x21, 0x31, 0x06, 0x12, 0x41, 0x51, 0x07, 0x61, 0x71,
+    0x13, 0x22, 0x32, 0x81, 0x08, 0x14, 0x42, 0x91, 0xA1, 0xB1, 0xC1, 0x09, 0x23, 0x33, 0x52, 0xF0,
+    0x15, 0x62, 0x72, 0xD1, 0x0A, 0x16, 0x24, 0x34, 0xE1, 0x25, 0xF1, 0x17, 0x18, 0x19, 0x1A, 0x26,
+    0x27, 0x28, 0x29, 0x2A, 0x35, 0x36, 0x37, 0x38, 0x39, 0x3A, 0x43, 0x44, 0x45, 0x46, 0x47, 0x48,
+    0x49, 0x4A, 0x53, 0x54, 0x55, 0x56, 0x57, 0x58, 0x59, 0x5A, 0x63, 0x64, 0x65, 0x66, 0x67, 0x68,
+    0x69, 0x6A, 0x73, 0x74, 0x75, 0x76, 0x77, 0x78, 0x79, 0x7A, 0x82, 0x83, 0x84, 0x85, 0x86, 0x87,
+    0x88, 0x89, 0x8A, 0x92, 0x93, 0x94, 0x95, 0x96, 0x97, 0x98, 0x99, 0x9A, 0xA2, 0xA3, 0xA4, 0xA5,
+    0xA6, 0xA7, 0xA8, 0xA9, 0xAA, 0xB2, 0xB3, 0xB4, 0xB5, 0xB6, 0xB7, 0xB8, 0xB9, 0xBA, 0xC2, 0xC3,
+    0xC4, 0xC5, 0xC6, 0xC7, 0xC8, 0xC9, 0xCA, 0xD2, 0xD3, 0xD4, 0xD5, 0xD6, 0xD7, 0xD8, 0xD9, 0xDA,
+    0xE2, 0xE3, 0xE4, 0xE5, 0xE6, 0xE7, 0xE8, 0xE9, 0xEA, 0xF2, 0xF3, 0xF4, 0xF5, 0xF6, 0xF7, 0xF8,
+    0xF9, 0xFA,
+];
+
+const SOI: u8 = 0xD8;
+const EOI: u8 = 0xD9;
+const APP0: u8 = 0xE0;
+const DQT: u8 = 0xDB;
+const DRI: u8 = 0xDD;
+const SOF0: u8 = 0xC0;
+const DHT: u8 = 0xC4;
+const SOS: u8 = 0xDA;
+
+/// Largest segment payload whose length, counting its own two bytes, fits the 16-bit length field.
+const MAX_SEGMENT_SIZE: usize = u16::MAX as usize - 2;
+
+/// Represents the parameters of a frame needed to synthesize its JFIF headers.
+///
+/// # Fields
+///
+/// * `subsampling_420` - Whether the chroma is subsampled vertically too, which is the case for type 1.
+/// * `width` - The width of the frame in pixels.
+/// * `height` - The height of the frame in pixels.
+/// * `restart_interval` - The number of MCUs between restart markers, if any.
+/// * `quantization_tables` - For each table, whether its values are 16-bit and its values in zig-zag order.
+pub(crate) struct FrameHeader<'a> {
+    pub subsampling_420: bool,
+    pub width: u16,
+    pub height: u16,
+    pub restart_interval: Option<u16>,
+    pub quantization_tables: &'a [(bool, &'a [u8])],
+}
+
+/// Scales the tables of ITU-T T.81 Annex K by a Q value between 1 and 99, RFC 2435 Appendix A.
+///
+/// # Returns
+///
+/// The luminance table followed by the chrominance table, in zig-zag order.
+pub(crate) fn scaled_quantization_tables(q: u8) -> Vec<u8> {
+    let factor = q.clamp(1, 99) as u32;
+    let scale = if factor < 50 { 5000 / factor } else { 200 - factor * 2 };
+    LUMA_QUANTIZER
+        .iter()
+        .chain(CHROMA_QUANTIZER.iter())
+        .map(|&value| ((value as u32 * scale + 50) / 100).clamp(1, 255) as u8)
+        .collect()
+}
+
+/// Writes the JFIF headers preceding the scan data, RFC 2435 Appendix B.
+///
+/// ```text
+/// SOI APP0 DQT [DRI] SOF0 DHT SOS <scan data> EOI
+/// ```
+///
+/// The first component uses the first quantization table and the chroma components the second one,
+/// or the first one again if there is only one. The Huffman tables are those of ITU-T T.81 Annex K.
+///
+/// # Returns
+///
+/// A `Result` that is an error if a segment does not fit its 16-bit length field.
+pub(crate) fn write_headers(output: &mut Vec<u8>, frame: &FrameHeader) -> Result<(), DepacketizerError> {
+    output.extend_from_slice(&[0xFF, SOI]);
+
+    write_segment(output, APP0, &[b'J', b'F', b'I', b'F', 0, 1, 1, 0, 0, 1, 0, 1, 0, 0])?;
+
+    let mut tables = Vec::new();
+    for (id, (wide, values)) in frame.quantization_tables.iter().enumerate() {
+        tables.push(((*wide as u8) << 4) | id as u8);
+        tables.extend_from_slice(values);
+    }
+    write_segment(output, DQT, &tables)?;
+
+    if let Some(restart_interval) = frame.restart_interval {
+        write_segment(output, DRI, &restart_interval.to_be_bytes())?;
+    }
+
+    let luma_sampling = if frame.subsampling_420 { 0x22 } else { 0x21 };
+    let chroma_table = (frame.quantization_tables.len() > 1) as u8;
+    let [height_high, height_low] = frame.height.to_be_bytes();
+    let [width_high, width_low] = frame.width.to_be_bytes();
+    write_segment(
+        output,
+        SOF0,
+        &[8, height_high, height_low, width_high, width_low, 3, 1, luma_sampling, 0, 2, 0x11, chroma_table, 3, 0x11, chroma_table],
+    )?;
+
+    write_huffman_table(output, 0x00, &LUMA_DC_CODE_LENGTHS, &LUMA_DC_SYMBOLS)?;
+    write_huffman_table(output, 0x10, &LUMA_AC_CODE_LENGTHS, &LUMA_AC_SYMBOLS)?;
+    write_huffman_table(output, 0x01, &CHROMA_DC_CODE_LENGTHS, &CHROMA_DC_SYMBOLS)?;
+    write_huffman_table(output, 0x11, &CHROMA_AC_CODE_LENGTHS, &CHROMA_AC_SYMBOLS)?;
+
+    write_segment(output, SOS, &[3, 1, 0x00, 2, 0x11, 3, 0x11, 0, 63, 0])
+}
+
+/// Appends the EOI marker unless the scan data already ends with it.
+pub(crate) fn write_end(output: &mut Vec<u8>) {
+    if !output.ends_with(&[0xFF, EOI]) {
+        output.extend_from_slice(&[0xFF, EOI]);
+    }
+}
+
+fn write_huffman_table(output: &mut Vec<u8>, class_and_id: u8, code_lengths: &[u8; 16], symbols: &[u8]) -> Result<(), DepacketizerError> {
+    let mut table = Vec::with_capacity(1 + code_lengths.len() + symbols.len());
+    table.push(class_and_id);
+    table.extend_from_slice(code_lengths);
+    table.extend_from_slice(symbols);
+    write_segment(output, DHT, &table)
+}
+
+fn write_segment(output: &mut Vec<u8>, marker: u8, data: &[u8]) -> Result<(), DepacketizerError> {
+    if data.len() > MAX_SEGMENT_SIZE {
+        return Err(DepacketizerError::Invalid("JPEG segment length"));
+    }
+    output.extend_from_slice(&[0xFF, marker]);
+    output.extend_from_slice(&(data.len() as u16 + 2).to_be_bytes());
+    output.extend_from_slice(data);
+    Ok(())
+}
@@ -0,0 +1,57 @@
+use abstractions::parsing::parsing_error::ParsingError;
+
+use crate::codec::{Codec, CodecHelper};
+
+pub(crate) const NAME: &str = "JPEG";
+
+/// Represents a Motion JPEG stream as described in RFC 2435, with the static payload type 26.
+///
+/// Every parameter of the stream, from the dimensions to the quantization tables, is carried
+/// in the RTP/JPEG headers of each frame, so the codec has no format parameters.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JpegCodec {
+    format: u16,
+    clock_rate: u32,
+    channel_count: Option<u8>,
+}
+
+impl JpegCodec {
+    pub fn new(format: u16, clock_rate: u32, channel_count: Option<u8>) -> Self {
+        Self { format, clock_rate, channel_count }
+    }
+}
+
+impl CodecHelper for JpegCodec {
+    fn from_fmtp_internal(format: u16, clock_rate: u32, channel_count: Option<u8>, _data: &[u8]) -> Result<Self, ParsingError> {
+        Ok(Self::new(format, clock_rate, channel_count))
+    }
+}
+
+impl Codec for JpegCodec {
+    fn channel_count(&self) -> &Option<u8> {
+        &self.channel_count
+    }
+
+    fn clock_rate(&self) -> u32 {
+        self.clock_rate
+    }
+
+    fn format(&self) -> u16 {
+        self.format
+    }
+
+    fn name(&self) -> &'static str {
+        NAME
+    }
+
+    fn fmtp(&self) -> Option<String> {
+        None
+    }
+
+    fn parse(clock_rate: u32, channel_count: Option<u8>, data: &[u8]) -> Result<Self, ParsingError>
+    where
+        Self: Sized,
+    {
+        Self::from_fmtp(clock_rate, channel_count, data)
+    }
+}
@@ -0,0 +1,187 @@
+use std::collections::{HashMap, VecDeque};
+
+use rtp::rtp_packet::RtpPacket;
+
+use crate::{
+    access_unit::AccessUnit,
+    depacketizer::Depacketizer,
+    depacketizer_error::DepacketizerError,
+    media_frame::MediaFrame,
+    packet_sequence::{PacketOrder, PacketSequence},
+    video::{
+        jfif::{scaled_quantization_tables, write_end, write_headers, FrameHeader},
+        jpeg_codec::{self, JpegCodec},
+        jpeg_header::{JpegPayload, QuantizationTableHeader, IN_BAND_Q},
+    },
+};
+
+/// Represents a frame being reassembled, whose JFIF headers were already written.
+#[derive(Debug)]
+struct Frame {
+    timestamp: u32,
+    headers_size: usize,
+    data: Vec<u8>,
+}
+
+impl Frame {
+    fn scan_size(&self) -> usize {
+        self.data.len() - self.headers_size
+    }
+}
+
+/// Reassembles JPEG frames from RTP payloads as defined in RFC 2435.
+///
+/// Packets are pushed in sequence number order and complete frames are pulled as JFIF images.
+/// RTP/JPEG strips every marker segment from the frames, so the DQT, DRI, SOF0, DHT and SOS
+/// segments are synthesized from the RTP/JPEG headers of the first packet: the quantization
+/// tables are either scaled from the Q value or carried in-band, and the Huffman tables are
+/// always those of ITU-T T.81 Annex K.
+///
+/// A frame is closed by the marker bit. Frames that lost a packet are dropped, and every frame is a keyframe.
+#[derive(Debug)]
+pub struct JpegDepacketizer {
+    frame: Option<Frame>,
+    quantization_tables: HashMap<u8, QuantizationTableHeader>,
+    sequence: PacketSequence,
+    ready: VecDeque<AccessUnit>,
+}
+
+impl JpegDepacketizer {
+    /// Creates a new `JpegDepacketizer` for the stream described by the codec.
+    ///
+    /// # Arguments
+    ///
+    /// * `_codec` - The codec negotiated in SDP, which has no parameters affecting the payload format.
+    pub fn new(_codec: &JpegCodec) -> Self {
+        Self {
+            frame: None,
+            quantization_tables: HashMap::new(),
+            sequence: PacketSequence::default(),
+            ready: VecDeque::new(),
+        }
+    }
+
+    /// Returns the number of packets detected as missing from the sequence.
+    pub fn lost_packets(&self) -> u64 {
+        self.sequence.lost_packets()
+    }
+
+    /// Pushes the next RTP packet of the stream.
+    ///
+    /// # Arguments
+    ///
+    /// * `packet` - The RTP packet carrying an RTP/JPEG payload.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` that is an error if the payload is malformed; the frame it belongs to is dropped.
+    pub fn push(&mut self, packet: &RtpPacket) -> Result<(), DepacketizerError> {
+        let header = packet.header();
+        match self.sequence.push(header.sequence_number()) {
+            PacketOrder::Late => return Ok(()),
+            PacketOrder::Next => {}
+            PacketOrder::AfterGap => self.frame = None,
+        }
+
+        let result = self.push_payload(header.timestamp(), packet.payload());
+        if result.is_err() {
+            self.frame = None;
+        }
+        if header.marker() {
+            if let Some(mut frame) = self.frame.take() {
+                write_end(&mut frame.data);
+                self.ready.push_back(AccessUnit::new(frame.timestamp, true, frame.data));
+            }
+        }
+        result
+    }
+
+    /// Pulls the next complete frame.
+    ///
+    /// # Returns
+    ///
+    /// An `Option` containing the frame as a JFIF image, or `None` if none is complete.
+    pub fn pull(&mut self) -> Option<AccessUnit> {
+        self.ready.pop_front()
+    }
+
+    fn push_payload(&mut self, timestamp: u32, payload: &[u8]) -> Result<(), DepacketizerError> {
+        let payload = JpegPayload::try_from(payload)?;
+        let offset = payload.header().fragment_offset() as usize;
+
+        if offset == 0 {
+            self.frame = Some(self.start_frame(timestamp, &payload)?);
+        }
+        let Some(frame) = self.frame.as_mut() else {
+            return Ok(());
+        };
+        if frame.timestamp != timestamp {
+            return Err(DepacketizerError::Invalid("timestamp"));
+        }
+        if frame.scan_size() != offset {
+            return Err(DepacketizerError::Invalid("fragment offset"));
+        }
+        frame.data.extend_from_slice(payload.data());
+        Ok(())
+    }
+
+    fn start_frame(&mut self, timestamp: u32, payload: &JpegPayload) -> Result<Frame, DepacketizerError> {
+        let header = payload.header();
+        let subsampling_420 = match header.jpeg_type() {
+            0 | 64 => false,
+            1 | 65 => true,
+            jpeg_type => return Err(DepacketizerError::UnexpectedPacketType(jpeg_type)),
+        };
+        if header.width() == 0 || header.height() == 0 {
+            return Err(DepacketizerError::Invalid("dimensions"));
+        }
+
+        let q = header.q();
+        let scaled;
+        let quantization_tables = match q {
+            1..=99 => {
+                scaled = scaled_quantization_tables(q);
+                vec![(false, &scaled[..64]), (false, &scaled[64..])]
+            }
+            IN_BAND_Q..=255 => {
+                let table_header = payload
+                    .quantization_table_header()
+                    .ok_or(DepacketizerError::Truncated("quantization table header"))?;
+                // An empty table header refers to the tables sent with the same Q value in an earlier frame.
+                if !table_header.tables().is_empty() {
+                    table_header.split()?;
+                    self.quantization_tables.insert(q, table_header.clone());
+                }
+                self.quantization_tables.get(&q).ok_or(DepacketizerError::Invalid("quantization tables"))?.split()?
+            }
+            _ => return Err(DepacketizerError::Invalid("Q")),
+        };
+
+        let mut data = Vec::new();
+        write_headers(
+            &mut data,
+            &FrameHeader {
+                subsampling_420,
+                width: header.width(),
+                height: header.height(),
+                restart_interval: payload.restart_header().map(|restart_header| restart_header.restart_interval()),
+                quantization_tables: &quantization_tables,
+            },
+        )?;
+        Ok(Frame { timestamp, headers_size: data.len(), data })
+    }
+}
+
+impl Depacketizer for JpegDepacketizer {
+    fn push(&mut self, packet: &RtpPacket) -> Result<(), DepacketizerError> {
+        JpegDepacketizer::push(self, packet)
+    }
+
+    fn pull(&mut self) -> Option<MediaFrame> {
+        JpegDepacketizer::pull(self).map(|access_unit| MediaFrame::from_access_unit(access_unit, jpeg_codec::NAME))
+    }
+
+    fn lost_packets(&self) -> u64 {
+        JpegDepacketizer::lost_packets(self)
+    }
+}
@@ -0,0 +1,297 @@
+use byteorder::{BigEndian, ByteOrder};
+
+use crate::depacketizer_error::DepacketizerError;
+
+const MAIN_HEADER_SIZE: usize = 8;
+const RESTART_HEADER_SIZE: usize = 4;
+const QUANTIZATION_TABLE_HEADER_SIZE: usize = 4;
+const MAX_QUANTIZATION_TABLES: usize = 4;
+
+/// Lowest Q value whose quantization tables are carried in the payload instead of being scaled.
+pub(crate) const IN_BAND_Q: u8 = 128;
+
+/// Represents the main RTP/JPEG header present in every packet, RFC 2435 section 3.1.
+///
+/// ```text
+///  0                   1                   2                   3
+///  0 1 2 3 4 5 6 7 8 9 0 1 2 3 4 5 6 7 8 9 0 1 2 3 4 5 6 7 8 9 0 1
+/// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+/// | Type-specific |              Fragment Offset                  |
+/// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+/// |      Type     |       Q       |     Width     |     Height    |
+/// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+/// ```
+///
+/// # Fields
+///
+/// * `type_specific` - Zero for progressive frames, or the field an interlaced frame carries.
+/// * `fragment_offset` - The offset of the payload data within the scan data of the frame.
+/// * `jpeg_type` - The sampling of the frame, 0 for 4:2:2 and 1 for 4:2:0, plus 64 when restart markers are used.
+/// * `q` - The quality factor of the quantization tables, or 128 and above for tables carried in-band.
+/// * `width` - The width of the frame in pixels.
+/// * `height` - The height of the frame in pixels.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct JpegHeader {
+    type_specific: u8,
+    fragment_offset: u32,
+    jpeg_type: u8,
+    q: u8,
+    width: u16,
+    height: u16,
+}
+
+impl JpegHeader {
+    pub fn new(type_specific: u8, fragment_offset: u32, jpeg_type: u8, q: u8, width: u16, height: u16) -> Self {
+        Self { type_specific, fragment_offset, jpeg_type, q, width, height }
+    }
+
+    pub fn type_specific(&self) -> u8 {
+        self.type_specific
+    }
+
+    pub fn fragment_offset(&self) -> u32 {
+        self.fragment_offset
+    }
+
+    pub fn jpeg_type(&self) -> u8 {
+        self.jpeg_type
+    }
+
+    pub fn q(&self) -> u8 {
+        self.q
+    }
+
+    pub fn width(&self) -> u16 {
+        self.width
+    }
+
+    pub fn height(&self) -> u16 {
+        self.height
+    }
+
+    /// Returns whether a restart marker header follows, which is the case for types 64 to 127.
+    pub fn has_restart_header(&self) -> bool {
+        (64..128).contains(&self.jpeg_type)
+    }
+
+    /// Returns whether a quantization table header follows, which is the case for the first packet of a frame with in-band tables.
+    pub fn has_quantization_table_header(&self) -> bool {
+        self.q >= IN_BAND_Q && self.fragment_offset == 0
+    }
+}
+
+impl TryFrom<&[u8]> for JpegHeader {
+    type Error = DepacketizerError;
+
+    fn try_from(data: &[u8]) -> Result<Self, Self::Error> {
+        if data.len() < MAIN_HEADER_SIZE {
+            return Err(DepacketizerError::Truncated("main JPEG header"));
+        }
+        Ok(Self::new(
+            data[0],
+            BigEndian::read_u24(&data[1..4]),
+            data[4],
+            data[5],
+            data[6] as u16 * 8,
+            data[7] as u16 * 8,
+        ))
+    }
+}
+
+/// Represents the restart marker header of types 64 to 127, RFC 2435 section 3.1.7.
+///
+/// ```text
+///  0                   1                   2                   3
+///  0 1 2 3 4 5 6 7 8 9 0 1 2 3 4 5 6 7 8 9 0 1 2 3 4 5 6 7 8 9 0 1
+/// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+/// |       Restart Interval        |F|L|       Restart Count       |
+/// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+/// ```
+///
+/// # Fields
+///
+/// * `restart_interval` - The number of MCUs between restart markers.
+/// * `first` - Whether the payload starts with the first restart interval of a chunk.
+/// * `last` - Whether the payload ends with the last restart interval of a chunk.
+/// * `restart_count` - The position of the chunk among the restart intervals of the frame.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RestartHeader {
+    restart_interval: u16,
+    first: bool,
+    last: bool,
+    restart_count: u16,
+}
+
+impl RestartHeader {
+    pub fn new(restart_interval: u16, first: bool, last: bool, restart_count: u16) -> Self {
+        Self { restart_interval, first, last, restart_count }
+    }
+
+    pub fn restart_interval(&self) -> u16 {
+        self.restart_interval
+    }
+
+    pub fn first(&self) -> bool {
+        self.first
+    }
+
+    pub fn last(&self) -> bool {
+        self.last
+    }
+
+    pub fn restart_count(&self) -> u16 {
+        self.restart_count
+    }
+}
+
+impl TryFrom<&[u8]> for RestartHeader {
+    type Error = DepacketizerError;
+
+    fn try_from(data: &[u8]) -> Result<Self, Self::Error> {
+        if data.len() < RESTART_HEADER_SIZE {
+            return Err(DepacketizerError::Truncated("restart marker header"));
+        }
+        let flags = BigEndian::read_u16(&data[2..4]);
+        Ok(Self::new(BigEndian::read_u16(&data[..2]), flags & 0x8000 != 0, flags & 0x4000 != 0, flags & 0x3FFF))
+    }
+}
+
+/// Represents the quantization table header of the first packet of a frame, RFC 2435 section 3.1.8.
+///
+/// ```text
+///  0                   1                   2                   3
+///  0 1 2 3 4 5 6 7 8 9 0 1 2 3 4 5 6 7 8 9 0 1 2 3 4 5 6 7 8 9 0 1
+/// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+/// |      MBZ      |   Precision   |             Length            |
+/// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+/// |                    Quantization Table Data                    |
+/// |                              ...                              |
+/// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+/// ```
+///
+/// # Fields
+///
+/// * `precision` - One bit per table, set when the table has 16-bit instead of 8-bit values.
+/// * `tables` - The tables in zig-zag order, empty when the tables of the Q value were sent in an earlier frame.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct QuantizationTableHeader {
+    precision: u8,
+    tables: Vec<u8>,
+}
+
+impl QuantizationTableHeader {
+    pub fn new(precision: u8, tables: Vec<u8>) -> Self {
+        Self { precision, tables }
+    }
+
+    pub fn precision(&self) -> u8 {
+        self.precision
+    }
+
+    pub fn tables(&self) -> &[u8] {
+        &self.tables
+    }
+
+    /// Returns the size of the header, table data included.
+    pub fn size(&self) -> usize {
+        QUANTIZATION_TABLE_HEADER_SIZE + self.tables.len()
+    }
+
+    /// Splits the table data into tables.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing, for each table, whether its values are 16-bit and its values,
+    /// or a `DepacketizerError` if the length does not add up to whole tables or holds more than four.
+    pub fn split(&self) -> Result<Vec<(bool, &[u8])>, DepacketizerError> {
+        let mut tables = Vec::new();
+        let mut data = self.tables.as_slice();
+        while !data.is_empty() {
+            // A JPEG frame addresses at most four quantization tables, ITU-T T.81 B.2.4.1.
+            if tables.len() == MAX_QUANTIZATION_TABLES {
+                return Err(DepacketizerError::Invalid("quantization table count"));
+            }
+            let wide = self.precision & (1 << tables.len()) != 0;
+            let size = if wide { 128 } else { 64 };
+            if data.len() < size {
+                return Err(DepacketizerError::Invalid("quantization table length"));
+            }
+            tables.push((wide, &data[..size]));
+            data = &data[size..];
+        }
+        Ok(tables)
+    }
+}
+
+impl TryFrom<&[u8]> for QuantizationTableHeader {
+    type Error = DepacketizerError;
+
+    fn try_from(data: &[u8]) -> Result<Self, Self::Error> {
+        if data.len() < QUANTIZATION_TABLE_HEADER_SIZE {
+            return Err(DepacketizerError::Truncated("quantization table header"));
+        }
+        let length = BigEndian::read_u16(&data[2..4]) as usize;
+        let tables = data
+            .get(QUANTIZATION_TABLE_HEADER_SIZE..QUANTIZATION_TABLE_HEADER_SIZE + length)
+            .ok_or(DepacketizerError::Truncated("quantization table data"))?;
+        Ok(Self::new(data[1], tables.to_vec()))
+    }
+}
+
+/// Represents an RTP/JPEG payload split into its headers and its scan data.
+///
+/// # Fields
+///
+/// * `header` - The main JPEG header.
+/// * `restart_header` - The restart marker header, present for types 64 to 127.
+/// * `quantization_table_header` - The quantization table header, present in the first packet of a frame when Q is 128 or above.
+/// * `data` - The fragment of the scan data.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JpegPayload<'a> {
+    header: JpegHeader,
+    restart_header: Option<RestartHeader>,
+    quantization_table_header: Option<QuantizationTableHeader>,
+    data: &'a [u8],
+}
+
+impl<'a> JpegPayload<'a> {
+    pub fn header(&self) -> &JpegHeader {
+        &self.header
+    }
+
+    pub fn restart_header(&self) -> Option<&RestartHeader> {
+        self.restart_header.as_ref()
+    }
+
+    pub fn quantization_table_header(&self) -> Option<&QuantizationTableHeader> {
+        self.quantization_table_header.as_ref()
+    }
+
+    pub fn data(&self) -> &'a [u8] {
+        self.data
+    }
+}
+
+impl<'a> TryFrom<&'a [u8]> for JpegPayload<'a> {
+    type Error = DepacketizerError;
+
+    fn try_from(payload: &'a [u8]) -> Result<Self, Self::Error> {
+        let header = JpegHeader::try_from(payload)?;
+        let mut data = &payload[MAIN_HEADER_SIZE..];
+
+        let mut restart_header = None;
+        if header.has_restart_header() {
+            restart_header = Some(RestartHeader::try_from(data)?);
+            data = &data[RESTART_HEADER_SIZE..];
+        }
+
+        let mut quantization_table_header = None;
+        if header.has_quantization_table_header() {
+            let table_header = QuantizationTableHeader::try_from(data)?;
+            data = &data[table_header.size()..];
+            quantization_table_header = Some(table_header);
+        }
+
+        Ok(Self { header, restart_header, quantization_table_header, data })
+    }
+}
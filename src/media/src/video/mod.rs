@@ -1,2 +1,31 @@
+pub mod annex_b;
+pub mod av1_codec;
+pub mod av1_depacketizer;
+pub mod av1_obu;
+pub mod av1_packetizer;
+pub mod avcc;
 pub mod h265_codec;
+pub mod h265_depacketizer;
+pub(crate) mod h265_nal;
+pub mod h265_packetizer;
+pub mod h265_pps;
+pub mod h265_profile_tier_level;
+pub mod h265_sps;
+pub mod h265_vps;
 pub mod h264_codec;
+pub mod h264_depacketizer;
+pub(crate) mod h264_nal;
+pub mod h264_packetizer;
+pub mod h264_pps;
+pub mod h264_sps;
+pub(crate) mod jfif;
+pub mod jpeg_codec;
+pub mod jpeg_depacketizer;
+pub mod jpeg_header;
+pub mod vp8_codec;
+pub mod vp8_depacketizer;
+pub mod vp8_descriptor;
+pub mod vp9_codec;
+pub mod vp9_depacketizer;
+pub mod vp9_descriptor;
+pub mod vui_timing;
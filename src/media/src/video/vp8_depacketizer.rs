@@ -0,0 +1,128 @@
+use std::collections::VecDeque;
+
+use rtp::rtp_packet::RtpPacket;
+
+use crate::{
+    access_unit::AccessUnit,
+    depacketizer::Depacketizer,
+    depacketizer_error::DepacketizerError,
+    media_frame::MediaFrame,
+    packet_sequence::{PacketOrder, PacketSequence},
+    video::{
+        vp8_codec::{self, Vp8Codec},
+        vp8_descriptor::Vp8Descriptor,
+    },
+};
+
+/// Inverse keyframe flag in the first byte of the VP8 frame tag, RFC 6386 section 9.1.
+const INTER_FRAME_MASK: u8 = 0x01;
+
+/// Represents a frame being reassembled.
+#[derive(Debug)]
+struct Frame {
+    timestamp: u32,
+    keyframe: bool,
+    data: Vec<u8>,
+}
+
+/// Reassembles VP8 frames from RTP payloads as defined in RFC 7741.
+///
+/// Packets are pushed in sequence number order and complete frames are pulled without their
+/// payload descriptors. A frame starts with the first packet of partition 0 and is closed by
+/// the marker bit; frames that lost a packet are dropped. Whether a frame is a keyframe is read
+/// from the frame tag at the start of the frame.
+#[derive(Debug)]
+pub struct Vp8Depacketizer {
+    frame: Option<Frame>,
+    sequence: PacketSequence,
+    ready: VecDeque<AccessUnit>,
+}
+
+impl Vp8Depacketizer {
+    /// Creates a new `Vp8Depacketizer` for the stream described by the codec.
+    ///
+    /// # Arguments
+    ///
+    /// * `_codec` - The codec negotiated in SDP, whose parameters do not affect the payload format.
+    pub fn new(_codec: &Vp8Codec) -> Self {
+        Self { frame: None, sequence: PacketSequence::default(), ready: VecDeque::new() }
+    }
+
+    /// Returns the number of packets detected as missing from the sequence.
+    pub fn lost_packets(&self) -> u64 {
+        self.sequence.lost_packets()
+    }
+
+    /// Pushes the next RTP packet of the stream.
+    ///
+    /// # Arguments
+    ///
+    /// * `packet` - The RTP packet carrying a VP8 payload.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` that is an error if the payload is malformed; the frame it belongs to is dropped.
+    pub fn push(&mut self, packet: &RtpPacket) -> Result<(), DepacketizerError> {
+        let header = packet.header();
+        match self.sequence.push(header.sequence_number()) {
+            PacketOrder::Late => return Ok(()),
+            PacketOrder::Next => {}
+            PacketOrder::AfterGap => self.frame = None,
+        }
+
+        let result = self.push_payload(header.timestamp(), packet.payload());
+        if result.is_err() {
+            self.frame = None;
+        }
+        if header.marker() {
+            if let Some(frame) = self.frame.take() {
+                self.ready.push_back(AccessUnit::new(frame.timestamp, frame.keyframe, frame.data));
+            }
+        }
+        result
+    }
+
+    /// Pulls the next complete frame.
+    ///
+    /// # Returns
+    ///
+    /// An `Option` containing the frame, or `None` if none is complete.
+    pub fn pull(&mut self) -> Option<AccessUnit> {
+        self.ready.pop_front()
+    }
+
+    fn push_payload(&mut self, timestamp: u32, payload: &[u8]) -> Result<(), DepacketizerError> {
+        let descriptor = Vp8Descriptor::try_from(payload)?;
+        let data = &payload[descriptor.size()..];
+        if data.is_empty() {
+            return Err(DepacketizerError::Truncated("VP8 payload"));
+        }
+
+        if descriptor.starts_frame() {
+            let keyframe = data[0] & INTER_FRAME_MASK == 0;
+            self.frame = Some(Frame { timestamp, keyframe, data: Vec::new() });
+        }
+        let Some(frame) = self.frame.as_mut() else {
+            return Ok(());
+        };
+        if frame.timestamp != timestamp {
+            return Err(DepacketizerError::Invalid("timestamp"));
+        }
+        frame.data.extend_from_slice(data);
+        Ok(())
+    }
+}
+
+impl Depacketizer for Vp8Depacketizer {
+    fn push(&mut self, packet: &RtpPacket) -> Result<(), DepacketizerError> {
+        Vp8Depacketizer::push(self, packet)
+    }
+
+    fn pull(&mut self) -> Option<MediaFrame> {
+        Vp8Depacketizer::pull(self).map(|access_unit| MediaFrame::from_access_unit(access_unit, vp8_codec::NAME))
+    }
+
+    fn lost_packets(&self) -> u64 {
+        Vp8Depacketizer::lost_packets(self)
+    }
+}
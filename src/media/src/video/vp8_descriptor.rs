@@ -0,0 +1,152 @@
+use crate::depacketizer_error::DepacketizerError;
+
+const EXTENDED_MASK: u8 = 0x80;
+const NON_REFERENCE_MASK: u8 = 0x20;
+const START_MASK: u8 = 0x10;
+const PARTITION_INDEX_MASK: u8 = 0x07;
+
+const PICTURE_ID_MASK: u8 = 0x80;
+const TL0_PIC_IDX_MASK: u8 = 0x40;
+const TID_MASK: u8 = 0x20;
+const KEY_IDX_MASK: u8 = 0x10;
+
+/// Marks a 15-bit picture ID in the first byte of the field.
+const LONG_PICTURE_ID_MASK: u8 = 0x80;
+
+/// Represents the VP8 payload descriptor that starts every payload, RFC 7741 section 4.2.
+///
+/// ```text
+///       0 1 2 3 4 5 6 7
+///      +-+-+-+-+-+-+-+-+
+///      |X|R|N|S|R| PID | (REQUIRED)
+///      +-+-+-+-+-+-+-+-+
+/// X:   |I|L|T|K| RSV   | (OPTIONAL)
+///      +-+-+-+-+-+-+-+-+
+/// I:   |M| PictureID   | (OPTIONAL)
+///      +-+-+-+-+-+-+-+-+
+///      |   PictureID   |
+///      +-+-+-+-+-+-+-+-+
+/// L:   |   TL0PICIDX   | (OPTIONAL)
+///      +-+-+-+-+-+-+-+-+
+/// T/K: |TID|Y| KEYIDX  | (OPTIONAL)
+///      +-+-+-+-+-+-+-+-+
+/// ```
+///
+/// # Fields
+///
+/// * `non_reference` - Whether the frame can be discarded without affecting any other frame.
+/// * `start_of_partition` - Whether the payload starts a VP8 partition.
+/// * `partition_index` - The index of the partition the payload starts in.
+/// * `picture_id` - The 7 or 15-bit running index of the frame.
+/// * `tl0_pic_idx` - The running index of the frames of the base temporal layer.
+/// * `temporal_id` - The temporal layer of the frame.
+/// * `layer_sync` - Whether the frame only depends on the base temporal layer.
+/// * `key_idx` - The running index of the keyframes.
+/// * `size` - The size of the descriptor in bytes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Vp8Descriptor {
+    non_reference: bool,
+    start_of_partition: bool,
+    partition_index: u8,
+    picture_id: Option<u16>,
+    tl0_pic_idx: Option<u8>,
+    temporal_id: Option<u8>,
+    layer_sync: bool,
+    key_idx: Option<u8>,
+    size: usize,
+}
+
+impl Vp8Descriptor {
+    pub fn non_reference(&self) -> bool {
+        self.non_reference
+    }
+
+    pub fn start_of_partition(&self) -> bool {
+        self.start_of_partition
+    }
+
+    pub fn partition_index(&self) -> u8 {
+        self.partition_index
+    }
+
+    pub fn picture_id(&self) -> Option<u16> {
+        self.picture_id
+    }
+
+    pub fn tl0_pic_idx(&self) -> Option<u8> {
+        self.tl0_pic_idx
+    }
+
+    pub fn temporal_id(&self) -> Option<u8> {
+        self.temporal_id
+    }
+
+    pub fn layer_sync(&self) -> bool {
+        self.layer_sync
+    }
+
+    pub fn key_idx(&self) -> Option<u8> {
+        self.key_idx
+    }
+
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// Returns whether the payload starts a frame, which is the start of its first partition.
+    pub fn starts_frame(&self) -> bool {
+        self.start_of_partition && self.partition_index == 0
+    }
+}
+
+impl TryFrom<&[u8]> for Vp8Descriptor {
+    type Error = DepacketizerError;
+
+    fn try_from(payload: &[u8]) -> Result<Self, Self::Error> {
+        let first = *payload.first().ok_or(DepacketizerError::Truncated("VP8 payload descriptor"))?;
+        let mut descriptor = Self {
+            non_reference: first & NON_REFERENCE_MASK != 0,
+            start_of_partition: first & START_MASK != 0,
+            partition_index: first & PARTITION_INDEX_MASK,
+            picture_id: None,
+            tl0_pic_idx: None,
+            temporal_id: None,
+            layer_sync: false,
+            key_idx: None,
+            size: 1,
+        };
+        if first & EXTENDED_MASK == 0 {
+            return Ok(descriptor);
+        }
+
+        let mut next = |name: &'static str| {
+            let byte = payload.get(descriptor.size).copied().ok_or(DepacketizerError::Truncated(name));
+            descriptor.size += 1;
+            byte
+        };
+        let extension = next("VP8 extension")?;
+        if extension & PICTURE_ID_MASK != 0 {
+            let high = next("PictureID")?;
+            descriptor.picture_id = Some(if high & LONG_PICTURE_ID_MASK != 0 {
+                u16::from_be_bytes([high & !LONG_PICTURE_ID_MASK, next("PictureID")?])
+            }
+            else {
+                high as u16
+            });
+        }
+        if extension & TL0_PIC_IDX_MASK != 0 {
+            descriptor.tl0_pic_idx = Some(next("TL0PICIDX")?);
+        }
+        if extension & (TID_MASK | KEY_IDX_MASK) != 0 {
+            let byte = next("TID/KEYIDX")?;
+            if extension & TID_MASK != 0 {
+                descriptor.temporal_id = Some(byte >> 6);
+                descriptor.layer_sync = byte & 0x20 != 0;
+            }
+            if extension & KEY_IDX_MASK != 0 {
+                descriptor.key_idx = Some(byte & 0x1F);
+            }
+        }
+        Ok(descriptor)
+    }
+}
@@ -0,0 +1,139 @@
+use abstractions::{
+    extensions::{array_extensions::ArrayExt, utf8_array_extensions::U8ArrayExt},
+    parsing::{parsing_error::ParsingError, SEMICOLON, WHITESPACE},
+};
+
+use crate::codec::{Codec, CodecHelper, FMTP_KEY};
+
+const MAX_FR_START: &[u8] = b"max-fr=";
+const MAX_FS_START: &[u8] = b"max-fs=";
+const PROFILE_ID_START: &[u8] = b"profile-id=";
+
+pub(crate) const NAME: &str = "VP9";
+
+/// Represents a VP9 stream as described in RFC 9628.
+///
+/// # Fields
+///
+/// * `max_fr` - The highest frame rate the receiver can decode, in frames per second.
+/// * `max_fs` - The largest frame the receiver can decode, in macroblocks.
+/// * `profile_id` - The VP9 profile of the stream, 0 when absent.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Vp9Codec {
+    format: u16,
+    clock_rate: u32,
+    channel_count: Option<u8>,
+    max_fr: Option<u32>,
+    max_fs: Option<u32>,
+    profile_id: Option<u8>,
+}
+
+impl Vp9Codec {
+    pub fn new(
+        format: u16,
+        clock_rate: u32,
+        channel_count: Option<u8>,
+        max_fr: Option<u32>,
+        max_fs: Option<u32>,
+        profile_id: Option<u8>,
+    ) -> Self {
+        Self { format, clock_rate, channel_count, max_fr, max_fs, profile_id }
+    }
+
+    pub fn max_fr(&self) -> Option<u32> {
+        self.max_fr
+    }
+
+    pub fn max_fs(&self) -> Option<u32> {
+        self.max_fs
+    }
+
+    pub fn profile_id(&self) -> Option<u8> {
+        self.profile_id
+    }
+
+    /// Parses the codec from the lines that follow its `a=rtpmap` line.
+    ///
+    /// Unlike `Codec::parse`, the `a=fmtp` line may be missing since every format parameter is optional.
+    ///
+    /// # Arguments
+    ///
+    /// * `format` - The payload format number of the `a=rtpmap` line.
+    /// * `clock_rate` - The clock rate of the codec.
+    /// * `channel_count` - The optional channel count.
+    /// * `data` - A byte slice that may contain the `a=fmtp` line of the codec.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the codec, or a `ParsingError` if the `a=fmtp` line is malformed.
+    pub fn from_rtpmap(format: u16, clock_rate: u32, channel_count: Option<u8>, data: &[u8]) -> Result<Self, ParsingError> {
+        if data.separate(FMTP_KEY).is_none() {
+            return Ok(Vp9Codec::new(format, clock_rate, channel_count, None, None, None));
+        }
+        Self::from_fmtp(clock_rate, channel_count, data)
+    }
+}
+
+impl CodecHelper for Vp9Codec {
+    fn from_fmtp_internal(format: u16, clock_rate: u32, channel_count: Option<u8>, data: &[u8]) -> Result<Self, ParsingError> {
+        let mut codec = Vp9Codec::new(format, clock_rate, channel_count, None, None, None);
+
+        let mut current = data;
+        while let Some((left, right)) = current.while_separate_trimmed(SEMICOLON, WHITESPACE) {
+            if let Some(value) = left.strip_prefix(MAX_FR_START) {
+                codec.max_fr = Some(value.utf8_to_number::<u32>()?);
+            }
+            else if let Some(value) = left.strip_prefix(MAX_FS_START) {
+                codec.max_fs = Some(value.utf8_to_number::<u32>()?);
+            }
+            else if let Some(value) = left.strip_prefix(PROFILE_ID_START) {
+                let profile_id = value.utf8_to_number::<u8>()?;
+                if profile_id > 3 {
+                    return Err(ParsingError::from_bytes(value));
+                }
+                codec.profile_id = Some(profile_id);
+            }
+            current = right;
+        }
+        Ok(codec)
+    }
+}
+
+impl Codec for Vp9Codec {
+    fn channel_count(&self) -> &Option<u8> {
+        &self.channel_count
+    }
+
+    fn clock_rate(&self) -> u32 {
+        self.clock_rate
+    }
+
+    fn format(&self) -> u16 {
+        self.format
+    }
+
+    fn name(&self) -> &'static str {
+        NAME
+    }
+
+    fn fmtp(&self) -> Option<String> {
+        let mut parameters = Vec::new();
+        if let Some(max_fr) = self.max_fr {
+            parameters.push(format!("max-fr={}", max_fr));
+        }
+        if let Some(max_fs) = self.max_fs {
+            parameters.push(format!("max-fs={}", max_fs));
+        }
+        if let Some(profile_id) = self.profile_id {
+            parameters.push(format!("profile-id={}", profile_id));
+        }
+        (!parameters.is_empty()).then(|| parameters.join("; "))
+    }
+
+    fn parse(clock_rate: u32, channel_count: Option<u8>, data: &[u8]) -> Result<Self, ParsingError>
+    where
+        Self: Sized,
+    {
+        Self::from_fmtp(clock_rate, channel_count, data)
+    }
+}
@@ -0,0 +1,141 @@
+use std::collections::VecDeque;
+
+use rtp::rtp_packet::RtpPacket;
+
+use crate::{
+    access_unit::AccessUnit,
+    depacketizer::Depacketizer,
+    depacketizer_error::DepacketizerError,
+    media_frame::MediaFrame,
+    packet_sequence::{PacketOrder, PacketSequence},
+    video::{
+        vp9_codec::{self, Vp9Codec},
+        vp9_descriptor::{Vp9Descriptor, Vp9ScalabilityStructure},
+    },
+};
+
+/// Represents a frame being reassembled.
+#[derive(Debug)]
+struct Frame {
+    timestamp: u32,
+    keyframe: bool,
+    data: Vec<u8>,
+}
+
+/// Reassembles VP9 frames from RTP payloads as defined in RFC 9628.
+///
+/// Packets are pushed in sequence number order and complete frames are pulled without their
+/// payload descriptors. A frame spans the packets from the one with the B bit to the one with
+/// the E bit; frames that lost a packet are dropped. With spatial scalability, every spatial
+/// layer of a picture is pulled as a separate frame sharing the RTP timestamp of the picture.
+///
+/// A frame is a keyframe when it neither references earlier pictures nor depends on a lower spatial layer.
+#[derive(Debug)]
+pub struct Vp9Depacketizer {
+    frame: Option<Frame>,
+    scalability_structure: Option<Vp9ScalabilityStructure>,
+    sequence: PacketSequence,
+    ready: VecDeque<AccessUnit>,
+}
+
+impl Vp9Depacketizer {
+    /// Creates a new `Vp9Depacketizer` for the stream described by the codec.
+    ///
+    /// # Arguments
+    ///
+    /// * `_codec` - The codec negotiated in SDP, whose parameters do not affect the payload format.
+    pub fn new(_codec: &Vp9Codec) -> Self {
+        Self { frame: None, scalability_structure: None, sequence: PacketSequence::default(), ready: VecDeque::new() }
+    }
+
+    /// Returns the number of packets detected as missing from the sequence.
+    pub fn lost_packets(&self) -> u64 {
+        self.sequence.lost_packets()
+    }
+
+    /// Returns the last scalability structure received, describing the layers and resolutions of the stream.
+    pub fn scalability_structure(&self) -> Option<&Vp9ScalabilityStructure> {
+        self.scalability_structure.as_ref()
+    }
+
+    /// Pushes the next RTP packet of the stream.
+    ///
+    /// # Arguments
+    ///
+    /// * `packet` - The RTP packet carrying a VP9 payload.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` that is an error if the payload is malformed; the frame it belongs to is dropped.
+    pub fn push(&mut self, packet: &RtpPacket) -> Result<(), DepacketizerError> {
+        let header = packet.header();
+        match self.sequence.push(header.sequence_number()) {
+            PacketOrder::Late => return Ok(()),
+            PacketOrder::Next => {}
+            PacketOrder::AfterGap => self.frame = None,
+        }
+
+        match self.push_payload(header.timestamp(), packet.payload()) {
+            Ok(end_of_frame) => {
+                if end_of_frame {
+                    if let Some(frame) = self.frame.take() {
+                        self.ready.push_back(AccessUnit::new(frame.timestamp, frame.keyframe, frame.data));
+                    }
+                }
+                Ok(())
+            }
+            Err(error) => {
+                self.frame = None;
+                Err(error)
+            }
+        }
+    }
+
+    /// Pulls the next complete frame.
+    ///
+    /// # Returns
+    ///
+    /// An `Option` containing the frame, or `None` if none is complete.
+    pub fn pull(&mut self) -> Option<AccessUnit> {
+        self.ready.pop_front()
+    }
+
+    /// Appends the payload to the current frame.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing whether the payload ends the frame.
+    fn push_payload(&mut self, timestamp: u32, payload: &[u8]) -> Result<bool, DepacketizerError> {
+        let descriptor = Vp9Descriptor::try_from(payload)?;
+        let data = &payload[descriptor.size()..];
+        if let Some(scalability_structure) = descriptor.scalability_structure() {
+            self.scalability_structure = Some(scalability_structure.clone());
+        }
+
+        if descriptor.beginning_of_frame() {
+            self.frame = Some(Frame { timestamp, keyframe: descriptor.is_keyframe(), data: Vec::new() });
+        }
+        let Some(frame) = self.frame.as_mut() else {
+            return Ok(false);
+        };
+        if frame.timestamp != timestamp {
+            return Err(DepacketizerError::Invalid("timestamp"));
+        }
+        frame.data.extend_from_slice(data);
+        Ok(descriptor.end_of_frame())
+    }
+}
+
+impl Depacketizer for Vp9Depacketizer {
+    fn push(&mut self, packet: &RtpPacket) -> Result<(), DepacketizerError> {
+        Vp9Depacketizer::push(self, packet)
+    }
+
+    fn pull(&mut self) -> Option<MediaFrame> {
+        Vp9Depacketizer::pull(self).map(|access_unit| MediaFrame::from_access_unit(access_unit, vp9_codec::NAME))
+    }
+
+    fn lost_packets(&self) -> u64 {
+        Vp9Depacketizer::lost_packets(self)
+    }
+}
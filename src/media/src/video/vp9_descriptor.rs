@@ -0,0 +1,353 @@
+use byteorder::{BigEndian, ByteOrder};
+
+use crate::depacketizer_error::DepacketizerError;
+
+const PICTURE_ID_MASK: u8 = 0x80;
+const INTER_PICTURE_PREDICTED_MASK: u8 = 0x40;
+const LAYER_INDICES_MASK: u8 = 0x20;
+const FLEXIBLE_MASK: u8 = 0x10;
+const BEGINNING_OF_FRAME_MASK: u8 = 0x08;
+const END_OF_FRAME_MASK: u8 = 0x04;
+const SCALABILITY_STRUCTURE_MASK: u8 = 0x02;
+const NOT_REFERENCE_MASK: u8 = 0x01;
+
+/// Marks a 15-bit picture ID in the first byte of the field.
+const LONG_PICTURE_ID_MASK: u8 = 0x80;
+
+/// Marks that another reference index follows in flexible mode.
+const NEXT_REFERENCE_MASK: u8 = 0x01;
+
+/// Highest number of reference indices of a frame in flexible mode.
+const MAX_REFERENCES: usize = 3;
+
+/// Represents the layer indices of a VP9 payload descriptor.
+///
+/// # Fields
+///
+/// * `temporal_id` - The temporal layer of the frame.
+/// * `switching_up` - Whether the decoder can switch up to a higher temporal layer at this frame.
+/// * `spatial_id` - The spatial layer of the frame.
+/// * `inter_layer_dependency` - Whether the frame depends on the lower spatial layer of the same picture.
+/// * `tl0_pic_idx` - The running index of the pictures of the base temporal layer, only present in non-flexible mode.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Vp9LayerIndices {
+    temporal_id: u8,
+    switching_up: bool,
+    spatial_id: u8,
+    inter_layer_dependency: bool,
+    tl0_pic_idx: Option<u8>,
+}
+
+impl Vp9LayerIndices {
+    pub fn new(temporal_id: u8, switching_up: bool, spatial_id: u8, inter_layer_dependency: bool, tl0_pic_idx: Option<u8>) -> Self {
+        Self { temporal_id, switching_up, spatial_id, inter_layer_dependency, tl0_pic_idx }
+    }
+
+    pub fn temporal_id(&self) -> u8 {
+        self.temporal_id
+    }
+
+    pub fn switching_up(&self) -> bool {
+        self.switching_up
+    }
+
+    pub fn spatial_id(&self) -> u8 {
+        self.spatial_id
+    }
+
+    pub fn inter_layer_dependency(&self) -> bool {
+        self.inter_layer_dependency
+    }
+
+    pub fn tl0_pic_idx(&self) -> Option<u8> {
+        self.tl0_pic_idx
+    }
+}
+
+/// Represents a picture of the group of pictures described by a scalability structure.
+///
+/// # Fields
+///
+/// * `temporal_id` - The temporal layer of the picture.
+/// * `switching_up` - Whether the decoder can switch up to a higher temporal layer at this picture.
+/// * `reference_diffs` - The differences between the picture ID of the picture and those of its references.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Vp9PictureGroupEntry {
+    temporal_id: u8,
+    switching_up: bool,
+    reference_diffs: Vec<u8>,
+}
+
+impl Vp9PictureGroupEntry {
+    pub fn new(temporal_id: u8, switching_up: bool, reference_diffs: Vec<u8>) -> Self {
+        Self { temporal_id, switching_up, reference_diffs }
+    }
+
+    pub fn temporal_id(&self) -> u8 {
+        self.temporal_id
+    }
+
+    pub fn switching_up(&self) -> bool {
+        self.switching_up
+    }
+
+    pub fn reference_diffs(&self) -> &[u8] {
+        &self.reference_diffs
+    }
+}
+
+/// Represents the scalability structure of a VP9 payload descriptor, RFC 9628 section 4.2.1.
+///
+/// ```text
+///      +-+-+-+-+-+-+-+-+
+/// V:   | N_S |Y|G|-|-|-|
+///      +-+-+-+-+-+-+-+-+              -\
+/// Y:   |     WIDTH     | (OPTIONAL)    .
+///      +               +               .
+///      |               | (OPTIONAL)    .
+///      +-+-+-+-+-+-+-+-+               . N_S + 1 times
+///      |     HEIGHT    | (OPTIONAL)    .
+///      +               +               .
+///      |               | (OPTIONAL)    .
+///      +-+-+-+-+-+-+-+-+              -/
+/// G:   |      N_G      | (OPTIONAL)
+///      +-+-+-+-+-+-+-+-+                           -\
+/// N_G: |  T  |U| R |-|-| (OPTIONAL)                 .
+///      +-+-+-+-+-+-+-+-+              -\            . N_G times
+///      |    P_DIFF     | (OPTIONAL)    . R times    .
+///      +-+-+-+-+-+-+-+-+              -/           -/
+/// ```
+///
+/// # Fields
+///
+/// * `spatial_layers` - The number of spatial layers of the stream.
+/// * `resolutions` - The width and height of each spatial layer, empty when not signaled.
+/// * `picture_group` - The pictures of the group of pictures, empty when not signaled.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Vp9ScalabilityStructure {
+    spatial_layers: u8,
+    resolutions: Vec<(u16, u16)>,
+    picture_group: Vec<Vp9PictureGroupEntry>,
+}
+
+impl Vp9ScalabilityStructure {
+    pub fn new(spatial_layers: u8, resolutions: Vec<(u16, u16)>, picture_group: Vec<Vp9PictureGroupEntry>) -> Self {
+        Self { spatial_layers, resolutions, picture_group }
+    }
+
+    pub fn spatial_layers(&self) -> u8 {
+        self.spatial_layers
+    }
+
+    pub fn resolutions(&self) -> &[(u16, u16)] {
+        &self.resolutions
+    }
+
+    pub fn picture_group(&self) -> &[Vp9PictureGroupEntry] {
+        &self.picture_group
+    }
+}
+
+/// Represents the VP9 payload descriptor that starts every payload, RFC 9628 section 4.2.
+///
+/// ```text
+///       0 1 2 3 4 5 6 7
+///      +-+-+-+-+-+-+-+-+
+///      |I|P|L|F|B|E|V|Z| (REQUIRED)
+///      +-+-+-+-+-+-+-+-+
+/// I:   |M| PICTURE ID  | (REQUIRED)
+///      +-+-+-+-+-+-+-+-+
+/// M:   | EXTENDED PID  | (RECOMMENDED)
+///      +-+-+-+-+-+-+-+-+
+/// L:   |  TID  |U| SID |D| (CONDITIONALLY RECOMMENDED)
+///      +-+-+-+-+-+-+-+-+
+///      |   TL0PICIDX   | (CONDITIONALLY REQUIRED, non-flexible mode)
+///      +-+-+-+-+-+-+-+-+
+/// P,F: | P_DIFF      |N| (CONDITIONALLY REQUIRED, flexible mode)    - up to 3 times
+///      +-+-+-+-+-+-+-+-+
+/// V:   | SS            |
+///      | ..            |
+///      +-+-+-+-+-+-+-+-+
+/// ```
+///
+/// # Fields
+///
+/// * `picture_id` - The 7 or 15-bit running index of the picture.
+/// * `inter_picture_predicted` - Whether the frame references earlier pictures.
+/// * `flexible` - Whether the references are signaled per frame instead of by a scalability structure.
+/// * `beginning_of_frame` - Whether the payload starts a frame.
+/// * `end_of_frame` - Whether the payload ends a frame.
+/// * `not_reference` - Whether no frame of a higher spatial layer of the picture references this frame.
+/// * `layer_indices` - The temporal and spatial layer of the frame.
+/// * `reference_diffs` - In flexible mode, the differences between the picture ID and those of the references.
+/// * `scalability_structure` - The scalability structure, usually sent with keyframes.
+/// * `size` - The size of the descriptor in bytes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Vp9Descriptor {
+    picture_id: Option<u16>,
+    inter_picture_predicted: bool,
+    flexible: bool,
+    beginning_of_frame: bool,
+    end_of_frame: bool,
+    not_reference: bool,
+    layer_indices: Option<Vp9LayerIndices>,
+    reference_diffs: Vec<u8>,
+    scalability_structure: Option<Vp9ScalabilityStructure>,
+    size: usize,
+}
+
+impl Vp9Descriptor {
+    pub fn picture_id(&self) -> Option<u16> {
+        self.picture_id
+    }
+
+    pub fn inter_picture_predicted(&self) -> bool {
+        self.inter_picture_predicted
+    }
+
+    pub fn flexible(&self) -> bool {
+        self.flexible
+    }
+
+    pub fn beginning_of_frame(&self) -> bool {
+        self.beginning_of_frame
+    }
+
+    pub fn end_of_frame(&self) -> bool {
+        self.end_of_frame
+    }
+
+    pub fn not_reference(&self) -> bool {
+        self.not_reference
+    }
+
+    pub fn layer_indices(&self) -> Option<&Vp9LayerIndices> {
+        self.layer_indices.as_ref()
+    }
+
+    pub fn reference_diffs(&self) -> &[u8] {
+        &self.reference_diffs
+    }
+
+    pub fn scalability_structure(&self) -> Option<&Vp9ScalabilityStructure> {
+        self.scalability_structure.as_ref()
+    }
+
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// Returns whether the frame can be decoded on its own: it neither references earlier
+    /// pictures nor depends on a lower spatial layer.
+    pub fn is_keyframe(&self) -> bool {
+        !self.inter_picture_predicted && !self.layer_indices.is_some_and(|layer_indices| layer_indices.inter_layer_dependency)
+    }
+}
+
+/// Reads the fields of a descriptor one byte at a time.
+struct Reader<'a> {
+    data: &'a [u8],
+    position: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn byte(&mut self, name: &'static str) -> Result<u8, DepacketizerError> {
+        let byte = self.data.get(self.position).copied().ok_or(DepacketizerError::Truncated(name))?;
+        self.position += 1;
+        Ok(byte)
+    }
+
+    fn u16(&mut self, name: &'static str) -> Result<u16, DepacketizerError> {
+        let bytes = self.data.get(self.position..self.position + 2).ok_or(DepacketizerError::Truncated(name))?;
+        self.position += 2;
+        Ok(BigEndian::read_u16(bytes))
+    }
+}
+
+impl TryFrom<&[u8]> for Vp9Descriptor {
+    type Error = DepacketizerError;
+
+    fn try_from(payload: &[u8]) -> Result<Self, Self::Error> {
+        let mut reader = Reader { data: payload, position: 0 };
+        let first = reader.byte("VP9 payload descriptor")?;
+        let inter_picture_predicted = first & INTER_PICTURE_PREDICTED_MASK != 0;
+        let flexible = first & FLEXIBLE_MASK != 0;
+
+        let mut picture_id = None;
+        if first & PICTURE_ID_MASK != 0 {
+            let high = reader.byte("PICTURE ID")?;
+            picture_id = Some(if high & LONG_PICTURE_ID_MASK != 0 {
+                u16::from_be_bytes([high & !LONG_PICTURE_ID_MASK, reader.byte("EXTENDED PID")?])
+            }
+            else {
+                high as u16
+            });
+        }
+
+        let mut layer_indices = None;
+        if first & LAYER_INDICES_MASK != 0 {
+            let byte = reader.byte("layer indices")?;
+            let tl0_pic_idx = if flexible { None } else { Some(reader.byte("TL0PICIDX")?) };
+            layer_indices = Some(Vp9LayerIndices::new(byte >> 5, byte & 0x10 != 0, (byte >> 1) & 0x07, byte & 0x01 != 0, tl0_pic_idx));
+        }
+
+        let mut reference_diffs = Vec::new();
+        if flexible && inter_picture_predicted {
+            loop {
+                if reference_diffs.len() == MAX_REFERENCES {
+                    return Err(DepacketizerError::Invalid("P_DIFF count"));
+                }
+                let byte = reader.byte("P_DIFF")?;
+                reference_diffs.push(byte >> 1);
+                if byte & NEXT_REFERENCE_MASK == 0 {
+                    break;
+                }
+            }
+        }
+
+        let mut scalability_structure = None;
+        if first & SCALABILITY_STRUCTURE_MASK != 0 {
+            scalability_structure = Some(read_scalability_structure(&mut reader)?);
+        }
+
+        Ok(Self {
+            picture_id,
+            inter_picture_predicted,
+            flexible,
+            beginning_of_frame: first & BEGINNING_OF_FRAME_MASK != 0,
+            end_of_frame: first & END_OF_FRAME_MASK != 0,
+            not_reference: first & NOT_REFERENCE_MASK != 0,
+            layer_indices,
+            reference_diffs,
+            scalability_structure,
+            size: reader.position,
+        })
+    }
+}
+
+fn read_scalability_structure(reader: &mut Reader) -> Result<Vp9ScalabilityStructure, DepacketizerError> {
+    let byte = reader.byte("SS")?;
+    let spatial_layers = (byte >> 5) + 1;
+
+    let mut resolutions = Vec::new();
+    if byte & 0x10 != 0 {
+        for _ in 0..spatial_layers {
+            let width = reader.u16("WIDTH")?;
+            let height = reader.u16("HEIGHT")?;
+            resolutions.push((width, height));
+        }
+    }
+
+    let mut picture_group = Vec::new();
+    if byte & 0x08 != 0 {
+        let count = reader.byte("N_G")?;
+        for _ in 0..count {
+            let entry = reader.byte("picture group entry")?;
+            let reference_count = (entry >> 2) & 0x03;
+            let reference_diffs = (0..reference_count).map(|_| reader.byte("P_DIFF")).collect::<Result<Vec<_>, _>>()?;
+            picture_group.push(Vp9PictureGroupEntry::new(entry >> 5, entry & 0x10 != 0, reference_diffs));
+        }
+    }
+
+    Ok(Vp9ScalabilityStructure::new(spatial_layers, resolutions, picture_group))
+}
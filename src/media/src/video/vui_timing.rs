@@ -0,0 +1,33 @@
+/// Represents the timing information of the video usability information of a sequence parameter set.
+///
+/// # Fields
+///
+/// * `num_units_in_tick` - The number of time units of a clock tick.
+/// * `time_scale` - The number of time units that pass in one second.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VuiTiming {
+    num_units_in_tick: u32,
+    time_scale: u32,
+}
+
+impl VuiTiming {
+    pub fn new(num_units_in_tick: u32, time_scale: u32) -> Self {
+        Self { num_units_in_tick, time_scale }
+    }
+
+    pub fn num_units_in_tick(&self) -> u32 {
+        self.num_units_in_tick
+    }
+
+    pub fn time_scale(&self) -> u32 {
+        self.time_scale
+    }
+
+    /// Returns the number of clock ticks per second, or `None` if `num_units_in_tick` is zero.
+    pub fn tick_rate(&self) -> Option<f64> {
+        if self.num_units_in_tick == 0 {
+            return None;
+        }
+        Some(self.time_scale as f64 / self.num_units_in_tick as f64)
+    }
+}
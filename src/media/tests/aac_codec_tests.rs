@@ -14,7 +14,7 @@ use rstest::rstest;
         3,
         3,
         Some(vec![0x11, 0x90]),
-    ))
+    ).with_stream_parameters(5, 15, "AAC-hbr"))
 )]
 #[case(
     48000,
@@ -37,4 +37,22 @@ fn test_from_fmtp_internal(
 {
     let result = AacCodec::parse(clock_rate, channel_count, data).map_err(|_| ());
     assert_eq!(result, expected);
-}
\ No newline at end of file
+}
+
+#[rstest]
+#[case(
+    b"a=fmtp:97 streamtype=5; profile-level-id=15; mode=AAC-hbr; config=1190; sizeLength=13; indexLength=3; indexDeltaLength=3",
+    "streamtype=5; profile-level-id=15; mode=AAC-hbr; sizeLength=13; indexLength=3; indexDeltaLength=3; config=1190"
+)]
+#[case(
+    b"a=fmtp:97 streamtype=5; profile-level-id=41; mode=AAC-lbr; config=1210; sizeLength=6; indexLength=2; indexDeltaLength=2",
+    "streamtype=5; profile-level-id=41; mode=AAC-lbr; sizeLength=6; indexLength=2; indexDeltaLength=2; config=1210"
+)]
+#[case(
+    b"a=fmtp:97 config=1190; sizeLength=13; indexLength=3; indexDeltaLength=3",
+    "streamtype=5; profile-level-id=1; mode=AAC-hbr; sizeLength=13; indexLength=3; indexDeltaLength=3; config=1190"
+)]
+fn test_fmtp(#[case] data: &[u8], #[case] expected: &str) {
+    let codec = AacCodec::parse(48000, Some(2), data).unwrap();
+    assert_eq!(codec.fmtp().as_deref(), Some(expected));
+}
@@ -0,0 +1,98 @@
+use media::{
+    audio::{aac_codec::AacCodec, aac_depacketizer::AacDepacketizer},
+    codec::Codec,
+    depacketizer_error::DepacketizerError,
+};
+use rstest::rstest;
+use rtp::{rtp_header::RtpHeader, rtp_packet::RtpPacket};
+
+fn depacketizer() -> AacDepacketizer {
+    let fmtp = b"a=fmtp:97 streamtype=5; profile-level-id=15; mode=AAC-hbr; config=1190; sizeLength=13; indexLength=3; indexDeltaLength=3";
+    AacDepacketizer::new(&AacCodec::parse(48000, Some(2), fmtp).unwrap())
+}
+
+fn push(depacketizer: &mut AacDepacketizer, sequence_number: u16, timestamp: u32, marker: bool, payload: &[u8]) -> Result<(), DepacketizerError> {
+    let header = RtpHeader::new(2, false, false, 0, marker, 97, sequence_number, timestamp, 0x1234, &[]);
+    depacketizer.push(&RtpPacket::new(header, payload))
+}
+
+#[rstest]
+#[case(&[0x00, 0x10, 0x00, 0x20, 1, 2, 3, 4], vec![(1000, vec![1, 2, 3, 4])])]
+#[case(
+    &[0x00, 0x30, 0x00, 0x10, 0x00, 0x18, 0x00, 0x08, 1, 2, 3, 4, 5, 6],
+    vec![(1000, vec![1, 2]), (2024, vec![3, 4, 5]), (3048, vec![6])]
+)]
+#[case(&[0x00, 0x20, 0x00, 0x11, 0x00, 0x09, 1, 2, 3], vec![(1000, vec![1, 2]), (3048, vec![3])])]
+fn test_au_headers(#[case] payload: &[u8], #[case] expected: Vec<(u32, Vec<u8>)>) {
+    let mut depacketizer = depacketizer();
+
+    push(&mut depacketizer, 1, 1000, true, payload).unwrap();
+
+    let access_units = std::iter::from_fn(|| depacketizer.pull())
+        .map(|access_unit| (access_unit.timestamp(), access_unit.into_data()))
+        .collect::<Vec<_>>();
+    assert_eq!(access_units, expected);
+}
+
+#[test]
+fn test_au_index_wraps_around() {
+    let fmtp = b"a=fmtp:97 streamtype=5; profile-level-id=15; mode=AAC-hbr; config=1190; sizeLength=8; indexLength=32; indexDeltaLength=32";
+    let mut depacketizer = AacDepacketizer::new(&AacCodec::parse(48000, Some(2), fmtp).unwrap());
+
+    let payload = [0x00, 0x50, 0x01, 0xFF, 0xFF, 0xFF, 0xFF, 0x01, 0x00, 0x00, 0x00, 0x00, 1, 2];
+    push(&mut depacketizer, 1, 1000, true, &payload).unwrap();
+
+    let access_units = std::iter::from_fn(|| depacketizer.pull())
+        .map(|access_unit| (access_unit.timestamp(), access_unit.into_data()))
+        .collect::<Vec<_>>();
+    assert_eq!(access_units, vec![(1000, vec![1]), (2024, vec![2])]);
+}
+
+#[test]
+fn test_fragmented_access_unit() {
+    let mut depacketizer = depacketizer();
+
+    push(&mut depacketizer, 10, 0, false, &[0x00, 0x10, 0x00, 0x30, 1, 2, 3]).unwrap();
+    assert_eq!(depacketizer.pull(), None);
+    push(&mut depacketizer, 11, 0, true, &[0x00, 0x10, 0x00, 0x30, 4, 5, 6]).unwrap();
+
+    let access_unit = depacketizer.pull().unwrap();
+    assert_eq!(access_unit.timestamp(), 0);
+    assert_eq!(access_unit.data(), &[1, 2, 3, 4, 5, 6]);
+}
+
+#[test]
+fn test_lost_fragment_drops_access_unit() {
+    let mut depacketizer = depacketizer();
+
+    push(&mut depacketizer, 10, 0, false, &[0x00, 0x10, 0x00, 0x48, 1, 2, 3]).unwrap();
+    push(&mut depacketizer, 12, 0, true, &[0x00, 0x10, 0x00, 0x48, 7, 8, 9]).unwrap();
+    push(&mut depacketizer, 13, 1024, true, &[0x00, 0x10, 0x00, 0x08, 10]).unwrap();
+
+    let access_unit = depacketizer.pull().unwrap();
+    assert_eq!(access_unit.timestamp(), 1024);
+    assert_eq!(access_unit.data(), &[10]);
+    assert_eq!(depacketizer.pull(), None);
+    assert_eq!(depacketizer.lost_packets(), 1);
+}
+
+#[rstest]
+#[case(&[0x00], DepacketizerError::Truncated("AU-headers-length"))]
+#[case(&[0x00, 0x20, 0x00, 0x10], DepacketizerError::Truncated("AU headers"))]
+#[case(&[0x00, 0x20, 0x00, 0x10, 0x00, 0x10, 1, 2, 3], DepacketizerError::Truncated("access unit"))]
+fn test_malformed_payload(#[case] payload: &[u8], #[case] expected: DepacketizerError) {
+    let mut depacketizer = depacketizer();
+
+    assert_eq!(push(&mut depacketizer, 1, 0, true, payload), Err(expected));
+}
+
+#[test]
+fn test_timestamps_follow_sbr_clock_rate() {
+    let fmtp = b"a=fmtp:97 streamtype=5; profile-level-id=44; mode=AAC-hbr; config=2B118800; sizeLength=13; indexLength=3; indexDeltaLength=3";
+    let mut depacketizer = AacDepacketizer::new(&AacCodec::parse(48000, Some(2), fmtp).unwrap());
+
+    push(&mut depacketizer, 1, 0, true, &[0x00, 0x20, 0x00, 0x08, 0x00, 0x08, 1, 2]).unwrap();
+
+    assert_eq!(depacketizer.pull().unwrap().timestamp(), 0);
+    assert_eq!(depacketizer.pull().unwrap().timestamp(), 2048);
+}
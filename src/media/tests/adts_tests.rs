@@ -0,0 +1,27 @@
+use media::{
+    audio::{adts, audio_specific_config::AudioSpecificConfig},
+    config_error::ConfigError,
+};
+use rstest::rstest;
+
+#[rstest]
+#[case(&[0x11, 0x90], 100, Ok([0xFF, 0xF1, 0x4C, 0x80, 0x0D, 0x7F, 0xFC]))]
+#[case(&[0x12, 0x08], 1, Ok([0xFF, 0xF1, 0x50, 0x40, 0x01, 0x1F, 0xFC]))]
+#[case(&[0x2B, 0x11, 0x88, 0x00], 8184, Ok([0xFF, 0xF1, 0x58, 0x83, 0xFF, 0xFF, 0xFC]))]
+#[case(&[0x11, 0x90], 8185, Err(ConfigError::FrameTooLarge(8185)))]
+#[case(&[0xB8, 0x98], 100, Err(ConfigError::Unsupported("ADTS audio object type", 23)))]
+#[case(&[0x17, 0x80, 0x03, 0xE8, 0x10], 100, Err(ConfigError::Unsupported("ADTS sampling frequency", 2000)))]
+fn test_write_header(#[case] config: &[u8], #[case] frame_size: usize, #[case] expected: Result<[u8; adts::ADTS_HEADER_SIZE], ConfigError>) {
+    let config = AudioSpecificConfig::try_from(config).unwrap();
+
+    assert_eq!(adts::write_header(&config, frame_size), expected);
+}
+
+#[test]
+fn test_write_frame() {
+    let config = AudioSpecificConfig::try_from([0x11, 0x90].as_slice()).unwrap();
+
+    let frame = adts::write_frame(&config, &[0x21, 0x10, 0x04]).unwrap();
+
+    assert_eq!(frame, &[0xFF, 0xF1, 0x4C, 0x80, 0x01, 0x5F, 0xFC, 0x21, 0x10, 0x04]);
+}
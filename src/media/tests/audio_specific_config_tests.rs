@@ -0,0 +1,48 @@
+use media::{
+    audio::{aac_codec::AacCodec, audio_specific_config::AudioSpecificConfig},
+    codec::Codec,
+    config_error::ConfigError,
+};
+use rstest::rstest;
+
+#[rstest]
+#[case(&[0x11, 0x90], Ok(AudioSpecificConfig::new(2, 3, 48000, None, 2, 1024, false, false)))]
+#[case(&[0x12, 0x10], Ok(AudioSpecificConfig::new(2, 4, 44100, None, 2, 1024, false, false)))]
+#[case(&[0x11, 0x94], Ok(AudioSpecificConfig::new(2, 3, 48000, None, 2, 960, false, false)))]
+#[case(&[0x2B, 0x11, 0x88, 0x00], Ok(AudioSpecificConfig::new(2, 6, 24000, Some(48000), 2, 1024, true, false)))]
+#[case(&[0xEB, 0x09, 0x88, 0x00], Ok(AudioSpecificConfig::new(2, 6, 24000, Some(48000), 1, 1024, true, true)))]
+#[case(&[0x13, 0x10, 0x56, 0xE5, 0x98], Ok(AudioSpecificConfig::new(2, 6, 24000, Some(48000), 2, 1024, true, false)))]
+#[case(&[0x13, 0x08, 0x56, 0xE5, 0x9D, 0x48, 0x80], Ok(AudioSpecificConfig::new(2, 6, 24000, Some(48000), 1, 1024, true, true)))]
+#[case(&[0xB8, 0x98], Ok(AudioSpecificConfig::new(23, 1, 88200, None, 3, 512, false, false)))]
+#[case(&[0x11], Err(ConfigError::Truncated("samplingFrequencyIndex")))]
+#[case(&[0x16, 0x80], Err(ConfigError::Unsupported("samplingFrequencyIndex", 13)))]
+fn test_parse(#[case] data: &[u8], #[case] expected: Result<AudioSpecificConfig, ConfigError>) {
+    assert_eq!(AudioSpecificConfig::try_from(data), expected);
+}
+
+#[rstest]
+#[case(&[0x11, 0x90], 48000, Some(2), Ok(()))]
+#[case(&[0x11, 0x90], 44100, Some(2), Err(ConfigError::ClockRateMismatch { rtpmap: 44100, config: 48000 }))]
+#[case(&[0x11, 0x90], 48000, None, Err(ConfigError::ChannelCountMismatch { rtpmap: 1, config: 2 }))]
+#[case(&[0x2B, 0x11, 0x88, 0x00], 24000, Some(2), Ok(()))]
+#[case(&[0x2B, 0x11, 0x88, 0x00], 48000, Some(2), Ok(()))]
+#[case(&[0xEB, 0x09, 0x88, 0x00], 48000, Some(2), Ok(()))]
+#[case(&[0xEB, 0x09, 0x88, 0x00], 48000, Some(6), Err(ConfigError::ChannelCountMismatch { rtpmap: 6, config: 1 }))]
+fn test_validate(#[case] data: &[u8], #[case] clock_rate: u32, #[case] channel_count: Option<u8>, #[case] expected: Result<(), ConfigError>) {
+    let config = AudioSpecificConfig::try_from(data).unwrap();
+
+    assert_eq!(config.validate(clock_rate, channel_count), expected);
+}
+
+#[test]
+fn test_codec_audio_specific_config() {
+    let fmtp = b"a=fmtp:97 streamtype=5; profile-level-id=15; mode=AAC-hbr; config=1190; sizeLength=13; indexLength=3; indexDeltaLength=3";
+
+    let config = AacCodec::parse(48000, Some(2), fmtp).unwrap().audio_specific_config().unwrap();
+    assert_eq!(config.object_type(), 2);
+    assert_eq!(config.output_sampling_frequency(), 48000);
+    assert_eq!(config.channel_count(), Some(2));
+
+    let mismatch = AacCodec::parse(44100, Some(2), fmtp).unwrap().audio_specific_config();
+    assert_eq!(mismatch, Err(ConfigError::ClockRateMismatch { rtpmap: 44100, config: 48000 }));
+}
@@ -0,0 +1,67 @@
+use abstractions::parsing::payload_parser::PayloadParser;
+use media::{
+    codec::Codec,
+    codec_type::CodecType,
+    video::{
+        av1_codec::Av1Codec,
+        av1_obu::{leb128_size, read_leb128, split_obus, starts_coded_video_sequence, write_leb128},
+    },
+};
+use rstest::rstest;
+
+#[rstest]
+#[case(b"a=fmtp:45 profile=1; level-idx=8; tier=1\r\n", Ok(Av1Codec::new(45, 90000, None, Some(1), Some(8), Some(1))))]
+#[case(b"a=fmtp:45 level-idx=12", Ok(Av1Codec::new(45, 90000, None, None, Some(12), None)))]
+#[case(b"a=fmtp:45 profile=3", Err(()))]
+#[case(b"a=fmtp:45 level-idx=32", Err(()))]
+#[case(b"a=fmtp:45 tier=2", Err(()))]
+#[case(b"profile=0", Err(()))]
+fn test_parse(#[case] data: &[u8], #[case] expected: Result<Av1Codec, ()>) {
+    assert_eq!(Av1Codec::parse(90000, None, data).map_err(|_| ()), expected);
+}
+
+#[rstest]
+#[case(Av1Codec::new(45, 90000, None, None, None, None), "a=rtpmap:45 AV1/90000\r\n")]
+#[case(Av1Codec::new(45, 90000, None, Some(0), Some(5), Some(0)), "a=rtpmap:45 AV1/90000\r\na=fmtp:45 profile=0; level-idx=5; tier=0\r\n")]
+fn test_display(#[case] codec: Av1Codec, #[case] expected: &str) {
+    let codec_type = CodecType::Av1(codec);
+
+    assert_eq!(codec_type.to_string(), expected);
+    assert_eq!(CodecType::parse(expected.trim_start_matches("a=rtpmap:").as_bytes()).unwrap(), codec_type);
+}
+
+#[rstest]
+#[case(0, &[0x00])]
+#[case(127, &[0x7F])]
+#[case(128, &[0x80, 0x01])]
+#[case(300, &[0xAC, 0x02])]
+#[case(16384, &[0x80, 0x80, 0x01])]
+fn test_leb128(#[case] value: u64, #[case] encoded: &[u8]) {
+    let mut output = Vec::new();
+    write_leb128(&mut output, value);
+    assert_eq!(output, encoded);
+    assert_eq!(leb128_size(value), encoded.len());
+    assert_eq!(read_leb128(&[encoded, &[0xFF]].concat()), Some((value, encoded.len())));
+}
+
+#[test]
+fn test_read_truncated_leb128() {
+    assert_eq!(read_leb128(&[0x80, 0x80]), None);
+    assert_eq!(read_leb128(&[0xFF; 9]), None);
+}
+
+#[rstest]
+// A sequence header followed by a key frame.
+#[case(&[0x12, 0x00, 0x0A, 0x01, 0x00, 0x32, 0x02, 0x10, 0xAA], Ok(true))]
+// A sequence header followed by an inter frame.
+#[case(&[0x0A, 0x01, 0x00, 0x32, 0x02, 0x30, 0xAA], Ok(false))]
+// A reduced still picture header implies a key frame.
+#[case(&[0x0A, 0x01, 0x18, 0x1A, 0x01, 0xE0], Ok(true))]
+// A key frame without a sequence header, whose last OBU has no size field.
+#[case(&[0x12, 0x00, 0x30, 0x10, 0xAA], Ok(false))]
+#[case(&[0x12, 0x00, 0x32, 0x05, 0x10], Err("OBU payload"))]
+#[case(&[0x0E], Err("OBU extension header"))]
+#[case(&[0x0A, 0x80], Err("obu_size"))]
+fn test_starts_coded_video_sequence(#[case] temporal_unit: &[u8], #[case] expected: Result<bool, &'static str>) {
+    assert_eq!(split_obus(temporal_unit).map(|obus| starts_coded_video_sequence(&obus)), expected);
+}
@@ -0,0 +1,107 @@
+use media::{
+    depacketizer_error::DepacketizerError,
+    video::{av1_codec::Av1Codec, av1_depacketizer::Av1Depacketizer},
+};
+use rstest::rstest;
+use rtp::{rtp_header::RtpHeader, rtp_packet::RtpPacket};
+
+fn push(depacketizer: &mut Av1Depacketizer, sequence_number: u16, timestamp: u32, marker: bool, payload: &[u8]) -> Result<(), DepacketizerError> {
+    let header = RtpHeader::new(2, false, false, 0, marker, 45, sequence_number, timestamp, 0x1234, &[]);
+    depacketizer.push(&RtpPacket::new(header, payload))
+}
+
+fn depacketizer() -> Av1Depacketizer {
+    Av1Depacketizer::new(&Av1Codec::new(45, 90000, None, None, None, None))
+}
+
+fn temporal_units(depacketizer: &mut Av1Depacketizer) -> Vec<(u32, bool, Vec<u8>)> {
+    std::iter::from_fn(|| depacketizer.pull())
+        .map(|access_unit| (access_unit.timestamp(), access_unit.keyframe(), access_unit.into_data()))
+        .collect()
+}
+
+#[test]
+fn test_aggregated_obus() {
+    let mut depacketizer = depacketizer();
+
+    // W = 2 and N: a sequence header with a length field, then a frame without one.
+    push(&mut depacketizer, 1, 3000, true, &[0x28, 0x04, 0x08, 0x00, 0x01, 0x02, 0x30, 0x10, 0xAA, 0xBB, 0xCC]).unwrap();
+
+    assert_eq!(
+        temporal_units(&mut depacketizer),
+        vec![(3000, true, vec![0x12, 0x00, 0x0A, 0x03, 0x00, 0x01, 0x02, 0x32, 0x04, 0x10, 0xAA, 0xBB, 0xCC])]
+    );
+}
+
+#[test]
+fn test_fragmented_obu() {
+    let mut depacketizer = depacketizer();
+
+    push(&mut depacketizer, 1, 3000, false, &[0x50, 0x30, 0x50, 0x01, 0x02]).unwrap();
+    push(&mut depacketizer, 2, 3000, false, &[0xD0, 0x03, 0x04]).unwrap();
+    // The last fragment is followed by a padding OBU.
+    push(&mut depacketizer, 3, 3000, true, &[0xA0, 0x02, 0x05, 0x06, 0x78, 0xEE]).unwrap();
+
+    assert_eq!(
+        temporal_units(&mut depacketizer),
+        vec![(3000, false, vec![0x12, 0x00, 0x32, 0x07, 0x50, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x7A, 0x01, 0xEE])]
+    );
+    assert_eq!(depacketizer.lost_packets(), 0);
+}
+
+#[test]
+fn test_temporal_delimiters_and_size_fields() {
+    let mut depacketizer = depacketizer();
+
+    // W = 0: a temporal delimiter, which is dropped, then an OBU that kept its size field.
+    push(&mut depacketizer, 1, 0, true, &[0x00, 0x01, 0x10, 0x03, 0x32, 0x01, 0xAB]).unwrap();
+
+    assert_eq!(temporal_units(&mut depacketizer), vec![(0, false, vec![0x12, 0x00, 0x32, 0x01, 0xAB])]);
+}
+
+#[test]
+fn test_lost_packet_drops_temporal_unit() {
+    let mut depacketizer = depacketizer();
+
+    push(&mut depacketizer, 65534, 0, false, &[0x50, 0x30, 0x50, 0x01]).unwrap();
+    push(&mut depacketizer, 0, 0, true, &[0x90, 0x03]).unwrap();
+    push(&mut depacketizer, 1, 3000, false, &[0x10, 0x30, 0x30, 0x01]).unwrap();
+    // The timestamp changes without a marker bit, which closes the previous temporal unit.
+    push(&mut depacketizer, 2, 6000, false, &[0x10, 0x30, 0x30, 0x02]).unwrap();
+    push(&mut depacketizer, 65535, 0, false, &[0x10, 0x30, 0x30, 0x03]).unwrap();
+    push(&mut depacketizer, 3, 6000, true, &[0x10, 0x30, 0x30, 0x04]).unwrap();
+
+    assert_eq!(
+        temporal_units(&mut depacketizer),
+        vec![
+            (3000, false, vec![0x12, 0x00, 0x32, 0x02, 0x30, 0x01]),
+            (6000, false, vec![0x12, 0x00, 0x32, 0x02, 0x30, 0x02, 0x32, 0x02, 0x30, 0x04]),
+        ]
+    );
+    assert_eq!(depacketizer.lost_packets(), 1);
+}
+
+#[rstest]
+#[case(&[], DepacketizerError::Truncated("aggregation header"))]
+#[case(&[0x00, 0x05, 0x30], DepacketizerError::Truncated("OBU element"))]
+#[case(&[0x00, 0x80], DepacketizerError::Truncated("OBU element length"))]
+#[case(&[0x00, 0x00], DepacketizerError::Truncated("OBU header"))]
+#[case(&[0x10, 0x32, 0x05, 0x01], DepacketizerError::Truncated("OBU payload"))]
+fn test_invalid_payload_drops_temporal_unit(#[case] payload: &[u8], #[case] expected: DepacketizerError) {
+    let mut depacketizer = depacketizer();
+
+    push(&mut depacketizer, 1, 0, false, &[0x10, 0x30, 0x30]).unwrap();
+    assert_eq!(push(&mut depacketizer, 2, 0, true, payload), Err(expected));
+    assert!(depacketizer.pull().is_none());
+}
+
+#[test]
+fn test_missing_continuation() {
+    let mut depacketizer = depacketizer();
+
+    push(&mut depacketizer, 1, 0, false, &[0x50, 0x30, 0x30]).unwrap();
+    assert_eq!(push(&mut depacketizer, 2, 0, true, &[0x10, 0x30, 0x31]), Err(DepacketizerError::Invalid("OBU fragment")));
+    push(&mut depacketizer, 3, 3000, true, &[0x10, 0x30, 0x32]).unwrap();
+
+    assert_eq!(temporal_units(&mut depacketizer), vec![(3000, false, vec![0x12, 0x00, 0x32, 0x01, 0x32])]);
+}
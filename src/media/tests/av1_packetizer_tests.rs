@@ -0,0 +1,99 @@
+use media::{
+    packetizer_error::PacketizerError,
+    video::{av1_codec::Av1Codec, av1_depacketizer::Av1Depacketizer, av1_packetizer::Av1Packetizer},
+};
+
+fn codec() -> Av1Codec {
+    Av1Codec::new(45, 90000, None, None, None, None)
+}
+
+/// A temporal unit with a sequence header and a key frame whose payload has the given size.
+fn key_temporal_unit(frame_size: usize) -> Vec<u8> {
+    let frame = [vec![0x10], (1..frame_size as u8).collect()].concat();
+    [vec![0x12, 0x00, 0x0A, 0x03, 0x00, 0x01, 0x02, 0x32, frame_size as u8], frame].concat()
+}
+
+#[test]
+fn test_aggregated_payload() {
+    let mut packetizer = Av1Packetizer::new(&codec(), 0x1234, 1200).unwrap();
+
+    let packets = packetizer.packetize(3000, &key_temporal_unit(4)).unwrap();
+    let payloads = packets.iter().map(|packet| packet.payload()).collect::<Vec<_>>();
+
+    assert_eq!(payloads, vec![&[0x28, 0x04, 0x08, 0x00, 0x01, 0x02, 0x30, 0x10, 0x01, 0x02, 0x03][..]]);
+    assert!(packets[0].header().marker());
+    assert_eq!(packets[0].header().timestamp(), 3000);
+}
+
+#[test]
+fn test_fragmented_payloads() {
+    let mut packetizer = Av1Packetizer::new(&codec(), 0x1234, 32).unwrap();
+    let temporal_unit = key_temporal_unit(40);
+    let frame = &temporal_unit[9..];
+
+    let packets = packetizer.packetize(3000, &temporal_unit).unwrap();
+    let payloads = packets.iter().map(|packet| packet.payload()).collect::<Vec<_>>();
+
+    assert_eq!(
+        payloads,
+        vec![
+            [&[0x68, 0x04, 0x08, 0x00, 0x01, 0x02, 0x30], &frame[..12]].concat(),
+            [&[0xD0], &frame[12..30]].concat(),
+            [&[0x90], &frame[30..]].concat(),
+        ]
+    );
+    assert!(packets.iter().all(|packet| packet.byte_size() <= 32));
+    assert_eq!(packets.iter().filter(|packet| packet.header().marker()).count(), 1);
+    assert!(packets[2].header().marker());
+}
+
+#[test]
+fn test_round_trip() {
+    let mut packetizer = Av1Packetizer::new(&codec(), 0x1234, 40).unwrap();
+    let mut depacketizer = Av1Depacketizer::new(&codec());
+    let inter_temporal_unit = [&[0x12, 0x00, 0x32, 0x64, 0x30][..], &[0xCD; 99]].concat();
+
+    for (timestamp, temporal_unit) in [(0, key_temporal_unit(100)), (3000, inter_temporal_unit.clone())] {
+        for packet in packetizer.packetize(timestamp, &temporal_unit).unwrap() {
+            depacketizer.push(&packet).unwrap();
+        }
+    }
+
+    let temporal_units = std::iter::from_fn(|| depacketizer.pull())
+        .map(|access_unit| (access_unit.timestamp(), access_unit.keyframe(), access_unit.into_data()))
+        .collect::<Vec<_>>();
+    assert_eq!(temporal_units, vec![(0, true, key_temporal_unit(100)), (3000, false, inter_temporal_unit)]);
+    assert_eq!(depacketizer.lost_packets(), 0);
+}
+
+#[test]
+fn test_uncounted_elements() {
+    let mut packetizer = Av1Packetizer::new(&codec(), 0x1234, 1200).unwrap();
+    let metadata = [0x2A, 0x01, 0x0A];
+
+    let packets = packetizer.packetize(0, &metadata.repeat(4)).unwrap();
+
+    // Four elements do not fit into the W field, so every element has a length field.
+    assert_eq!(packets[0].payload(), &[0x00, 0x02, 0x28, 0x0A, 0x02, 0x28, 0x0A, 0x02, 0x28, 0x0A, 0x02, 0x28, 0x0A]);
+}
+
+#[test]
+fn test_sequence_numbers() {
+    let mut packetizer = Av1Packetizer::new(&codec(), 0x1234, 32).unwrap();
+    let first = packetizer.sequence_number();
+
+    let sequence_numbers =
+        packetizer.packetize(0, &key_temporal_unit(40)).unwrap().iter().map(|packet| packet.header().sequence_number()).collect::<Vec<_>>();
+
+    assert_eq!(sequence_numbers, vec![first, first.wrapping_add(1), first.wrapping_add(2)]);
+    assert_eq!(packetizer.sequence_number(), first.wrapping_add(3));
+    assert!(packetizer.packetize(3000, &[0x12, 0x00]).unwrap().is_empty());
+}
+
+#[test]
+fn test_errors() {
+    assert_eq!(Av1Packetizer::new(&codec(), 0x1234, 27).err(), Some(PacketizerError::MtuTooSmall(27)));
+
+    let mut packetizer = Av1Packetizer::new(&codec(), 0x1234, 1200).unwrap();
+    assert_eq!(packetizer.packetize(0, &[0x32, 0x05, 0x01]).err(), Some(PacketizerError::Truncated("OBU payload")));
+}
@@ -0,0 +1,73 @@
+use abstractions::parsing::payload_parser::PayloadParser;
+use media::{codec_type::CodecType, depacketizer::Depacketizer, media_frame::MediaFrame};
+use rstest::rstest;
+use rtp::{rtp_header::RtpHeader, rtp_packet::RtpPacket};
+
+const H264_RTPMAP: &str =
+    "96 H264/90000\r\na=fmtp:96 profile-level-id=42e01f; packetization-mode=1; sprop-parameter-sets=Z0IAH5WoFAFuQA==,aM4G4g==";
+
+fn depacketizer(rtpmap: &str) -> Box<dyn Depacketizer> {
+    CodecType::parse(rtpmap.as_bytes()).unwrap().depacketizer().unwrap()
+}
+
+fn push(depacketizer: &mut dyn Depacketizer, sequence_number: u16, marker: bool, payload: &[u8]) {
+    let header = RtpHeader::new(2, false, false, 0, marker, 96, sequence_number, 3000, 0x1234, &[]);
+    depacketizer.push(&RtpPacket::new(header, payload)).unwrap();
+}
+
+#[rstest]
+#[case("0 PCMU/8000", &[0xFF, 0x7F], MediaFrame::new(3000, 3000, true, vec![0xFF, 0x7F], "PCMU"))]
+#[case("8 PCMA/8000", &[0xD5, 0x55], MediaFrame::new(3000, 3000, true, vec![0xD5, 0x55], "PCMA"))]
+#[case("111 opus/48000/2", &[0xF8, 0xAA], MediaFrame::new(3000, 3000, true, vec![0xF8, 0xAA], "opus"))]
+#[case(H264_RTPMAP, &[0x41, 0x9A], MediaFrame::new(3000, 3000, false, vec![0x00, 0x00, 0x00, 0x01, 0x41, 0x9A], "H264"))]
+#[case("97 VP8/90000", &[0x10, 0x50, 0x01], MediaFrame::new(3000, 3000, true, vec![0x50, 0x01], "VP8"))]
+#[case("45 AV1/90000", &[0x10, 0x30, 0xAA], MediaFrame::new(3000, 3000, false, vec![0x12, 0x00, 0x32, 0x01, 0xAA], "AV1"))]
+fn test_media_frames(#[case] rtpmap: &str, #[case] payload: &[u8], #[case] expected: MediaFrame) {
+    let mut depacketizer = depacketizer(rtpmap);
+
+    push(depacketizer.as_mut(), 1, true, payload);
+
+    assert_eq!(depacketizer.pull(), Some(expected));
+    assert_eq!(depacketizer.pull(), None);
+}
+
+#[test]
+fn test_unsupported_codec() {
+    assert!(CodecType::parse(b"98 L16/44100/2").unwrap().depacketizer().is_none());
+}
+
+#[test]
+fn test_static_payload_type() {
+    let mut depacketizer = CodecType::from_static_payload_type(0).unwrap().depacketizer().unwrap();
+
+    push(depacketizer.as_mut(), 1, false, &[0xFF]);
+    push(depacketizer.as_mut(), 4, false, &[0x7F]);
+
+    let frames = std::iter::from_fn(|| depacketizer.pull()).map(MediaFrame::into_data).collect::<Vec<_>>();
+    assert_eq!(frames, vec![vec![0xFF], vec![0x7F]]);
+    assert_eq!(depacketizer.lost_packets(), 2);
+}
+
+#[test]
+fn test_flush() {
+    let mut depacketizer = depacketizer(H264_RTPMAP);
+
+    push(depacketizer.as_mut(), 1, false, &[0x41, 0x9A]);
+    assert_eq!(depacketizer.pull(), None);
+
+    depacketizer.flush();
+    let frame = depacketizer.pull().unwrap();
+    assert_eq!((frame.pts(), frame.keyframe(), frame.codec()), (3000, false, "H264"));
+    assert_eq!(frame.data(), &[0x00, 0x00, 0x00, 0x01, 0x41, 0x9A]);
+}
+
+#[test]
+fn test_rtp_tracker() {
+    let mut tracker = CodecType::parse(b"111 opus/48000/2").unwrap().rtp_tracker();
+
+    let first = tracker.track(&RtpHeader::new(2, false, false, 0, false, 111, 65535, u32::MAX - 479, 0x1234, &[]));
+    let second = tracker.track(&RtpHeader::new(2, false, false, 0, false, 111, 0, 480, 0x1234, &[]));
+
+    assert_eq!(second.sequence_number() - first.sequence_number(), 1);
+    assert_eq!(second.presentation_time(), Some(std::time::Duration::from_millis(20)));
+}
@@ -0,0 +1,86 @@
+use abstractions::parsing::payload_parser::PayloadParser;
+use media::{
+    audio::{
+        g711::{a_law_to_linear, linear_to_a_law, linear_to_mu_law, mu_law_to_linear},
+        g711_codec::{PcmaCodec, PcmuCodec},
+    },
+    codec_type::CodecType,
+    static_payload_type::StaticPayloadType,
+    unsupported_codec::UnsupportedCodec,
+};
+use rstest::rstest;
+
+#[rstest]
+#[case(0xFF, 0)]
+#[case(0x7F, 0)]
+#[case(0x80, 32124)]
+#[case(0x00, -32124)]
+#[case(0xFE, 8)]
+#[case(0x6F, -132)]
+#[case(0x9F, 8316)]
+fn test_mu_law_to_linear(#[case] code: u8, #[case] expected: i16) {
+    assert_eq!(mu_law_to_linear(code), expected);
+}
+
+#[rstest]
+#[case(0xD5, 8)]
+#[case(0x55, -8)]
+#[case(0xAA, 32256)]
+#[case(0x2A, -32256)]
+#[case(0xC5, 264)]
+#[case(0xE5, 1056)]
+fn test_a_law_to_linear(#[case] code: u8, #[case] expected: i16) {
+    assert_eq!(a_law_to_linear(code), expected);
+}
+
+#[rstest]
+#[case(0, 0xFF, 0xD5)]
+#[case(-1, 0x7E, 0x55)]
+#[case(i16::MAX, 0x80, 0xAA)]
+#[case(i16::MIN, 0x00, 0x2A)]
+#[case(1000, 0xCE, 0xFA)]
+#[case(-1000, 0x4E, 0x7A)]
+fn test_linear_to_g711(#[case] sample: i16, #[case] mu_law: u8, #[case] a_law: u8) {
+    assert_eq!(linear_to_mu_law(sample), mu_law);
+    assert_eq!(linear_to_a_law(sample), a_law);
+}
+
+#[test]
+fn test_code_words_survive_round_trip() {
+    for code in 0..=u8::MAX {
+        assert_eq!(mu_law_to_linear(linear_to_mu_law(mu_law_to_linear(code))), mu_law_to_linear(code));
+        assert_eq!(linear_to_a_law(a_law_to_linear(code)), code);
+    }
+}
+
+#[test]
+fn test_codec_encode_decode() {
+    let samples = [0, 1000, -1000, 12000, -32768];
+
+    let pcmu = PcmuCodec::new(0, 8000, None);
+    assert_eq!(pcmu.decode(&pcmu.encode(&samples)), vec![0, 988, -988, 11900, -32124]);
+    let pcma = PcmaCodec::new(8, 8000, None);
+    assert_eq!(pcma.decode(&pcma.encode(&samples)), vec![8, 1008, -1008, 12032, -32256]);
+}
+
+#[rstest]
+#[case(0, Some(CodecType::Pcmu(PcmuCodec::new(0, 8000, None))))]
+#[case(8, Some(CodecType::Pcma(PcmaCodec::new(8, 8000, None))))]
+#[case(10, Some(CodecType::Unsupported(UnsupportedCodec::new("L16".to_string(), 10, 44100, Some(2), None))))]
+#[case(14, Some(CodecType::Unsupported(UnsupportedCodec::new("MPA".to_string(), 14, 90000, None, None))))]
+#[case(2, None)]
+#[case(96, None)]
+fn test_from_static_payload_type(#[case] format: u16, #[case] expected: Option<CodecType>) {
+    assert_eq!(CodecType::from_static_payload_type(format), expected);
+    assert_eq!(StaticPayloadType::find(format).is_some(), expected.is_some());
+}
+
+#[rstest]
+#[case(b"0 PCMU/8000", CodecType::Pcmu(PcmuCodec::new(0, 8000, None)))]
+#[case(b"8 pcma/8000/1\r\n", CodecType::Pcma(PcmaCodec::new(8, 8000, Some(1))))]
+fn test_codec_type(#[case] data: &[u8], #[case] expected: CodecType) {
+    let codec_type = CodecType::parse(data).unwrap();
+
+    assert_eq!(codec_type, expected);
+    assert_eq!(codec_type.fmtp(), None);
+}
@@ -14,7 +14,7 @@ use rstest::rstest;
         PackatizationMode::NonInterleaved,
         vec![
             0, 0, 0, 1, 103, 66, 0, 31, 149, 168, 20, 1, 110, 64, 
-            0, 0, 0, 1, 103, 66, 0, 31, 149, 168, 20, 1, 110, 64
+            0, 0, 0, 1, 104, 206, 6, 226
         ],
     ))
 )]
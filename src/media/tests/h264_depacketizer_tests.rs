@@ -0,0 +1,159 @@
+use media::{
+    codec::Codec,
+    depacketizer_error::DepacketizerError,
+    video::{h264_codec::H264Codec, h264_depacketizer::H264Depacketizer},
+};
+use rtp::{rtp_header::RtpHeader, rtp_packet::RtpPacket};
+
+const SPS_PPS: &[u8] = &[0, 0, 0, 1, 103, 66, 0, 31, 149, 168, 20, 1, 110, 64, 0, 0, 0, 1, 104, 206, 6, 226];
+
+fn depacketizer(packetization_mode: u8) -> H264Depacketizer {
+    let fmtp = format!(
+        "a=fmtp:96 profile-level-id=42e01f; packetization-mode={}; sprop-parameter-sets=Z0IAH5WoFAFuQA==,aM4G4g==",
+        packetization_mode
+    );
+    H264Depacketizer::new(&H264Codec::parse(90000, None, fmtp.as_bytes()).unwrap())
+}
+
+fn push(depacketizer: &mut H264Depacketizer, sequence_number: u16, timestamp: u32, marker: bool, payload: &[u8]) -> Result<(), DepacketizerError> {
+    let header = RtpHeader::new(2, false, false, 0, marker, 96, sequence_number, timestamp, 0x1234, &[]);
+    depacketizer.push(&RtpPacket::new(header, payload))
+}
+
+#[test]
+fn test_single_nal_units() {
+    let mut depacketizer = depacketizer(1);
+
+    push(&mut depacketizer, 1, 3000, false, &[0x65, 1, 2, 3]).unwrap();
+    push(&mut depacketizer, 2, 3000, true, &[0x65, 4, 5]).unwrap();
+    push(&mut depacketizer, 3, 6000, true, &[0x41, 6]).unwrap();
+
+    let idr = depacketizer.pull().unwrap();
+    assert_eq!(idr.timestamp(), 3000);
+    assert!(idr.keyframe());
+    assert_eq!(idr.data(), [SPS_PPS, &[0, 0, 0, 1, 0x65, 1, 2, 3, 0, 0, 0, 1, 0x65, 4, 5]].concat());
+
+    let slice = depacketizer.pull().unwrap();
+    assert!(!slice.keyframe());
+    assert_eq!(slice.data(), &[0, 0, 0, 1, 0x41, 6]);
+    assert_eq!(depacketizer.pull(), None);
+}
+
+#[test]
+fn test_stap_a_keeps_in_band_parameter_sets() {
+    let mut depacketizer = depacketizer(1);
+
+    push(&mut depacketizer, 10, 0, true, &[0x78, 0, 2, 0x67, 1, 0, 2, 0x68, 2, 0, 3, 0x65, 3, 4]).unwrap();
+
+    let access_unit = depacketizer.pull().unwrap();
+    assert!(access_unit.keyframe());
+    assert_eq!(access_unit.data(), &[0, 0, 0, 1, 0x67, 1, 0, 0, 0, 1, 0x68, 2, 0, 0, 0, 1, 0x65, 3, 4]);
+}
+
+#[test]
+fn test_fu_a_reassembly() {
+    let mut depacketizer = depacketizer(1);
+
+    push(&mut depacketizer, 65535, 90, false, &[0x7C, 0x85, 1, 2]).unwrap();
+    push(&mut depacketizer, 0, 90, false, &[0x7C, 0x05, 3, 4]).unwrap();
+    push(&mut depacketizer, 1, 90, true, &[0x7C, 0x45, 5]).unwrap();
+
+    let access_unit = depacketizer.pull().unwrap();
+    assert_eq!(access_unit.data(), [SPS_PPS, &[0, 0, 0, 1, 0x65, 1, 2, 3, 4, 5]].concat());
+    assert_eq!(depacketizer.lost_packets(), 0);
+}
+
+#[test]
+fn test_timestamp_change_closes_access_unit() {
+    let mut depacketizer = depacketizer(1);
+
+    push(&mut depacketizer, 1, 0, false, &[0x41, 1]).unwrap();
+    assert_eq!(depacketizer.pull(), None);
+    push(&mut depacketizer, 2, 3000, false, &[0x41, 2]).unwrap();
+
+    assert_eq!(depacketizer.pull().unwrap().data(), &[0, 0, 0, 1, 0x41, 1]);
+    assert_eq!(depacketizer.pull(), None);
+}
+
+#[test]
+fn test_packet_loss_drops_access_unit() {
+    let mut depacketizer = depacketizer(1);
+
+    push(&mut depacketizer, 1, 0, false, &[0x7C, 0x85, 1, 2]).unwrap();
+    push(&mut depacketizer, 3, 0, true, &[0x7C, 0x45, 5]).unwrap();
+    push(&mut depacketizer, 4, 3000, true, &[0x41, 6]).unwrap();
+    push(&mut depacketizer, 2, 0, false, &[0x7C, 0x05, 3, 4]).unwrap();
+
+    assert_eq!(depacketizer.lost_packets(), 1);
+    assert_eq!(depacketizer.pull().unwrap().timestamp(), 3000);
+    assert_eq!(depacketizer.pull(), None);
+}
+
+#[test]
+fn test_interleaved_reordering() {
+    let mut depacketizer = depacketizer(2);
+
+    // MTAP16 with DONB 10 carrying DON 11 at timestamp 3000 and DON 10 at timestamp 0.
+    push(&mut depacketizer, 1, 0, false, &[0x1A, 0, 10, 0, 5, 1, 0x0B, 0xB8, 0x41, 2, 0, 5, 0, 0, 0, 0x65, 1]).unwrap();
+    // STAP-B with DON 12 at timestamp 3000.
+    push(&mut depacketizer, 2, 3000, false, &[0x19, 0, 12, 0, 2, 0x41, 3]).unwrap();
+    // FU-B with DON 13 followed by FU-A, at timestamp 6000.
+    push(&mut depacketizer, 3, 6000, false, &[0x7D, 0x81, 0, 13, 4]).unwrap();
+    push(&mut depacketizer, 4, 6000, true, &[0x7C, 0x41, 5]).unwrap();
+    assert_eq!(depacketizer.pull(), None);
+
+    depacketizer.flush();
+
+    let first = depacketizer.pull().unwrap();
+    assert_eq!(first.timestamp(), 0);
+    assert_eq!(first.data(), [SPS_PPS, &[0, 0, 0, 1, 0x65, 1]].concat());
+    let second = depacketizer.pull().unwrap();
+    assert_eq!(second.timestamp(), 3000);
+    assert_eq!(second.data(), &[0, 0, 0, 1, 0x41, 2, 0, 0, 0, 1, 0x41, 3]);
+    let third = depacketizer.pull().unwrap();
+    assert_eq!(third.timestamp(), 6000);
+    assert_eq!(third.data(), &[0, 0, 0, 1, 0x61, 4, 5]);
+}
+
+#[test]
+fn test_interleaved_decode_timestamps() {
+    let mut depacketizer = depacketizer(2);
+
+    // An I-frame, a P-frame and the B-frame presented between them, in decoding order.
+    push(&mut depacketizer, 1, 0, false, &[0x19, 0, 1, 0, 2, 0x65, 1]).unwrap();
+    push(&mut depacketizer, 2, 6000, false, &[0x19, 0, 2, 0, 2, 0x41, 2]).unwrap();
+    push(&mut depacketizer, 3, 3000, false, &[0x19, 0, 3, 0, 2, 0x01, 3]).unwrap();
+    depacketizer.flush();
+
+    let timestamps = std::iter::from_fn(|| depacketizer.pull())
+        .map(|access_unit| (access_unit.timestamp(), access_unit.decode_timestamp()))
+        .collect::<Vec<_>>();
+    assert_eq!(timestamps, vec![(0, 0), (6000, 3000), (3000, 3000)]);
+}
+
+#[test]
+fn test_interleaved_packet_loss_drops_access_unit() {
+    let mut depacketizer = depacketizer(2);
+
+    // STAP-B packets with DON 0 to 3, the one with DON 1 is lost.
+    push(&mut depacketizer, 1, 3000, false, &[0x19, 0, 0, 0, 2, 0x41, 0xA1]).unwrap();
+    push(&mut depacketizer, 3, 3000, true, &[0x19, 0, 2, 0, 2, 0x41, 0xA3]).unwrap();
+    push(&mut depacketizer, 4, 6000, true, &[0x19, 0, 3, 0, 2, 0x41, 0xA4]).unwrap();
+    depacketizer.flush();
+
+    assert_eq!(depacketizer.lost_packets(), 1);
+    let access_unit = depacketizer.pull().unwrap();
+    assert_eq!(access_unit.timestamp(), 6000);
+    assert_eq!(access_unit.data(), &[0, 0, 0, 1, 0x41, 0xA4]);
+    assert_eq!(depacketizer.pull(), None);
+}
+
+#[test]
+fn test_invalid_payloads() {
+    let mut depacketizer = depacketizer(1);
+
+    assert_eq!(push(&mut depacketizer, 1, 0, false, &[0x19, 0, 1]), Err(DepacketizerError::UnexpectedPacketType(25)));
+    assert_eq!(push(&mut depacketizer, 2, 0, false, &[0x78, 0, 9, 0x41]), Err(DepacketizerError::Truncated("aggregation unit")));
+    assert_eq!(push(&mut depacketizer, 3, 0, true, &[]), Err(DepacketizerError::Truncated("empty payload")));
+    assert_eq!(depacketizer.pull(), None);
+}
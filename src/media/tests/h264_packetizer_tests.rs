@@ -0,0 +1,109 @@
+use media::{
+    codec::Codec,
+    packetizer_error::PacketizerError,
+    video::{h264_codec::H264Codec, h264_depacketizer::H264Depacketizer, h264_packetizer::H264Packetizer},
+};
+use rstest::rstest;
+
+const SPS_PPS: &[u8] = &[0, 0, 0, 1, 103, 66, 0, 31, 149, 168, 20, 1, 110, 64, 0, 0, 0, 1, 104, 206, 6, 226];
+
+fn codec(packetization_mode: u8) -> H264Codec {
+    let fmtp = format!(
+        "a=fmtp:96 profile-level-id=42e01f; packetization-mode={}; sprop-parameter-sets=Z0IAH5WoFAFuQA==,aM4G4g==",
+        packetization_mode
+    );
+    H264Codec::parse(90000, None, fmtp.as_bytes()).unwrap()
+}
+
+fn idr_access_unit(size: usize) -> Vec<u8> {
+    let slice = (0..size).map(|index| index as u8).collect::<Vec<_>>();
+    [SPS_PPS, &[0, 0, 0, 1, 0x65], &slice].concat()
+}
+
+#[test]
+fn test_stap_a_aggregates_small_nal_units() {
+    let mut packetizer = H264Packetizer::new(&codec(1), 0x1234, 1200).unwrap();
+    let sequence_number = packetizer.sequence_number();
+
+    let packets = packetizer.packetize_annex_b(3000, &idr_access_unit(4)).unwrap();
+
+    assert_eq!(packets.len(), 1);
+    assert!(packets[0].header().marker());
+    assert_eq!(packets[0].header().sequence_number(), sequence_number);
+    assert_eq!(packets[0].header().payload_type(), 96);
+    assert_eq!(packets[0].payload()[0], 0x78);
+    assert_eq!(&packets[0].payload()[1..3], &[0, 10]);
+    assert_eq!(packetizer.sequence_number(), sequence_number.wrapping_add(1));
+}
+
+#[test]
+fn test_fu_a_fragments_respect_mtu() {
+    let mtu = 100;
+    let mut packetizer = H264Packetizer::new(&codec(1), 0x1234, mtu).unwrap();
+
+    let packets = packetizer.packetize(0, &[&[0x65; 500]]).unwrap();
+
+    assert_eq!(packets.len(), 6);
+    assert!(packets.iter().all(|packet| packet.byte_size() <= mtu));
+    assert!(packets.iter().all(|packet| packet.payload()[0] == 0x7C));
+    assert_eq!(packets[0].payload()[1], 0x85);
+    assert_eq!(packets[5].payload()[1], 0x45);
+    assert!(packets[..5].iter().all(|packet| !packet.header().marker()));
+    assert!(packets[5].header().marker());
+}
+
+#[test]
+fn test_single_nal_unit_mode_rejects_large_units() {
+    let mut packetizer = H264Packetizer::new(&codec(0), 0x1234, 100).unwrap();
+
+    assert_eq!(packetizer.packetize(0, &[&[0x65; 100]]).unwrap_err(), PacketizerError::UnitTooLarge { size: 100, max: 88 });
+}
+
+#[test]
+fn test_mtu_too_small() {
+    assert_eq!(H264Packetizer::new(&codec(1), 0x1234, 20).unwrap_err(), PacketizerError::MtuTooSmall(20));
+}
+
+#[rstest]
+#[case(&[0, 0, 0, 2, 0x41, 1, 0, 0, 0, 3, 0x41, 2, 3], 4, Ok(2))]
+#[case(&[0, 2, 0x41, 1], 2, Ok(1))]
+#[case(&[0, 0, 0, 9, 0x41], 4, Err(PacketizerError::Truncated("NAL unit")))]
+#[case(&[2, 0x41, 1], 5, Err(PacketizerError::InvalidLengthSize(5)))]
+fn test_packetize_avcc(#[case] sample: &[u8], #[case] length_size: usize, #[case] expected: Result<usize, PacketizerError>) {
+    let mut packetizer = H264Packetizer::new(&codec(0), 0x1234, 1200).unwrap();
+
+    let packets = packetizer.packetize_avcc(0, sample, length_size).map(|packets| packets.len());
+
+    assert_eq!(packets, expected);
+}
+
+#[rstest]
+#[case(0, 40, 1200)]
+#[case(1, 40, 1200)]
+#[case(1, 3000, 1200)]
+#[case(1, 1185, 1200)]
+#[case(2, 40, 1200)]
+#[case(2, 3000, 1200)]
+#[case(2, 1184, 1200)]
+fn test_round_trip(#[case] packetization_mode: u8, #[case] slice_size: usize, #[case] mtu: usize) {
+    let codec = codec(packetization_mode);
+    let mut packetizer = H264Packetizer::new(&codec, 0x1234, mtu).unwrap();
+    let mut depacketizer = H264Depacketizer::new(&codec);
+    let access_units = [idr_access_unit(slice_size), [&[0, 0, 0, 1, 0x41], idr_access_unit(slice_size).as_slice()].concat()];
+
+    for (index, access_unit) in access_units.iter().enumerate() {
+        for packet in packetizer.packetize_annex_b(index as u32 * 3000, access_unit).unwrap() {
+            assert!(packet.byte_size() <= mtu);
+            depacketizer.push(&packet).unwrap();
+        }
+    }
+    depacketizer.flush();
+
+    for (index, access_unit) in access_units.iter().enumerate() {
+        let pulled = depacketizer.pull().unwrap();
+        assert_eq!(pulled.timestamp(), index as u32 * 3000);
+        assert_eq!(pulled.data(), access_unit.as_slice());
+    }
+    assert_eq!(depacketizer.pull(), None);
+    assert_eq!(depacketizer.lost_packets(), 0);
+}
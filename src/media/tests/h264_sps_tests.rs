@@ -0,0 +1,111 @@
+use media::{
+    codec::Codec,
+    config_error::ConfigError,
+    video::{h264_codec::H264Codec, h264_pps::H264Pps, h264_sps::H264Sps},
+};
+use rstest::rstest;
+
+/// High profile 1920x1080 with a scaling matrix, HRD parameters and emulation prevention bytes.
+const HIGH_1080P: &[u8] = &[
+    0x67, 0x64, 0x00, 0x28, 0xAD, 0xAF, 0xFF, 0xE0, 0x36, 0x50, 0x1E, 0x00, 0x89, 0xF9, 0x70, 0x16, 0xA0, 0x20, 0x20, 0x28, 0x00, 0x00,
+    0x03, 0x00, 0x08, 0x00, 0x00, 0x03, 0x01, 0x97, 0x03, 0x00, 0x13, 0x88, 0x00, 0x27, 0x11, 0x7B, 0xDF, 0x03, 0xC2, 0x21, 0x16, 0x58,
+];
+/// Constrained baseline 640x480 without VUI.
+const BASELINE_480P: &[u8] = &[0x67, 0x42, 0xC0, 0x1E, 0xDA, 0x02, 0x80, 0xF6, 0x40];
+/// Main profile 720x576 interlaced.
+const MAIN_576I: &[u8] = &[0x67, 0x4D, 0x40, 0x1E, 0xEC, 0xA0, 0x5A, 0x12, 0x3F, 0x40];
+/// High 4:2:2 10-bit 1276x720 at 29.97 frames per second without reordering.
+const HIGH_422_720P: &[u8] = &[
+    0x67, 0x7A, 0x00, 0x29, 0xB6, 0xCD, 0x94, 0x05, 0x00, 0x5B, 0xEF, 0xC0, 0x5A, 0x80, 0x80, 0x80, 0xA0, 0x00, 0x00, 0x7D, 0x20, 0x00,
+    0x1D, 0x4C, 0x1C, 0x0C, 0x00, 0x4E, 0x20, 0x00, 0x9C, 0x45, 0xEF, 0x7C, 0x0F, 0x08, 0x84, 0x65, 0x80,
+];
+
+#[rstest]
+#[case(HIGH_1080P, (100, 40, 1, 8, 0, 1920, 1080, true), Some(25.0), true)]
+#[case(BASELINE_480P, (66, 30, 1, 8, 2, 640, 480, true), None, false)]
+#[case(MAIN_576I, (77, 30, 1, 8, 0, 720, 576, false), None, true)]
+#[case(HIGH_422_720P, (122, 41, 2, 10, 0, 1276, 720, true), Some(29.97002997002997), false)]
+#[case(&[0x67, 0x42, 0x00, 0x1F, 0x95, 0xA8, 0x14, 0x01, 0x6E, 0x40], (66, 31, 1, 8, 2, 1280, 720, true), None, false)]
+fn test_parse_sps(
+    #[case] nal_unit: &[u8],
+    #[case] expected: (u8, u8, u32, u32, u32, u32, u32, bool),
+    #[case] frame_rate: Option<f64>,
+    #[case] may_use_b_frames: bool,
+) {
+    let sps = H264Sps::try_from(nal_unit).unwrap();
+
+    let (profile_idc, level_idc, chroma_format_idc, bit_depth, pic_order_cnt_type, width, height, frame_mbs_only) = expected;
+    assert_eq!(sps.profile_idc(), profile_idc);
+    assert_eq!(sps.level_idc(), level_idc);
+    assert_eq!(sps.chroma_format_idc(), chroma_format_idc);
+    assert_eq!(sps.bit_depth_luma(), bit_depth);
+    assert_eq!(sps.bit_depth_chroma(), bit_depth);
+    assert_eq!(sps.pic_order_cnt_type(), pic_order_cnt_type);
+    assert_eq!((sps.width(), sps.height()), (width, height));
+    assert_eq!(sps.frame_mbs_only(), frame_mbs_only);
+    assert_eq!(sps.frame_rate(), frame_rate);
+    assert_eq!(sps.may_use_b_frames(), may_use_b_frames);
+}
+
+#[rstest]
+#[case(&[], ConfigError::Truncated("NAL unit header"))]
+#[case(&[0x68, 0xCE], ConfigError::Unsupported("SPS NAL unit type", 8))]
+#[case(&[0x67, 0x64, 0x00], ConfigError::Truncated("level_idc"))]
+#[case(&[0x67, 0x42, 0xC0, 0x1E, 0xDA], ConfigError::Truncated("gaps_in_frame_num_value_allowed_flag"))]
+#[case(
+    &[0x67, 0x64, 0x00, 0x28, 0xA0, 0x00, 0x00, 0x00, 0x1F, 0xFF, 0xFF, 0xFF, 0xF0],
+    ConfigError::Invalid("bit_depth_luma_minus8")
+)]
+#[case(
+    &[0x67, 0x42, 0xC0, 0x1E, 0xDA, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x79],
+    ConfigError::Invalid("pic_width_in_mbs_minus1")
+)]
+#[case(
+    &[0x67, 0x64, 0x00, 0x28, 0xAD, 0x80, 0x00, 0x00, 0x00, 0xFF, 0xFF, 0xFF, 0xFE, 0x80],
+    ConfigError::Invalid("delta_scale")
+)]
+fn test_parse_sps_errors(#[case] nal_unit: &[u8], #[case] expected: ConfigError) {
+    assert_eq!(H264Sps::try_from(nal_unit), Err(expected));
+}
+
+#[rstest]
+#[case(&[0x68, 0xEB, 0xE3, 0xCB, 0x2C], true, 1, true)]
+#[case(&[0x68, 0xCB, 0xE3, 0xCB, 0x20], false, 1, false)]
+#[case(&[0x68, 0xC6, 0x72, 0x0C, 0x3E, 0x3C, 0xB2], false, 3, false)]
+fn test_parse_pps(#[case] nal_unit: &[u8], #[case] entropy_coding_mode: bool, #[case] num_slice_groups: u32, #[case] transform_8x8_mode: bool) {
+    let pps = H264Pps::try_from(nal_unit).unwrap();
+
+    assert_eq!(pps.pic_parameter_set_id(), 0);
+    assert_eq!(pps.seq_parameter_set_id(), 0);
+    assert_eq!(pps.entropy_coding_mode(), entropy_coding_mode);
+    assert_eq!(pps.num_slice_groups(), num_slice_groups);
+    assert_eq!(pps.num_ref_idx_l0_default_active(), 3);
+    assert_eq!(pps.weighted_bipred_idc(), 2);
+    assert_eq!(pps.pic_init_qp(), 23);
+    assert_eq!(pps.chroma_qp_index_offset(), -2);
+    assert!(pps.deblocking_filter_control_present());
+    assert_eq!(pps.transform_8x8_mode(), transform_8x8_mode);
+}
+
+#[rstest]
+#[case(&[0x68, 0xCE, 0x00, 0x00, 0x00, 0x00, 0x7F, 0xFF, 0xFF, 0xFF, 0x72], ConfigError::Invalid("pic_init_qp_minus26"))]
+#[case(&[0x68, 0x00, 0x80, 0xC0], ConfigError::Invalid("pic_parameter_set_id"))]
+fn test_parse_pps_errors(#[case] nal_unit: &[u8], #[case] expected: ConfigError) {
+    assert_eq!(H264Pps::try_from(nal_unit), Err(expected));
+}
+
+#[rstest]
+#[case("42e01f", Ok(()))]
+#[case("42e01e", Err(ConfigError::ParameterMismatch { parameter: "level_idc", fmtp: 30, bitstream: 31 }))]
+#[case("64001f", Err(ConfigError::ParameterMismatch { parameter: "profile_idc", fmtp: 100, bitstream: 66 }))]
+#[case("42e0", Err(ConfigError::Invalid("profile-level-id")))]
+fn test_check_profile_level_id(#[case] profile_level_id: &str, #[case] expected: Result<(), ConfigError>) {
+    let fmtp = format!(
+        "a=fmtp:96 profile-level-id={}; packetization-mode=1; sprop-parameter-sets=Z0IAH5WoFAFuQA==,aM4G4g==",
+        profile_level_id
+    );
+    let codec = H264Codec::parse(90000, None, fmtp.as_bytes()).unwrap();
+
+    assert_eq!(codec.check_profile_level_id().map(|_| ()), expected);
+    assert_eq!(codec.pps().unwrap().pic_parameter_set_id(), 0);
+}
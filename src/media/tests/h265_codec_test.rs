@@ -50,4 +50,47 @@ fn test_from_fmtp_internal(
 ) {
     let result = H265Codec::parse(clock_rate, channel_count, data).map_err(|_| ());
     assert_eq!(result, expected);
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_from_parameter_sets() {
+    let data = b"a=fmtp:96 profile-id=1; sprop-vps=Z0IAH5WoFAFuQA==; sprop-sps=Z0IAH5WoFAFuQA==; sprop-pps=aM4G4g==";
+    let codec = H265Codec::parse(90000, None, data).unwrap();
+
+    assert_eq!(codec.vps_bytes(), &[103, 66, 0, 31, 149, 168, 20, 1, 110, 64]);
+    assert_eq!(codec.sps_bytes(), &[103, 66, 0, 31, 149, 168, 20, 1, 110, 64]);
+    assert_eq!(codec.pps_bytes(), &[104, 206, 6, 226]);
+    assert_eq!(
+        codec,
+        H265Codec::from_parameter_sets(
+            96,
+            90000,
+            None,
+            1,
+            codec.vps_bytes().to_vec(),
+            codec.sps_bytes().to_vec(),
+            codec.pps_bytes().to_vec()
+        )
+    );
+}
+
+#[test]
+fn test_h265_fmtp() {
+    let data = b"a=fmtp:96 profile-id=1; sprop-vps=Z0IAH5WoFAFuQA==; sprop-sps=Z0IAH5WoFAFuQA==; sprop-pps=aM4G4g==";
+    let codec = H265Codec::parse(90000, None, data).unwrap();
+
+    assert_eq!(
+        codec.sps_pps_vps_bytes(),
+        &[103, 66, 0, 31, 149, 168, 20, 1, 110, 64, 104, 206, 6, 226, 103, 66, 0, 31, 149, 168, 20, 1, 110, 64]
+    );
+    assert_eq!(codec.fmtp().unwrap().as_bytes(), &data[10..]);
+}
+
+#[test]
+fn test_h265_max_don_diff() {
+    let data = b"a=fmtp:96 profile-id=1; sprop-vps=Z0IAH5WoFAFuQA==; sprop-sps=Z0IAH5WoFAFuQA==; sprop-pps=aM4G4g==; sprop-max-don-diff=2";
+    let codec = H265Codec::parse(90000, None, data).unwrap();
+
+    assert_eq!(codec.max_don_diff(), 2);
+    assert_eq!(codec.fmtp().unwrap().as_bytes(), &data[10..]);
+}
@@ -0,0 +1,119 @@
+use media::{
+    codec::Codec,
+    depacketizer_error::DepacketizerError,
+    video::{h265_codec::H265Codec, h265_depacketizer::H265Depacketizer},
+};
+use rtp::{rtp_header::RtpHeader, rtp_packet::RtpPacket};
+
+const VPS: &[u8] = &[0x40, 0x01, 0x0c, 0x01, 0xff, 0xff, 0x01, 0x60, 0, 0, 3, 0, 0x90, 0, 0, 3, 0, 0, 3, 0, 0x5d, 0x95, 0x98, 0x09];
+const SPS: &[u8] = &[
+    0x42, 0x01, 0x01, 0x01, 0x60, 0, 0, 3, 0, 0x90, 0, 0, 3, 0, 0, 3, 0, 0x05, 0xda, 0, 0x28, 0x08, 0x02, 0xd1, 0x65, 0x95, 0x9a, 0x49,
+    0x32, 0xbc, 0x05, 0xa7, 0x08, 0, 0, 0x1f, 0x48, 0, 0, 0x30, 0xd4, 0x20,
+];
+const PPS: &[u8] = &[0x44, 0x01, 0xc1, 0x72, 0xb4, 0x62, 0x40];
+
+fn depacketizer(max_don_diff: u16) -> H265Depacketizer {
+    let fmtp = format!(
+        "a=fmtp:96 profile-id=1; sprop-vps=QAEMAf//AWAAAAMAkAAAAwAAAwBdlZgJ; sprop-sps=QgEBAWAAAAMAkAAAAwAAAwAF2gAoCALRZZWaSTK8BacIAAAfSAAAMNQg; sprop-pps=RAHBcrRiQA==; sprop-max-don-diff={}",
+        max_don_diff
+    );
+    H265Depacketizer::new(&H265Codec::parse(90000, None, fmtp.as_bytes()).unwrap())
+}
+
+fn push(depacketizer: &mut H265Depacketizer, sequence_number: u16, timestamp: u32, marker: bool, payload: &[u8]) -> Result<(), DepacketizerError> {
+    let header = RtpHeader::new(2, false, false, 0, marker, 96, sequence_number, timestamp, 0x1234, &[]);
+    depacketizer.push(&RtpPacket::new(header, payload))
+}
+
+fn annex_b(nal_units: &[&[u8]]) -> Vec<u8> {
+    nal_units.iter().flat_map(|nal_unit| [&[0, 0, 0, 1], *nal_unit].concat()).collect()
+}
+
+#[test]
+fn test_aggregation_packet_and_fragmentation_units() {
+    let mut depacketizer = depacketizer(0);
+    let aggregation_packet = [&[0x60, 0x01, 0, 24], VPS, &[0, 42], SPS, &[0, 7], PPS].concat();
+
+    push(&mut depacketizer, 100, 9000, false, &aggregation_packet).unwrap();
+    push(&mut depacketizer, 101, 9000, false, &[0x62, 0x01, 0x93, 0xaf, 0x00]).unwrap();
+    push(&mut depacketizer, 102, 9000, false, &[0x62, 0x01, 0x13, 0x1b, 0x2c]).unwrap();
+    push(&mut depacketizer, 103, 9000, true, &[0x62, 0x01, 0x53, 0x3d]).unwrap();
+
+    let access_unit = depacketizer.pull().unwrap();
+    assert_eq!(access_unit.timestamp(), 9000);
+    assert!(access_unit.keyframe());
+    assert_eq!(access_unit.data(), annex_b(&[VPS, SPS, PPS, &[0x26, 0x01, 0xaf, 0x00, 0x1b, 0x2c, 0x3d]]));
+    assert_eq!(depacketizer.pull(), None);
+}
+
+#[test]
+fn test_parameter_sets_prepended_to_irap() {
+    let mut depacketizer = depacketizer(0);
+
+    push(&mut depacketizer, 1, 0, true, &[0x26, 0x01, 0xaf, 0x01]).unwrap();
+    push(&mut depacketizer, 2, 3000, true, &[0x02, 0x01, 0xd0, 0x02]).unwrap();
+
+    let irap = depacketizer.pull().unwrap();
+    assert!(irap.keyframe());
+    assert_eq!(irap.data(), annex_b(&[VPS, SPS, PPS, &[0x26, 0x01, 0xaf, 0x01]]));
+
+    let trailing = depacketizer.pull().unwrap();
+    assert!(!trailing.keyframe());
+    assert_eq!(trailing.data(), annex_b(&[&[0x02, 0x01, 0xd0, 0x02]]));
+}
+
+#[test]
+fn test_packet_loss_drops_access_unit() {
+    let mut depacketizer = depacketizer(0);
+
+    push(&mut depacketizer, 1, 0, false, &[0x62, 0x01, 0x93, 0xaf]).unwrap();
+    push(&mut depacketizer, 3, 0, true, &[0x62, 0x01, 0x53, 0x3d]).unwrap();
+    push(&mut depacketizer, 4, 3000, true, &[0x02, 0x01, 0xd0]).unwrap();
+
+    assert_eq!(depacketizer.pull().unwrap().timestamp(), 3000);
+    assert_eq!(depacketizer.pull(), None);
+    assert_eq!(depacketizer.lost_packets(), 1);
+}
+
+#[test]
+fn test_decoding_order_numbers_restore_order() {
+    let mut depacketizer = depacketizer(2);
+
+    push(&mut depacketizer, 1, 0, false, &[0x60, 0x01, 0, 0, 0, 3, 0x26, 0x01, 0xaf, 0, 0, 3, 0x26, 0x01, 0xb0]).unwrap();
+    push(&mut depacketizer, 2, 6000, false, &[0x02, 0x01, 0, 3, 0xd3]).unwrap();
+    push(&mut depacketizer, 3, 3000, false, &[0x02, 0x01, 0, 2, 0xd2]).unwrap();
+    push(&mut depacketizer, 4, 9000, false, &[0x62, 0x01, 0x81, 0, 4, 0xd4]).unwrap();
+    push(&mut depacketizer, 5, 9000, true, &[0x62, 0x01, 0x41, 0xd5]).unwrap();
+    depacketizer.flush();
+
+    let access_units = std::iter::from_fn(|| depacketizer.pull()).collect::<Vec<_>>();
+    let timestamps = access_units.iter().map(|access_unit| access_unit.timestamp()).collect::<Vec<_>>();
+    assert_eq!(timestamps, vec![0, 3000, 6000, 9000]);
+    assert_eq!(access_units[0].data(), annex_b(&[VPS, SPS, PPS, &[0x26, 0x01, 0xaf], &[0x26, 0x01, 0xb0]]));
+    assert_eq!(access_units[3].data(), annex_b(&[&[0x02, 0x01, 0xd4, 0xd5]]));
+}
+
+#[test]
+fn test_decoding_order_numbers_packet_loss_drops_access_unit() {
+    let mut depacketizer = depacketizer(2);
+
+    // Single NAL unit packets with DONL 0 to 3, the one with DONL 1 is lost.
+    push(&mut depacketizer, 1, 3000, false, &[0x02, 0x01, 0, 0, 0xd0]).unwrap();
+    push(&mut depacketizer, 3, 3000, true, &[0x02, 0x01, 0, 2, 0xd2]).unwrap();
+    push(&mut depacketizer, 4, 6000, true, &[0x02, 0x01, 0, 3, 0xd3]).unwrap();
+    depacketizer.flush();
+
+    assert_eq!(depacketizer.lost_packets(), 1);
+    let access_unit = depacketizer.pull().unwrap();
+    assert_eq!(access_unit.timestamp(), 6000);
+    assert_eq!(access_unit.data(), annex_b(&[&[0x02, 0x01, 0xd3]]));
+    assert_eq!(depacketizer.pull(), None);
+}
+
+#[test]
+fn test_unexpected_packet_type() {
+    let mut depacketizer = depacketizer(0);
+
+    assert_eq!(push(&mut depacketizer, 1, 0, true, &[0x64, 0x01, 0, 0]), Err(DepacketizerError::UnexpectedPacketType(50)));
+    assert_eq!(push(&mut depacketizer, 2, 0, true, &[0x60]), Err(DepacketizerError::Truncated("payload header")));
+}
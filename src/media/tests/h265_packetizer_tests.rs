@@ -0,0 +1,106 @@
+use media::{
+    codec::Codec,
+    packetizer_error::PacketizerError,
+    video::{h265_codec::H265Codec, h265_depacketizer::H265Depacketizer, h265_packetizer::H265Packetizer},
+};
+use rstest::rstest;
+
+const VPS: &[u8] = &[0x40, 0x01, 0x0c, 0x01, 0xff, 0xff, 0x01, 0x60, 0, 0, 3, 0, 0x90, 0, 0, 3, 0, 0, 3, 0, 0x5d, 0x95, 0x98, 0x09];
+const SPS: &[u8] = &[
+    0x42, 0x01, 0x01, 0x01, 0x60, 0, 0, 3, 0, 0x90, 0, 0, 3, 0, 0, 3, 0, 0x05, 0xda, 0, 0x28, 0x08, 0x02, 0xd1, 0x65, 0x95, 0x9a, 0x49,
+    0x32, 0xbc, 0x05, 0xa7, 0x08, 0, 0, 0x1f, 0x48, 0, 0, 0x30, 0xd4, 0x20,
+];
+const PPS: &[u8] = &[0x44, 0x01, 0xc1, 0x72, 0xb4, 0x62, 0x40];
+
+fn codec(max_don_diff: u16) -> H265Codec {
+    let fmtp = format!(
+        "a=fmtp:96 profile-id=1; sprop-vps=QAEMAf//AWAAAAMAkAAAAwAAAwBdlZgJ; sprop-sps=QgEBAWAAAAMAkAAAAwAAAwAF2gAoCALRZZWaSTK8BacIAAAfSAAAMNQg; sprop-pps=RAHBcrRiQA==; sprop-max-don-diff={}",
+        max_don_diff
+    );
+    H265Codec::parse(90000, None, fmtp.as_bytes()).unwrap()
+}
+
+fn irap_access_unit(size: usize) -> Vec<u8> {
+    let slice = (0..size).map(|index| index as u8).collect::<Vec<_>>();
+    [&[0, 0, 0, 1], VPS, &[0, 0, 0, 1], SPS, &[0, 0, 0, 1], PPS, &[0, 0, 0, 1, 0x26, 0x01], &slice].concat()
+}
+
+#[test]
+fn test_aggregation_and_fragmentation_payloads() {
+    let mut packetizer = H265Packetizer::new(&codec(0), 0x1234, 100).unwrap();
+    let slice = (0..298).map(|index| index as u8).collect::<Vec<_>>();
+
+    let packets = packetizer.packetize_annex_b(9000, &irap_access_unit(298)).unwrap();
+    let payloads = packets.iter().map(|packet| packet.payload()).collect::<Vec<_>>();
+
+    assert_eq!(
+        payloads,
+        vec![
+            [&[0x60, 0x01, 0, 24], VPS, &[0, 42], SPS, &[0, 7], PPS].concat(),
+            [&[0x62, 0x01, 0x93], &slice[..85]].concat(),
+            [&[0x62, 0x01, 0x13], &slice[85..170]].concat(),
+            [&[0x62, 0x01, 0x13], &slice[170..255]].concat(),
+            [&[0x62, 0x01, 0x53], &slice[255..]].concat(),
+        ]
+    );
+    assert!(packets.iter().all(|packet| packet.byte_size() <= 100));
+    assert_eq!(packets.iter().filter(|packet| packet.header().marker()).count(), 1);
+    assert!(packets[4].header().marker());
+}
+
+#[test]
+fn test_decoding_order_numbers() {
+    let mut packetizer = H265Packetizer::new(&codec(4), 0x1234, 100).unwrap();
+    let first_slice = [[0x26, 0x01].as_slice(), &[0xaf; 80]].concat();
+    let second_slice = [[0x26, 0x01].as_slice(), &[0xb0; 70]].concat();
+
+    let payloads = packetizer
+        .packetize(0, &[&[0x02, 0x01, 0xd0], &[0x02, 0x01, 0xd1], &first_slice, &second_slice])
+        .unwrap()
+        .iter()
+        .map(|packet| packet.payload().to_vec())
+        .collect::<Vec<_>>();
+
+    assert_eq!(payloads[0], [0x60, 0x01, 0, 0, 0, 3, 0x02, 0x01, 0xd0, 0, 0, 3, 0x02, 0x01, 0xd1]);
+    assert_eq!(&payloads[1][..4], &[0x26, 0x01, 0, 2]);
+    assert_eq!(&payloads[2][..4], &[0x26, 0x01, 0, 3]);
+}
+
+#[test]
+fn test_invalid_input() {
+    assert_eq!(H265Packetizer::new(&codec(0), 0x1234, 20).unwrap_err(), PacketizerError::MtuTooSmall(20));
+
+    let mut packetizer = H265Packetizer::new(&codec(0), 0x1234, 1200).unwrap();
+    assert_eq!(packetizer.packetize(0, &[&[0x26]]).unwrap_err(), PacketizerError::Truncated("NAL unit header"));
+    assert_eq!(packetizer.packetize_hvcc(0, &[0, 0, 0, 9, 0x26], 4).unwrap_err(), PacketizerError::Truncated("NAL unit"));
+}
+
+#[rstest]
+#[case(0, 40, 1200)]
+#[case(0, 3000, 1200)]
+#[case(0, 1186, 1200)]
+#[case(3, 40, 1200)]
+#[case(3, 3000, 1200)]
+#[case(3, 1184, 1200)]
+fn test_round_trip(#[case] max_don_diff: u16, #[case] slice_size: usize, #[case] mtu: usize) {
+    let codec = codec(max_don_diff);
+    let mut packetizer = H265Packetizer::new(&codec, 0x1234, mtu).unwrap();
+    let mut depacketizer = H265Depacketizer::new(&codec);
+    let access_units = [irap_access_unit(slice_size), [&[0, 0, 0, 1, 0x02, 0x01], irap_access_unit(slice_size).as_slice()].concat()];
+
+    for (index, access_unit) in access_units.iter().enumerate() {
+        for packet in packetizer.packetize_annex_b(index as u32 * 3000, access_unit).unwrap() {
+            assert!(packet.byte_size() <= mtu);
+            depacketizer.push(&packet).unwrap();
+        }
+    }
+    depacketizer.flush();
+
+    for (index, access_unit) in access_units.iter().enumerate() {
+        let pulled = depacketizer.pull().unwrap();
+        assert_eq!(pulled.timestamp(), index as u32 * 3000);
+        assert_eq!(pulled.data(), access_unit.as_slice());
+    }
+    assert_eq!(depacketizer.pull(), None);
+    assert_eq!(depacketizer.lost_packets(), 0);
+}
@@ -0,0 +1,129 @@
+use media::{
+    codec::Codec,
+    config_error::ConfigError,
+    video::{h265_codec::H265Codec, h265_pps::H265Pps, h265_sps::H265Sps, h265_vps::H265Vps},
+};
+use rstest::rstest;
+
+/// Main profile level 3.1 with timing information of 59.94 pictures per second.
+const MAIN_VPS: &[u8] = &[
+    0x40, 0x01, 0x0C, 0x01, 0xFF, 0xFF, 0x01, 0x60, 0x00, 0x00, 0x03, 0x00, 0x90, 0x00, 0x00, 0x03, 0x00, 0x00, 0x03, 0x00, 0x5D, 0x95,
+    0xC0, 0xC0, 0x00, 0x00, 0xFA, 0x40, 0x00, 0x3A, 0x98, 0x14,
+];
+/// Main profile 1920x1088 cropped to 1080 lines, with scaling lists, reference picture sets and VUI.
+const MAIN_1080P_SPS: &[u8] = &[
+    0x42, 0x01, 0x01, 0x01, 0x60, 0x00, 0x00, 0x03, 0x00, 0x90, 0x00, 0x00, 0x03, 0x00, 0x00, 0x03, 0x00, 0x5D, 0xA0, 0x03, 0xC0, 0x80,
+    0x11, 0x07, 0xCB, 0x96, 0x57, 0x92, 0x4F, 0x6B, 0xF5, 0xFA, 0xAA, 0xAA, 0xAC, 0x20, 0xBF, 0x5F, 0xAF, 0xD7, 0xEB, 0xF5, 0xFA, 0xFD,
+    0x7E, 0xBF, 0x4A, 0xAA, 0xE6, 0xD6, 0xBD, 0x96, 0xC0, 0xE2, 0x7F, 0x01, 0x6A, 0x02, 0x02, 0x02, 0x08, 0x00, 0x00, 0x1F, 0x48, 0x00,
+    0x07, 0x53, 0x00, 0x40,
+];
+/// Range extensions 4:2:2 10-bit high tier 3840x2160 cropped by 16 columns, with three sub-layers.
+const REXT_2160P_SPS: &[u8] = &[
+    0x42, 0x01, 0x04, 0x24, 0x08, 0x00, 0x00, 0x03, 0x00, 0x90, 0x00, 0x00, 0x03, 0x00, 0x00, 0x03, 0x00, 0x99, 0x90, 0x00, 0x80, 0x00,
+    0x00, 0x03, 0x00, 0x00, 0x03, 0x00, 0x00, 0x03, 0x00, 0x00, 0x30, 0x39, 0x5A, 0xB0, 0x01, 0xE0, 0x20, 0x02, 0x1C, 0x71, 0x3B, 0x62,
+    0x46, 0x26, 0xE4, 0x48, 0xE6, 0x6B, 0x44,
+];
+
+const VPS_SPROP: &str = "QAEMAf//AWAAAAMAkAAAAwAAAwBdlcDAAAD6QAA6mBQ=";
+const SPS_SPROP: &str = "QgEBAWAAAAMAkAAAAwAAAwBdoAPAgBEHy5ZXkk9r9fqqqqwgv1+v1+v1+v1+v0qq5ta9lsDifwFqAgICCAAAH0gAB1MAQA==";
+const PPS_SPROP: &str = "RAHBuJaZEmgoUIxI";
+
+#[test]
+fn test_parse_vps() {
+    let vps = H265Vps::try_from(MAIN_VPS).unwrap();
+
+    assert_eq!(vps.video_parameter_set_id(), 0);
+    assert_eq!(vps.max_layers(), 1);
+    assert_eq!(vps.max_sub_layers(), 1);
+    assert!(vps.temporal_id_nesting());
+    assert_eq!(vps.profile_tier_level().profile_idc(), 1);
+    assert_eq!(vps.profile_tier_level().level_idc(), 93);
+    assert_eq!(vps.max_num_reorder_pics(), 2);
+    assert_eq!(vps.timing().map(|timing| (timing.num_units_in_tick(), timing.time_scale())), Some((1001, 60000)));
+}
+
+#[rstest]
+#[case(MAIN_1080P_SPS, (1, false, 93, 0x6000_0000), (1, 1920, 1088, 1920, 1080), (8, 2, 1), Some(59.94005994005994))]
+#[case(REXT_2160P_SPS, (4, true, 153, 0x0800_0000), (2, 3840, 2160, 3824, 2160), (10, 3, 3), None)]
+fn test_parse_sps(
+    #[case] nal_unit: &[u8],
+    #[case] profile_tier_level: (u8, bool, u8, u32),
+    #[case] picture: (u32, u32, u32, u32, u32),
+    #[case] sequence: (u32, u32, u8),
+    #[case] frame_rate: Option<f64>,
+) {
+    let sps = H265Sps::try_from(nal_unit).unwrap();
+
+    let general = sps.profile_tier_level();
+    assert_eq!(
+        (general.profile_idc(), general.high_tier(), general.level_idc(), general.profile_compatibility_flags()),
+        profile_tier_level
+    );
+    assert!(general.progressive_source());
+    assert_eq!((sps.chroma_format_idc(), sps.pic_width(), sps.pic_height(), sps.width(), sps.height()), picture);
+    assert_eq!((sps.bit_depth_luma(), sps.max_num_reorder_pics(), sps.max_sub_layers()), sequence);
+    assert_eq!(sps.bit_depth_chroma(), sps.bit_depth_luma());
+    assert_eq!(sps.frame_rate(), frame_rate);
+    assert!(!sps.field_seq());
+}
+
+#[rstest]
+#[case(&[], ConfigError::Truncated("NAL unit header"))]
+#[case(MAIN_VPS, ConfigError::Unsupported("SPS NAL unit type", 32))]
+#[case(&MAIN_1080P_SPS[..17], ConfigError::Truncated("general_level_idc"))]
+#[case(&[&MAIN_1080P_SPS[..18], &[0x9F]].concat(), ConfigError::Invalid("chroma_format_idc"))]
+#[case(
+    &[&MAIN_1080P_SPS[..18], &[0xA0, 0x03, 0xC0, 0x80, 0x10, 0xE4, 0x00, 0x00, 0x03, 0x00, 0x03, 0xFF, 0xFF, 0xFF, 0xFE]].concat(),
+    ConfigError::Invalid("bit_depth_luma_minus8")
+)]
+#[case(
+    &[&MAIN_1080P_SPS[..18], &[0xA0, 0x03, 0xC0, 0x80, 0x10, 0xE5, 0x80, 0x00, 0x00, 0x03, 0x00, 0xFF, 0xFF, 0xFF, 0xFF, 0x80]].concat(),
+    ConfigError::Invalid("log2_max_pic_order_cnt_lsb_minus4")
+)]
+fn test_parse_sps_errors(#[case] nal_unit: &[u8], #[case] expected: ConfigError) {
+    assert_eq!(H265Sps::try_from(nal_unit), Err(expected));
+}
+
+#[rstest]
+#[case(&[0x44, 0x01, 0xC1, 0xB8, 0x96, 0x99, 0x12, 0x68, 0x28, 0x50, 0x8C, 0x48], (0, false, 0, true), (3, 22, -1, 2), (true, false), (3, 2))]
+#[case(&[0x44, 0x01, 0x59, 0x1C, 0x64, 0xC4, 0x80], (1, true, 2, false), (1, 26, 0, 0), (false, true), (1, 1))]
+fn test_parse_pps(
+    #[case] nal_unit: &[u8],
+    #[case] slices: (u32, bool, u8, bool),
+    #[case] quantization: (u32, i32, i32, i32),
+    #[case] weighted_pred_and_wavefront: (bool, bool),
+    #[case] tiles: (u32, u32),
+) {
+    let pps = H265Pps::try_from(nal_unit).unwrap();
+
+    assert_eq!(
+        (pps.pic_parameter_set_id(), pps.dependent_slice_segments_enabled(), pps.num_extra_slice_header_bits(), pps.cabac_init_present()),
+        slices
+    );
+    assert_eq!((pps.num_ref_idx_l0_default_active(), pps.init_qp(), pps.cb_qp_offset(), pps.cr_qp_offset()), quantization);
+    assert_eq!((pps.weighted_pred(), pps.entropy_coding_sync_enabled()), weighted_pred_and_wavefront);
+    assert_eq!((pps.num_tile_columns(), pps.num_tile_rows()), tiles);
+    assert_eq!(pps.tiles_enabled(), tiles != (1, 1));
+}
+
+#[rstest]
+#[case(&[0x44, 0x01, 0xC0, 0x60, 0x00, 0x00, 0x03, 0x00, 0x3F, 0xFF, 0xFF, 0xFF, 0xA0], ConfigError::Invalid("init_qp_minus26"))]
+#[case(&[0x44, 0x01, 0x02, 0x0C], ConfigError::Invalid("pps_pic_parameter_set_id"))]
+fn test_parse_pps_errors(#[case] nal_unit: &[u8], #[case] expected: ConfigError) {
+    assert_eq!(H265Pps::try_from(nal_unit), Err(expected));
+}
+
+#[rstest]
+#[case(1, Ok(()))]
+#[case(2, Err(ConfigError::ParameterMismatch { parameter: "profile-id", fmtp: 2, bitstream: 1 }))]
+fn test_check_profile_id(#[case] profile_id: u16, #[case] expected: Result<(), ConfigError>) {
+    let fmtp = format!(
+        "a=fmtp:96 profile-id={}; sprop-vps={}; sprop-sps={}; sprop-pps={}",
+        profile_id, VPS_SPROP, SPS_SPROP, PPS_SPROP
+    );
+    let codec = H265Codec::parse(90000, None, fmtp.as_bytes()).unwrap();
+
+    assert_eq!(codec.check_profile_id().map(|sps| (sps.width(), sps.height())), expected.map(|_| (1920, 1080)));
+    assert_eq!(codec.vps().unwrap().profile_tier_level(), codec.sps().unwrap().profile_tier_level());
+    assert_eq!(codec.pps().unwrap().num_tile_columns(), 3);
+}
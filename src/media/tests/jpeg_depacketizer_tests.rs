@@ -0,0 +1,238 @@
+use media::{
+    codec_type::CodecType,
+    depacketizer_error::DepacketizerError,
+    video::{
+        jpeg_codec::JpegCodec,
+        jpeg_depacketizer::JpegDepacketizer,
+        jpeg_header::{JpegHeader, JpegPayload, QuantizationTableHeader, RestartHeader},
+    },
+};
+use rstest::rstest;
+use rtp::{rtp_header::RtpHeader, rtp_packet::RtpPacket};
+
+/// Scan data of a 16x8 mid-gray 4:2:2 image, a single MCU whose blocks only have a zero DC difference and an EOB.
+const GRAY_SCAN: &[u8] = &[0x28, 0xA0, 0x00];
+
+type ParsedPayload<'a> = (JpegHeader, Option<RestartHeader>, Option<QuantizationTableHeader>, &'a [u8]);
+
+fn push(depacketizer: &mut JpegDepacketizer, sequence_number: u16, timestamp: u32, marker: bool, payload: &[u8]) -> Result<(), DepacketizerError> {
+    let header = RtpHeader::new(2, false, false, 0, marker, 26, sequence_number, timestamp, 0x1234, &[]);
+    depacketizer.push(&RtpPacket::new(header, payload))
+}
+
+fn depacketizer() -> JpegDepacketizer {
+    JpegDepacketizer::new(&JpegCodec::new(26, 90000, None))
+}
+
+fn main_header(fragment_offset: u32, jpeg_type: u8, q: u8, width: u8, height: u8) -> Vec<u8> {
+    let offset = fragment_offset.to_be_bytes();
+    vec![0, offset[1], offset[2], offset[3], jpeg_type, q, width, height]
+}
+
+fn frames(depacketizer: &mut JpegDepacketizer) -> Vec<(u32, Vec<u8>)> {
+    std::iter::from_fn(|| depacketizer.pull())
+        .inspect(|access_unit| assert!(access_unit.keyframe()))
+        .map(|access_unit| (access_unit.timestamp(), access_unit.into_data()))
+        .collect()
+}
+
+/// Returns the payload of the first segment with the marker, searching the headers before the scan data.
+fn segment(image: &[u8], marker: u8) -> Option<&[u8]> {
+    let mut position = 2;
+    while position + 4 <= image.len() && image[position] == 0xFF {
+        let length = u16::from_be_bytes([image[position + 2], image[position + 3]]) as usize;
+        if image[position + 1] == marker {
+            return Some(&image[position + 4..position + 2 + length]);
+        }
+        if image[position + 1] == 0xDA {
+            return None;
+        }
+        position += 2 + length;
+    }
+    None
+}
+
+/// Returns the scan data following the SOS segment.
+fn scan_data(image: &[u8]) -> &[u8] {
+    let sos = segment(image, 0xDA).unwrap();
+    let start = sos.as_ptr() as usize - image.as_ptr() as usize + sos.len();
+    &image[start..]
+}
+
+#[rstest]
+#[case(&[0, 0, 0, 0, 1, 50, 80, 60, 0xAA], Ok((JpegHeader::new(0, 0, 1, 50, 640, 480), None, None, &[0xAA][..])))]
+#[case(&[1, 0x01, 0x02, 0x03, 0, 255, 40, 30, 0xAA], Ok((JpegHeader::new(1, 0x010203, 0, 255, 320, 240), None, None, &[0xAA][..])))]
+#[case(
+    &[0, 0, 0, 0, 64, 50, 80, 60, 0x00, 0x10, 0xC0, 0x05, 0xAA],
+    Ok((JpegHeader::new(0, 0, 64, 50, 640, 480), Some(RestartHeader::new(16, true, true, 5)), None, &[0xAA][..])),
+)]
+#[case(
+    &[0, 0, 0, 0, 1, 128, 80, 60, 0, 0, 0, 2, 7, 9, 0xAA],
+    Ok((JpegHeader::new(0, 0, 1, 128, 640, 480), None, Some(QuantizationTableHeader::new(0, vec![7, 9])), &[0xAA][..])),
+)]
+#[case(&[0, 0, 0, 8, 1, 128, 80, 60, 0xAA], Ok((JpegHeader::new(0, 8, 1, 128, 640, 480), None, None, &[0xAA][..])))]
+#[case(&[0, 0, 0, 0, 1, 50, 80], Err(DepacketizerError::Truncated("main JPEG header")))]
+#[case(&[0, 0, 0, 0, 65, 50, 80, 60, 0x00], Err(DepacketizerError::Truncated("restart marker header")))]
+#[case(&[0, 0, 0, 0, 1, 200, 80, 60, 0, 0, 0, 64, 1, 2], Err(DepacketizerError::Truncated("quantization table data")))]
+fn test_parse_payload(
+    #[case] payload: &[u8],
+    #[case] expected: Result<ParsedPayload, DepacketizerError>,
+) {
+    let result = JpegPayload::try_from(payload).map(|payload| {
+        (*payload.header(), payload.restart_header().copied(), payload.quantization_table_header().cloned(), payload.data())
+    });
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_single_packet_frame() {
+    let mut depacketizer = depacketizer();
+    let payload = [main_header(0, 0, 50, 2, 1), GRAY_SCAN.to_vec()].concat();
+    push(&mut depacketizer, 1, 3000, true, &payload).unwrap();
+
+    let frames = frames(&mut depacketizer);
+    assert_eq!(frames.len(), 1);
+    let (timestamp, image) = &frames[0];
+    assert_eq!(*timestamp, 3000);
+    assert_eq!(&image[..2], &[0xFF, 0xD8]);
+    assert_eq!(&segment(image, 0xE0).unwrap()[..5], b"JFIF\0");
+
+    // Q = 50 keeps the tables of ITU-T T.81 Annex K as they are.
+    let tables = segment(image, 0xDB).unwrap();
+    assert_eq!(tables.len(), 130);
+    assert_eq!(&tables[..5], &[0x00, 16, 11, 12, 14]);
+    assert_eq!(&tables[65..70], &[0x01, 17, 18, 18, 24]);
+
+    assert_eq!(segment(image, 0xC0).unwrap(), &[8, 0, 8, 0, 16, 3, 1, 0x21, 0, 2, 0x11, 1, 3, 0x11, 1]);
+    assert_eq!(segment(image, 0xDD), None);
+    assert_eq!(segment(image, 0xDA).unwrap(), &[3, 1, 0x00, 2, 0x11, 3, 0x11, 0, 63, 0]);
+    assert_eq!(scan_data(image), &[0x28, 0xA0, 0x00, 0xFF, 0xD9]);
+}
+
+#[test]
+fn test_huffman_tables() {
+    let mut depacketizer = depacketizer();
+    push(&mut depacketizer, 1, 0, true, &[main_header(0, 1, 50, 2, 2), GRAY_SCAN.to_vec()].concat()).unwrap();
+    let (_, image) = frames(&mut depacketizer).remove(0);
+
+    let mut position = 2;
+    let mut tables = Vec::new();
+    while image[position + 1] != 0xDA {
+        let length = u16::from_be_bytes([image[position + 2], image[position + 3]]) as usize;
+        if image[position + 1] == 0xC4 {
+            let table = &image[position + 4..position + 2 + length];
+            let symbol_count = table[1..17].iter().map(|&count| count as usize).sum::<usize>();
+            assert_eq!(table.len(), 17 + symbol_count);
+            tables.push((table[0], symbol_count));
+        }
+        position += 2 + length;
+    }
+    assert_eq!(tables, vec![(0x00, 12), (0x10, 162), (0x01, 12), (0x11, 162)]);
+}
+
+#[rstest]
+#[case(80, [6, 4, 5, 6], [7, 7, 7, 10])]
+#[case(25, [32, 22, 24, 28], [34, 36, 36, 48])]
+#[case(99, [1, 1, 1, 1], [1, 1, 1, 1])]
+#[case(1, [255, 255, 255, 255], [255, 255, 255, 255])]
+fn test_scaled_quantization_tables(#[case] q: u8, #[case] luma: [u8; 4], #[case] chroma: [u8; 4]) {
+    let mut depacketizer = depacketizer();
+    push(&mut depacketizer, 1, 0, true, &[main_header(0, 0, q, 2, 1), GRAY_SCAN.to_vec()].concat()).unwrap();
+    let (_, image) = frames(&mut depacketizer).remove(0);
+
+    let tables = segment(&image, 0xDB).unwrap();
+    assert_eq!(&tables[1..5], &luma);
+    assert_eq!(&tables[66..70], &chroma);
+}
+
+#[test]
+fn test_fragmented_frame_with_in_band_tables() {
+    let mut depacketizer = depacketizer();
+    let luma = [2u8; 64];
+    let chroma = [3u8; 64];
+    let restart = [0x00, 0x04, 0xFF, 0xFF];
+
+    let first = [main_header(0, 65, 255, 4, 2), restart.to_vec(), vec![0, 0, 0, 128], luma.to_vec(), chroma.to_vec(), vec![1, 2, 3]].concat();
+    let second = [main_header(3, 65, 255, 4, 2), restart.to_vec(), vec![4, 5, 0xFF, 0xD9]].concat();
+    push(&mut depacketizer, 10, 9000, false, &first).unwrap();
+    push(&mut depacketizer, 11, 9000, true, &second).unwrap();
+
+    // The next frame refers to the tables sent with the same Q value.
+    let third = [main_header(0, 65, 255, 4, 2), restart.to_vec(), vec![0, 0, 0, 0], vec![6]].concat();
+    push(&mut depacketizer, 12, 12000, true, &third).unwrap();
+
+    let frames = frames(&mut depacketizer);
+    assert_eq!(frames.len(), 2);
+    for (image, scan) in [(&frames[0].1, &[1, 2, 3, 4, 5, 0xFF, 0xD9][..]), (&frames[1].1, &[6, 0xFF, 0xD9][..])] {
+        let tables = segment(image, 0xDB).unwrap();
+        assert_eq!(tables, [&[0x00][..], &luma, &[0x01], &chroma].concat());
+        assert_eq!(segment(image, 0xDD).unwrap(), &[0x00, 0x04]);
+        assert_eq!(segment(image, 0xC0).unwrap(), &[8, 0, 16, 0, 32, 3, 1, 0x22, 0, 2, 0x11, 1, 3, 0x11, 1]);
+        assert_eq!(scan_data(image), scan);
+    }
+    assert_eq!(frames[1].0, 12000);
+}
+
+#[test]
+fn test_sixteen_bit_quantization_table() {
+    let mut depacketizer = depacketizer();
+    let table = [0u8; 128];
+    let payload = [main_header(0, 0, 128, 2, 1), vec![0, 0x01, 0, 128], table.to_vec(), GRAY_SCAN.to_vec()].concat();
+    push(&mut depacketizer, 1, 0, true, &payload).unwrap();
+
+    let (_, image) = frames(&mut depacketizer).remove(0);
+    let tables = segment(&image, 0xDB).unwrap();
+    assert_eq!(tables.len(), 129);
+    assert_eq!(tables[0], 0x10);
+    // A single table is shared by the chroma components.
+    assert_eq!(segment(&image, 0xC0).unwrap()[11], 0);
+}
+
+#[test]
+fn test_lost_fragment_drops_frame() {
+    let mut depacketizer = depacketizer();
+
+    push(&mut depacketizer, 65535, 0, false, &[main_header(0, 0, 50, 2, 1), vec![1, 2]].concat()).unwrap();
+    push(&mut depacketizer, 1, 0, true, &[main_header(4, 0, 50, 2, 1), vec![5, 6]].concat()).unwrap();
+    push(&mut depacketizer, 2, 3000, false, &[main_header(2, 0, 50, 2, 1), vec![3]].concat()).unwrap();
+    push(&mut depacketizer, 3, 3000, true, &[main_header(3, 0, 50, 2, 1), vec![4]].concat()).unwrap();
+    push(&mut depacketizer, 4, 6000, true, &[main_header(0, 0, 50, 2, 1), GRAY_SCAN.to_vec()].concat()).unwrap();
+    push(&mut depacketizer, 0, 0, true, &[main_header(2, 0, 50, 2, 1), vec![3, 4]].concat()).unwrap();
+
+    let timestamps = frames(&mut depacketizer).into_iter().map(|(timestamp, _)| timestamp).collect::<Vec<_>>();
+    assert_eq!(timestamps, vec![6000]);
+    assert_eq!(depacketizer.lost_packets(), 1);
+}
+
+#[rstest]
+#[case(main_header(0, 2, 50, 2, 1), DepacketizerError::UnexpectedPacketType(2))]
+#[case(main_header(0, 0, 0, 2, 1), DepacketizerError::Invalid("Q"))]
+#[case(main_header(0, 0, 100, 2, 1), DepacketizerError::Invalid("Q"))]
+#[case(main_header(0, 0, 50, 0, 1), DepacketizerError::Invalid("dimensions"))]
+#[case([main_header(0, 0, 128, 2, 1), vec![0, 0, 0, 0]].concat(), DepacketizerError::Invalid("quantization tables"))]
+#[case([main_header(0, 0, 128, 2, 1), vec![0, 0x01, 0, 64], vec![1; 64]].concat(), DepacketizerError::Invalid("quantization table length"))]
+#[case([main_header(0, 0, 128, 2, 1), vec![0, 0, 0x01, 0x40], vec![1; 320]].concat(), DepacketizerError::Invalid("quantization table count"))]
+fn test_invalid_frame_start(#[case] header: Vec<u8>, #[case] expected: DepacketizerError) {
+    let mut depacketizer = depacketizer();
+    let payload = [header, GRAY_SCAN.to_vec()].concat();
+    assert_eq!(push(&mut depacketizer, 1, 0, true, &payload), Err(expected));
+    assert!(depacketizer.pull().is_none());
+}
+
+#[test]
+fn test_fragment_offset_mismatch_drops_frame() {
+    let mut depacketizer = depacketizer();
+
+    push(&mut depacketizer, 1, 0, false, &[main_header(0, 0, 50, 2, 1), vec![1, 2]].concat()).unwrap();
+    assert_eq!(
+        push(&mut depacketizer, 2, 0, true, &[main_header(3, 0, 50, 2, 1), vec![4]].concat()),
+        Err(DepacketizerError::Invalid("fragment offset")),
+    );
+    assert!(depacketizer.pull().is_none());
+    assert_eq!(depacketizer.lost_packets(), 0);
+}
+
+#[test]
+fn test_static_payload_type() {
+    assert_eq!(CodecType::from_static_payload_type(26), Some(CodecType::Jpeg(JpegCodec::new(26, 90000, None))));
+}
@@ -0,0 +1,50 @@
+use abstractions::parsing::payload_parser::PayloadParser;
+use media::{audio::opus_codec::OpusCodec, codec::Codec, codec_type::CodecType};
+use rstest::rstest;
+
+#[rstest]
+#[case(
+    b"a=fmtp:111 minptime=10; useinbandfec=1\r\n",
+    Ok(OpusCodec::new(111, 48000, Some(2), false, false, None, true, false, None))
+)]
+#[case(
+    b"a=fmtp:111 maxplaybackrate=16000;stereo=1;sprop-stereo=1;usedtx=1;ptime=40;maxptime=120",
+    Ok(OpusCodec::new(111, 48000, Some(2), true, true, Some(16000), false, true, Some(40)))
+)]
+#[case(b"a=fmtp:111 stereo=2", Err(()))]
+#[case(b"a=fmtp:111 maxplaybackrate=high", Err(()))]
+#[case(b"stereo=1", Err(()))]
+fn test_parse(#[case] data: &[u8], #[case] expected: Result<OpusCodec, ()>) {
+    assert_eq!(OpusCodec::parse(48000, Some(2), data).map_err(|_| ()), expected);
+}
+
+#[rstest]
+#[case(b"111 opus/48000/2", OpusCodec::new(111, 48000, Some(2), false, false, None, false, false, None))]
+#[case(
+    b"96 OPUS/48000/2\r\na=fmtp:96 sprop-stereo=1; useinbandfec=1\r\n",
+    OpusCodec::new(96, 48000, Some(2), false, true, None, true, false, None)
+)]
+fn test_codec_type(#[case] data: &[u8], #[case] expected: OpusCodec) {
+    assert_eq!(CodecType::parse(data).unwrap(), CodecType::Opus(expected));
+}
+
+#[rstest]
+#[case(b"111 opus/8000/2")]
+#[case(b"111 opus/48000")]
+#[case(b"111 opus/48000/1")]
+fn test_codec_type_invalid_rtpmap(#[case] data: &[u8]) {
+    assert!(CodecType::parse(data).is_err());
+}
+
+#[rstest]
+#[case(OpusCodec::new(111, 48000, Some(2), false, false, None, false, false, None), "a=rtpmap:111 opus/48000/2\r\n")]
+#[case(
+    OpusCodec::new(111, 48000, Some(2), true, true, Some(24000), true, true, Some(20)),
+    "a=rtpmap:111 opus/48000/2\r\na=fmtp:111 maxplaybackrate=24000; stereo=1; sprop-stereo=1; useinbandfec=1; usedtx=1; ptime=20\r\n"
+)]
+fn test_display(#[case] codec: OpusCodec, #[case] expected: &str) {
+    let codec_type = CodecType::Opus(codec);
+
+    assert_eq!(codec_type.to_string(), expected);
+    assert_eq!(CodecType::parse(expected.trim_start_matches("a=rtpmap:").as_bytes()).unwrap(), codec_type);
+}
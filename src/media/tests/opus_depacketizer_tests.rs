@@ -0,0 +1,78 @@
+use media::{
+    audio::{
+        opus_codec::OpusCodec,
+        opus_depacketizer::OpusDepacketizer,
+        opus_packet::{frame_count, frame_duration, packet_duration},
+    },
+    depacketizer_error::DepacketizerError,
+};
+use rstest::rstest;
+use rtp::{rtp_header::RtpHeader, rtp_packet::RtpPacket};
+
+fn push(depacketizer: &mut OpusDepacketizer, sequence_number: u16, timestamp: u32, payload: &[u8]) -> Result<(), DepacketizerError> {
+    let header = RtpHeader::new(2, false, false, 0, true, 111, sequence_number, timestamp, 0x1234, &[]);
+    depacketizer.push(&RtpPacket::new(header, payload))
+}
+
+fn depacketizer() -> OpusDepacketizer {
+    OpusDepacketizer::new(&OpusCodec::new(111, 48000, Some(2), false, false, None, true, false, None))
+}
+
+#[rstest]
+#[case(0x00, 480)]
+#[case(0x08, 960)]
+#[case(0x18, 2880)]
+#[case(0x60, 480)]
+#[case(0x68, 960)]
+#[case(0x80, 120)]
+#[case(0xF8, 960)]
+fn test_frame_duration(#[case] toc: u8, #[case] expected: u32) {
+    assert_eq!(frame_duration(toc), expected);
+}
+
+#[rstest]
+#[case(&[0xF8, 1, 2], Ok(960))]
+#[case(&[0xF9, 1, 2], Ok(1920))]
+#[case(&[0xFA, 1, 1, 2], Ok(1920))]
+#[case(&[0xFB, 0x06, 1, 2], Ok(5760))]
+#[case(&[0x1B, 0x03], Err(DepacketizerError::Invalid("frame count")))]
+#[case(&[0xFB, 0x00], Err(DepacketizerError::Invalid("frame count")))]
+#[case(&[0xFB], Err(DepacketizerError::Truncated("frame count")))]
+#[case(&[], Err(DepacketizerError::Truncated("TOC")))]
+fn test_packet_duration(#[case] packet: &[u8], #[case] expected: Result<u32, DepacketizerError>) {
+    assert_eq!(packet_duration(packet), expected);
+    if let Ok(duration) = expected {
+        assert_eq!(frame_count(packet).unwrap() * frame_duration(packet[0]), duration);
+    }
+}
+
+#[test]
+fn test_packets_keep_rtp_timestamps() {
+    let mut depacketizer = depacketizer();
+
+    push(&mut depacketizer, 1, 48000, &[0xF8, 1, 2, 3]).unwrap();
+    assert_eq!(depacketizer.next_timestamp(), Some(48960));
+    // The sender stopped transmitting during silence.
+    push(&mut depacketizer, 2, 57600, &[0xF9, 4, 5]).unwrap();
+    assert_eq!(depacketizer.next_timestamp(), Some(59520));
+
+    let packets = std::iter::from_fn(|| depacketizer.pull())
+        .map(|access_unit| (access_unit.timestamp(), access_unit.keyframe(), access_unit.into_data()))
+        .collect::<Vec<_>>();
+    assert_eq!(packets, vec![(48000, true, vec![0xF8, 1, 2, 3]), (57600, true, vec![0xF9, 4, 5])]);
+    assert_eq!(depacketizer.lost_packets(), 0);
+}
+
+#[test]
+fn test_lost_and_late_packets() {
+    let mut depacketizer = depacketizer();
+
+    push(&mut depacketizer, 65535, 0, &[0xF8, 1]).unwrap();
+    push(&mut depacketizer, 2, 2880, &[0xF8, 2]).unwrap();
+    push(&mut depacketizer, 1, 1920, &[0xF8, 3]).unwrap();
+    assert_eq!(push(&mut depacketizer, 3, 3840, &[]), Err(DepacketizerError::Truncated("TOC")));
+
+    let timestamps = std::iter::from_fn(|| depacketizer.pull()).map(|access_unit| access_unit.timestamp()).collect::<Vec<_>>();
+    assert_eq!(timestamps, vec![0, 2880]);
+    assert_eq!(depacketizer.lost_packets(), 2);
+}
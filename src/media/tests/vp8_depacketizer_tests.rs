@@ -0,0 +1,102 @@
+use media::{
+    depacketizer_error::DepacketizerError,
+    video::{vp8_codec::Vp8Codec, vp8_depacketizer::Vp8Depacketizer, vp8_descriptor::Vp8Descriptor},
+};
+use rstest::rstest;
+use rtp::{rtp_header::RtpHeader, rtp_packet::RtpPacket};
+
+/// The non-reference flag, start of partition, partition index, PictureID, TL0PICIDX, TID, layer sync, KEYIDX and size.
+type DescriptorFields = (bool, bool, u8, Option<u16>, Option<u8>, Option<u8>, bool, Option<u8>, usize);
+
+fn push(depacketizer: &mut Vp8Depacketizer, sequence_number: u16, timestamp: u32, marker: bool, payload: &[u8]) -> Result<(), DepacketizerError> {
+    let header = RtpHeader::new(2, false, false, 0, marker, 96, sequence_number, timestamp, 0x1234, &[]);
+    depacketizer.push(&RtpPacket::new(header, payload))
+}
+
+fn depacketizer() -> Vp8Depacketizer {
+    Vp8Depacketizer::new(&Vp8Codec::new(96, 90000, None, None, None))
+}
+
+fn frames(depacketizer: &mut Vp8Depacketizer) -> Vec<(u32, bool, Vec<u8>)> {
+    std::iter::from_fn(|| depacketizer.pull())
+        .map(|access_unit| (access_unit.timestamp(), access_unit.keyframe(), access_unit.into_data()))
+        .collect()
+}
+
+#[rstest]
+#[case(&[0x10, 0xAA], (false, true, 0, None, None, None, false, None, 1))]
+#[case(&[0x23, 0xAA], (true, false, 3, None, None, None, false, None, 1))]
+#[case(&[0x18, 0xAA], (false, true, 0, None, None, None, false, None, 1))]
+#[case(&[0x90, 0x80, 0x7F, 0xAA], (false, true, 0, Some(127), None, None, false, None, 3))]
+#[case(&[0x90, 0x80, 0x92, 0x34, 0xAA], (false, true, 0, Some(0x1234), None, None, false, None, 4))]
+#[case(&[0x90, 0xF0, 0x80, 0x01, 0x05, 0x63, 0xAA], (false, true, 0, Some(1), Some(5), Some(1), true, Some(3), 6))]
+#[case(&[0x80, 0x10, 0x1F, 0xAA], (false, false, 0, None, None, None, false, Some(31), 3))]
+fn test_parse_descriptor(
+    #[case] payload: &[u8],
+    #[case] expected: DescriptorFields,
+) {
+    let descriptor = Vp8Descriptor::try_from(payload).unwrap();
+    let result = (
+        descriptor.non_reference(),
+        descriptor.start_of_partition(),
+        descriptor.partition_index(),
+        descriptor.picture_id(),
+        descriptor.tl0_pic_idx(),
+        descriptor.temporal_id(),
+        descriptor.layer_sync(),
+        descriptor.key_idx(),
+        descriptor.size(),
+    );
+    assert_eq!(result, expected);
+}
+
+#[rstest]
+#[case(&[], "VP8 payload descriptor")]
+#[case(&[0x90], "VP8 extension")]
+#[case(&[0x90, 0x80, 0x92], "PictureID")]
+#[case(&[0x90, 0x40], "TL0PICIDX")]
+#[case(&[0x90, 0x20], "TID/KEYIDX")]
+fn test_parse_truncated_descriptor(#[case] payload: &[u8], #[case] expected: &'static str) {
+    assert_eq!(Vp8Descriptor::try_from(payload), Err(DepacketizerError::Truncated(expected)));
+}
+
+#[test]
+fn test_frames_across_partitions() {
+    let mut depacketizer = depacketizer();
+
+    // A keyframe whose second partition starts in the second packet.
+    push(&mut depacketizer, 1, 3000, false, &[0x90, 0x80, 0x01, 0x10, 0x02]).unwrap();
+    push(&mut depacketizer, 2, 3000, false, &[0x80, 0x80, 0x01, 0x03]).unwrap();
+    push(&mut depacketizer, 3, 3000, true, &[0x91, 0x80, 0x01, 0x04, 0x05]).unwrap();
+    // An interframe in a single packet.
+    push(&mut depacketizer, 4, 6000, true, &[0x10, 0x11, 0x06]).unwrap();
+
+    assert_eq!(frames(&mut depacketizer), vec![(3000, true, vec![0x10, 0x02, 0x03, 0x04, 0x05]), (6000, false, vec![0x11, 0x06])]);
+    assert_eq!(depacketizer.lost_packets(), 0);
+}
+
+#[test]
+fn test_lost_packet_drops_frame() {
+    let mut depacketizer = depacketizer();
+
+    push(&mut depacketizer, 65534, 0, false, &[0x10, 0x10, 0x01]).unwrap();
+    push(&mut depacketizer, 0, 0, true, &[0x00, 0x02]).unwrap();
+    push(&mut depacketizer, 1, 3000, false, &[0x00, 0x03]).unwrap();
+    push(&mut depacketizer, 2, 3000, true, &[0x00, 0x04]).unwrap();
+    push(&mut depacketizer, 3, 6000, true, &[0x10, 0x11, 0x05]).unwrap();
+    push(&mut depacketizer, 65535, 0, false, &[0x00, 0x06]).unwrap();
+
+    assert_eq!(frames(&mut depacketizer), vec![(6000, false, vec![0x11, 0x05])]);
+    assert_eq!(depacketizer.lost_packets(), 1);
+}
+
+#[rstest]
+#[case(&[0x10], DepacketizerError::Truncated("VP8 payload"))]
+#[case(&[0x90, 0x80], DepacketizerError::Truncated("PictureID"))]
+fn test_invalid_payload_drops_frame(#[case] payload: &[u8], #[case] expected: DepacketizerError) {
+    let mut depacketizer = depacketizer();
+
+    push(&mut depacketizer, 1, 0, false, &[0x10, 0x10, 0x01]).unwrap();
+    assert_eq!(push(&mut depacketizer, 2, 0, true, payload), Err(expected));
+    assert!(depacketizer.pull().is_none());
+}
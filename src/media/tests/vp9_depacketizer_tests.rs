@@ -0,0 +1,133 @@
+use media::{
+    depacketizer_error::DepacketizerError,
+    video::{
+        vp9_codec::Vp9Codec,
+        vp9_depacketizer::Vp9Depacketizer,
+        vp9_descriptor::{Vp9Descriptor, Vp9LayerIndices, Vp9PictureGroupEntry, Vp9ScalabilityStructure},
+    },
+};
+use rstest::rstest;
+use rtp::{rtp_header::RtpHeader, rtp_packet::RtpPacket};
+
+fn push(depacketizer: &mut Vp9Depacketizer, sequence_number: u16, timestamp: u32, marker: bool, payload: &[u8]) -> Result<(), DepacketizerError> {
+    let header = RtpHeader::new(2, false, false, 0, marker, 98, sequence_number, timestamp, 0x1234, &[]);
+    depacketizer.push(&RtpPacket::new(header, payload))
+}
+
+fn depacketizer() -> Vp9Depacketizer {
+    Vp9Depacketizer::new(&Vp9Codec::new(98, 90000, None, None, None, None))
+}
+
+fn frames(depacketizer: &mut Vp9Depacketizer) -> Vec<(u32, bool, Vec<u8>)> {
+    std::iter::from_fn(|| depacketizer.pull())
+        .map(|access_unit| (access_unit.timestamp(), access_unit.keyframe(), access_unit.into_data()))
+        .collect()
+}
+
+#[test]
+fn test_parse_non_flexible_descriptor() {
+    let descriptor = Vp9Descriptor::try_from(&[0xAC, 0x81, 0x02, 0x52, 0x07, 0xAA][..]).unwrap();
+
+    assert_eq!(descriptor.picture_id(), Some(0x0102));
+    assert!(!descriptor.inter_picture_predicted());
+    assert!(!descriptor.flexible());
+    assert!(descriptor.beginning_of_frame());
+    assert!(descriptor.end_of_frame());
+    assert!(!descriptor.not_reference());
+    assert_eq!(descriptor.layer_indices(), Some(&Vp9LayerIndices::new(2, true, 1, false, Some(7))));
+    assert!(descriptor.reference_diffs().is_empty());
+    assert_eq!(descriptor.scalability_structure(), None);
+    assert_eq!(descriptor.size(), 5);
+    assert!(descriptor.is_keyframe());
+}
+
+#[test]
+fn test_parse_flexible_descriptor() {
+    let descriptor = Vp9Descriptor::try_from(&[0xF9, 0x05, 0x03, 0x03, 0x08, 0xAA][..]).unwrap();
+
+    assert_eq!(descriptor.picture_id(), Some(5));
+    assert!(descriptor.inter_picture_predicted());
+    assert!(descriptor.flexible());
+    assert!(descriptor.not_reference());
+    assert_eq!(descriptor.layer_indices(), Some(&Vp9LayerIndices::new(0, false, 1, true, None)));
+    assert_eq!(descriptor.reference_diffs(), &[1, 4]);
+    assert_eq!(descriptor.size(), 5);
+    assert!(!descriptor.is_keyframe());
+}
+
+#[test]
+fn test_parse_scalability_structure() {
+    let payload = [0x0E, 0x38, 0x01, 0x40, 0x00, 0xB4, 0x02, 0x80, 0x01, 0x68, 0x02, 0x00, 0x34, 0x01, 0xAA];
+    let descriptor = Vp9Descriptor::try_from(&payload[..]).unwrap();
+
+    let expected = Vp9ScalabilityStructure::new(
+        2,
+        vec![(320, 180), (640, 360)],
+        vec![Vp9PictureGroupEntry::new(0, false, vec![]), Vp9PictureGroupEntry::new(1, true, vec![1])],
+    );
+    assert_eq!(descriptor.scalability_structure(), Some(&expected));
+    assert_eq!(descriptor.size(), 14);
+}
+
+#[rstest]
+#[case(&[], DepacketizerError::Truncated("VP9 payload descriptor"))]
+#[case(&[0x80], DepacketizerError::Truncated("PICTURE ID"))]
+#[case(&[0x80, 0x80], DepacketizerError::Truncated("EXTENDED PID"))]
+#[case(&[0x20, 0x00], DepacketizerError::Truncated("TL0PICIDX"))]
+#[case(&[0x50, 0x03], DepacketizerError::Truncated("P_DIFF"))]
+#[case(&[0x50, 0x03, 0x03, 0x03, 0x02], DepacketizerError::Invalid("P_DIFF count"))]
+#[case(&[0x02, 0x10, 0x01], DepacketizerError::Truncated("WIDTH"))]
+#[case(&[0x02, 0x08, 0x01, 0x04], DepacketizerError::Truncated("P_DIFF"))]
+fn test_parse_invalid_descriptor(#[case] payload: &[u8], #[case] expected: DepacketizerError) {
+    assert_eq!(Vp9Descriptor::try_from(payload), Err(expected));
+}
+
+#[test]
+fn test_frames() {
+    let mut depacketizer = depacketizer();
+
+    // A keyframe carrying the scalability structure of a single 320x180 layer.
+    push(&mut depacketizer, 1, 3000, false, &[0x8A, 0x01, 0x10, 0x01, 0x40, 0x00, 0xB4, 0xA1]).unwrap();
+    push(&mut depacketizer, 2, 3000, false, &[0x80, 0x01, 0xA2]).unwrap();
+    push(&mut depacketizer, 3, 3000, true, &[0x84, 0x01, 0xA3]).unwrap();
+    // An interframe in a single packet.
+    push(&mut depacketizer, 4, 6000, true, &[0xCC, 0x02, 0xB1]).unwrap();
+
+    assert_eq!(frames(&mut depacketizer), vec![(3000, true, vec![0xA1, 0xA2, 0xA3]), (6000, false, vec![0xB1])]);
+    assert_eq!(depacketizer.scalability_structure(), Some(&Vp9ScalabilityStructure::new(1, vec![(320, 180)], vec![])));
+}
+
+#[test]
+fn test_spatial_layers_are_separate_frames() {
+    let mut depacketizer = depacketizer();
+
+    // A key picture whose upper spatial layer predicts from the lower one.
+    push(&mut depacketizer, 1, 9000, false, &[0x2C, 0x00, 0x00, 0xC1]).unwrap();
+    push(&mut depacketizer, 2, 9000, true, &[0x2C, 0x03, 0x00, 0xC2]).unwrap();
+
+    assert_eq!(frames(&mut depacketizer), vec![(9000, true, vec![0xC1]), (9000, false, vec![0xC2])]);
+}
+
+#[test]
+fn test_lost_packet_drops_frame() {
+    let mut depacketizer = depacketizer();
+
+    push(&mut depacketizer, 65534, 0, false, &[0x08, 0x01]).unwrap();
+    push(&mut depacketizer, 0, 0, true, &[0x04, 0x02]).unwrap();
+    push(&mut depacketizer, 1, 3000, false, &[0x2C, 0x00, 0x00, 0x03]).unwrap();
+    push(&mut depacketizer, 2, 3000, false, &[0x24, 0x03, 0x00, 0x04]).unwrap();
+    push(&mut depacketizer, 3, 3000, true, &[0x2C, 0x03, 0x00, 0x05]).unwrap();
+    push(&mut depacketizer, 65535, 0, false, &[0x00, 0x06]).unwrap();
+
+    assert_eq!(frames(&mut depacketizer), vec![(3000, true, vec![0x03]), (3000, false, vec![0x05])]);
+    assert_eq!(depacketizer.lost_packets(), 1);
+}
+
+#[test]
+fn test_invalid_payload_drops_frame() {
+    let mut depacketizer = depacketizer();
+
+    push(&mut depacketizer, 1, 0, false, &[0x08, 0x01]).unwrap();
+    assert_eq!(push(&mut depacketizer, 2, 0, true, &[0x84]), Err(DepacketizerError::Truncated("PICTURE ID")));
+    assert!(depacketizer.pull().is_none());
+}
@@ -0,0 +1,48 @@
+use abstractions::parsing::payload_parser::PayloadParser;
+use media::{
+    codec::Codec,
+    codec_type::CodecType,
+    video::{vp8_codec::Vp8Codec, vp9_codec::Vp9Codec},
+};
+use rstest::rstest;
+
+#[rstest]
+#[case(b"a=fmtp:96 max-fr=30; max-fs=3600\r\n", Ok(Vp8Codec::new(96, 90000, None, Some(30), Some(3600))))]
+#[case(b"a=fmtp:96 max-fs=1200", Ok(Vp8Codec::new(96, 90000, None, None, Some(1200))))]
+#[case(b"a=fmtp:96 max-fr=fast", Err(()))]
+#[case(b"max-fr=30", Err(()))]
+fn test_parse_vp8(#[case] data: &[u8], #[case] expected: Result<Vp8Codec, ()>) {
+    assert_eq!(Vp8Codec::parse(90000, None, data).map_err(|_| ()), expected);
+}
+
+#[rstest]
+#[case(b"a=fmtp:98 profile-id=2\r\n", Ok(Vp9Codec::new(98, 90000, None, None, None, Some(2))))]
+#[case(b"a=fmtp:98 max-fr=60;max-fs=8160;profile-id=0", Ok(Vp9Codec::new(98, 90000, None, Some(60), Some(8160), Some(0))))]
+#[case(b"a=fmtp:98 profile-id=4", Err(()))]
+fn test_parse_vp9(#[case] data: &[u8], #[case] expected: Result<Vp9Codec, ()>) {
+    assert_eq!(Vp9Codec::parse(90000, None, data).map_err(|_| ()), expected);
+}
+
+#[rstest]
+#[case(b"96 VP8/90000", CodecType::Vp8(Vp8Codec::new(96, 90000, None, None, None)))]
+#[case(b"96 vp8/90000\r\na=fmtp:96 max-fr=30\r\n", CodecType::Vp8(Vp8Codec::new(96, 90000, None, Some(30), None)))]
+#[case(b"98 VP9/90000", CodecType::Vp9(Vp9Codec::new(98, 90000, None, None, None, None)))]
+#[case(b"98 VP9/90000\r\na=fmtp:98 profile-id=1\r\n", CodecType::Vp9(Vp9Codec::new(98, 90000, None, None, None, Some(1))))]
+fn test_codec_type(#[case] data: &[u8], #[case] expected: CodecType) {
+    assert_eq!(CodecType::parse(data).unwrap(), expected);
+}
+
+#[rstest]
+#[case(CodecType::Vp8(Vp8Codec::new(96, 90000, None, None, None)), "a=rtpmap:96 VP8/90000\r\n")]
+#[case(
+    CodecType::Vp8(Vp8Codec::new(96, 90000, None, Some(30), Some(3600))),
+    "a=rtpmap:96 VP8/90000\r\na=fmtp:96 max-fr=30; max-fs=3600\r\n"
+)]
+#[case(
+    CodecType::Vp9(Vp9Codec::new(98, 90000, None, Some(60), None, Some(2))),
+    "a=rtpmap:98 VP9/90000\r\na=fmtp:98 max-fr=60; profile-id=2\r\n"
+)]
+fn test_display(#[case] codec_type: CodecType, #[case] expected: &str) {
+    assert_eq!(codec_type.to_string(), expected);
+    assert_eq!(CodecType::parse(expected.trim_start_matches("a=rtpmap:").as_bytes()).unwrap(), codec_type);
+}
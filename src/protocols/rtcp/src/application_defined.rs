@@ -23,7 +23,7 @@ impl<'a> TryFrom<&'a [u8]> for ApplicationDefined<'a> {
         }
 
         let header = RtcpHeader::try_from(bytes)?;
-        let subtype = (bytes[0] & 0x1F) as u8;
+        let subtype = bytes[0] & 0x1F;
         let ssrc = BigEndian::read_u32(&bytes[4..8]);
         let name = [bytes[8], bytes[9], bytes[10], bytes[11]];
         let data = &bytes[12..];
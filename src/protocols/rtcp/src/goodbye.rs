@@ -8,7 +8,7 @@ use byteorder::{BigEndian, ByteOrder};
 #[derive(Debug)]
 pub struct Goodbye<'a> {
     header: RtcpHeader,
-    sources: &'a [u32],
+    sources: Vec<u32>,
     reason: Option<&'a str>,
 }
 
@@ -28,15 +28,10 @@ impl<'a> TryFrom<&'a [u8]> for Goodbye<'a> {
             return Err(CastError::from_str("Buffer too short to contain all sources"));
         }
 
-        let sources = &bytes[offset..offset + source_count * 4];
-        let mut sources_vec = Vec::with_capacity(source_count);
-        for i in 0..source_count {
-            let source = BigEndian::read_u32(&sources[i * 4..(i + 1) * 4]);
-            sources_vec.push(source);
-        }
-        let sources = unsafe {
-            std::slice::from_raw_parts(sources_vec.as_ptr(), sources_vec.len())
-        };
+        let sources = bytes[offset..offset + source_count * 4]
+            .chunks_exact(4)
+            .map(BigEndian::read_u32)
+            .collect();
         offset += source_count * 4;
 
         let reason = if offset < bytes.len() {
@@ -60,7 +55,7 @@ impl<'a> Goodbye<'a> {
     }
 
     pub fn sources(&self) -> &[u32] {
-        self.sources
+        &self.sources
     }
 
     pub fn reason(&self) -> Option<&str> {
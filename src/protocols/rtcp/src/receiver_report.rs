@@ -9,16 +9,16 @@ pub(crate) const REPORT_BLOCK_SIZE: usize = 24;
 
 /// Represents an RTCP Receiver Report (RR) packet.
 #[derive(Debug)]
-pub struct ReceiverReport<'a> {
+pub struct ReceiverReport {
     header: RtcpHeader,
     ssrc: u32,
-    report_blocks: &'a [ReportBlock],
+    report_blocks: Vec<ReportBlock>,
 }
 
-impl<'a> TryFrom<&'a [u8]> for ReceiverReport<'a> {
+impl TryFrom<&[u8]> for ReceiverReport {
     type Error = CastError;
 
-    fn try_from(value: &'a [u8]) -> Result<Self, Self::Error> {
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
         if value.len() < RECEIVER_REPORT_HEADER_SIZE {
             return Err(CastError::from_str("Buffer too short to contain Receiver Report header"));
         }
@@ -40,9 +40,6 @@ impl<'a> TryFrom<&'a [u8]> for ReceiverReport<'a> {
             let block = ReportBlock::try_from(&value[start..end])?;
             report_blocks.push(block);
         }
-        let report_blocks = unsafe {
-            std::slice::from_raw_parts(report_blocks.as_ptr(), report_count)
-        };
 
         Ok(Self {
             header,
@@ -52,7 +49,7 @@ impl<'a> TryFrom<&'a [u8]> for ReceiverReport<'a> {
     }
 }
 
-impl<'a> ReceiverReport<'a> {
+impl ReceiverReport {
     pub fn header(&self) -> &RtcpHeader {
         &self.header
     }
@@ -62,6 +59,6 @@ impl<'a> ReceiverReport<'a> {
     }
 
     pub fn report_blocks(&self) -> &[ReportBlock] {
-        self.report_blocks
+        &self.report_blocks
     }
 }
\ No newline at end of file
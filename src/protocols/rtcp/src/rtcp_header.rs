@@ -1,6 +1,7 @@
 use crate::rtcp_packet_type::RtcpPacketType;
 use abstractions::extensions::cast_error::CastError;
 use byteorder::{BigEndian, ByteOrder};
+use num_traits::FromPrimitive;
 use std::fmt;
 
 pub(crate) const RTCP_HEADER_SIZE: usize = 4;
@@ -65,7 +66,8 @@ impl TryFrom<&[u8]> for RtcpHeader {
         }
 
         let v_p_rc = value[0];
-        let packet_type = RtcpPacketType::from(value[1]);
+        let packet_type = RtcpPacketType::from_u8(value[1])
+            .ok_or_else(|| CastError::from_str("Unknown RTCP packet type"))?;
         let length = BigEndian::read_u16(&value[2..4]);
 
         Ok(Self {
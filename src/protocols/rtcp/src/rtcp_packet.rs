@@ -1,11 +1,78 @@
-use crate::{application_defined::ApplicationDefined, goodbye::Goodbye, receiver_report::ReceiverReport, sender_report::SenderReport, source_description::SourceDescription};
+use std::convert::TryFrom;
+
+use abstractions::extensions::cast_error::CastError;
+use byteorder::{BigEndian, ByteOrder};
+use num_traits::FromPrimitive;
+
+use crate::{application_defined::ApplicationDefined, goodbye::Goodbye, receiver_report::ReceiverReport, rtcp_header::{RtcpHeader, RTCP_HEADER_SIZE}, rtcp_packet_type::RtcpPacketType, sender_report::SenderReport, source_description::SourceDescription};
 
 /// Represents an RTCP packet.
 #[derive(Debug)]
 pub enum RtcpPacket<'a> {
-    SenderReport(SenderReport<'a>),
-    ReceiverReport(ReceiverReport<'a>),
+    SenderReport(SenderReport),
+    ReceiverReport(ReceiverReport),
     SourceDescription(SourceDescription<'a>),
     Goodbye(Goodbye<'a>),
     ApplicationDefined(ApplicationDefined<'a>),
-}
\ No newline at end of file
+}
+
+impl<'a> TryFrom<&'a [u8]> for RtcpPacket<'a> {
+    type Error = CastError;
+
+    /// Parses a single RTCP packet, dispatching on the packet type of its header.
+    fn try_from(value: &'a [u8]) -> Result<Self, Self::Error> {
+        let header = RtcpHeader::try_from(value)?;
+        let packet = match header.packet_type() {
+            RtcpPacketType::SenderReport => RtcpPacket::SenderReport(SenderReport::try_from(value)?),
+            RtcpPacketType::ReceiverReport => RtcpPacket::ReceiverReport(ReceiverReport::try_from(value)?),
+            RtcpPacketType::SourceDescription => RtcpPacket::SourceDescription(SourceDescription::try_from(value)?),
+            RtcpPacketType::Goodbye => RtcpPacket::Goodbye(Goodbye::try_from(value)?),
+            RtcpPacketType::ApplicationDefined => RtcpPacket::ApplicationDefined(ApplicationDefined::try_from(value)?),
+        };
+        Ok(packet)
+    }
+}
+
+impl<'a> RtcpPacket<'a> {
+    /// Parses a compound RTCP packet as sent in a single datagram or interleaved frame.
+    ///
+    /// Packets of a type this crate does not model (e.g. RTPFB, PSFB or XR) are skipped by their
+    /// length, so that the reports next to them are still returned.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The bytes of one or more RTCP packets stacked back to back.
+    ///
+    /// # Returns
+    ///
+    /// The known packets in order, or a `CastError` if any of them is invalid.
+    pub fn parse_compound(value: &'a [u8]) -> Result<Vec<Self>, CastError> {
+        let mut packets = Vec::new();
+        let mut offset = 0;
+
+        while offset < value.len() {
+            if value.len() - offset < RTCP_HEADER_SIZE {
+                return Err(CastError::from_str("Buffer too short to contain RTCP header"));
+            }
+            let length = (BigEndian::read_u16(&value[offset + 2..offset + 4]) as usize + 1) * 4;
+            if value.len() - offset < length {
+                return Err(CastError::from_str("Buffer too short to contain RTCP packet"));
+            }
+            if RtcpPacketType::from_u8(value[offset + 1]).is_some() {
+                packets.push(RtcpPacket::try_from(&value[offset..offset + length])?);
+            }
+            offset += length;
+        }
+        Ok(packets)
+    }
+
+    pub fn header(&self) -> &RtcpHeader {
+        match self {
+            RtcpPacket::SenderReport(packet) => packet.header(),
+            RtcpPacket::ReceiverReport(packet) => packet.header(),
+            RtcpPacket::SourceDescription(packet) => packet.header(),
+            RtcpPacket::Goodbye(packet) => packet.header(),
+            RtcpPacket::ApplicationDefined(packet) => packet.header(),
+        }
+    }
+}
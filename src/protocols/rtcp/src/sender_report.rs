@@ -8,20 +8,20 @@ pub(crate) const SENDER_REPORT_HEADER_SIZE: usize = 28;
 
 /// Represents an RTCP Sender Report (SR) packet.
 #[derive(Debug)]
-pub struct SenderReport<'a> {
+pub struct SenderReport {
     header: RtcpHeader,
     ssrc: u32,
     ntp_timestamp: u64,
     rtp_timestamp: u32,
     packet_count: u32,
     octet_count: u32,
-    report_blocks: &'a [ReportBlock],
+    report_blocks: Vec<ReportBlock>,
 }
 
-impl<'a> TryFrom<&'a [u8]> for SenderReport<'a> {
+impl TryFrom<&[u8]> for SenderReport {
     type Error = CastError;
 
-    fn try_from(value: &'a [u8]) -> Result<Self, Self::Error> {
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
         if value.len() < SENDER_REPORT_HEADER_SIZE {
             return Err(CastError::from_str("Buffer too short to contain Sender Report header"));
         }
@@ -49,9 +49,6 @@ impl<'a> TryFrom<&'a [u8]> for SenderReport<'a> {
             let block = ReportBlock::try_from(&value[start..end])?;
             report_blocks.push(block);
         }
-        let report_blocks = unsafe {
-            std::slice::from_raw_parts(report_blocks.as_ptr(), report_count)
-        };
 
         Ok(Self {
             header,
@@ -65,7 +62,7 @@ impl<'a> TryFrom<&'a [u8]> for SenderReport<'a> {
     }
 }
 
-impl<'a> SenderReport<'a> {
+impl SenderReport {
     pub fn header(&self) -> &RtcpHeader {
         &self.header
     }
@@ -91,6 +88,6 @@ impl<'a> SenderReport<'a> {
     }
     
     pub fn report_blocks(&self) -> &[ReportBlock] {
-        self.report_blocks
+        &self.report_blocks
     }
 }
\ No newline at end of file
@@ -0,0 +1,53 @@
+use rtcp::rtcp_packet::RtcpPacket;
+use rtcp::rtcp_packet_type::RtcpPacketType;
+
+#[test]
+fn test_rtcp_packet_parse_compound() {
+    let buffer = [
+        0b10000000, 201, 0x00, 0x01, // RTCP header (RR, no report blocks)
+        0x12, 0x34, 0x56, 0x78, // SSRC
+        0b10000001, 203, 0x00, 0x01, // RTCP header (BYE)
+        0x12, 0x34, 0x56, 0x78, // SSRC
+    ];
+
+    let packets = RtcpPacket::parse_compound(&buffer[..]).unwrap();
+
+    assert_eq!(packets.len(), 2);
+    assert_eq!(packets[0].header().packet_type(), &RtcpPacketType::ReceiverReport);
+    match &packets[1] {
+        RtcpPacket::Goodbye(goodbye) => assert_eq!(goodbye.sources(), &[0x12345678]),
+        packet => panic!("expected a goodbye packet, got {:?}", packet),
+    }
+}
+
+#[test]
+fn test_rtcp_packet_parse_compound_skips_unknown_types() {
+    let buffer = [
+        0b10000000, 200, 0x00, 0x06, // RTCP header (SR, no report blocks)
+        0x12, 0x34, 0x56, 0x78, // SSRC
+        0xE8, 0x4F, 0x1A, 0x00, 0x00, 0x00, 0x00, 0x00, // NTP timestamp
+        0x00, 0x00, 0x03, 0xE8, // RTP timestamp
+        0x00, 0x00, 0x00, 0x0A, // Packet count
+        0x00, 0x00, 0x06, 0x40, // Octet count
+        0b10000001, 205, 0x00, 0x03, // RTCP header (RTPFB, generic NACK)
+        0x12, 0x34, 0x56, 0x78, // SSRC of packet sender
+        0x2A, 0x3F, 0x5C, 0x10, // SSRC of media source
+        0x00, 0x07, 0x00, 0x00, // PID and BLP
+        0b10000001, 203, 0x00, 0x01, // RTCP header (BYE)
+        0x12, 0x34, 0x56, 0x78, // SSRC
+    ];
+
+    let packets = RtcpPacket::parse_compound(&buffer[..]).unwrap();
+
+    assert_eq!(packets.len(), 2);
+    assert_eq!(packets[0].header().packet_type(), &RtcpPacketType::SenderReport);
+    assert_eq!(packets[1].header().packet_type(), &RtcpPacketType::Goodbye);
+}
+
+#[test]
+fn test_rtcp_packet_invalid() {
+    // Unknown packet type
+    assert!(RtcpPacket::try_from(&[0b10000000, 210, 0x00, 0x00][..]).is_err());
+    // Length beyond the end of the buffer
+    assert!(RtcpPacket::parse_compound(&[0b10000000, 201, 0x00, 0x01, 0x12, 0x34][..]).is_err());
+}
@@ -0,0 +1,222 @@
+use std::{
+    collections::{BTreeMap, VecDeque},
+    time::{Duration, Instant},
+};
+
+use crate::rtp_packet_buf::RtpPacketBuf;
+
+/// Extended index of the first packet of a stream, a multiple of 2^16 so that the low 16 bits
+/// of an extended index are the sequence number, far enough from zero to index older packets.
+const EXTENDED_BASE: u64 = 1 << 32;
+/// Distance behind the next expected packet beyond which a packet is taken as a sequence restart
+/// rather than a late packet, as in RFC 3550 Appendix A.1.
+const MAX_MISORDER: u64 = 100;
+
+/// Represents what a `JitterBuffer` releases, in sequence number order for every SSRC.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum JitterBufferEvent {
+    /// Represents the next packet of a stream.
+    Packet(RtpPacketBuf),
+
+    /// Represents packets that did not arrive in time and are skipped.
+    Loss { ssrc: u32, sequence_number: u16, count: u16 },
+}
+
+/// Represents the packets of one SSRC waiting to be released.
+#[derive(Debug)]
+struct Stream {
+    anchor: Option<(u16, u64)>,
+    next_index: Option<u64>,
+    bad_sequence_number: Option<u16>,
+    packets: BTreeMap<u64, (Instant, RtpPacketBuf)>,
+    ready: VecDeque<JitterBufferEvent>,
+}
+
+impl Stream {
+    fn new() -> Self {
+        Self { anchor: None, next_index: None, bad_sequence_number: None, packets: BTreeMap::new(), ready: VecDeque::new() }
+    }
+
+    /// Extends a sequence number to the index closest to the last pushed one.
+    fn extended_index(&self, sequence_number: u16) -> u64 {
+        match self.anchor {
+            Some((anchor_sequence_number, anchor_index)) => {
+                let delta = sequence_number.wrapping_sub(anchor_sequence_number) as i16;
+                anchor_index.wrapping_add_signed(delta as i64)
+            }
+            None => EXTENDED_BASE + sequence_number as u64,
+        }
+    }
+
+    /// Moves every buffered packet to the ready queue, with the losses between them.
+    fn drain(&mut self, ssrc: u32) {
+        while let Some((index, (_, packet))) = self.packets.pop_first() {
+            while let Some(next_index) = self.next_index.filter(|&next_index| index > next_index) {
+                let (event, next_index) = loss(ssrc, next_index, index);
+                self.ready.push_back(event);
+                self.next_index = Some(next_index);
+            }
+            self.ready.push_back(JitterBufferEvent::Packet(packet));
+            self.next_index = Some(index + 1);
+        }
+        self.anchor = None;
+        self.next_index = None;
+    }
+
+    /// Returns the instant at which the stream stops waiting for a missing packet.
+    fn deadline(&self, latency: Duration) -> Option<Instant> {
+        let (&index, _) = self.packets.first_key_value()?;
+        if self.next_index == Some(index) {
+            return None;
+        }
+        self.packets.values().map(|(arrival, _)| *arrival).min().map(|arrival| arrival + latency)
+    }
+
+    fn pop(&mut self, ssrc: u32, now: Instant, latency: Duration, max_depth: usize) -> Option<JitterBufferEvent> {
+        if let Some(event) = self.ready.pop_front() {
+            return Some(event);
+        }
+        let (&index, _) = self.packets.first_key_value()?;
+        if self.next_index != Some(index) {
+            let expired = self.deadline(latency).is_some_and(|deadline| now >= deadline);
+            if !expired && self.packets.len() <= max_depth {
+                return None;
+            }
+            if let Some(next_index) = self.next_index {
+                let (event, next_index) = loss(ssrc, next_index, index);
+                self.next_index = Some(next_index);
+                return Some(event);
+            }
+        }
+        let (_, packet) = self.packets.remove(&index)?;
+        self.next_index = Some(index + 1);
+        Some(JitterBufferEvent::Packet(packet))
+    }
+}
+
+/// Reorders incoming RTP packets of every SSRC by sequence number.
+///
+/// Packets are released in order as soon as they follow the last released packet of their SSRC.
+/// When a packet is missing, the buffer waits until the oldest buffered packet has been held for
+/// the latency, or until more packets than the depth are buffered, then skips the gap with a
+/// `JitterBufferEvent::Loss`. Packets that arrive after their place was released, and duplicates,
+/// are dropped. The first packet of a stream is held for the latency so that packets sent before
+/// it can still be ordered.
+///
+/// A packet more than 100 sequence numbers behind the next expected one is dropped, unless it
+/// directly follows the previous such packet, as in RFC 3550 Appendix A.1: two consecutive
+/// packets from far behind restart the stream, the buffered packets are released and ordering
+/// starts over from the second one.
+///
+/// The caller provides the current time, and should call `pop` again at `next_deadline`.
+#[derive(Debug)]
+pub struct JitterBuffer {
+    latency: Duration,
+    max_depth: usize,
+    streams: BTreeMap<u32, Stream>,
+    dropped_packets: u64,
+}
+
+impl JitterBuffer {
+    /// Creates a new `JitterBuffer`.
+    ///
+    /// # Arguments
+    ///
+    /// * `latency` - The longest time a packet is held while waiting for a missing one.
+    /// * `max_depth` - The highest number of packets held per SSRC while waiting for a missing one.
+    pub fn new(latency: Duration, max_depth: usize) -> Self {
+        Self { latency, max_depth, streams: BTreeMap::new(), dropped_packets: 0 }
+    }
+
+    /// Returns the number of late and duplicate packets dropped.
+    pub fn dropped_packets(&self) -> u64 {
+        self.dropped_packets
+    }
+
+    /// Forgets an SSRC, for instance after an RTCP BYE, discarding its packets and events.
+    pub fn remove(&mut self, ssrc: u32) {
+        self.streams.remove(&ssrc);
+    }
+
+    /// Returns the number of packets held for an SSRC, events ready to be popped excluded.
+    pub fn buffered_packets(&self, ssrc: u32) -> usize {
+        self.streams.get(&ssrc).map_or(0, |stream| stream.packets.len())
+    }
+
+    /// Pushes a received packet.
+    ///
+    /// # Arguments
+    ///
+    /// * `packet` - The received packet.
+    /// * `now` - The arrival time of the packet.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the packet was buffered, `false` if it was dropped as late, duplicate or far behind.
+    pub fn push(&mut self, packet: RtpPacketBuf, now: Instant) -> bool {
+        let ssrc = packet.ssrc();
+        let stream = self.streams.entry(ssrc).or_insert_with(Stream::new);
+        let sequence_number = packet.sequence_number();
+
+        let mut index = stream.extended_index(sequence_number);
+        if let Some(next_index) = stream.next_index {
+            if index + MAX_MISORDER < next_index {
+                if stream.bad_sequence_number != Some(sequence_number) {
+                    stream.bad_sequence_number = Some(sequence_number.wrapping_add(1));
+                    self.dropped_packets += 1;
+                    return false;
+                }
+                stream.drain(ssrc);
+                index = stream.extended_index(sequence_number);
+            }
+            else if index < next_index {
+                self.dropped_packets += 1;
+                return false;
+            }
+        }
+        if stream.packets.contains_key(&index) {
+            self.dropped_packets += 1;
+            return false;
+        }
+
+        stream.bad_sequence_number = None;
+        stream.anchor = Some((sequence_number, index));
+        stream.packets.insert(index, (now, packet));
+        true
+    }
+
+    /// Pops the next event of any SSRC.
+    ///
+    /// # Arguments
+    ///
+    /// * `now` - The current time, compared with the latency of the packets waiting for a missing one.
+    ///
+    /// # Returns
+    ///
+    /// An `Option` containing the next packet or loss, or `None` if every SSRC is waiting.
+    pub fn pop(&mut self, now: Instant) -> Option<JitterBufferEvent> {
+        self.streams
+            .iter_mut()
+            .find_map(|(ssrc, stream)| stream.pop(*ssrc, now, self.latency, self.max_depth))
+    }
+
+    /// Returns the earliest time at which `pop` releases a packet that is waiting for a missing one.
+    ///
+    /// # Returns
+    ///
+    /// An `Option` containing the deadline, or `None` if no SSRC is waiting.
+    pub fn next_deadline(&self) -> Option<Instant> {
+        self.streams.values().filter_map(|stream| stream.deadline(self.latency)).min()
+    }
+}
+
+/// Creates the loss event for the packets from one extended index up to another, excluded.
+///
+/// # Returns
+///
+/// The event and the index following the packets it covers, which is short of `to` when more
+/// packets are lost than a single event can count.
+fn loss(ssrc: u32, from: u64, to: u64) -> (JitterBufferEvent, u64) {
+    let count = (to - from).min(u16::MAX as u64);
+    (JitterBufferEvent::Loss { ssrc, sequence_number: from as u16, count: count as u16 }, from + count)
+}
@@ -1,2 +1,8 @@
 pub mod rtp_header;
+pub mod rtp_header_extension;
 pub mod rtp_packet;
+pub mod rtp_packet_buf;
+pub mod rtp_packet_buf_builder;
+pub mod rtp_error;
+pub mod jitter_buffer;
+pub mod rtp_tracker;
@@ -0,0 +1,17 @@
+use thiserror::Error;
+
+/// Represents errors that can occur while building or writing an RTP packet.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum RtpError {
+    /// Represents a required field that was not set.
+    #[error("Missing field: {0}")]
+    MissingField(&'static str),
+
+    /// Represents a field whose value does not fit into the RTP header.
+    #[error("Invalid field: {0}")]
+    InvalidField(&'static str),
+
+    /// Represents a buffer that cannot hold the packet.
+    #[error("Buffer too small: {needed} bytes needed, {available} available")]
+    BufferTooSmall { needed: usize, available: usize },
+}
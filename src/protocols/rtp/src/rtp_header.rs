@@ -2,6 +2,8 @@ use abstractions::extensions::cast_error::CastError;
 use byteorder::{BigEndian, ByteOrder};
 use std::fmt;
 
+use crate::rtp_header_extension::RtpHeaderExtension;
+
 pub(crate) const RTP_HEADER_FIXED_SIZE: usize = 12;
 
 // Bit masks for extracting fields from the v_p_x_cc byte
@@ -23,6 +25,7 @@ pub struct RtpHeader<'a> {
     timestamp: u32, // Timestamp
     ssrc: u32, // SSRC identifier
     csrc_list: &'a [u8], // CSRC list
+    header_extension: Option<RtpHeaderExtension<'a>>, // Header extension, present if the X bit is set
 }
 
 impl<'a> TryFrom<&'a [u8]> for RtpHeader<'a> {
@@ -50,6 +53,13 @@ impl<'a> TryFrom<&'a [u8]> for RtpHeader<'a> {
         }
         let csrc_list = &value[csrc_list_start..csrc_list_end];
 
+        let header_extension = if v_p_x_cc & EXTENSION_MASK != 0 {
+            Some(RtpHeaderExtension::try_from(&value[csrc_list_end..])?)
+        }
+        else {
+            None
+        };
+
         Ok(Self {
             v_p_x_cc,
             marker_payload_type,
@@ -57,6 +67,7 @@ impl<'a> TryFrom<&'a [u8]> for RtpHeader<'a> {
             timestamp,
             ssrc,
             csrc_list,
+            header_extension,
         })
     }
 }
@@ -68,7 +79,7 @@ impl<'a> RtpHeader<'a> {
     ///
     /// * `version` - The RTP version.
     /// * `padding` - Indicates if padding is present.
-    /// * `extension` - Indicates if an extension header is present.
+    /// * `extension` - Indicates if an extension header is present; attach it with `with_header_extension`.
     /// * `csrc_count` - The number of CSRC identifiers.
     /// * `marker` - The marker bit.
     /// * `payload_type` - The payload type.
@@ -80,6 +91,7 @@ impl<'a> RtpHeader<'a> {
     /// # Returns
     ///
     /// A new `RtpHeader` instance.
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         version: u8,
         padding: bool,
@@ -99,9 +111,35 @@ impl<'a> RtpHeader<'a> {
             timestamp,
             ssrc,
             csrc_list,
+            header_extension: None,
         }
     }
 
+    /// Sets or clears the P bit.
+    pub(crate) fn set_padding(&mut self, padding: bool) {
+        if padding {
+            self.v_p_x_cc |= PADDING_MASK;
+        }
+        else {
+            self.v_p_x_cc &= !PADDING_MASK;
+        }
+    }
+
+    /// Attaches a header extension and sets the X bit.
+    ///
+    /// # Arguments
+    ///
+    /// * `header_extension` - The header extension written after the CSRC list.
+    ///
+    /// # Returns
+    ///
+    /// The `RtpHeader` with the header extension.
+    pub fn with_header_extension(mut self, header_extension: RtpHeaderExtension<'a>) -> Self {
+        self.v_p_x_cc |= EXTENSION_MASK;
+        self.header_extension = Some(header_extension);
+        self
+    }
+
     /// Write `RtpHeader` to a buffer byte vector.
     ///
     /// # Arguments
@@ -117,8 +155,12 @@ impl<'a> RtpHeader<'a> {
         BigEndian::write_u16(&mut buffer[2..4], self.sequence_number);
         BigEndian::write_u32(&mut buffer[4..8], self.timestamp);
         BigEndian::write_u32(&mut buffer[8..12], self.ssrc);
-        buffer[12..12 + self.csrc_list.len()].copy_from_slice(self.csrc_list);
-        RTP_HEADER_FIXED_SIZE + self.csrc_list.len()
+        let csrc_list_end = RTP_HEADER_FIXED_SIZE + self.csrc_list.len();
+        buffer[RTP_HEADER_FIXED_SIZE..csrc_list_end].copy_from_slice(self.csrc_list);
+        match &self.header_extension {
+            Some(header_extension) => csrc_list_end + header_extension.write(&mut buffer[csrc_list_end..]),
+            None => csrc_list_end,
+        }
     }
 
     /// Returns the RTP version.
@@ -211,13 +253,23 @@ impl<'a> RtpHeader<'a> {
         self.csrc_list
     }
 
+    /// Returns the header extension.
+    ///
+    /// # Returns
+    ///
+    /// An `Option` containing the header extension, or `None` if the X bit is not set.
+    pub fn header_extension(&self) -> Option<&RtpHeaderExtension<'a>> {
+        self.header_extension.as_ref()
+    }
+
     /// Returns the total byte size of the `RtpHeader`.
     ///
     /// # Returns
     ///
-    /// The total byte size of the `RtpHeader`.
+    /// The total byte size of the `RtpHeader`, header extension included.
     pub fn byte_size(&self) -> usize {
-        RTP_HEADER_FIXED_SIZE + (self.csrc_count() as usize * 4)
+        let header_extension_size = self.header_extension.as_ref().map_or(0, RtpHeaderExtension::byte_size);
+        RTP_HEADER_FIXED_SIZE + (self.csrc_count() as usize * 4) + header_extension_size
     }
 }
 
@@ -234,6 +286,7 @@ impl<'a> fmt::Debug for RtpHeader<'a> {
             .field("timestamp", &self.timestamp())
             .field("ssrc", &self.ssrc())
             .field("csrc_list", &self.csrc_list())
+            .field("header_extension", &self.header_extension())
             .field("byte_size", &self.byte_size())
             .finish()
     }
@@ -0,0 +1,244 @@
+use abstractions::extensions::cast_error::CastError;
+use byteorder::{BigEndian, ByteOrder};
+
+/// Size of the profile and length fields that precede the extension data.
+pub(crate) const RTP_HEADER_EXTENSION_FIXED_SIZE: usize = 4;
+
+/// Profile of the one-byte header form of RFC 8285.
+pub const ONE_BYTE_PROFILE: u16 = 0xBEDE;
+/// Profile of the two-byte header form of RFC 8285, whose low 4 bits are application bits.
+pub const TWO_BYTE_PROFILE: u16 = 0x1000;
+const TWO_BYTE_PROFILE_MASK: u16 = 0xFFF0;
+
+/// Identifier that ends the elements of a one-byte header extension.
+const ONE_BYTE_STOP_ID: u8 = 15;
+/// Highest identifier and data size of a one-byte element.
+const ONE_BYTE_MAX_ID: u8 = 14;
+const ONE_BYTE_MAX_SIZE: usize = 16;
+
+/// Represents one element of a header extension in the forms of RFC 8285.
+///
+/// # Fields
+///
+/// * `id` - The local identifier mapped to an extension URI by an `a=extmap` line.
+/// * `data` - The data of the element.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HeaderExtensionElement<'a> {
+    id: u8,
+    data: &'a [u8],
+}
+
+impl<'a> HeaderExtensionElement<'a> {
+    pub fn new(id: u8, data: &'a [u8]) -> Self {
+        Self { id, data }
+    }
+
+    pub fn id(&self) -> u8 {
+        self.id
+    }
+
+    pub fn data(&self) -> &'a [u8] {
+        self.data
+    }
+}
+
+/// Represents the header extension that follows the CSRC list when the X bit is set.
+///
+/// According to RFC 3550, the extension starts with a profile-defined identifier and its
+/// length in 32-bit words:
+///
+/// ```text
+///  0                   1                   2                   3
+///  0 1 2 3 4 5 6 7 8 9 0 1 2 3 4 5 6 7 8 9 0 1 2 3 4 5 6 7 8 9 0 1
+/// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+/// |      defined by profile       |           length              |
+/// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+/// |                        header extension                       |
+/// |                             ....                              |
+/// ```
+///
+/// RFC 8285 defines two profiles that carry a list of elements: `0xBEDE` with one-byte element
+/// headers, and `0x100X` with two-byte element headers:
+///
+/// ```text
+///  0 1 2 3 4 5 6 7     0                   1
+/// +-+-+-+-+-+-+-+-+    0 1 2 3 4 5 6 7 8 9 0 1 2 3 4 5
+/// |  ID   |  len  |   +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+/// +-+-+-+-+-+-+-+-+   |       ID      |     length    |
+///                     +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+/// ```
+///
+/// In the one-byte form, `len` is the data size minus one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RtpHeaderExtension<'a> {
+    profile: u16,
+    data: &'a [u8],
+}
+
+impl<'a> TryFrom<&'a [u8]> for RtpHeaderExtension<'a> {
+    type Error = CastError;
+
+    fn try_from(value: &'a [u8]) -> Result<Self, Self::Error> {
+        if value.len() < RTP_HEADER_EXTENSION_FIXED_SIZE {
+            return Err(CastError::from_str("Buffer too short to contain RTP header extension"));
+        }
+        let profile = BigEndian::read_u16(&value[0..2]);
+        let length = BigEndian::read_u16(&value[2..4]) as usize * 4;
+        let data = value
+            .get(RTP_HEADER_EXTENSION_FIXED_SIZE..RTP_HEADER_EXTENSION_FIXED_SIZE + length)
+            .ok_or_else(|| CastError::from_str("Buffer too short to contain RTP header extension"))?;
+        Ok(Self { profile, data })
+    }
+}
+
+impl<'a> RtpHeaderExtension<'a> {
+    /// Creates a new `RtpHeaderExtension`.
+    ///
+    /// # Arguments
+    ///
+    /// * `profile` - The profile-defined identifier.
+    /// * `data` - The extension data, padded with zeros to a multiple of 4 bytes when written.
+    ///
+    /// # Returns
+    ///
+    /// A new `RtpHeaderExtension` instance.
+    pub fn new(profile: u16, data: &'a [u8]) -> Self {
+        Self { profile, data }
+    }
+
+    /// Writes elements in the one-byte form if they allow it, or in the two-byte form otherwise.
+    ///
+    /// # Arguments
+    ///
+    /// * `elements` - The elements to write.
+    /// * `buffer` - The buffer the extension data is appended to, padded to a multiple of 4 bytes.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the profile to create the extension with, or a `CastError` if an
+    /// element has the reserved identifier 0 or more than 255 bytes of data.
+    pub fn write_elements(elements: &[HeaderExtensionElement], buffer: &mut Vec<u8>) -> Result<u16, CastError> {
+        if elements.iter().any(|element| element.id == 0 || element.data.len() > u8::MAX as usize) {
+            return Err(CastError::from_str("Invalid RTP header extension element"));
+        }
+        let one_byte = elements
+            .iter()
+            .all(|element| element.id <= ONE_BYTE_MAX_ID && (1..=ONE_BYTE_MAX_SIZE).contains(&element.data.len()));
+
+        let start = buffer.len();
+        for element in elements {
+            if one_byte {
+                buffer.push((element.id << 4) | (element.data.len() - 1) as u8);
+            }
+            else {
+                buffer.extend_from_slice(&[element.id, element.data.len() as u8]);
+            }
+            buffer.extend_from_slice(element.data);
+        }
+        let padding = (4 - (buffer.len() - start) % 4) % 4;
+        buffer.resize(buffer.len() + padding, 0);
+
+        Ok(if one_byte { ONE_BYTE_PROFILE } else { TWO_BYTE_PROFILE })
+    }
+
+    /// Write `RtpHeaderExtension` to a buffer byte vector.
+    ///
+    /// # Arguments
+    ///
+    /// * `buffer` - The buffer to write the header extension to.
+    ///
+    /// # Returns
+    ///
+    /// The number of bytes written to the buffer.
+    pub fn write(&self, buffer: &mut [u8]) -> usize {
+        let size = self.byte_size();
+        BigEndian::write_u16(&mut buffer[0..2], self.profile);
+        BigEndian::write_u16(&mut buffer[2..4], ((size - RTP_HEADER_EXTENSION_FIXED_SIZE) / 4) as u16);
+        let data_end = RTP_HEADER_EXTENSION_FIXED_SIZE + self.data.len();
+        buffer[RTP_HEADER_EXTENSION_FIXED_SIZE..data_end].copy_from_slice(self.data);
+        buffer[data_end..size].fill(0);
+        size
+    }
+
+    /// Returns the profile-defined identifier.
+    pub fn profile(&self) -> u16 {
+        self.profile
+    }
+
+    /// Returns the extension data, without the profile and length fields.
+    pub fn data(&self) -> &'a [u8] {
+        self.data
+    }
+
+    /// Indicates if the extension uses the one-byte form of RFC 8285.
+    pub fn is_one_byte(&self) -> bool {
+        self.profile == ONE_BYTE_PROFILE
+    }
+
+    /// Indicates if the extension uses the two-byte form of RFC 8285.
+    pub fn is_two_byte(&self) -> bool {
+        self.profile & TWO_BYTE_PROFILE_MASK == TWO_BYTE_PROFILE
+    }
+
+    /// Returns the elements of an extension in one of the forms of RFC 8285.
+    ///
+    /// Padding bytes between elements are skipped.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the elements, or a `CastError` if the profile is not one of the forms
+    /// of RFC 8285 or an element is truncated.
+    pub fn elements(&self) -> Result<Vec<HeaderExtensionElement<'a>>, CastError> {
+        let one_byte = self.is_one_byte();
+        if !one_byte && !self.is_two_byte() {
+            return Err(CastError::from_str("RTP header extension profile has no elements"));
+        }
+
+        let mut elements = Vec::new();
+        let mut data = self.data;
+        while let Some(&first) = data.first() {
+            let (id, size, header_size) = if one_byte {
+                let id = first >> 4;
+                if id == ONE_BYTE_STOP_ID {
+                    break;
+                }
+                (id, (first & 0x0F) as usize + 1, 1)
+            }
+            else {
+                (first, data.get(1).copied().unwrap_or_default() as usize, 2)
+            };
+            if id == 0 {
+                data = &data[1..];
+                continue;
+            }
+            let element = data
+                .get(header_size..header_size + size)
+                .ok_or_else(|| CastError::from_str("Buffer too short to contain RTP header extension element"))?;
+            elements.push(HeaderExtensionElement::new(id, element));
+            data = &data[header_size + size..];
+        }
+        Ok(elements)
+    }
+
+    /// Returns the data of the element with an identifier.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The identifier mapped to the extension URI by an `a=extmap` line.
+    ///
+    /// # Returns
+    ///
+    /// An `Option` containing the element data, or `None` if there is no valid element with this identifier.
+    pub fn element(&self, id: u8) -> Option<&'a [u8]> {
+        self.elements().ok()?.into_iter().find(|element| element.id == id).map(|element| element.data)
+    }
+
+    /// Returns the total byte size of the `RtpHeaderExtension`.
+    ///
+    /// # Returns
+    ///
+    /// The size of the profile and length fields and of the data padded to a multiple of 4 bytes.
+    pub fn byte_size(&self) -> usize {
+        RTP_HEADER_EXTENSION_FIXED_SIZE + self.data.len().div_ceil(4) * 4
+    }
+}
@@ -2,10 +2,22 @@ use abstractions::extensions::cast_error::CastError;
 
 use crate::rtp_header::{RtpHeader, RTP_HEADER_FIXED_SIZE};
 
+/// Represents an RTP packet: a header, a payload and, if the P bit is set, padding octets.
+///
+/// The last padding octet holds the number of padding octets, itself included:
+///
+/// ```text
+/// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+/// |                            payload                            |
+/// |                               +-------------------------------+
+/// |                               | 0 ... 0       | padding count |
+/// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+/// ```
 #[derive(Debug)]
 pub struct RtpPacket<'a> {
     header: RtpHeader<'a>,
     payload: &'a [u8],
+    padding_size: u8,
 }
 
 impl<'a> TryFrom<&'a [u8]> for RtpPacket<'a> {
@@ -26,17 +38,23 @@ impl<'a> TryFrom<&'a [u8]> for RtpPacket<'a> {
             return Err(CastError::from_str("Buffer too short to contain RTP payload"));
         }
 
-        let payload = &value[payload_start..];
-        Ok(Self { header, payload })
+        let mut payload = &value[payload_start..];
+        let mut padding_size = 0;
+        if header.padding() {
+            padding_size = *payload.last().ok_or_else(|| CastError::from_str("Buffer too short to contain RTP padding"))?;
+            if padding_size == 0 || padding_size as usize > payload.len() {
+                return Err(CastError::from_str("Invalid RTP padding count"));
+            }
+            payload = &payload[..payload.len() - padding_size as usize];
+        }
+        Ok(Self { header, payload, padding_size })
     }
 }
 
-
-
-
-
 impl<'a> RtpPacket<'a> {
-    /// Creates a new `RtpPacket`.
+    /// Creates a new `RtpPacket` without padding.
+    ///
+    /// The P bit of the header is cleared; use `with_padding` to add padding octets.
     ///
     /// # Arguments
     ///
@@ -46,8 +64,40 @@ impl<'a> RtpPacket<'a> {
     /// # Returns
     ///
     /// A new `RtpPacket` instance.
-    pub fn new(header: RtpHeader<'a>, payload: &'a [u8]) -> Self {
-        Self { header, payload }
+    pub fn new(mut header: RtpHeader<'a>, payload: &'a [u8]) -> Self {
+        header.set_padding(false);
+        Self { header, payload, padding_size: 0 }
+    }
+
+    /// Appends padding octets and sets the P bit, or clears it if the size is zero.
+    ///
+    /// # Arguments
+    ///
+    /// * `padding_size` - The number of padding octets, the padding count octet included.
+    ///
+    /// # Returns
+    ///
+    /// The `RtpPacket` with the padding.
+    pub fn with_padding(mut self, padding_size: u8) -> Self {
+        self.header.set_padding(padding_size > 0);
+        self.padding_size = padding_size;
+        self
+    }
+
+    /// Pads the packet to a multiple of a block size, as needed by block ciphers in SRTP.
+    ///
+    /// # Arguments
+    ///
+    /// * `block_size` - The block size, from 1 to 255 octets.
+    ///
+    /// # Returns
+    ///
+    /// The `RtpPacket` with the padding needed to fill its last block.
+    pub fn with_alignment(self, block_size: u8) -> Self {
+        let unpadded_size = self.header.byte_size() + self.payload.len();
+        let block_size = block_size.max(1) as usize;
+        let padding_size = (block_size - unpadded_size % block_size) % block_size;
+        self.with_padding(padding_size as u8)
     }
 
     /// Converts the `RtpPacket` to a byte slice.
@@ -66,7 +116,13 @@ impl<'a> RtpPacket<'a> {
         // Use the payload memory directly without copying
         buffer[header_len..header_len + payload_len].copy_from_slice(self.payload);
 
-        header_len + payload_len
+        let padding_start = header_len + payload_len;
+        let padding_end = padding_start + self.padding_size as usize;
+        if self.padding_size > 0 {
+            buffer[padding_start..padding_end - 1].fill(0);
+            buffer[padding_end - 1] = self.padding_size;
+        }
+        padding_end
     }
 
     /// Returns a reference to the RTP header.
@@ -82,17 +138,26 @@ impl<'a> RtpPacket<'a> {
     ///
     /// # Returns
     ///
-    /// A reference to the RTP payload.
+    /// A reference to the RTP payload, without padding.
     pub fn payload(&self) -> &[u8] {
         self.payload
     }
 
+    /// Returns the number of padding octets.
+    ///
+    /// # Returns
+    ///
+    /// The number of padding octets, the padding count octet included, or zero if the P bit is not set.
+    pub fn padding_size(&self) -> u8 {
+        self.padding_size
+    }
+
     /// Returns the total byte size of the `RtpPacket`.
     ///
     /// # Returns
     ///
-    /// The total byte size of the `RtpPacket`.
+    /// The total byte size of the `RtpPacket`, padding included.
     pub fn byte_size(&self) -> usize {
-        self.header.byte_size() + self.payload.len()
+        self.header.byte_size() + self.payload.len() + self.padding_size as usize
     }
 }
\ No newline at end of file
@@ -0,0 +1,166 @@
+use bytes::BufMut;
+
+use crate::{
+    rtp_error::RtpError,
+    rtp_header::RTP_HEADER_FIXED_SIZE,
+    rtp_header_extension::{RtpHeaderExtension, RTP_HEADER_EXTENSION_FIXED_SIZE},
+    rtp_packet::RtpPacket,
+};
+
+/// RTP version written in every packet.
+const RTP_VERSION: u8 = 2;
+
+/// Represents an RTP packet that owns its fields, for sending.
+///
+/// Built with `RtpPacketBufBuilder`, or copied from a received `RtpPacket` to keep it beyond
+/// the lifetime of its buffer. The P and X bits and the CSRC count are derived from the padding,
+/// the header extension and the CSRC list.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RtpPacketBuf {
+    marker: bool,
+    payload_type: u8,
+    sequence_number: u16,
+    timestamp: u32,
+    ssrc: u32,
+    csrcs: Vec<u32>,
+    header_extension: Option<(u16, Vec<u8>)>,
+    payload: Vec<u8>,
+    padding_size: u8,
+}
+
+impl From<&RtpPacket<'_>> for RtpPacketBuf {
+    fn from(packet: &RtpPacket<'_>) -> Self {
+        let header = packet.header();
+        Self {
+            marker: header.marker(),
+            payload_type: header.payload_type(),
+            sequence_number: header.sequence_number(),
+            timestamp: header.timestamp(),
+            ssrc: header.ssrc(),
+            csrcs: header
+                .csrc_list()
+                .chunks_exact(4)
+                .map(|csrc| u32::from_be_bytes([csrc[0], csrc[1], csrc[2], csrc[3]]))
+                .collect(),
+            header_extension: header
+                .header_extension()
+                .map(|header_extension| (header_extension.profile(), header_extension.data().to_vec())),
+            payload: packet.payload().to_vec(),
+            padding_size: packet.padding_size(),
+        }
+    }
+}
+
+impl RtpPacketBuf {
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn new(
+        marker: bool,
+        payload_type: u8,
+        sequence_number: u16,
+        timestamp: u32,
+        ssrc: u32,
+        csrcs: Vec<u32>,
+        header_extension: Option<(u16, Vec<u8>)>,
+        payload: Vec<u8>,
+        padding_size: u8,
+    ) -> Self {
+        Self { marker, payload_type, sequence_number, timestamp, ssrc, csrcs, header_extension, payload, padding_size }
+    }
+
+    pub fn marker(&self) -> bool {
+        self.marker
+    }
+
+    pub fn payload_type(&self) -> u8 {
+        self.payload_type
+    }
+
+    pub fn sequence_number(&self) -> u16 {
+        self.sequence_number
+    }
+
+    pub fn timestamp(&self) -> u32 {
+        self.timestamp
+    }
+
+    pub fn ssrc(&self) -> u32 {
+        self.ssrc
+    }
+
+    pub fn csrcs(&self) -> &[u32] {
+        &self.csrcs
+    }
+
+    /// Returns the header extension, whose elements are read with `RtpHeaderExtension::elements`.
+    pub fn header_extension(&self) -> Option<RtpHeaderExtension<'_>> {
+        self.header_extension.as_ref().map(|(profile, data)| RtpHeaderExtension::new(*profile, data))
+    }
+
+    pub fn payload(&self) -> &[u8] {
+        &self.payload
+    }
+
+    /// Returns the number of padding octets, the padding count octet included.
+    pub fn padding_size(&self) -> u8 {
+        self.padding_size
+    }
+
+    /// Returns the total byte size of the packet once written.
+    ///
+    /// # Returns
+    ///
+    /// The size of the header, the header extension, the payload and the padding.
+    pub fn byte_size(&self) -> usize {
+        let header_extension_size = self.header_extension().map_or(0, |header_extension| header_extension.byte_size());
+        RTP_HEADER_FIXED_SIZE + self.csrcs.len() * 4 + header_extension_size + self.payload.len() + self.padding_size as usize
+    }
+
+    /// Writes the packet to a buffer.
+    ///
+    /// # Arguments
+    ///
+    /// * `buffer` - The buffer the packet is appended to.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the number of bytes written, or an `RtpError` if the buffer cannot hold
+    /// the packet, in which case nothing is written.
+    pub fn write_to(&self, buffer: &mut impl BufMut) -> Result<usize, RtpError> {
+        let size = self.byte_size();
+        if buffer.remaining_mut() < size {
+            return Err(RtpError::BufferTooSmall { needed: size, available: buffer.remaining_mut() });
+        }
+
+        let padding = (self.padding_size > 0) as u8;
+        let extension = self.header_extension.is_some() as u8;
+        buffer.put_u8((RTP_VERSION << 6) | (padding << 5) | (extension << 4) | self.csrcs.len() as u8);
+        buffer.put_u8(((self.marker as u8) << 7) | self.payload_type);
+        buffer.put_u16(self.sequence_number);
+        buffer.put_u32(self.timestamp);
+        buffer.put_u32(self.ssrc);
+        for csrc in &self.csrcs {
+            buffer.put_u32(*csrc);
+        }
+        if let Some(header_extension) = self.header_extension() {
+            let data_size = header_extension.byte_size() - RTP_HEADER_EXTENSION_FIXED_SIZE;
+            buffer.put_u16(header_extension.profile());
+            buffer.put_u16((data_size / 4) as u16);
+            buffer.put_slice(header_extension.data());
+            buffer.put_bytes(0, data_size - header_extension.data().len());
+        }
+        buffer.put_slice(&self.payload);
+        if self.padding_size > 0 {
+            buffer.put_bytes(0, self.padding_size as usize - 1);
+            buffer.put_u8(self.padding_size);
+        }
+        Ok(size)
+    }
+
+    /// Writes the packet to a new vector of exactly `byte_size()` bytes.
+    pub fn to_vec(&self) -> Vec<u8> {
+        let mut buffer = Vec::with_capacity(self.byte_size());
+        // A vector grows as needed, so writing cannot fail.
+        let _ = self.write_to(&mut buffer);
+        buffer
+    }
+}
@@ -0,0 +1,149 @@
+use crate::{
+    rtp_error::RtpError,
+    rtp_header_extension::{HeaderExtensionElement, RtpHeaderExtension},
+    rtp_packet_buf::RtpPacketBuf,
+};
+
+/// Highest payload type that fits into the 7-bit PT field.
+const MAX_PAYLOAD_TYPE: u8 = 0x7F;
+/// Highest number of CSRCs that fits into the 4-bit CC field.
+const MAX_CSRC_COUNT: usize = 15;
+/// Highest number of 32-bit words that fits into the 16-bit header extension length field.
+const MAX_HEADER_EXTENSION_WORDS: usize = u16::MAX as usize;
+
+/// Builds an [`RtpPacketBuf`] for sending.
+///
+/// The payload type and the SSRC are required; the sequence number and the timestamp default
+/// to `0`. Header extension elements are written in the one-byte form of RFC 8285 when their
+/// identifiers and sizes allow it, and in the two-byte form otherwise.
+///
+/// # Example
+///
+/// ```rust
+/// use rtp::rtp_packet_buf_builder::RtpPacketBufBuilder;
+///
+/// let packet = RtpPacketBufBuilder::new()
+///     .payload_type(96)
+///     .ssrc(0x1234)
+///     .sequence_number(1)
+///     .timestamp(3000)
+///     .marker(true)
+///     .payload(vec![0x65, 0x88])
+///     .build()
+///     .unwrap();
+///
+/// assert_eq!(packet.to_vec(), [0x80, 0xE0, 0x00, 0x01, 0x00, 0x00, 0x0B, 0xB8, 0x00, 0x00, 0x12, 0x34, 0x65, 0x88]);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct RtpPacketBufBuilder {
+    marker: bool,
+    payload_type: Option<u8>,
+    sequence_number: u16,
+    timestamp: u32,
+    ssrc: Option<u32>,
+    csrcs: Vec<u32>,
+    extension_elements: Vec<(u8, Vec<u8>)>,
+    payload: Vec<u8>,
+    padding_size: u8,
+}
+
+impl RtpPacketBufBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn marker(mut self, marker: bool) -> Self {
+        self.marker = marker;
+        self
+    }
+
+    pub fn payload_type(mut self, payload_type: u8) -> Self {
+        self.payload_type = Some(payload_type);
+        self
+    }
+
+    pub fn sequence_number(mut self, sequence_number: u16) -> Self {
+        self.sequence_number = sequence_number;
+        self
+    }
+
+    pub fn timestamp(mut self, timestamp: u32) -> Self {
+        self.timestamp = timestamp;
+        self
+    }
+
+    pub fn ssrc(mut self, ssrc: u32) -> Self {
+        self.ssrc = Some(ssrc);
+        self
+    }
+
+    /// Adds a contributing source.
+    pub fn csrc(mut self, csrc: u32) -> Self {
+        self.csrcs.push(csrc);
+        self
+    }
+
+    /// Adds a header extension element with the identifier mapped by an `a=extmap` line.
+    pub fn extension_element(mut self, id: u8, data: impl Into<Vec<u8>>) -> Self {
+        self.extension_elements.push((id, data.into()));
+        self
+    }
+
+    pub fn payload(mut self, payload: impl Into<Vec<u8>>) -> Self {
+        self.payload = payload.into();
+        self
+    }
+
+    /// Sets the number of padding octets, the padding count octet included.
+    pub fn padding(mut self, padding_size: u8) -> Self {
+        self.padding_size = padding_size;
+        self
+    }
+
+    /// Builds the packet.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the packet, or an `RtpError` if the payload type or the SSRC was not set,
+    /// or if a field does not fit into the RTP header.
+    pub fn build(self) -> Result<RtpPacketBuf, RtpError> {
+        let payload_type = self.payload_type.ok_or(RtpError::MissingField("payload type"))?;
+        let ssrc = self.ssrc.ok_or(RtpError::MissingField("SSRC"))?;
+        if payload_type > MAX_PAYLOAD_TYPE {
+            return Err(RtpError::InvalidField("payload type"));
+        }
+        if self.csrcs.len() > MAX_CSRC_COUNT {
+            return Err(RtpError::InvalidField("CSRC count"));
+        }
+
+        let header_extension = if self.extension_elements.is_empty() {
+            None
+        }
+        else {
+            let elements = self
+                .extension_elements
+                .iter()
+                .map(|(id, data)| HeaderExtensionElement::new(*id, data))
+                .collect::<Vec<_>>();
+            let mut data = Vec::new();
+            let profile = RtpHeaderExtension::write_elements(&elements, &mut data)
+                .map_err(|_| RtpError::InvalidField("header extension element"))?;
+            if data.len() / 4 > MAX_HEADER_EXTENSION_WORDS {
+                return Err(RtpError::InvalidField("header extension length"));
+            }
+            Some((profile, data))
+        };
+
+        Ok(RtpPacketBuf::new(
+            self.marker,
+            payload_type,
+            self.sequence_number,
+            self.timestamp,
+            ssrc,
+            self.csrcs,
+            header_extension,
+            self.payload,
+            self.padding_size,
+        ))
+    }
+}
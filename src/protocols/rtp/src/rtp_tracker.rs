@@ -0,0 +1,198 @@
+use std::{collections::HashMap, time::Duration};
+
+use crate::rtp_header::RtpHeader;
+
+const NANOS_PER_SECOND: u128 = 1_000_000_000;
+
+/// Extends a value that wraps around after `bits` bits to the 64-bit value closest to the
+/// highest one extended so far.
+///
+/// The first value is extended one cycle above zero, so that values reordered before it still
+/// extend to smaller positive values.
+fn extend(highest: &mut Option<u64>, value: u64, bits: u32) -> u64 {
+    let cycle = 1u64 << bits;
+    let extended = match *highest {
+        Some(highest) => {
+            let forward = value.wrapping_sub(highest) & (cycle - 1);
+            if forward < cycle / 2 {
+                highest + forward
+            }
+            else {
+                highest.saturating_sub(cycle - forward)
+            }
+        }
+        None => cycle + value,
+    };
+    *highest = Some(highest.map_or(extended, |highest| highest.max(extended)));
+    extended
+}
+
+/// Extends 16-bit RTP sequence numbers to monotonic 64-bit sequence numbers.
+#[derive(Debug, Clone, Default)]
+pub struct SequenceNumberTracker {
+    highest: Option<u64>,
+}
+
+impl SequenceNumberTracker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Extends a sequence number across wraparounds.
+    ///
+    /// # Arguments
+    ///
+    /// * `sequence_number` - The sequence number of a packet.
+    ///
+    /// # Returns
+    ///
+    /// The extended sequence number, whose low 16 bits are the sequence number.
+    pub fn extend(&mut self, sequence_number: u16) -> u64 {
+        extend(&mut self.highest, sequence_number as u64, u16::BITS)
+    }
+
+    /// Returns the highest extended sequence number so far.
+    pub fn highest(&self) -> Option<u64> {
+        self.highest
+    }
+}
+
+/// Extends 32-bit RTP timestamps to monotonic 64-bit timestamps and converts them to
+/// presentation times.
+///
+/// Presentation times are relative to the first timestamp, as RTP timestamps start at a
+/// random offset.
+#[derive(Debug, Clone)]
+pub struct TimestampTracker {
+    clock_rate: u32,
+    first: Option<u64>,
+    highest: Option<u64>,
+}
+
+impl TimestampTracker {
+    /// Creates a new `TimestampTracker`.
+    ///
+    /// # Arguments
+    ///
+    /// * `clock_rate` - The clock rate of the timestamps, as in the `a=rtpmap` line of the codec.
+    pub fn new(clock_rate: u32) -> Self {
+        Self { clock_rate, first: None, highest: None }
+    }
+
+    pub fn clock_rate(&self) -> u32 {
+        self.clock_rate
+    }
+
+    /// Extends a timestamp across wraparounds.
+    ///
+    /// # Arguments
+    ///
+    /// * `timestamp` - The timestamp of a packet.
+    ///
+    /// # Returns
+    ///
+    /// The extended timestamp, whose low 32 bits are the timestamp.
+    pub fn extend(&mut self, timestamp: u32) -> u64 {
+        let extended = extend(&mut self.highest, timestamp as u64, u32::BITS);
+        self.first.get_or_insert(extended);
+        extended
+    }
+
+    /// Converts an extended timestamp to a presentation time.
+    ///
+    /// # Arguments
+    ///
+    /// * `extended_timestamp` - A timestamp returned by `extend`.
+    ///
+    /// # Returns
+    ///
+    /// An `Option` containing the time elapsed since the first timestamp, or `None` if the
+    /// timestamp precedes it or the clock rate is zero.
+    pub fn presentation_time(&self, extended_timestamp: u64) -> Option<Duration> {
+        let ticks = extended_timestamp.checked_sub(self.first?)?;
+        if self.clock_rate == 0 {
+            return None;
+        }
+        let nanos = ticks as u128 * NANOS_PER_SECOND / self.clock_rate as u128;
+        Some(Duration::from_nanos(nanos as u64))
+    }
+}
+
+/// Represents the extended fields of a packet tracked by an `RtpTracker`.
+///
+/// # Fields
+///
+/// * `ssrc` - The synchronization source of the packet.
+/// * `sequence_number` - The extended sequence number.
+/// * `timestamp` - The extended timestamp.
+/// * `presentation_time` - The time since the first timestamp of the SSRC, `None` if the packet precedes it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TrackedPacket {
+    ssrc: u32,
+    sequence_number: u64,
+    timestamp: u64,
+    presentation_time: Option<Duration>,
+}
+
+impl TrackedPacket {
+    pub fn ssrc(&self) -> u32 {
+        self.ssrc
+    }
+
+    pub fn sequence_number(&self) -> u64 {
+        self.sequence_number
+    }
+
+    pub fn timestamp(&self) -> u64 {
+        self.timestamp
+    }
+
+    pub fn presentation_time(&self) -> Option<Duration> {
+        self.presentation_time
+    }
+}
+
+/// Tracks the sequence numbers and timestamps of every SSRC of a stream.
+///
+/// Every SSRC starts at its own random offsets, so each has its own trackers.
+#[derive(Debug, Clone)]
+pub struct RtpTracker {
+    clock_rate: u32,
+    sources: HashMap<u32, (SequenceNumberTracker, TimestampTracker)>,
+}
+
+impl RtpTracker {
+    /// Creates a new `RtpTracker`.
+    ///
+    /// # Arguments
+    ///
+    /// * `clock_rate` - The clock rate of the codec carried by the stream.
+    pub fn new(clock_rate: u32) -> Self {
+        Self { clock_rate, sources: HashMap::new() }
+    }
+
+    /// Extends the sequence number and the timestamp of a packet.
+    ///
+    /// # Arguments
+    ///
+    /// * `header` - The header of a received packet.
+    ///
+    /// # Returns
+    ///
+    /// The extended fields and the presentation time of the packet.
+    pub fn track(&mut self, header: &RtpHeader) -> TrackedPacket {
+        let ssrc = header.ssrc();
+        let (sequence_numbers, timestamps) = self
+            .sources
+            .entry(ssrc)
+            .or_insert_with(|| (SequenceNumberTracker::new(), TimestampTracker::new(self.clock_rate)));
+        let sequence_number = sequence_numbers.extend(header.sequence_number());
+        let timestamp = timestamps.extend(header.timestamp());
+        TrackedPacket { ssrc, sequence_number, timestamp, presentation_time: timestamps.presentation_time(timestamp) }
+    }
+
+    /// Forgets an SSRC, for instance after an RTCP BYE.
+    pub fn remove(&mut self, ssrc: u32) {
+        self.sources.remove(&ssrc);
+    }
+}
@@ -0,0 +1,150 @@
+use std::time::{Duration, Instant};
+
+use rtp::{
+    jitter_buffer::{JitterBuffer, JitterBufferEvent},
+    rtp_packet_buf::RtpPacketBuf,
+    rtp_packet_buf_builder::RtpPacketBufBuilder,
+};
+
+const LATENCY: Duration = Duration::from_millis(100);
+
+fn packet(ssrc: u32, sequence_number: u16) -> RtpPacketBuf {
+    RtpPacketBufBuilder::new().payload_type(96).ssrc(ssrc).sequence_number(sequence_number).build().unwrap()
+}
+
+/// Pops every event and describes packets by their sequence number and losses by their range.
+fn pop_all(jitter_buffer: &mut JitterBuffer, now: Instant) -> Vec<(u32, u16, u16)> {
+    std::iter::from_fn(|| jitter_buffer.pop(now))
+        .map(|event| match event {
+            JitterBufferEvent::Packet(packet) => (packet.ssrc(), packet.sequence_number(), 0),
+            JitterBufferEvent::Loss { ssrc, sequence_number, count } => (ssrc, sequence_number, count),
+        })
+        .collect()
+}
+
+#[test]
+fn test_reordering_across_wraparound() {
+    let mut jitter_buffer = JitterBuffer::new(LATENCY, 16);
+    let start = Instant::now();
+
+    for sequence_number in [65534, 0, 65535, 1] {
+        assert!(jitter_buffer.push(packet(1, sequence_number), start));
+    }
+
+    // The first packet is held for the latency in case earlier packets are still on their way.
+    assert_eq!(pop_all(&mut jitter_buffer, start), vec![]);
+    assert_eq!(jitter_buffer.next_deadline(), Some(start + LATENCY));
+    assert_eq!(pop_all(&mut jitter_buffer, start + LATENCY), vec![(1, 65534, 0), (1, 65535, 0), (1, 0, 0), (1, 1, 0)]);
+
+    // Packets that follow the last released one are released at once.
+    jitter_buffer.push(packet(1, 2), start + LATENCY);
+    assert_eq!(pop_all(&mut jitter_buffer, start + LATENCY), vec![(1, 2, 0)]);
+    assert_eq!(jitter_buffer.next_deadline(), None);
+}
+
+#[test]
+fn test_gap_reported_after_latency() {
+    let mut jitter_buffer = JitterBuffer::new(LATENCY, 16);
+    let start = Instant::now();
+    jitter_buffer.push(packet(1, 10), start);
+    assert_eq!(pop_all(&mut jitter_buffer, start + LATENCY), vec![(1, 10, 0)]);
+
+    let arrival = start + Duration::from_millis(150);
+    jitter_buffer.push(packet(1, 14), arrival);
+    jitter_buffer.push(packet(1, 13), arrival + Duration::from_millis(10));
+
+    assert_eq!(pop_all(&mut jitter_buffer, arrival + Duration::from_millis(99)), vec![]);
+    assert_eq!(jitter_buffer.next_deadline(), Some(arrival + LATENCY));
+    assert_eq!(pop_all(&mut jitter_buffer, arrival + LATENCY), vec![(1, 11, 2), (1, 13, 0), (1, 14, 0)]);
+}
+
+#[test]
+fn test_gap_reported_beyond_depth() {
+    let mut jitter_buffer = JitterBuffer::new(LATENCY, 2);
+    let start = Instant::now();
+    jitter_buffer.push(packet(1, 0), start);
+    assert_eq!(pop_all(&mut jitter_buffer, start + LATENCY), vec![(1, 0, 0)]);
+
+    jitter_buffer.push(packet(1, 2), start + LATENCY);
+    jitter_buffer.push(packet(1, 3), start + LATENCY);
+    assert_eq!(pop_all(&mut jitter_buffer, start + LATENCY), vec![]);
+    assert_eq!(jitter_buffer.buffered_packets(1), 2);
+
+    jitter_buffer.push(packet(1, 4), start + LATENCY);
+    assert_eq!(pop_all(&mut jitter_buffer, start + LATENCY), vec![(1, 1, 1), (1, 2, 0), (1, 3, 0), (1, 4, 0)]);
+}
+
+#[test]
+fn test_late_and_duplicate_packets_dropped() {
+    let mut jitter_buffer = JitterBuffer::new(LATENCY, 16);
+    let start = Instant::now();
+    jitter_buffer.push(packet(1, 5), start);
+    jitter_buffer.push(packet(1, 7), start);
+    assert!(!jitter_buffer.push(packet(1, 7), start));
+    assert_eq!(pop_all(&mut jitter_buffer, start + LATENCY), vec![(1, 5, 0), (1, 6, 1), (1, 7, 0)]);
+
+    assert!(!jitter_buffer.push(packet(1, 6), start + LATENCY));
+    assert!(!jitter_buffer.push(packet(1, 5), start + LATENCY));
+    assert_eq!(jitter_buffer.dropped_packets(), 3);
+    assert_eq!(pop_all(&mut jitter_buffer, start + LATENCY * 2), vec![]);
+}
+
+#[test]
+fn test_sequence_restart() {
+    let mut jitter_buffer = JitterBuffer::new(LATENCY, 16);
+    let start = Instant::now();
+    jitter_buffer.push(packet(1, 1000), start);
+    assert_eq!(pop_all(&mut jitter_buffer, start + LATENCY), vec![(1, 1000, 0)]);
+    jitter_buffer.push(packet(1, 1002), start + LATENCY);
+
+    // A packet far behind the stream is dropped, the next one continuing from it starts the stream
+    // over, releasing what was buffered.
+    assert!(!jitter_buffer.push(packet(1, 10), start + LATENCY));
+    assert!(jitter_buffer.push(packet(1, 11), start + LATENCY));
+    assert!(jitter_buffer.push(packet(1, 12), start + LATENCY));
+    assert_eq!(pop_all(&mut jitter_buffer, start + LATENCY), vec![(1, 1001, 1), (1, 1002, 0)]);
+    assert_eq!(pop_all(&mut jitter_buffer, start + LATENCY * 2), vec![(1, 11, 0), (1, 12, 0)]);
+}
+
+#[test]
+fn test_stale_packet_does_not_restart() {
+    let mut jitter_buffer = JitterBuffer::new(LATENCY, 16);
+    let start = Instant::now();
+    for sequence_number in 1000..=1004 {
+        jitter_buffer.push(packet(1, sequence_number), start);
+    }
+    assert_eq!(pop_all(&mut jitter_buffer, start + LATENCY).len(), 5);
+
+    assert!(!jitter_buffer.push(packet(1, 700), start + LATENCY));
+    for sequence_number in 1005..=1007 {
+        assert!(jitter_buffer.push(packet(1, sequence_number), start + LATENCY));
+    }
+
+    assert_eq!(pop_all(&mut jitter_buffer, start + LATENCY), vec![(1, 1005, 0), (1, 1006, 0), (1, 1007, 0)]);
+    assert_eq!(jitter_buffer.dropped_packets(), 1);
+}
+
+#[test]
+fn test_remove() {
+    let mut jitter_buffer = JitterBuffer::new(LATENCY, 16);
+    let start = Instant::now();
+    jitter_buffer.push(packet(1, 10), start);
+    jitter_buffer.push(packet(2, 20), start);
+
+    jitter_buffer.remove(1);
+
+    assert_eq!(jitter_buffer.buffered_packets(1), 0);
+    assert_eq!(pop_all(&mut jitter_buffer, start + LATENCY), vec![(2, 20, 0)]);
+}
+
+#[test]
+fn test_streams_ordered_independently() {
+    let mut jitter_buffer = JitterBuffer::new(LATENCY, 16);
+    let start = Instant::now();
+
+    for (ssrc, sequence_number) in [(2, 501), (1, 8), (2, 500), (1, 7)] {
+        jitter_buffer.push(packet(ssrc, sequence_number), start);
+    }
+
+    assert_eq!(pop_all(&mut jitter_buffer, start + LATENCY), vec![(1, 7, 0), (1, 8, 0), (2, 500, 0), (2, 501, 0)]);
+}
@@ -0,0 +1,97 @@
+use rtp::{
+    rtp_header::RtpHeader,
+    rtp_header_extension::{HeaderExtensionElement, RtpHeaderExtension, ONE_BYTE_PROFILE, TWO_BYTE_PROFILE},
+    rtp_packet::RtpPacket,
+};
+
+const ONE_BYTE_PACKET: [u8; 27] = [
+    0x90, 0x60, 0x30, 0x39, 0x00, 0x01, 0x09, 0x32, 0x49, 0x96, 0x02, 0xd2, // fixed header with the X bit
+    0xBE, 0xDE, 0x00, 0x02, // one-byte profile, 2 words
+    0x10, 0xAA, 0x00, 0x22, 0xBB, 0xCC, 0xDD, 0x00, // ID 1 (1 byte), padding, ID 2 (3 bytes), padding
+    1, 2, 3, // payload
+];
+
+#[test]
+fn test_one_byte_elements() {
+    let packet = RtpPacket::try_from(&ONE_BYTE_PACKET[..]).unwrap();
+    let header_extension = packet.header().header_extension().unwrap();
+
+    assert!(header_extension.is_one_byte());
+    assert_eq!(
+        header_extension.elements().unwrap(),
+        vec![HeaderExtensionElement::new(1, &[0xAA]), HeaderExtensionElement::new(2, &[0xBB, 0xCC, 0xDD])]
+    );
+    assert_eq!(header_extension.element(2), Some(&[0xBB, 0xCC, 0xDD][..]));
+    assert_eq!(header_extension.element(3), None);
+    assert_eq!(packet.header().byte_size(), 24);
+    assert_eq!(packet.payload(), &[1, 2, 3]);
+}
+
+#[test]
+fn test_one_byte_stop_id() {
+    let header_extension = RtpHeaderExtension::new(ONE_BYTE_PROFILE, &[0x10, 0xAA, 0xF0, 0x10]);
+
+    assert_eq!(header_extension.elements().unwrap(), vec![HeaderExtensionElement::new(1, &[0xAA])]);
+}
+
+#[test]
+fn test_two_byte_elements() {
+    let data = [0x01, 0x00, 0x00, 0x20, 0x02, 0xAA, 0xBB, 0x00];
+    let header_extension = RtpHeaderExtension::new(0x1003, &data);
+
+    assert!(header_extension.is_two_byte());
+    assert_eq!(
+        header_extension.elements().unwrap(),
+        vec![HeaderExtensionElement::new(1, &[]), HeaderExtensionElement::new(32, &[0xAA, 0xBB])]
+    );
+}
+
+#[test]
+fn test_invalid_elements() {
+    assert!(RtpHeaderExtension::new(ONE_BYTE_PROFILE, &[0x13, 0xAA]).elements().is_err());
+    assert!(RtpHeaderExtension::new(TWO_BYTE_PROFILE, &[0x01]).elements().is_err());
+    assert!(RtpHeaderExtension::new(0xABCD, &[0x10, 0xAA, 0x00, 0x00]).elements().is_err());
+}
+
+#[test]
+fn test_truncated_header_extension() {
+    let mut buffer = ONE_BYTE_PACKET;
+    buffer[15] = 0x08;
+
+    assert!(RtpPacket::try_from(&buffer[..]).is_err());
+    assert!(RtpHeader::try_from(&ONE_BYTE_PACKET[..14]).is_err());
+}
+
+#[test]
+fn test_write_one_byte_elements() {
+    let mut data = Vec::new();
+    let profile =
+        RtpHeaderExtension::write_elements(&[HeaderExtensionElement::new(1, &[0xAA]), HeaderExtensionElement::new(2, &[0xBB, 0xCC, 0xDD])], &mut data)
+            .unwrap();
+    let header = RtpHeader::new(2, false, false, 0, false, 96, 12345, 67890, 1234567890, &[])
+        .with_header_extension(RtpHeaderExtension::new(profile, &data));
+    let packet = RtpPacket::new(header, &[1, 2, 3]);
+
+    let mut buffer = vec![0u8; 1024];
+    let len = packet.write(&mut buffer);
+
+    assert_eq!(profile, ONE_BYTE_PROFILE);
+    assert_eq!(len, packet.byte_size());
+    assert_eq!(&buffer[..len], &[&ONE_BYTE_PACKET[..16], &[0x10, 0xAA, 0x22, 0xBB, 0xCC, 0xDD, 0x00, 0x00], &[1, 2, 3]].concat());
+}
+
+#[test]
+fn test_write_two_byte_elements() {
+    let mut data = Vec::new();
+    let profile =
+        RtpHeaderExtension::write_elements(&[HeaderExtensionElement::new(1, &[]), HeaderExtensionElement::new(20, &[0xAA])], &mut data).unwrap();
+
+    assert_eq!(profile, TWO_BYTE_PROFILE);
+    assert_eq!(data, vec![0x01, 0x00, 0x14, 0x01, 0xAA, 0x00, 0x00, 0x00]);
+
+    let mut buffer = [0xFF; 12];
+    let header_extension = RtpHeaderExtension::new(profile, &data[..5]);
+    assert_eq!(header_extension.write(&mut buffer), 12);
+    assert_eq!(buffer, [0x10, 0x00, 0x00, 0x02, 0x01, 0x00, 0x14, 0x01, 0xAA, 0x00, 0x00, 0x00]);
+    assert!(RtpHeaderExtension::write_elements(&[HeaderExtensionElement::new(0, &[0xAA])], &mut data).is_err());
+}
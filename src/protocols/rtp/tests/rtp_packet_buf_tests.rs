@@ -0,0 +1,93 @@
+use rtp::{
+    rtp_error::RtpError,
+    rtp_header_extension::{HeaderExtensionElement, ONE_BYTE_PROFILE, TWO_BYTE_PROFILE},
+    rtp_packet::RtpPacket,
+    rtp_packet_buf::RtpPacketBuf,
+    rtp_packet_buf_builder::RtpPacketBufBuilder,
+};
+
+fn builder() -> RtpPacketBufBuilder {
+    RtpPacketBufBuilder::new().payload_type(96).ssrc(1234567890).sequence_number(12345).timestamp(67890).payload(vec![1, 2, 3])
+}
+
+#[test]
+fn test_build_and_write() {
+    let packet = builder().marker(true).csrc(0x01020304).csrc(0x05060708).build().unwrap();
+
+    let buffer = packet.to_vec();
+
+    assert_eq!(buffer.len(), packet.byte_size());
+    assert_eq!(buffer.capacity(), packet.byte_size());
+    assert_eq!(
+        buffer,
+        [0x82, 0xE0, 0x30, 0x39, 0x00, 0x01, 0x09, 0x32, 0x49, 0x96, 0x02, 0xd2, 1, 2, 3, 4, 5, 6, 7, 8, 1, 2, 3]
+    );
+
+    let parsed = RtpPacket::try_from(&buffer[..]).unwrap();
+    assert_eq!(parsed.header().csrc_count(), 2);
+    assert_eq!(RtpPacketBuf::from(&parsed), packet);
+}
+
+#[test]
+fn test_extension_elements_and_padding() {
+    let packet = builder().extension_element(1, [0xAA]).extension_element(2, vec![0xBB, 0xCC]).padding(4).build().unwrap();
+
+    let buffer = packet.to_vec();
+
+    assert_eq!(
+        buffer,
+        [
+            0xB0, 0x60, 0x30, 0x39, 0x00, 0x01, 0x09, 0x32, 0x49, 0x96, 0x02, 0xd2, // header with the P and X bits
+            0xBE, 0xDE, 0x00, 0x02, 0x10, 0xAA, 0x21, 0xBB, 0xCC, 0x00, 0x00, 0x00, // one-byte header extension
+            1, 2, 3, 0, 0, 0, 4, // payload and padding
+        ]
+    );
+    assert_eq!(packet.header_extension().unwrap().profile(), ONE_BYTE_PROFILE);
+
+    let parsed = RtpPacket::try_from(&buffer[..]).unwrap();
+    assert_eq!(parsed.payload(), &[1, 2, 3]);
+    assert_eq!(
+        parsed.header().header_extension().unwrap().elements().unwrap(),
+        vec![HeaderExtensionElement::new(1, &[0xAA]), HeaderExtensionElement::new(2, &[0xBB, 0xCC])]
+    );
+    assert_eq!(RtpPacketBuf::from(&parsed), packet);
+}
+
+#[test]
+fn test_two_byte_extension_elements() {
+    let packet = builder().extension_element(20, [0xAA]).build().unwrap();
+
+    let header_extension = packet.header_extension().unwrap();
+    assert_eq!(header_extension.profile(), TWO_BYTE_PROFILE);
+    assert_eq!(header_extension.element(20), Some(&[0xAA][..]));
+}
+
+#[test]
+fn test_write_to_slice() {
+    let packet = builder().build().unwrap();
+
+    let mut buffer = [0u8; 16];
+    let mut slice = &mut buffer[..];
+    assert_eq!(packet.write_to(&mut slice), Ok(15));
+    assert_eq!(slice.len(), 1);
+    assert_eq!(&buffer[12..15], &[1, 2, 3]);
+
+    let mut buffer = [0u8; 14];
+    assert_eq!(packet.write_to(&mut &mut buffer[..]), Err(RtpError::BufferTooSmall { needed: 15, available: 14 }));
+    assert_eq!(buffer, [0u8; 14]);
+}
+
+#[test]
+fn test_build_errors() {
+    assert_eq!(RtpPacketBufBuilder::new().ssrc(1).build(), Err(RtpError::MissingField("payload type")));
+    assert_eq!(RtpPacketBufBuilder::new().payload_type(96).build(), Err(RtpError::MissingField("SSRC")));
+    assert_eq!(builder().payload_type(128).build(), Err(RtpError::InvalidField("payload type")));
+    assert_eq!((0..16).fold(builder(), |builder, csrc| builder.csrc(csrc)).build(), Err(RtpError::InvalidField("CSRC count")));
+    assert_eq!(builder().extension_element(0, [0xAA]).build(), Err(RtpError::InvalidField("header extension element")));
+    // 1024 two-byte elements of 256 bytes each need 65536 words.
+    assert_eq!(
+        (0..1024).fold(builder(), |builder, _| builder.extension_element(1, [0xAA; 254])).build(),
+        Err(RtpError::InvalidField("header extension length"))
+    );
+    assert!((0..1023).fold(builder(), |builder, _| builder.extension_element(1, [0xAA; 254])).build().is_ok());
+}
@@ -57,4 +57,71 @@ fn test_rtp_packet_byte_size() {
     let packet = RtpPacket::new(header, payload);
 
     assert_eq!(packet.byte_size(), 17);
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_rtp_packet_try_from_with_padding() {
+    let buffer = [
+        0xA0, 0x60, 0x30, 0x39, 0x00, 0x01, 0x09, 0x32, 0x49, 0x96, 0x02, 0xd2, 1, 2, 3, 4, 5, 0, 0, 3,
+    ];
+
+    let packet = RtpPacket::try_from(&buffer[..]).unwrap();
+
+    assert!(packet.header().padding());
+    assert_eq!(packet.payload(), &[1, 2, 3, 4, 5]);
+    assert_eq!(packet.padding_size(), 3);
+    assert_eq!(packet.byte_size(), buffer.len());
+}
+
+#[test]
+fn test_rtp_packet_try_from_with_invalid_padding() {
+    let header = [0xA0, 0x60, 0x30, 0x39, 0x00, 0x01, 0x09, 0x32, 0x49, 0x96, 0x02, 0xd2];
+
+    // The padding count is larger than the payload, zero, or missing.
+    assert!(RtpPacket::try_from(&[&header[..], &[1, 2, 4]].concat()[..]).is_err());
+    assert!(RtpPacket::try_from(&[&header[..], &[1, 2, 0]].concat()[..]).is_err());
+    assert!(RtpPacket::try_from(&header[..]).is_err());
+    // The padding may take the whole payload.
+    assert_eq!(RtpPacket::try_from(&[&header[..], &[0, 2]].concat()[..]).unwrap().payload(), &[]);
+}
+
+#[test]
+fn test_rtp_packet_write_with_padding() {
+    let header = RtpHeader::new(2, false, false, 0, false, 96, 12345, 67890, 1234567890, &[]);
+    let packet = RtpPacket::new(header, &[1, 2, 3, 4, 5]).with_padding(3);
+
+    let mut buffer = vec![0xFFu8; 1024];
+    let len = packet.write(&mut buffer);
+
+    assert_eq!(len, packet.byte_size());
+    assert_eq!(&buffer[..len], &[0xA0, 0x60, 0x30, 0x39, 0x00, 0x01, 0x09, 0x32, 0x49, 0x96, 0x02, 0xd2, 1, 2, 3, 4, 5, 0, 0, 3]);
+    assert_eq!(RtpPacket::try_from(&buffer[..len]).unwrap().payload(), &[1, 2, 3, 4, 5]);
+}
+
+#[test]
+fn test_rtp_packet_with_alignment() {
+    let header = RtpHeader::new(2, false, false, 0, false, 96, 12345, 67890, 1234567890, &[]);
+    let packet = RtpPacket::new(header, &[1, 2, 3, 4, 5]).with_alignment(16);
+
+    assert_eq!(packet.padding_size(), 15);
+    assert_eq!(packet.byte_size(), 32);
+
+    let header = RtpHeader::new(2, true, false, 0, false, 96, 12345, 67890, 1234567890, &[]);
+    let packet = RtpPacket::new(header, &[1, 2, 3, 4]).with_alignment(16);
+
+    assert_eq!(packet.padding_size(), 0);
+    assert!(!packet.header().padding());
+}
+
+#[test]
+fn test_rtp_packet_new_clears_padding() {
+    let header = RtpHeader::new(2, true, false, 0, false, 96, 12345, 67890, 1234567890, &[]);
+    let payload = &[1, 2, 3, 4, 5];
+    let packet = RtpPacket::new(header, payload);
+
+    assert!(!packet.header().padding());
+    assert_eq!(packet.padding_size(), 0);
+    let mut buffer = vec![0; packet.byte_size()];
+    let len = packet.write(&mut buffer);
+    assert_eq!(RtpPacket::try_from(&buffer[..len]).unwrap().payload(), payload);
+}
@@ -0,0 +1,67 @@
+use std::time::Duration;
+
+use rtp::{
+    rtp_header::RtpHeader,
+    rtp_tracker::{RtpTracker, SequenceNumberTracker, TimestampTracker},
+};
+
+#[test]
+fn test_sequence_number_wraparound() {
+    let mut tracker = SequenceNumberTracker::new();
+
+    let extended = [65534, 65535, 0, 65533, 1, 32767, 32768, 40000, 0]
+        .into_iter()
+        .map(|sequence_number| tracker.extend(sequence_number))
+        .collect::<Vec<_>>();
+
+    assert_eq!(extended, vec![131070, 131071, 131072, 131069, 131073, 163839, 163840, 171072, 196608]);
+    assert_eq!(tracker.highest(), Some(196608));
+    assert!(extended.iter().zip([65534u16, 65535, 0, 65533, 1, 32767, 32768, 40000, 0]).all(|(&extended, sequence_number)| extended as u16 == sequence_number));
+}
+
+#[test]
+fn test_sequence_number_before_first() {
+    let mut tracker = SequenceNumberTracker::new();
+
+    assert_eq!(tracker.extend(0), 65536);
+    assert_eq!(tracker.extend(65535), 65535);
+    assert_eq!(tracker.extend(1), 65537);
+}
+
+#[test]
+fn test_timestamp_wraparound() {
+    let mut tracker = TimestampTracker::new(90000);
+
+    let first = tracker.extend(u32::MAX - 2999);
+    let second = tracker.extend(0);
+    let third = tracker.extend(87000);
+
+    assert_eq!(second - first, 3000);
+    assert_eq!(third - first, 90000);
+    assert_eq!(tracker.presentation_time(first), Some(Duration::ZERO));
+    assert_eq!(tracker.presentation_time(second), Some(Duration::from_nanos(33_333_333)));
+    assert_eq!(tracker.presentation_time(third), Some(Duration::from_secs(1)));
+
+    // A frame presented before the first one, e.g. a B-frame, has no presentation time.
+    let earlier = tracker.extend(u32::MAX - 5999);
+    assert_eq!(earlier, first - 3000);
+    assert_eq!(tracker.presentation_time(earlier), None);
+}
+
+#[test]
+fn test_rtp_tracker_per_ssrc() {
+    let mut tracker = RtpTracker::new(8000);
+
+    let first = tracker.track(&RtpHeader::new(2, false, false, 0, false, 0, 65535, 1000, 1, &[]));
+    let other = tracker.track(&RtpHeader::new(2, false, false, 0, false, 0, 10, 50000, 2, &[]));
+    let second = tracker.track(&RtpHeader::new(2, false, false, 0, false, 0, 0, 1160, 1, &[]));
+
+    assert_eq!(second.sequence_number() - first.sequence_number(), 1);
+    assert_eq!(second.timestamp() - first.timestamp(), 160);
+    assert_eq!(second.presentation_time(), Some(Duration::from_millis(20)));
+    assert_eq!((other.ssrc(), other.presentation_time()), (2, Some(Duration::ZERO)));
+
+    tracker.remove(1);
+    let restarted = tracker.track(&RtpHeader::new(2, false, false, 0, false, 0, 5, 99999, 1, &[]));
+    assert_eq!(restarted.presentation_time(), Some(Duration::ZERO));
+}
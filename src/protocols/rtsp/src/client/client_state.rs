@@ -0,0 +1,26 @@
+/// Represents the state of an RTSP client session.
+///
+/// The states follow the client state machine of RFC 2326, appendix A.1:
+///
+/// ```text
+/// Init --SETUP--> Ready --PLAY--> Playing --PAUSE--> Ready
+///   ^               |                |
+///   +---TEARDOWN----+----TEARDOWN----+
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ClientState {
+    #[default]
+    Init,
+    Ready,
+    Playing,
+}
+
+impl ClientState {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ClientState::Init => "Init",
+            ClientState::Ready => "Ready",
+            ClientState::Playing => "Playing",
+        }
+    }
+}
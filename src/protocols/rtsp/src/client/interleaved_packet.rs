@@ -0,0 +1,22 @@
+use rtcp::rtcp_packet::RtcpPacket;
+use rtp::rtp_packet::RtpPacket;
+
+/// Represents an interleaved frame routed to the track that negotiated its channel.
+///
+/// # Variants
+///
+/// * `Rtp` - A frame on the RTP channel of a track.
+/// * `Rtcp` - A frame on the RTCP channel of a track, holding a compound RTCP packet.
+#[derive(Debug)]
+pub enum InterleavedPacket<'a> {
+    Rtp { media_index: usize, packet: RtpPacket<'a> },
+    Rtcp { media_index: usize, packets: Vec<RtcpPacket<'a>> },
+}
+
+impl InterleavedPacket<'_> {
+    pub fn media_index(&self) -> usize {
+        match self {
+            InterleavedPacket::Rtp { media_index, .. } | InterleavedPacket::Rtcp { media_index, .. } => *media_index,
+        }
+    }
+}
@@ -0,0 +1,8 @@
+/// Module containing the RTSP client.
+///
+/// The client drives the standard method sequence (`OPTIONS`, `DESCRIBE`, `SETUP`, `PLAY`,
+/// `PAUSE`, `TEARDOWN`) over any asynchronous byte stream, usually a `TcpStream`.
+pub mod client_state;
+pub mod interleaved_packet;
+pub mod rtsp_client;
+pub mod rtsp_track;
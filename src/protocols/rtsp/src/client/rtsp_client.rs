@@ -0,0 +1,485 @@
+use std::collections::VecDeque;
+
+use abstractions::parsing::payload_parser::PayloadParser;
+use http::Uri;
+use rtcp::rtcp_packet::RtcpPacket;
+use rtp::rtp_packet::RtpPacket;
+use sdp::{media_description::MediaDescription, media_session::MediaSession};
+use tokio::{
+    io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt},
+    net::TcpStream,
+};
+
+use crate::{
+    client::{client_state::ClientState, interleaved_packet::InterleavedPacket, rtsp_track::RtspTrack},
+    interleaved_frame::InterleavedFrame,
+    rtsp_decoder::RtspDecoder,
+    rtsp_error::RtspError,
+    rtsp_frame::RtspFrame,
+    rtsp_headers::{ACCEPT, CONTENT_BASE, CONTENT_LOCATION, PUBLIC, RANGE, SESSION, TRANSPORT, USER_AGENT},
+    rtsp_message::RtspMessage,
+    rtsp_method::RtspMethod,
+    rtsp_request::RtspRequest,
+    rtsp_response::RtspResponse,
+    rtsp_transport::RtspTransport,
+    status_code::StatusCode,
+};
+
+pub const DEFAULT_PORT: u16 = 554;
+const DEFAULT_USER_AGENT: &str = "rtsp-rs";
+const SDP_CONTENT_TYPE: &str = "application/sdp";
+const READ_BUFFER_SIZE: usize = 4096;
+
+/// An asynchronous RTSP client session.
+///
+/// The client keeps the `CSeq` counter, the `Session` identifier and the session state,
+/// and matches every response to its request. Interleaved frames received while waiting
+/// for a response are queued and returned by `read_frame`.
+///
+/// # Example
+///
+/// ```no_run
+/// use rtsp::{client::rtsp_client::RtspClient, rtsp_transport::RtspTransport};
+///
+/// # async fn run() -> Result<(), rtsp::rtsp_error::RtspError> {
+/// let mut client = RtspClient::connect("rtsp://192.0.2.10/stream".parse().unwrap()).await?;
+/// let tracks = client
+///     .negotiate(|index, _| RtspTransport::tcp(index as u8 * 2, index as u8 * 2 + 1))
+///     .await?;
+/// client.play().await?;
+/// loop {
+///     let frame = client.read_frame().await?;
+///     if let Some(packet) = client.route(&frame)? {
+///         // hand the RTP packet of packet.media_index() to its depacketizer
+///     }
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct RtspClient<S> {
+    stream: S,
+    decoder: RtspDecoder,
+    frames: VecDeque<InterleavedFrame>,
+    url: Uri,
+    base_url: Uri,
+    cseq: u32,
+    state: ClientState,
+    paused: bool,
+    session_id: Option<String>,
+    session_timeout: Option<u64>,
+    user_agent: String,
+    media_session: Option<MediaSession>,
+    tracks: Vec<RtspTrack>,
+}
+
+impl RtspClient<TcpStream> {
+    /// Opens a TCP connection to the host of the URL and creates a client for it.
+    ///
+    /// # Arguments
+    ///
+    /// * `url` - The `rtsp://` URL of the presentation.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the client, or an `RtspError` if the connection fails.
+    pub async fn connect(url: Uri) -> Result<Self, RtspError> {
+        let host = url
+            .host()
+            .ok_or_else(|| RtspError::InvalidData(url.to_string()))?;
+        let port = url.port_u16().unwrap_or(DEFAULT_PORT);
+        let stream = TcpStream::connect((host, port)).await?;
+        Ok(Self::new(stream, url))
+    }
+}
+
+impl<S> RtspClient<S>
+where
+    S: AsyncRead + AsyncWrite + Unpin,
+{
+    /// Creates a client over an already connected stream.
+    ///
+    /// # Arguments
+    ///
+    /// * `stream` - The connection to the RTSP server.
+    /// * `url` - The `rtsp://` URL of the presentation.
+    pub fn new(stream: S, url: Uri) -> Self {
+        Self {
+            stream,
+            decoder: RtspDecoder::new(),
+            frames: VecDeque::new(),
+            base_url: url.clone(),
+            url,
+            cseq: 0,
+            state: ClientState::default(),
+            paused: false,
+            session_id: None,
+            session_timeout: None,
+            user_agent: DEFAULT_USER_AGENT.to_string(),
+            media_session: None,
+            tracks: Vec::new(),
+        }
+    }
+
+    pub fn url(&self) -> &Uri {
+        &self.url
+    }
+
+    pub fn state(&self) -> ClientState {
+        self.state
+    }
+
+    pub fn session_id(&self) -> Option<&str> {
+        self.session_id.as_deref()
+    }
+
+    /// Returns the session timeout in seconds announced by the server, if any.
+    pub fn session_timeout(&self) -> Option<u64> {
+        self.session_timeout
+    }
+
+    /// Returns the session description received with `DESCRIBE`.
+    pub fn media_session(&self) -> Option<&MediaSession> {
+        self.media_session.as_ref()
+    }
+
+    /// Returns the tracks set up so far.
+    pub fn tracks(&self) -> &[RtspTrack] {
+        &self.tracks
+    }
+
+    pub fn set_user_agent(&mut self, user_agent: String) {
+        self.user_agent = user_agent;
+    }
+
+    /// Returns the underlying stream.
+    pub fn stream_mut(&mut self) -> &mut S {
+        &mut self.stream
+    }
+
+    /// Sends `OPTIONS` for the presentation.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the methods listed in the `Public` header.
+    pub async fn options(&mut self) -> Result<Vec<RtspMethod>, RtspError> {
+        let request = RtspRequest::new(RtspMethod::Options, self.url.clone());
+        let response = self.execute(request).await?;
+
+        let methods = match response.headers().get(PUBLIC) {
+            Some(public) => public
+                .split(',')
+                .map(|method| RtspMethod::from_bytes(method.trim().as_bytes()))
+                .collect::<Result<Vec<_>, _>>()?,
+            None => Vec::new(),
+        };
+        Ok(methods)
+    }
+
+    /// Sends `DESCRIBE` and parses the SDP body.
+    ///
+    /// The base URL for relative `a=control` attributes is taken from `Content-Base`,
+    /// `Content-Location` or, failing both, the request URL.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the session description.
+    pub async fn describe(&mut self) -> Result<&MediaSession, RtspError> {
+        let mut request = RtspRequest::new(RtspMethod::Describe, self.url.clone());
+        request.headers_mut().insert(ACCEPT, SDP_CONTENT_TYPE);
+        let response = self.execute(request).await?;
+
+        let base_url = response
+            .headers()
+            .get(CONTENT_BASE)
+            .or_else(|| response.headers().get(CONTENT_LOCATION));
+        if let Some(base_url) = base_url {
+            self.base_url = base_url
+                .parse()
+                .map_err(|_| RtspError::InvalidData(base_url.to_string()))?;
+        }
+
+        let media_session = MediaSession::parse(response.body())?;
+        Ok(self.media_session.insert(media_session))
+    }
+
+    /// Sends `SETUP` for a media description of the `DESCRIBE` session.
+    ///
+    /// # Arguments
+    ///
+    /// * `media_index` - The index of the media description.
+    /// * `transport` - The transport requested by the client.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the track with the transport confirmed by the server.
+    pub async fn setup(&mut self, media_index: usize, transport: RtspTransport) -> Result<&RtspTrack, RtspError> {
+        let media_description = self
+            .media_session
+            .as_ref()
+            .and_then(|session| session.media_descriptions().get(media_index))
+            .ok_or_else(|| RtspError::InvalidData(format!("No media description at index {}", media_index)))?;
+        let control = resolve_control(&self.base_url, media_description.control().unwrap_or("*"))?;
+
+        let mut request = RtspRequest::new(RtspMethod::Setup, control.clone());
+        request.headers_mut().insert(TRANSPORT, &transport.to_string());
+        let response = self.execute(request).await?;
+
+        let session = response.headers().get(SESSION).ok_or(RtspError::MissingHeader(SESSION))?;
+        let mut parameters = session.split(';');
+        self.session_id = parameters.next().map(|id| id.trim().to_string());
+        self.session_timeout = parameters
+            .filter_map(|parameter| parameter.trim().strip_prefix("timeout="))
+            .find_map(|timeout| timeout.parse().ok());
+
+        let transport = match response.headers().get(TRANSPORT) {
+            Some(transport) => RtspTransport::parse(transport.as_bytes())?,
+            None => transport,
+        };
+        if self.state == ClientState::Init {
+            self.state = ClientState::Ready;
+        }
+
+        self.tracks.retain(|track| track.media_index() != media_index);
+        self.tracks.push(RtspTrack::new(media_index, control, transport));
+        Ok(&self.tracks[self.tracks.len() - 1])
+    }
+
+    /// Runs `OPTIONS`, `DESCRIBE` and `SETUP` for every media description.
+    ///
+    /// # Arguments
+    ///
+    /// * `transport` - Returns the transport to request for a media description.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the negotiated tracks, ready for `PLAY`.
+    pub async fn negotiate<F>(&mut self, mut transport: F) -> Result<Vec<RtspTrack>, RtspError>
+    where
+        F: FnMut(usize, &MediaDescription) -> RtspTransport,
+    {
+        self.options().await?;
+        let transports = self
+            .describe()
+            .await?
+            .media_descriptions()
+            .iter()
+            .enumerate()
+            .map(|(index, media_description)| transport(index, media_description))
+            .collect::<Vec<_>>();
+
+        for (index, transport) in transports.into_iter().enumerate() {
+            self.setup(index, transport).await?;
+        }
+        Ok(self.tracks.clone())
+    }
+
+    /// Sends `PLAY` for the whole presentation from its current position.
+    ///
+    /// The first `PLAY` starts at the beginning; after `pause` the `Range` header is left out,
+    /// so that the server resumes where it paused.
+    pub async fn play(&mut self) -> Result<RtspResponse, RtspError> {
+        self.ensure_state(RtspMethod::Play, &[ClientState::Ready, ClientState::Playing])?;
+        let mut request = RtspRequest::new(RtspMethod::Play, self.aggregate_control()?);
+        if !self.paused {
+            request.headers_mut().insert(RANGE, "npt=0.000-");
+        }
+        let response = self.execute(request).await?;
+        self.state = ClientState::Playing;
+        self.paused = false;
+        Ok(response)
+    }
+
+    /// Sends `PAUSE` for the whole presentation.
+    pub async fn pause(&mut self) -> Result<RtspResponse, RtspError> {
+        self.ensure_state(RtspMethod::Pause, &[ClientState::Playing])?;
+        let request = RtspRequest::new(RtspMethod::Pause, self.aggregate_control()?);
+        let response = self.execute(request).await?;
+        self.state = ClientState::Ready;
+        self.paused = true;
+        Ok(response)
+    }
+
+    /// Sends `TEARDOWN` for the whole presentation and forgets the session.
+    pub async fn teardown(&mut self) -> Result<RtspResponse, RtspError> {
+        self.ensure_state(RtspMethod::Teardown, &[ClientState::Ready, ClientState::Playing])?;
+        let request = RtspRequest::new(RtspMethod::Teardown, self.aggregate_control()?);
+        let response = self.execute(request).await?;
+        self.state = ClientState::Init;
+        self.paused = false;
+        self.session_id = None;
+        self.session_timeout = None;
+        self.tracks.clear();
+        Ok(response)
+    }
+
+    /// Sends a request and waits for the response with the same `CSeq`.
+    ///
+    /// The `CSeq`, `Session` and `User-Agent` headers are filled in by the client. Unlike the
+    /// method helpers, the status code of the response is not checked.
+    ///
+    /// # Arguments
+    ///
+    /// * `request` - The request to send.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the response, or an `RtspError` if the connection fails.
+    pub async fn send(&mut self, mut request: RtspRequest) -> Result<RtspResponse, RtspError> {
+        self.cseq += 1;
+        request.set_cseq(self.cseq);
+        request.headers_mut().insert(USER_AGENT, &self.user_agent);
+        if let Some(session_id) = &self.session_id {
+            if !request.headers().contains(SESSION) {
+                request.headers_mut().insert(SESSION, session_id);
+            }
+        }
+
+        self.stream.write_all(&request.to_bytes()).await?;
+        self.stream.flush().await?;
+        self.read_response(self.cseq).await
+    }
+
+    /// Reads the next interleaved frame from the connection.
+    ///
+    /// Frames queued while waiting for a response are returned first; responses that
+    /// arrive without a pending request are skipped and requests from the server are
+    /// answered with `501 Not Implemented`.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the frame, or an `RtspError` if the connection fails.
+    pub async fn read_frame(&mut self) -> Result<InterleavedFrame, RtspError> {
+        if let Some(frame) = self.frames.pop_front() {
+            return Ok(frame);
+        }
+        let mut buffer = [0u8; READ_BUFFER_SIZE];
+        loop {
+            while let Some(frame) = self.decoder.decode_frame()? {
+                match frame {
+                    RtspFrame::Interleaved(frame) => return Ok(frame),
+                    RtspFrame::Message(RtspMessage::Request(request)) => self.reject_request(&request).await?,
+                    RtspFrame::Message(RtspMessage::Response(_)) => {}
+                }
+            }
+            self.read_more(&mut buffer).await?;
+        }
+    }
+
+    /// Routes an interleaved frame to the track that negotiated its channel.
+    ///
+    /// # Arguments
+    ///
+    /// * `frame` - The frame returned by `read_frame`.
+    ///
+    /// # Returns
+    ///
+    /// `Ok(None)` if no track uses the channel, otherwise the parsed RTP packet or compound
+    /// RTCP packet, or an `RtspError` if the packet is invalid.
+    pub fn route<'a>(&self, frame: &'a InterleavedFrame) -> Result<Option<InterleavedPacket<'a>>, RtspError> {
+        for track in &self.tracks {
+            let media_index = track.media_index();
+            match track.transport().interleaved() {
+                Some((rtp, _)) if rtp == frame.channel() => {
+                    let packet = RtpPacket::try_from(frame.data())?;
+                    return Ok(Some(InterleavedPacket::Rtp { media_index, packet }));
+                }
+                Some((_, rtcp)) if rtcp == frame.channel() => {
+                    let packets = RtcpPacket::parse_compound(frame.data())?;
+                    return Ok(Some(InterleavedPacket::Rtcp { media_index, packets }));
+                }
+                _ => {}
+            }
+        }
+        Ok(None)
+    }
+
+    async fn execute(&mut self, request: RtspRequest) -> Result<RtspResponse, RtspError> {
+        let method = request.method().clone();
+        let response = self.send(request).await?;
+        if !response.status().is_success() {
+            return Err(RtspError::UnexpectedStatus { method, status: response.status() });
+        }
+        Ok(response)
+    }
+
+    async fn read_response(&mut self, cseq: u32) -> Result<RtspResponse, RtspError> {
+        let mut buffer = [0u8; READ_BUFFER_SIZE];
+        loop {
+            while let Some(frame) = self.decoder.decode_frame()? {
+                match frame {
+                    RtspFrame::Message(RtspMessage::Response(response)) => {
+                        if response.cseq().is_none_or(|value| value == cseq) {
+                            return Ok(response);
+                        }
+                    }
+                    RtspFrame::Message(RtspMessage::Request(request)) => self.reject_request(&request).await?,
+                    RtspFrame::Interleaved(frame) => self.frames.push_back(frame),
+                }
+            }
+            self.read_more(&mut buffer).await?;
+        }
+    }
+
+    /// Answers a request sent by the server, such as `SET_PARAMETER` or `ANNOUNCE`, none of which the client supports.
+    async fn reject_request(&mut self, request: &RtspRequest) -> Result<(), RtspError> {
+        let mut response = RtspResponse::new(StatusCode::NotImplemented);
+        if let Some(cseq) = request.cseq() {
+            response.set_cseq(cseq);
+        }
+        self.stream.write_all(&response.to_bytes()).await?;
+        self.stream.flush().await?;
+        Ok(())
+    }
+
+    async fn read_more(&mut self, buffer: &mut [u8]) -> Result<(), RtspError> {
+        let read = self.stream.read(buffer).await?;
+        if read == 0 {
+            return Err(RtspError::ConnectionClosed);
+        }
+        self.decoder.extend(&buffer[..read]);
+        Ok(())
+    }
+
+    fn ensure_state(&self, method: RtspMethod, allowed: &[ClientState]) -> Result<(), RtspError> {
+        if !allowed.contains(&self.state) || self.session_id.is_none() {
+            return Err(RtspError::InvalidState { method, state: self.state.as_str() });
+        }
+        Ok(())
+    }
+
+    /// Returns the URL for requests on the whole presentation, from the session-level `a=control` attribute.
+    fn aggregate_control(&self) -> Result<Uri, RtspError> {
+        let control = self.media_session.as_ref().and_then(|session| session.control()).unwrap_or("*");
+        resolve_control(&self.base_url, control)
+    }
+}
+
+/// Resolves an `a=control` attribute against the base URL of the presentation.
+///
+/// # Arguments
+///
+/// * `base_url` - The base URL from `Content-Base`, `Content-Location` or the request URL.
+/// * `control` - The absolute or relative control URL, or `*` for the base URL itself.
+///
+/// # Returns
+///
+/// A `Result` containing the absolute control URL.
+pub fn resolve_control(base_url: &Uri, control: &str) -> Result<Uri, RtspError> {
+    if control == "*" {
+        return Ok(base_url.clone());
+    }
+    if control.starts_with("rtsp://") || control.starts_with("rtsps://") {
+        return control.parse().map_err(|_| RtspError::InvalidData(control.to_string()));
+    }
+
+    // The query of the presentation URL does not apply to the streams it contains.
+    let base_url = base_url.to_string();
+    let base_url = base_url.split('?').next().unwrap_or_default();
+    let url = if base_url.ends_with('/') {
+        format!("{}{}", base_url, control)
+    } else {
+        format!("{}/{}", base_url, control)
+    };
+    url.parse().map_err(|_| RtspError::InvalidData(url))
+}
@@ -0,0 +1,35 @@
+use http::Uri;
+
+use crate::rtsp_transport::RtspTransport;
+
+/// Represents a media stream negotiated with `SETUP`.
+///
+/// # Fields
+///
+/// * `media_index` - The index of the media description in the `DESCRIBE` session.
+/// * `control` - The resolved control URL used for the `SETUP` request.
+/// * `transport` - The transport confirmed by the server.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RtspTrack {
+    media_index: usize,
+    control: Uri,
+    transport: RtspTransport,
+}
+
+impl RtspTrack {
+    pub fn new(media_index: usize, control: Uri, transport: RtspTransport) -> Self {
+        Self { media_index, control, transport }
+    }
+
+    pub fn media_index(&self) -> usize {
+        self.media_index
+    }
+
+    pub fn control(&self) -> &Uri {
+        &self.control
+    }
+
+    pub fn transport(&self) -> &RtspTransport {
+        &self.transport
+    }
+}
@@ -0,0 +1,83 @@
+use tpkt::{
+    tpkt_frame::TpktFrame,
+    tpkt_payload_header::{INTERLEAVED_VERSION, TPKT_HEADER_SIZE},
+};
+
+use crate::rtsp_error::RtspError;
+
+/// The byte that starts an interleaved binary frame.
+pub const INTERLEAVED_MARKER: u8 = INTERLEAVED_VERSION;
+/// The size of the marker, channel and length prefix of an interleaved frame.
+pub const INTERLEAVED_HEADER_SIZE: usize = TPKT_HEADER_SIZE;
+
+/// Represents an RTP or RTCP packet carried over the RTSP connection.
+///
+/// According to RFC 2326, section 10.12, every packet is prefixed with a dollar sign,
+/// a one-byte channel identifier and the length of the packet as a 16-bit big-endian integer:
+///
+/// ```text
+/// $<channel><length><data>
+/// ```
+///
+/// The channels are negotiated with the `interleaved` parameter of the `Transport` header.
+/// The header is read and written by the `tpkt` crate, which shares it with RFC 1006 framing.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InterleavedFrame {
+    channel: u8,
+    data: Vec<u8>,
+}
+
+impl InterleavedFrame {
+    /// Creates a new `InterleavedFrame`.
+    ///
+    /// # Arguments
+    ///
+    /// * `channel` - The interleaved channel identifier.
+    /// * `data` - The packet, at most 65535 bytes long.
+    pub fn new(channel: u8, data: Vec<u8>) -> Self {
+        Self { channel, data }
+    }
+
+    /// Parses a frame from the start of a buffer that may hold a partial frame.
+    ///
+    /// # Arguments
+    ///
+    /// * `data` - The buffered bytes, starting with the `$` marker.
+    ///
+    /// # Returns
+    ///
+    /// `None` if the frame is incomplete, or the frame and the number of bytes it occupies.
+    pub fn parse_partial(data: &[u8]) -> Option<(Self, usize)> {
+        let frame = TpktFrame::try_from(data).ok().filter(|frame| frame.header().is_interleaved())?;
+        Some((Self::new(frame.header().channel(), frame.payload().to_vec()), frame.byte_size()))
+    }
+
+    pub fn channel(&self) -> u8 {
+        self.channel
+    }
+
+    pub fn data(&self) -> &[u8] {
+        &self.data
+    }
+
+    /// Serializes the frame into a buffer.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` that is an `RtspError::PacketTooLarge` if the packet is longer than 65535 bytes,
+    /// in which case nothing is written.
+    pub fn write_to(&self, buffer: &mut Vec<u8>) -> Result<(), RtspError> {
+        let frame = TpktFrame::interleaved(self.channel, &self.data)
+            .map_err(|_| RtspError::PacketTooLarge(self.data.len()))?;
+        let start = buffer.len();
+        buffer.resize(start + frame.byte_size(), 0);
+        frame.write(&mut buffer[start..]);
+        Ok(())
+    }
+
+    pub fn to_bytes(&self) -> Result<Vec<u8>, RtspError> {
+        let mut buffer = Vec::with_capacity(INTERLEAVED_HEADER_SIZE + self.data.len());
+        self.write_to(&mut buffer)?;
+        Ok(buffer)
+    }
+}
@@ -0,0 +1,38 @@
+/// RTSP (Real Time Streaming Protocol) module for handling RTSP messages.
+///
+/// This module provides functionality for working with RTSP 1.0 messages as defined in
+/// RFC 2326, including parsing and generating requests and responses, header maps, and
+/// incremental decoding of messages and interleaved RTP/RTCP frames read from a connection,
+/// an asynchronous client session, and a server that streams RTP packets to clients from
+/// registered mounts.
+///
+/// # Examples
+///
+/// ## Parsing an RTSP Request
+///
+/// ```rust
+/// use rtsp::{rtsp_method::RtspMethod, rtsp_request::RtspRequest};
+/// use abstractions::parsing::payload_parser::PayloadParser;
+///
+/// let data = b"DESCRIBE rtsp://192.0.2.10/stream RTSP/1.0\r\n\
+///              CSeq: 2\r\n\
+///              Accept: application/sdp\r\n\r\n";
+///
+/// let request = RtspRequest::parse(data).unwrap();
+/// assert_eq!(request.method(), &RtspMethod::Describe);
+/// assert_eq!(request.cseq(), Some(2));
+/// ```
+pub mod rtsp_method;
+pub mod rtsp_version;
+pub mod status_code;
+pub mod rtsp_headers;
+pub mod rtsp_request;
+pub mod rtsp_response;
+pub mod rtsp_message;
+pub mod rtsp_decoder;
+pub mod interleaved_frame;
+pub mod rtsp_frame;
+pub mod rtsp_transport;
+pub mod rtsp_error;
+pub mod client;
+pub mod server;
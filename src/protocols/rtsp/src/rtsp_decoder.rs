@@ -0,0 +1,92 @@
+use abstractions::parsing::parsing_error::ParsingError;
+
+use crate::{
+    interleaved_frame::{InterleavedFrame, INTERLEAVED_MARKER},
+    rtsp_frame::RtspFrame,
+    rtsp_message::RtspMessage,
+};
+
+/// Accumulates bytes read from a connection and yields complete RTSP messages.
+///
+/// A single read may contain a fraction of a message or several messages at once;
+/// the decoder keeps the unconsumed tail between calls. On connections that carry
+/// interleaved RTP and RTCP, `decode_frame` also splits off the `$`-framed packets.
+///
+/// # Example
+///
+/// ```
+/// use rtsp::rtsp_decoder::RtspDecoder;
+///
+/// let mut decoder = RtspDecoder::new();
+/// decoder.extend(b"RTSP/1.0 200 OK\r\nCSeq: 1\r\n");
+/// assert!(decoder.decode().unwrap().is_none());
+///
+/// decoder.extend(b"\r\n");
+/// let message = decoder.decode().unwrap().unwrap();
+/// assert_eq!(message.cseq(), Some(1));
+/// ```
+#[derive(Debug, Default)]
+pub struct RtspDecoder {
+    buffer: Vec<u8>,
+}
+
+impl RtspDecoder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends received bytes to the internal buffer.
+    pub fn extend(&mut self, data: &[u8]) {
+        self.buffer.extend_from_slice(data);
+    }
+
+    /// Takes the next complete message out of the buffer.
+    ///
+    /// # Returns
+    ///
+    /// `Ok(None)` if the buffer does not hold a complete message yet, or a `ParsingError`
+    /// if the buffered bytes are not a valid RTSP message.
+    pub fn decode(&mut self) -> Result<Option<RtspMessage>, ParsingError> {
+        match RtspMessage::parse_partial(&self.buffer)? {
+            Some((message, consumed)) => {
+                self.buffer.drain(..consumed);
+                Ok(Some(message))
+            }
+            None => Ok(None),
+        }
+    }
+
+    /// Takes the next complete message or interleaved frame out of the buffer.
+    ///
+    /// # Returns
+    ///
+    /// `Ok(None)` if the buffer does not hold a complete frame yet, or a `ParsingError`
+    /// if the buffered bytes are neither an interleaved frame nor a valid RTSP message.
+    pub fn decode_frame(&mut self) -> Result<Option<RtspFrame>, ParsingError> {
+        let start = self
+            .buffer
+            .iter()
+            .position(|byte| !matches!(byte, b'\r' | b'\n'))
+            .unwrap_or(self.buffer.len());
+        if self.buffer.get(start) != Some(&INTERLEAVED_MARKER) {
+            return Ok(self.decode()?.map(RtspFrame::Message));
+        }
+
+        match InterleavedFrame::parse_partial(&self.buffer[start..]) {
+            Some((frame, consumed)) => {
+                self.buffer.drain(..start + consumed);
+                Ok(Some(RtspFrame::Interleaved(frame)))
+            }
+            None => Ok(None),
+        }
+    }
+
+    /// Returns the bytes that have not been decoded yet.
+    pub fn remaining(&self) -> &[u8] {
+        &self.buffer
+    }
+
+    pub fn clear(&mut self) {
+        self.buffer.clear();
+    }
+}
@@ -0,0 +1,44 @@
+use abstractions::{extensions::cast_error::CastError, parsing::parsing_error::ParsingError};
+use thiserror::Error;
+
+use crate::{rtsp_method::RtspMethod, status_code::StatusCode};
+
+/// Represents errors that can occur while exchanging RTSP messages.
+#[derive(Error, Debug)]
+pub enum RtspError {
+    /// Represents an error of the underlying connection.
+    #[error("I/O error")]
+    Io(#[from] std::io::Error),
+
+    /// Represents an error for a message that could not be parsed.
+    #[error("Parsing error")]
+    Parsing(#[from] ParsingError),
+
+    /// Represents an interleaved RTP or RTCP packet that could not be parsed.
+    #[error("Packet error")]
+    Packet(#[from] CastError),
+
+    /// Represents a packet too long for the 16-bit length of an interleaved frame.
+    #[error("Packet of {0} bytes is too large for an interleaved frame")]
+    PacketTooLarge(usize),
+
+    /// Represents a response with a status code outside of the `2xx` class.
+    #[error("{method:?} failed with status {status:?}")]
+    UnexpectedStatus { method: RtspMethod, status: StatusCode },
+
+    /// Represents a request that is not allowed in the current session state.
+    #[error("{method:?} is not valid in this state: {state}")]
+    InvalidState { method: RtspMethod, state: &'static str },
+
+    /// Represents a response without a header required by the request.
+    #[error("Missing header: {0}")]
+    MissingHeader(&'static str),
+
+    /// Represents a connection closed by the peer.
+    #[error("Connection closed")]
+    ConnectionClosed,
+
+    /// Represents an error for invalid data.
+    #[error("Invalid data: {0:?}")]
+    InvalidData(String),
+}
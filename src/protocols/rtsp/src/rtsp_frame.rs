@@ -0,0 +1,20 @@
+use crate::{interleaved_frame::InterleavedFrame, rtsp_message::RtspMessage};
+
+/// Represents a unit read from an RTSP connection that carries interleaved data.
+#[derive(Debug, Clone, PartialEq)]
+pub enum RtspFrame {
+    Message(RtspMessage),
+    Interleaved(InterleavedFrame),
+}
+
+impl From<RtspMessage> for RtspFrame {
+    fn from(message: RtspMessage) -> Self {
+        RtspFrame::Message(message)
+    }
+}
+
+impl From<InterleavedFrame> for RtspFrame {
+    fn from(frame: InterleavedFrame) -> Self {
+        RtspFrame::Interleaved(frame)
+    }
+}
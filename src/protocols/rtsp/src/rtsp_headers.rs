@@ -0,0 +1,178 @@
+use std::fmt;
+
+use abstractions::{
+    extensions::{array_extensions::ArrayExt, utf8_array_extensions::U8ArrayExt},
+    parsing::{parsing_error::ParsingError, payload_parser::PayloadParser, COLON, NEW_LINE, TRIM_NEW_LINE, WHITESPACE},
+};
+
+pub const ACCEPT: &str = "Accept";
+pub const AUTHORIZATION: &str = "Authorization";
+pub const CONTENT_BASE: &str = "Content-Base";
+pub const CONTENT_LENGTH: &str = "Content-Length";
+pub const CONTENT_LOCATION: &str = "Content-Location";
+pub const CONTENT_TYPE: &str = "Content-Type";
+pub const CSEQ: &str = "CSeq";
+pub const PUBLIC: &str = "Public";
+pub const RANGE: &str = "Range";
+pub const RTP_INFO: &str = "RTP-Info";
+pub const SERVER: &str = "Server";
+pub const SESSION: &str = "Session";
+pub const TRANSPORT: &str = "Transport";
+pub const USER_AGENT: &str = "User-Agent";
+pub const WWW_AUTHENTICATE: &str = "WWW-Authenticate";
+
+/// Represents the header fields of an RTSP message.
+///
+/// Header names are compared case-insensitively, as required by RFC 2326, section 4.2,
+/// while the original spelling and order are preserved for serialization.
+///
+/// Example:
+///
+/// ```text
+/// CSeq: 2
+/// Content-Type: application/sdp
+/// Content-Length: 460
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RtspHeaders {
+    headers: Vec<(String, String)>,
+}
+
+impl PayloadParser for RtspHeaders {
+    fn parse(data: &[u8]) -> Result<Self, ParsingError> {
+        let mut headers = RtspHeaders::default();
+        let mut slice = data.trim(TRIM_NEW_LINE);
+
+        while let Some((line, other)) = slice.while_separate_trimmed(NEW_LINE, TRIM_NEW_LINE) {
+            let (name, value) = line
+                .separate_trimmed(COLON, WHITESPACE)
+                .ok_or_else(|| ParsingError::from_bytes(line))?;
+            if name.is_empty() {
+                return Err(ParsingError::from_bytes(line));
+            }
+            headers.append(name.utf8_to_str()?, value.utf8_to_str()?);
+            slice = other;
+        }
+        Ok(headers)
+    }
+}
+
+impl RtspHeaders {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the value of the first header with the given name.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The case-insensitive header name.
+    ///
+    /// # Returns
+    ///
+    /// An `Option` containing the header value, or `None` if the header is absent.
+    pub fn get(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// Returns the values of all headers with the given name, in order of appearance.
+    pub fn get_all<'a>(&'a self, name: &'a str) -> impl Iterator<Item = &'a str> + 'a {
+        self.headers
+            .iter()
+            .filter(move |(key, _)| key.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+
+    pub fn contains(&self, name: &str) -> bool {
+        self.get(name).is_some()
+    }
+
+    /// Sets the header, replacing every existing header with the same name.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The header name.
+    /// * `value` - The header value.
+    pub fn insert(&mut self, name: &str, value: &str) {
+        let mut found = false;
+        self.headers.retain_mut(|(key, existing)| {
+            if !key.eq_ignore_ascii_case(name) {
+                return true;
+            }
+            if found {
+                return false;
+            }
+            found = true;
+            *existing = value.to_string();
+            true
+        });
+        if !found {
+            self.append(name, value);
+        }
+    }
+
+    /// Adds the header without touching existing headers with the same name.
+    pub fn append(&mut self, name: &str, value: &str) {
+        self.headers.push((name.to_string(), value.to_string()));
+    }
+
+    /// Removes every header with the given name.
+    ///
+    /// # Returns
+    ///
+    /// `true` if at least one header was removed.
+    pub fn remove(&mut self, name: &str) -> bool {
+        let len = self.headers.len();
+        self.headers.retain(|(key, _)| !key.eq_ignore_ascii_case(name));
+        len != self.headers.len()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.headers.iter().map(|(key, value)| (key.as_str(), value.as_str()))
+    }
+
+    pub fn len(&self) -> usize {
+        self.headers.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.headers.is_empty()
+    }
+
+    /// Returns the value of the `CSeq` header.
+    ///
+    /// # Returns
+    ///
+    /// An `Option` containing the sequence number, or `None` if the header is absent or invalid.
+    pub fn cseq(&self) -> Option<u32> {
+        self.get(CSEQ).and_then(|value| value.trim().parse().ok())
+    }
+
+    /// Returns the value of the `Content-Length` header.
+    ///
+    /// # Returns
+    ///
+    /// An `Option` containing the body length, or `None` if the header is absent or invalid.
+    pub fn content_length(&self) -> Option<usize> {
+        self.get(CONTENT_LENGTH).and_then(|value| value.trim().parse().ok())
+    }
+
+    /// Returns the session identifier from the `Session` header, without the `timeout` parameter.
+    pub fn session_id(&self) -> Option<&str> {
+        self.get(SESSION)
+            .and_then(|value| value.split(';').next())
+            .map(str::trim)
+    }
+}
+
+impl fmt::Display for RtspHeaders {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (name, value) in &self.headers {
+            write!(f, "{}: {}\r\n", name, value)?;
+        }
+        Ok(())
+    }
+}
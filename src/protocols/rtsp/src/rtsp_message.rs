@@ -0,0 +1,153 @@
+use abstractions::{
+    extensions::array_extensions::ArrayExt,
+    parsing::{parsing_error::ParsingError, payload_parser::PayloadParser, NEW_LINE},
+};
+
+use crate::{rtsp_headers::{RtspHeaders, CONTENT_LENGTH}, rtsp_request::RtspRequest, rtsp_response::RtspResponse};
+
+/// `&[u8]` representation of the empty line that terminates the message head.
+pub const HEADER_END: &[u8] = b"\r\n\r\n";
+
+/// The status line of every response starts with the protocol name.
+const RESPONSE_START: &[u8] = b"RTSP/";
+
+/// Upper bound for the message head; a peer that sends more without a blank line is misbehaving.
+pub const MAX_HEAD_SIZE: usize = 64 * 1024;
+
+/// Upper bound for the message body, so that a bogus `Content-Length` cannot make the receiver buffer forever.
+pub const MAX_BODY_SIZE: usize = 1024 * 1024;
+
+/// Represents either an RTSP request or an RTSP response.
+///
+/// Both clients and servers can receive requests and responses on the same connection
+/// (e.g. `SET_PARAMETER` sent by a server), so the decoding side works with this enum.
+#[derive(Debug, Clone, PartialEq)]
+pub enum RtspMessage {
+    Request(RtspRequest),
+    Response(RtspResponse),
+}
+
+impl PayloadParser for RtspMessage {
+    fn parse(data: &[u8]) -> Result<Self, ParsingError> {
+        match Self::parse_partial(data)? {
+            Some((message, _)) => Ok(message),
+            None => Err(ParsingError::from_bytes(data)),
+        }
+    }
+}
+
+impl RtspMessage {
+    /// Parses a message from the beginning of a buffer that may hold an incomplete message.
+    ///
+    /// Leading empty lines are skipped. The body is read according to the `Content-Length`
+    /// header; bytes after the body are left untouched.
+    ///
+    /// # Arguments
+    ///
+    /// * `data` - A byte slice received from the connection.
+    ///
+    /// # Returns
+    ///
+    /// `Ok(None)` if more data is needed, or `Ok(Some((message, consumed)))` where `consumed`
+    /// is the number of bytes the message occupies in `data`.
+    pub fn parse_partial(data: &[u8]) -> Result<Option<(Self, usize)>, ParsingError> {
+        let skipped = data.iter().take_while(|byte| **byte == b'\r' || **byte == b'\n').count();
+        let slice = &data[skipped..];
+
+        let Some((head, _)) = slice.separate(HEADER_END) else {
+            if slice.len() > MAX_HEAD_SIZE {
+                return Err(ParsingError::from_str("RTSP message head is too large"));
+            }
+            return Ok(None);
+        };
+        let (start_line, headers, rest) = split_head(slice)?;
+        let Some(body) = split_body(&headers, rest)? else {
+            return Ok(None);
+        };
+        let consumed = skipped + head.len() + HEADER_END.len() + body.len();
+
+        let message = if start_line.starts_with(RESPONSE_START) {
+            RtspMessage::Response(RtspResponse::from_parts(start_line, headers, body)?)
+        } else {
+            RtspMessage::Request(RtspRequest::from_parts(start_line, headers, body)?)
+        };
+        Ok(Some((message, consumed)))
+    }
+
+    pub fn headers(&self) -> &RtspHeaders {
+        match self {
+            RtspMessage::Request(request) => request.headers(),
+            RtspMessage::Response(response) => response.headers(),
+        }
+    }
+
+    pub fn body(&self) -> &[u8] {
+        match self {
+            RtspMessage::Request(request) => request.body(),
+            RtspMessage::Response(response) => response.body(),
+        }
+    }
+
+    /// Returns the value of the `CSeq` header.
+    pub fn cseq(&self) -> Option<u32> {
+        self.headers().cseq()
+    }
+
+    /// Converts the `RtspMessage` to a byte vector.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        match self {
+            RtspMessage::Request(request) => request.to_bytes(),
+            RtspMessage::Response(response) => response.to_bytes(),
+        }
+    }
+}
+
+impl From<RtspRequest> for RtspMessage {
+    fn from(value: RtspRequest) -> Self {
+        RtspMessage::Request(value)
+    }
+}
+
+impl From<RtspResponse> for RtspMessage {
+    fn from(value: RtspResponse) -> Self {
+        RtspMessage::Response(value)
+    }
+}
+
+/// Splits a message into the start line, the parsed headers and the bytes after the head.
+///
+/// # Arguments
+///
+/// * `data` - A byte slice starting with the request line or the status line.
+///
+/// # Returns
+///
+/// A `Result` containing the start line, the headers and the remaining bytes if successful,
+/// or a `ParsingError` if the head is not terminated by an empty line or is malformed.
+pub(crate) fn split_head(data: &[u8]) -> Result<(&[u8], RtspHeaders, &[u8]), ParsingError> {
+    let (head, rest) = data
+        .separate(HEADER_END)
+        .ok_or_else(|| ParsingError::from_bytes(data))?;
+    let (start_line, headers) = head
+        .while_separate(NEW_LINE)
+        .ok_or_else(|| ParsingError::from_bytes(data))?;
+    Ok((start_line, RtspHeaders::parse(headers)?, rest))
+}
+
+/// Returns the body announced by `Content-Length`, or `None` if `rest` is too short to hold it.
+///
+/// A message without the header has no body; a header that is not a number or exceeds
+/// `MAX_BODY_SIZE` is an error, as the end of the message cannot be found or would never arrive.
+pub(crate) fn split_body<'a>(headers: &RtspHeaders, rest: &'a [u8]) -> Result<Option<&'a [u8]>, ParsingError> {
+    let content_length = match headers.get(CONTENT_LENGTH) {
+        Some(value) => value
+            .trim()
+            .parse::<usize>()
+            .map_err(|_| ParsingError::from_str("Invalid Content-Length header"))?,
+        None => 0,
+    };
+    if content_length > MAX_BODY_SIZE {
+        return Err(ParsingError::from_str("RTSP message body is too large"));
+    }
+    Ok((rest.len() >= content_length).then(|| &rest[..content_length]))
+}
@@ -0,0 +1,79 @@
+use abstractions::{extensions::utf8_array_extensions::U8ArrayExt, parsing::parsing_error::ParsingError};
+
+/// Represents the method of an RTSP request.
+///
+/// The `RtspMethod` enum corresponds to the first token of the request line, as
+/// defined in RFC 2326, section 10.
+///
+/// Example:
+///
+/// ```text
+/// DESCRIBE rtsp://example.com/media.mp4 RTSP/1.0
+/// ```
+///
+/// # Variants
+///
+/// * `Options` - Queries the methods supported by the server.
+/// * `Describe` - Retrieves the description of a presentation.
+/// * `Announce` - Posts the description of a presentation.
+/// * `Setup` - Specifies the transport mechanism for a stream.
+/// * `Play` - Starts data transmission.
+/// * `Pause` - Temporarily halts data transmission.
+/// * `Teardown` - Stops data transmission and frees the session.
+/// * `GetParameter` - Retrieves the value of a parameter.
+/// * `SetParameter` - Sets the value of a parameter.
+/// * `Redirect` - Informs the client that it must connect to another location.
+/// * `Record` - Starts recording a range of media data.
+/// * `Unknown` - Extension method not defined by RFC 2326.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum RtspMethod {
+    Options,
+    Describe,
+    Announce,
+    Setup,
+    Play,
+    Pause,
+    Teardown,
+    GetParameter,
+    SetParameter,
+    Redirect,
+    Record,
+    Unknown(String),
+}
+
+impl RtspMethod {
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, ParsingError> {
+        match bytes {
+            b"OPTIONS" => Ok(RtspMethod::Options),
+            b"DESCRIBE" => Ok(RtspMethod::Describe),
+            b"ANNOUNCE" => Ok(RtspMethod::Announce),
+            b"SETUP" => Ok(RtspMethod::Setup),
+            b"PLAY" => Ok(RtspMethod::Play),
+            b"PAUSE" => Ok(RtspMethod::Pause),
+            b"TEARDOWN" => Ok(RtspMethod::Teardown),
+            b"GET_PARAMETER" => Ok(RtspMethod::GetParameter),
+            b"SET_PARAMETER" => Ok(RtspMethod::SetParameter),
+            b"REDIRECT" => Ok(RtspMethod::Redirect),
+            b"RECORD" => Ok(RtspMethod::Record),
+            _ if bytes.is_empty() => Err(ParsingError::from_bytes(bytes)),
+            _ => Ok(RtspMethod::Unknown(bytes.utf8_to_str()?.to_string())),
+        }
+    }
+
+    pub fn as_str(&self) -> &str {
+        match self {
+            RtspMethod::Options => "OPTIONS",
+            RtspMethod::Describe => "DESCRIBE",
+            RtspMethod::Announce => "ANNOUNCE",
+            RtspMethod::Setup => "SETUP",
+            RtspMethod::Play => "PLAY",
+            RtspMethod::Pause => "PAUSE",
+            RtspMethod::Teardown => "TEARDOWN",
+            RtspMethod::GetParameter => "GET_PARAMETER",
+            RtspMethod::SetParameter => "SET_PARAMETER",
+            RtspMethod::Redirect => "REDIRECT",
+            RtspMethod::Record => "RECORD",
+            RtspMethod::Unknown(name) => name,
+        }
+    }
+}
@@ -0,0 +1,160 @@
+use std::fmt::Write;
+
+use abstractions::{
+    extensions::array_extensions::ArrayExt,
+    parsing::{parsing_error::ParsingError, payload_parser::PayloadParser, WHITESPACE},
+};
+use http::Uri;
+
+use crate::{
+    rtsp_headers::{RtspHeaders, CONTENT_LENGTH, CONTENT_TYPE, CSEQ},
+    rtsp_message::{split_body, split_head},
+    rtsp_method::RtspMethod,
+    rtsp_version::RtspVersion,
+};
+
+/// Represents an RTSP request.
+///
+/// According to RFC 2326, section 6, a request has the following syntax:
+///
+/// ```text
+/// <method> <request-uri> <rtsp-version>CRLF
+/// *(<header>CRLF)
+/// CRLF
+/// [<message-body>]
+/// ```
+///
+/// Example:
+///
+/// ```text
+/// DESCRIBE rtsp://example.com/media.mp4 RTSP/1.0
+/// CSeq: 2
+/// Accept: application/sdp
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct RtspRequest {
+    method: RtspMethod,
+    uri: Uri,
+    version: RtspVersion,
+    headers: RtspHeaders,
+    body: Vec<u8>,
+}
+
+impl PayloadParser for RtspRequest {
+    fn parse(data: &[u8]) -> Result<Self, ParsingError> {
+        let (request_line, headers, rest) = split_head(data)?;
+        let body = split_body(&headers, rest)?
+            .ok_or_else(|| ParsingError::from_str("Buffer too short to contain RTSP message body"))?;
+        Self::from_parts(request_line, headers, body)
+    }
+}
+
+impl RtspRequest {
+    /// Creates a new `RtspRequest` without headers and body.
+    ///
+    /// # Arguments
+    ///
+    /// * `method` - The request method.
+    /// * `uri` - The request URI, or `*` for requests that do not apply to a resource.
+    ///
+    /// # Returns
+    ///
+    /// A new `RtspRequest` instance.
+    pub fn new(method: RtspMethod, uri: Uri) -> Self {
+        Self {
+            method,
+            uri,
+            version: RtspVersion::default(),
+            headers: RtspHeaders::default(),
+            body: Vec::new(),
+        }
+    }
+
+    pub(crate) fn from_parts(request_line: &[u8], headers: RtspHeaders, body: &[u8]) -> Result<Self, ParsingError> {
+        let (method, other) = request_line
+            .separate(WHITESPACE)
+            .ok_or_else(|| ParsingError::from_bytes(request_line))?;
+        let (uri, version) = other
+            .separate_trimmed(WHITESPACE, WHITESPACE)
+            .ok_or_else(|| ParsingError::from_bytes(request_line))?;
+
+        Ok(Self {
+            method: RtspMethod::from_bytes(method)?,
+            uri: Uri::try_from(uri).map_err(|_| ParsingError::from_bytes(uri))?,
+            version: RtspVersion::from_bytes(version)?,
+            headers,
+            body: body.to_vec(),
+        })
+    }
+
+    pub fn method(&self) -> &RtspMethod {
+        &self.method
+    }
+
+    pub fn uri(&self) -> &Uri {
+        &self.uri
+    }
+
+    pub fn version(&self) -> RtspVersion {
+        self.version
+    }
+
+    pub fn headers(&self) -> &RtspHeaders {
+        &self.headers
+    }
+
+    pub fn headers_mut(&mut self) -> &mut RtspHeaders {
+        &mut self.headers
+    }
+
+    pub fn body(&self) -> &[u8] {
+        &self.body
+    }
+
+    /// Returns the value of the `CSeq` header.
+    pub fn cseq(&self) -> Option<u32> {
+        self.headers.cseq()
+    }
+
+    pub fn set_cseq(&mut self, cseq: u32) {
+        self.headers.insert(CSEQ, &cseq.to_string());
+    }
+
+    /// Replaces the message body and updates the `Content-Type` and `Content-Length` headers.
+    ///
+    /// # Arguments
+    ///
+    /// * `content_type` - The media type of the body, e.g. `application/sdp`.
+    /// * `body` - The body bytes.
+    pub fn set_body(&mut self, content_type: &str, body: Vec<u8>) {
+        self.headers.insert(CONTENT_TYPE, content_type);
+        self.headers.insert(CONTENT_LENGTH, &body.len().to_string());
+        self.body = body;
+    }
+
+    /// Writes the `RtspRequest` to a buffer byte vector.
+    ///
+    /// # Arguments
+    ///
+    /// * `buffer` - The buffer to append the request to.
+    pub fn write_to(&self, buffer: &mut Vec<u8>) {
+        let mut head = String::new();
+        let _ = write!(
+            head,
+            "{} {} {}\r\n{}\r\n",
+            self.method.as_str(),
+            self.uri,
+            self.version.as_str(),
+            self.headers
+        );
+        buffer.extend_from_slice(head.as_bytes());
+        buffer.extend_from_slice(&self.body);
+    }
+
+    /// Converts the `RtspRequest` to a byte vector.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut buffer = Vec::new();
+        self.write_to(&mut buffer);
+        buffer
+    }
+}
@@ -0,0 +1,159 @@
+use std::fmt::Write;
+
+use abstractions::{
+    extensions::{array_extensions::ArrayExt, utf8_array_extensions::U8ArrayExt},
+    parsing::{parsing_error::ParsingError, payload_parser::PayloadParser, WHITESPACE},
+};
+
+use crate::{
+    rtsp_headers::{RtspHeaders, CONTENT_LENGTH, CONTENT_TYPE, CSEQ},
+    rtsp_message::{split_body, split_head},
+    rtsp_version::RtspVersion,
+    status_code::StatusCode,
+};
+
+/// Represents an RTSP response.
+///
+/// According to RFC 2326, section 7, a response has the following syntax:
+///
+/// ```text
+/// <rtsp-version> <status-code> <reason-phrase>CRLF
+/// *(<header>CRLF)
+/// CRLF
+/// [<message-body>]
+/// ```
+///
+/// Example:
+///
+/// ```text
+/// RTSP/1.0 200 OK
+/// CSeq: 2
+/// Content-Type: application/sdp
+/// Content-Length: 460
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RtspResponse {
+    version: RtspVersion,
+    status: StatusCode,
+    reason_phrase: String,
+    headers: RtspHeaders,
+    body: Vec<u8>,
+}
+
+impl PayloadParser for RtspResponse {
+    fn parse(data: &[u8]) -> Result<Self, ParsingError> {
+        let (status_line, headers, rest) = split_head(data)?;
+        let body = split_body(&headers, rest)?
+            .ok_or_else(|| ParsingError::from_str("Buffer too short to contain RTSP message body"))?;
+        Self::from_parts(status_line, headers, body)
+    }
+}
+
+impl RtspResponse {
+    /// Creates a new `RtspResponse` with the default reason phrase of the status code.
+    ///
+    /// # Arguments
+    ///
+    /// * `status` - The response status code.
+    ///
+    /// # Returns
+    ///
+    /// A new `RtspResponse` instance.
+    pub fn new(status: StatusCode) -> Self {
+        Self {
+            version: RtspVersion::default(),
+            status,
+            reason_phrase: status.reason_phrase().to_string(),
+            headers: RtspHeaders::default(),
+            body: Vec::new(),
+        }
+    }
+
+    pub(crate) fn from_parts(status_line: &[u8], headers: RtspHeaders, body: &[u8]) -> Result<Self, ParsingError> {
+        let (version, other) = status_line
+            .separate(WHITESPACE)
+            .ok_or_else(|| ParsingError::from_bytes(status_line))?;
+        let (status, reason_phrase) = other
+            .while_separate_trimmed(WHITESPACE, WHITESPACE)
+            .ok_or_else(|| ParsingError::from_bytes(status_line))?;
+
+        Ok(Self {
+            version: RtspVersion::from_bytes(version)?,
+            status: StatusCode::from_u16(status.utf8_to_number::<u16>()?),
+            reason_phrase: reason_phrase.utf8_to_str()?.to_string(),
+            headers,
+            body: body.to_vec(),
+        })
+    }
+
+    pub fn version(&self) -> RtspVersion {
+        self.version
+    }
+
+    pub fn status(&self) -> StatusCode {
+        self.status
+    }
+
+    pub fn reason_phrase(&self) -> &str {
+        &self.reason_phrase
+    }
+
+    pub fn headers(&self) -> &RtspHeaders {
+        &self.headers
+    }
+
+    pub fn headers_mut(&mut self) -> &mut RtspHeaders {
+        &mut self.headers
+    }
+
+    pub fn body(&self) -> &[u8] {
+        &self.body
+    }
+
+    /// Returns the value of the `CSeq` header.
+    pub fn cseq(&self) -> Option<u32> {
+        self.headers.cseq()
+    }
+
+    pub fn set_cseq(&mut self, cseq: u32) {
+        self.headers.insert(CSEQ, &cseq.to_string());
+    }
+
+    /// Replaces the message body and updates the `Content-Type` and `Content-Length` headers.
+    ///
+    /// # Arguments
+    ///
+    /// * `content_type` - The media type of the body, e.g. `application/sdp`.
+    /// * `body` - The body bytes.
+    pub fn set_body(&mut self, content_type: &str, body: Vec<u8>) {
+        self.headers.insert(CONTENT_TYPE, content_type);
+        self.headers.insert(CONTENT_LENGTH, &body.len().to_string());
+        self.body = body;
+    }
+
+    /// Writes the `RtspResponse` to a buffer byte vector.
+    ///
+    /// # Arguments
+    ///
+    /// * `buffer` - The buffer to append the response to.
+    pub fn write_to(&self, buffer: &mut Vec<u8>) {
+        let mut head = String::new();
+        let _ = write!(
+            head,
+            "{} {} {}\r\n{}\r\n",
+            self.version.as_str(),
+            self.status.as_u16(),
+            self.reason_phrase,
+            self.headers
+        );
+        buffer.extend_from_slice(head.as_bytes());
+        buffer.extend_from_slice(&self.body);
+    }
+
+    /// Converts the `RtspResponse` to a byte vector.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut buffer = Vec::new();
+        self.write_to(&mut buffer);
+        buffer
+    }
+}
@@ -0,0 +1,270 @@
+use std::{fmt, net::IpAddr, str::FromStr};
+
+use abstractions::{
+    extensions::{array_extensions::ArrayExt, utf8_array_extensions::U8ArrayExt},
+    parsing::{parsing_error::ParsingError, payload_parser::PayloadParser, COMMA, EQUAL, SEMICOLON, WHITESPACE},
+};
+
+const RTP_AVP: &[u8] = b"RTP/AVP";
+const RTP_AVP_UDP: &[u8] = b"RTP/AVP/UDP";
+const RTP_AVP_TCP: &[u8] = b"RTP/AVP/TCP";
+const UNICAST: &[u8] = b"unicast";
+const MULTICAST: &[u8] = b"multicast";
+const DESTINATION: &[u8] = b"destination";
+const SOURCE: &[u8] = b"source";
+const INTERLEAVED: &[u8] = b"interleaved";
+const TTL: &[u8] = b"ttl";
+const PORT: &[u8] = b"port";
+const CLIENT_PORT: &[u8] = b"client_port";
+const SERVER_PORT: &[u8] = b"server_port";
+const SSRC: &[u8] = b"ssrc";
+const MODE: &[u8] = b"mode";
+const DASH: &[u8] = b"-";
+const QUOTE: &[u8] = b"\"";
+
+/// Represents the lower transport of an RTP stream negotiated with `SETUP`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LowerTransport {
+    #[default]
+    Udp,
+    Tcp,
+}
+
+/// Represents a single transport specification of the `Transport` header.
+///
+/// According to RFC 2326, section 12.39, the header has the following syntax:
+///
+/// ```text
+/// Transport: RTP/AVP[/<lower-transport>];<unicast|multicast>;<parameter>=<value>;...
+/// ```
+///
+/// Example:
+///
+/// ```text
+/// Transport: RTP/AVP;unicast;client_port=5000-5001;server_port=6256-6257;ssrc=2A3F5C10
+/// Transport: RTP/AVP/TCP;unicast;interleaved=0-1
+/// ```
+///
+/// Only the first specification of a comma-separated list is parsed; servers answer
+/// with exactly one specification.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct RtspTransport {
+    lower_transport: LowerTransport,
+    multicast: bool,
+    destination: Option<IpAddr>,
+    source: Option<IpAddr>,
+    interleaved: Option<(u8, u8)>,
+    ttl: Option<u8>,
+    port: Option<(u16, u16)>,
+    client_port: Option<(u16, u16)>,
+    server_port: Option<(u16, u16)>,
+    ssrc: Option<u32>,
+    mode: Option<String>,
+}
+
+impl PayloadParser for RtspTransport {
+    fn parse(data: &[u8]) -> Result<Self, ParsingError> {
+        let (spec, _) = data
+            .while_separate_trimmed(COMMA, WHITESPACE)
+            .ok_or_else(|| ParsingError::from_bytes(data))?;
+        let (protocol, mut current) = spec
+            .while_separate_trimmed(SEMICOLON, WHITESPACE)
+            .ok_or_else(|| ParsingError::from_bytes(data))?;
+
+        let mut transport = RtspTransport {
+            lower_transport: match protocol {
+                RTP_AVP | RTP_AVP_UDP => LowerTransport::Udp,
+                RTP_AVP_TCP => LowerTransport::Tcp,
+                _ => return Err(ParsingError::from_bytes(protocol)),
+            },
+            ..Default::default()
+        };
+
+        while let Some((parameter, other)) = current.while_separate_trimmed(SEMICOLON, WHITESPACE) {
+            let (name, value) = parameter
+                .while_separate_trimmed(EQUAL, WHITESPACE)
+                .ok_or_else(|| ParsingError::from_bytes(parameter))?;
+            match name {
+                UNICAST => transport.multicast = false,
+                MULTICAST => transport.multicast = true,
+                DESTINATION if !value.is_empty() => transport.destination = Some(get_address(value)?),
+                SOURCE => transport.source = Some(get_address(value)?),
+                INTERLEAVED => {
+                    let (rtp, rtcp) = get_range::<u8>(value)?;
+                    transport.interleaved = Some((rtp, rtcp));
+                }
+                TTL => transport.ttl = Some(value.utf8_to_number::<u8>()?),
+                PORT => transport.port = Some(get_range::<u16>(value)?),
+                CLIENT_PORT => transport.client_port = Some(get_range::<u16>(value)?),
+                SERVER_PORT => transport.server_port = Some(get_range::<u16>(value)?),
+                SSRC => {
+                    transport.ssrc = Some(
+                        u32::from_str_radix(value.utf8_to_str()?, 16)
+                            .map_err(|_| ParsingError::from_bytes(value))?,
+                    )
+                }
+                MODE => transport.mode = Some(value.trim(QUOTE).utf8_to_str()?.to_string()),
+                _ => {}
+            }
+            current = other;
+        }
+        Ok(transport)
+    }
+}
+
+impl RtspTransport {
+    /// Creates a unicast UDP transport that asks the server to send to the given client ports.
+    ///
+    /// # Arguments
+    ///
+    /// * `rtp_port` - The client port for RTP.
+    /// * `rtcp_port` - The client port for RTCP, usually `rtp_port + 1`.
+    pub fn udp(rtp_port: u16, rtcp_port: u16) -> Self {
+        Self {
+            lower_transport: LowerTransport::Udp,
+            client_port: Some((rtp_port, rtcp_port)),
+            ..Default::default()
+        }
+    }
+
+    /// Creates a unicast transport that carries RTP and RTCP over the RTSP connection.
+    ///
+    /// # Arguments
+    ///
+    /// * `rtp_channel` - The interleaved channel for RTP.
+    /// * `rtcp_channel` - The interleaved channel for RTCP, usually `rtp_channel + 1`.
+    pub fn tcp(rtp_channel: u8, rtcp_channel: u8) -> Self {
+        Self {
+            lower_transport: LowerTransport::Tcp,
+            interleaved: Some((rtp_channel, rtcp_channel)),
+            ..Default::default()
+        }
+    }
+
+    pub fn lower_transport(&self) -> LowerTransport {
+        self.lower_transport
+    }
+
+    pub fn is_multicast(&self) -> bool {
+        self.multicast
+    }
+
+    pub fn destination(&self) -> Option<IpAddr> {
+        self.destination
+    }
+
+    pub fn source(&self) -> Option<IpAddr> {
+        self.source
+    }
+
+    pub fn interleaved(&self) -> Option<(u8, u8)> {
+        self.interleaved
+    }
+
+    pub fn ttl(&self) -> Option<u8> {
+        self.ttl
+    }
+
+    pub fn port(&self) -> Option<(u16, u16)> {
+        self.port
+    }
+
+    pub fn client_port(&self) -> Option<(u16, u16)> {
+        self.client_port
+    }
+
+    pub fn server_port(&self) -> Option<(u16, u16)> {
+        self.server_port
+    }
+
+    pub fn ssrc(&self) -> Option<u32> {
+        self.ssrc
+    }
+
+    pub fn mode(&self) -> Option<&str> {
+        self.mode.as_deref()
+    }
+
+    pub fn set_destination(&mut self, destination: Option<IpAddr>) {
+        self.destination = destination;
+    }
+
+    pub fn set_source(&mut self, source: Option<IpAddr>) {
+        self.source = source;
+    }
+
+    pub fn set_interleaved(&mut self, interleaved: Option<(u8, u8)>) {
+        self.interleaved = interleaved;
+    }
+
+    pub fn set_server_port(&mut self, server_port: Option<(u16, u16)>) {
+        self.server_port = server_port;
+    }
+
+    pub fn set_ssrc(&mut self, ssrc: Option<u32>) {
+        self.ssrc = ssrc;
+    }
+
+    pub fn set_mode(&mut self, mode: Option<String>) {
+        self.mode = mode;
+    }
+}
+
+impl fmt::Display for RtspTransport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.lower_transport {
+            LowerTransport::Udp => write!(f, "RTP/AVP")?,
+            LowerTransport::Tcp => write!(f, "RTP/AVP/TCP")?,
+        }
+        write!(f, ";{}", if self.multicast { "multicast" } else { "unicast" })?;
+        if let Some(destination) = self.destination {
+            write!(f, ";destination={}", destination)?;
+        }
+        if let Some(source) = self.source {
+            write!(f, ";source={}", source)?;
+        }
+        if let Some((rtp, rtcp)) = self.interleaved {
+            write!(f, ";interleaved={}-{}", rtp, rtcp)?;
+        }
+        if let Some(ttl) = self.ttl {
+            write!(f, ";ttl={}", ttl)?;
+        }
+        if let Some((rtp, rtcp)) = self.port {
+            write!(f, ";port={}-{}", rtp, rtcp)?;
+        }
+        if let Some((rtp, rtcp)) = self.client_port {
+            write!(f, ";client_port={}-{}", rtp, rtcp)?;
+        }
+        if let Some((rtp, rtcp)) = self.server_port {
+            write!(f, ";server_port={}-{}", rtp, rtcp)?;
+        }
+        if let Some(ssrc) = self.ssrc {
+            write!(f, ";ssrc={:08X}", ssrc)?;
+        }
+        if let Some(mode) = &self.mode {
+            write!(f, ";mode=\"{}\"", mode)?;
+        }
+        Ok(())
+    }
+}
+
+fn get_address(data: &[u8]) -> Result<IpAddr, ParsingError> {
+    IpAddr::from_str(data.utf8_to_str()?).map_err(|_| ParsingError::from_bytes(data))
+}
+
+/// Parses a `<first>[-<second>]` range; a single value implies `second = first + 1`.
+fn get_range<T>(data: &[u8]) -> Result<(T, T), ParsingError>
+where
+    T: num::Num + num::CheckedAdd + num::CheckedMul + num::traits::CheckedNeg + num::CheckedDiv + num::NumCast + Copy,
+{
+    match data.separate_trimmed(DASH, WHITESPACE) {
+        Some((first, second)) => Ok((first.utf8_to_number::<T>()?, second.utf8_to_number::<T>()?)),
+        None => {
+            let first = data.utf8_to_number::<T>()?;
+            let second = first
+                .checked_add(&T::one())
+                .ok_or_else(|| ParsingError::from_bytes(data))?;
+            Ok((first, second))
+        }
+    }
+}
@@ -0,0 +1,33 @@
+use abstractions::parsing::parsing_error::ParsingError;
+
+/// Represents the protocol version of an RTSP message.
+///
+/// The version is carried in the request line and in the status line:
+///
+/// ```text
+/// OPTIONS * RTSP/1.0
+/// RTSP/1.0 200 OK
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RtspVersion {
+    #[default]
+    V1_0,
+    V2_0,
+}
+
+impl RtspVersion {
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, ParsingError> {
+        match bytes {
+            b"RTSP/1.0" => Ok(RtspVersion::V1_0),
+            b"RTSP/2.0" => Ok(RtspVersion::V2_0),
+            _ => Err(ParsingError::from_bytes(bytes)),
+        }
+    }
+
+    pub fn as_str(&self) -> &str {
+        match self {
+            RtspVersion::V1_0 => "RTSP/1.0",
+            RtspVersion::V2_0 => "RTSP/2.0",
+        }
+    }
+}
@@ -0,0 +1,7 @@
+/// Module containing the RTSP server.
+///
+/// The server accepts TCP connections, routes requests by URL path to registered mounts,
+/// and streams the RTP packets published on a mount to every client that plays it.
+pub mod mount;
+pub mod rtsp_server;
+pub mod server_session;
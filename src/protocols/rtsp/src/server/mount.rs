@@ -0,0 +1,105 @@
+use std::sync::Arc;
+
+use abstractions::parsing::{parsing_error::ParsingError, payload_parser::PayloadParser};
+use rtp::rtp_packet::RtpPacket;
+use sdp::media_session::MediaSession;
+use tokio::sync::broadcast;
+
+/// Number of packets a slow client may fall behind before it starts losing packets.
+const CHANNEL_CAPACITY: usize = 1024;
+
+/// Represents an RTP packet published on a mount.
+///
+/// # Fields
+///
+/// * `track` - The index of the media description the packet belongs to.
+/// * `data` - The serialized RTP packet.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MountPacket {
+    track: usize,
+    data: Arc<[u8]>,
+}
+
+impl MountPacket {
+    pub fn track(&self) -> usize {
+        self.track
+    }
+
+    pub fn data(&self) -> &[u8] {
+        &self.data
+    }
+}
+
+/// Represents a presentation served under a URL path.
+///
+/// A mount owns the session description returned by `DESCRIBE` and fans the RTP packets
+/// of every track out to all clients currently playing it.
+#[derive(Debug)]
+pub struct Mount {
+    media_session: MediaSession,
+    sdp: Vec<u8>,
+    sender: broadcast::Sender<MountPacket>,
+}
+
+impl Mount {
+    /// Creates a new `Mount` from a session description.
+    ///
+    /// # Arguments
+    ///
+    /// * `sdp` - The SDP text describing the tracks of the presentation.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the mount, or a `ParsingError` if the description is invalid.
+    pub fn new(sdp: &[u8]) -> Result<Self, ParsingError> {
+        let media_session = MediaSession::parse(sdp)?;
+        let (sender, _) = broadcast::channel(CHANNEL_CAPACITY);
+        Ok(Self {
+            sdp: media_session.to_bytes(),
+            media_session,
+            sender,
+        })
+    }
+
+    pub fn media_session(&self) -> &MediaSession {
+        &self.media_session
+    }
+
+    /// Returns the session description sent in response to `DESCRIBE`.
+    ///
+    /// The description is serialized from the parsed `MediaSession`, so it is always in canonical form.
+    pub fn sdp(&self) -> &[u8] {
+        &self.sdp
+    }
+
+    pub fn track_count(&self) -> usize {
+        self.media_session.media_descriptions().len()
+    }
+
+    /// Publishes an RTP packet to every client playing the track.
+    ///
+    /// # Arguments
+    ///
+    /// * `track` - The index of the media description the packet belongs to.
+    /// * `packet` - The RTP packet.
+    ///
+    /// # Returns
+    ///
+    /// The number of clients the packet was queued for.
+    pub fn send(&self, track: usize, packet: &RtpPacket) -> usize {
+        let mut data = vec![0u8; packet.byte_size()];
+        packet.write(&mut data);
+        self.sender
+            .send(MountPacket { track, data: data.into() })
+            .unwrap_or(0)
+    }
+
+    /// Returns the number of clients currently receiving packets.
+    pub fn receiver_count(&self) -> usize {
+        self.sender.receiver_count()
+    }
+
+    pub(crate) fn subscribe(&self) -> broadcast::Receiver<MountPacket> {
+        self.sender.subscribe()
+    }
+}
@@ -0,0 +1,488 @@
+use std::{
+    collections::HashMap,
+    net::SocketAddr,
+    sync::{Arc, RwLock},
+};
+
+use abstractions::parsing::payload_parser::PayloadParser;
+use http::Uri;
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::{TcpListener, TcpStream, ToSocketAddrs, UdpSocket},
+    sync::{broadcast::error::RecvError, mpsc, watch},
+    task::JoinHandle,
+};
+
+use crate::{
+    client::rtsp_client::resolve_control,
+    interleaved_frame::InterleavedFrame,
+    rtsp_decoder::RtspDecoder,
+    rtsp_error::RtspError,
+    rtsp_frame::RtspFrame,
+    rtsp_headers::{CONTENT_BASE, PUBLIC, RANGE, SERVER, SESSION, TRANSPORT},
+    rtsp_message::RtspMessage,
+    rtsp_method::RtspMethod,
+    rtsp_request::RtspRequest,
+    rtsp_response::RtspResponse,
+    rtsp_transport::{LowerTransport, RtspTransport},
+    server::{
+        mount::Mount,
+        server_session::{ServerSession, SessionState, SessionTrack},
+    },
+    status_code::StatusCode,
+};
+
+const SERVER_NAME: &str = "rtsp-rs";
+const SDP_CONTENT_TYPE: &str = "application/sdp";
+const PUBLIC_METHODS: &str = "OPTIONS, DESCRIBE, SETUP, PLAY, PAUSE, TEARDOWN, GET_PARAMETER";
+const READ_BUFFER_SIZE: usize = 4096;
+/// Number of responses and interleaved frames queued for a connection before frames are dropped.
+const WRITE_QUEUE_CAPACITY: usize = 1024;
+
+/// An RTSP server that serves mounts registered under URL paths.
+///
+/// The server is cheap to clone; all clones share the same set of mounts, so mounts can be
+/// added or removed while the server is running.
+///
+/// # Example
+///
+/// ```no_run
+/// use rtsp::server::{mount::Mount, rtsp_server::RtspServer};
+///
+/// # async fn run(sdp: &[u8]) -> Result<(), rtsp::rtsp_error::RtspError> {
+/// let server = RtspServer::new();
+/// let mount = server.add_mount("/live", Mount::new(sdp)?);
+/// tokio::spawn(server.clone().run("0.0.0.0:8554"));
+/// // mount.send(track, &packet) for every packet of the encoder
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct RtspServer {
+    mounts: Arc<RwLock<HashMap<String, Arc<Mount>>>>,
+}
+
+impl RtspServer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers a mount under a URL path, replacing any mount with the same path.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The URL path, e.g. `/live`.
+    /// * `mount` - The mount to serve.
+    ///
+    /// # Returns
+    ///
+    /// The shared mount, used to publish RTP packets.
+    pub fn add_mount(&self, path: &str, mount: Mount) -> Arc<Mount> {
+        let mount = Arc::new(mount);
+        self.mounts
+            .write()
+            .unwrap_or_else(|e| e.into_inner())
+            .insert(normalize_path(path), mount.clone());
+        mount
+    }
+
+    pub fn remove_mount(&self, path: &str) -> Option<Arc<Mount>> {
+        self.mounts
+            .write()
+            .unwrap_or_else(|e| e.into_inner())
+            .remove(&normalize_path(path))
+    }
+
+    pub fn mount(&self, path: &str) -> Option<Arc<Mount>> {
+        self.mounts
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .get(&normalize_path(path))
+            .cloned()
+    }
+
+    /// Binds a TCP listener and serves connections until an accept error occurs.
+    pub async fn run(self, address: impl ToSocketAddrs) -> Result<(), RtspError> {
+        let listener = TcpListener::bind(address).await?;
+        self.serve(listener).await
+    }
+
+    /// Serves connections accepted by the listener, each one in its own task.
+    pub async fn serve(self, listener: TcpListener) -> Result<(), RtspError> {
+        loop {
+            let (stream, peer) = listener.accept().await?;
+            tokio::spawn(Connection::run(self.clone(), stream, peer));
+        }
+    }
+
+    /// Finds the mount with the longest path that is a prefix of the request path.
+    fn find_mount(&self, path: &str) -> Option<(String, Arc<Mount>)> {
+        let path = normalize_path(path);
+        self.mounts
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .iter()
+            .filter(|(key, _)| {
+                path == **key || key.as_str() == "/" || path.starts_with(&format!("{}/", key))
+            })
+            .max_by_key(|(key, _)| key.len())
+            .map(|(key, mount)| (key.clone(), mount.clone()))
+    }
+}
+
+/// Represents where the RTP packets of a track are sent.
+#[derive(Debug, Clone)]
+enum Target {
+    Udp(Arc<UdpSocket>, SocketAddr),
+    Interleaved(u8),
+}
+
+/// Handles the requests of one client connection.
+#[derive(Debug)]
+struct Connection {
+    server: RtspServer,
+    peer: SocketAddr,
+    local: SocketAddr,
+    writer: mpsc::Sender<Vec<u8>>,
+    session: Option<ServerSession>,
+    rtp_socket: Option<Arc<UdpSocket>>,
+    rtcp_socket: Option<UdpSocket>,
+    targets: watch::Sender<Vec<(usize, Target)>>,
+    forwarder: Option<JoinHandle<()>>,
+}
+
+impl Connection {
+    fn new(server: RtspServer, peer: SocketAddr, local: SocketAddr, writer: mpsc::Sender<Vec<u8>>) -> Self {
+        Self {
+            server,
+            peer,
+            local,
+            writer,
+            session: None,
+            rtp_socket: None,
+            rtcp_socket: None,
+            targets: watch::Sender::new(Vec::new()),
+            forwarder: None,
+        }
+    }
+
+    /// Reads requests until the client disconnects.
+    ///
+    /// Responses and interleaved frames share the connection, so both are queued to a
+    /// writer task that owns the write half of the stream.
+    async fn run(server: RtspServer, stream: TcpStream, peer: SocketAddr) -> Result<(), RtspError> {
+        let local = stream.local_addr()?;
+        let (mut reader, mut writer) = stream.into_split();
+        let (sender, mut receiver) = mpsc::channel::<Vec<u8>>(WRITE_QUEUE_CAPACITY);
+        tokio::spawn(async move {
+            while let Some(data) = receiver.recv().await {
+                if writer.write_all(&data).await.is_err() {
+                    break;
+                }
+            }
+        });
+
+        let mut connection = Connection::new(server, peer, local, sender);
+        let mut decoder = RtspDecoder::new();
+        let mut buffer = [0u8; READ_BUFFER_SIZE];
+
+        loop {
+            let read = reader.read(&mut buffer).await?;
+            if read == 0 {
+                return Ok(());
+            }
+            decoder.extend(&buffer[..read]);
+
+            loop {
+                let frame = match decoder.decode_frame() {
+                    Ok(Some(frame)) => frame,
+                    Ok(None) => break,
+                    Err(e) => {
+                        connection.write(RtspResponse::new(StatusCode::BadRequest).to_bytes()).await?;
+                        return Err(e.into());
+                    }
+                };
+                // Interleaved frames from the client carry RTCP receiver reports, which are not used.
+                if let RtspFrame::Message(RtspMessage::Request(request)) = frame {
+                    let response = connection.handle(&request).await;
+                    connection.write(response.to_bytes()).await?;
+                }
+            }
+        }
+    }
+
+    async fn write(&self, data: Vec<u8>) -> Result<(), RtspError> {
+        self.writer.send(data).await.map_err(|_| RtspError::ConnectionClosed)
+    }
+
+    async fn handle(&mut self, request: &RtspRequest) -> RtspResponse {
+        let mut response = match self.dispatch(request).await {
+            Ok(response) => response,
+            Err(status) => RtspResponse::new(status),
+        };
+        if let Some(cseq) = request.cseq() {
+            response.set_cseq(cseq);
+        }
+        response.headers_mut().insert(SERVER, SERVER_NAME);
+        response
+    }
+
+    async fn dispatch(&mut self, request: &RtspRequest) -> Result<RtspResponse, StatusCode> {
+        if let Some(session_id) = request.headers().session_id() {
+            if self.session.as_ref().is_none_or(|session| session.id() != session_id) {
+                return Err(StatusCode::SessionNotFound);
+            }
+        }
+
+        match request.method() {
+            RtspMethod::Options => {
+                let mut response = RtspResponse::new(StatusCode::Ok);
+                response.headers_mut().insert(PUBLIC, PUBLIC_METHODS);
+                Ok(response)
+            }
+            RtspMethod::Describe => self.describe(request),
+            RtspMethod::Setup => self.setup(request).await,
+            RtspMethod::Play => self.play(),
+            RtspMethod::Pause => self.pause(),
+            RtspMethod::Teardown => self.teardown(),
+            RtspMethod::GetParameter => Ok(self.session_response()),
+            _ => Err(StatusCode::NotImplemented),
+        }
+    }
+
+    fn describe(&self, request: &RtspRequest) -> Result<RtspResponse, StatusCode> {
+        let (mount_path, mount) = self
+            .server
+            .find_mount(request.uri().path())
+            .ok_or(StatusCode::NotFound)?;
+
+        let mut response = RtspResponse::new(StatusCode::Ok);
+        response
+            .headers_mut()
+            .insert(CONTENT_BASE, &base_url(request.uri(), &mount_path, self.local).to_string());
+        response.set_body(SDP_CONTENT_TYPE, mount.sdp().to_vec());
+        Ok(response)
+    }
+
+    async fn setup(&mut self, request: &RtspRequest) -> Result<RtspResponse, StatusCode> {
+        let (mount_path, mount) = self
+            .server
+            .find_mount(request.uri().path())
+            .ok_or(StatusCode::NotFound)?;
+        if let Some(session) = &self.session {
+            if session.mount_path() != mount_path {
+                return Err(StatusCode::AggregateOperationNotAllowed);
+            }
+        }
+        let index = find_track(&mount, &base_url(request.uri(), &mount_path, self.local), request.uri())
+            .ok_or(StatusCode::NotFound)?;
+
+        let transport = request.headers().get(TRANSPORT).ok_or(StatusCode::BadRequest)?;
+        let mut transport =
+            RtspTransport::parse(transport.as_bytes()).map_err(|_| StatusCode::UnsupportedTransport)?;
+        if transport.is_multicast() {
+            return Err(StatusCode::UnsupportedTransport);
+        }
+
+        let rtp_target = match transport.lower_transport() {
+            LowerTransport::Udp => {
+                let (client_rtp_port, _) = transport.client_port().ok_or(StatusCode::UnsupportedTransport)?;
+                let server_port = self
+                    .bind_sockets()
+                    .await
+                    .map_err(|_| StatusCode::InternalServerError)?;
+                transport.set_server_port(Some(server_port));
+                Some(SocketAddr::new(self.peer.ip(), client_rtp_port))
+            }
+            LowerTransport::Tcp => {
+                if transport.interleaved().is_none() {
+                    let channel = u8::try_from(index * 2).map_err(|_| StatusCode::UnsupportedTransport)?;
+                    transport.set_interleaved(Some((channel, channel.saturating_add(1))));
+                }
+                None
+            }
+        };
+        transport.set_destination(None);
+
+        let session = self
+            .session
+            .get_or_insert_with(|| ServerSession::new(mount_path));
+        session.add_track(SessionTrack::new(index, transport.clone(), rtp_target));
+        if session.state() == SessionState::Init {
+            session.set_state(SessionState::Ready);
+        }
+        let header = session.header();
+        self.update_targets();
+
+        let mut response = RtspResponse::new(StatusCode::Ok);
+        response.headers_mut().insert(TRANSPORT, &transport.to_string());
+        response.headers_mut().insert(SESSION, &header);
+        Ok(response)
+    }
+
+    fn play(&mut self) -> Result<RtspResponse, StatusCode> {
+        let session = self.session.as_mut().ok_or(StatusCode::SessionNotFound)?;
+        if session.state() == SessionState::Init {
+            return Err(StatusCode::MethodNotValidInThisState);
+        }
+        let mount = self
+            .server
+            .mount(session.mount_path())
+            .ok_or(StatusCode::NotFound)?;
+
+        if self.forwarder.is_none() {
+            // Tracks set up while playing are picked up by the forwarder through the channel.
+            let targets = self.targets.subscribe();
+            let writer = self.writer.clone();
+            let mut receiver = mount.subscribe();
+
+            self.forwarder = Some(tokio::spawn(async move {
+                loop {
+                    let packet = match receiver.recv().await {
+                        Ok(packet) => packet,
+                        Err(RecvError::Lagged(_)) => continue,
+                        Err(RecvError::Closed) => break,
+                    };
+                    let track_targets = targets
+                        .borrow()
+                        .iter()
+                        .filter(|(index, _)| *index == packet.track())
+                        .map(|(_, target)| target.clone())
+                        .collect::<Vec<_>>();
+                    for target in track_targets {
+                        match target {
+                            Target::Udp(socket, address) => {
+                                let _ = socket.send_to(packet.data(), address).await;
+                            }
+                            Target::Interleaved(channel) => {
+                                // Packets too long for an interleaved frame cannot be forwarded.
+                                let Ok(bytes) = InterleavedFrame::new(channel, packet.data().to_vec()).to_bytes() else {
+                                    continue;
+                                };
+                                // A client that does not keep up loses frames instead of stalling the mount.
+                                if let Err(mpsc::error::TrySendError::Closed(_)) = writer.try_send(bytes) {
+                                    return;
+                                }
+                            }
+                        }
+                    }
+                }
+            }));
+        }
+        session.set_state(SessionState::Playing);
+
+        let mut response = self.session_response();
+        response.headers_mut().insert(RANGE, "npt=0.000-");
+        Ok(response)
+    }
+
+    fn pause(&mut self) -> Result<RtspResponse, StatusCode> {
+        let session = self.session.as_mut().ok_or(StatusCode::SessionNotFound)?;
+        if session.state() != SessionState::Playing {
+            return Err(StatusCode::MethodNotValidInThisState);
+        }
+        session.set_state(SessionState::Ready);
+        self.stop_forwarder();
+        Ok(self.session_response())
+    }
+
+    fn teardown(&mut self) -> Result<RtspResponse, StatusCode> {
+        if self.session.is_none() {
+            return Err(StatusCode::SessionNotFound);
+        }
+        let response = self.session_response();
+        self.stop_forwarder();
+        self.session = None;
+        self.rtp_socket = None;
+        self.rtcp_socket = None;
+        self.update_targets();
+        Ok(response)
+    }
+
+    fn session_response(&self) -> RtspResponse {
+        let mut response = RtspResponse::new(StatusCode::Ok);
+        if let Some(session) = &self.session {
+            response.headers_mut().insert(SESSION, &session.header());
+        }
+        response
+    }
+
+    /// Binds the RTP and RTCP sockets of the session once, returning their ports.
+    async fn bind_sockets(&mut self) -> Result<(u16, u16), RtspError> {
+        if self.rtp_socket.is_none() {
+            self.rtp_socket = Some(Arc::new(UdpSocket::bind((self.local.ip(), 0)).await?));
+            self.rtcp_socket = Some(UdpSocket::bind((self.local.ip(), 0)).await?);
+        }
+        let rtp_port = match &self.rtp_socket {
+            Some(socket) => socket.local_addr()?.port(),
+            None => 0,
+        };
+        let rtcp_port = match &self.rtcp_socket {
+            Some(socket) => socket.local_addr()?.port(),
+            None => 0,
+        };
+        Ok((rtp_port, rtcp_port))
+    }
+
+    /// Publishes where the packets of each track set up in the session are sent to the forwarder.
+    fn update_targets(&self) {
+        let tracks = self.session.as_ref().map_or(&[][..], |session| session.tracks());
+        let targets = tracks
+            .iter()
+            .filter_map(|track| {
+                let target = match (track.rtp_target(), track.transport().interleaved(), &self.rtp_socket) {
+                    (Some(address), _, Some(socket)) => Target::Udp(socket.clone(), address),
+                    (None, Some((channel, _)), _) => Target::Interleaved(channel),
+                    _ => return None,
+                };
+                Some((track.index(), target))
+            })
+            .collect::<Vec<_>>();
+        self.targets.send_replace(targets);
+    }
+
+    fn stop_forwarder(&mut self) {
+        if let Some(forwarder) = self.forwarder.take() {
+            forwarder.abort();
+        }
+    }
+}
+
+impl Drop for Connection {
+    fn drop(&mut self) {
+        self.stop_forwarder();
+    }
+}
+
+/// Builds the `Content-Base` of a mount, using the authority of the request when present.
+fn base_url(uri: &Uri, mount_path: &str, local: SocketAddr) -> Uri {
+    let authority = uri
+        .authority()
+        .map(|authority| authority.to_string())
+        .unwrap_or_else(|| local.to_string());
+    let path = mount_path.trim_end_matches('/');
+    format!("rtsp://{}{}/", authority, path)
+        .parse()
+        .unwrap_or_else(|_| uri.clone())
+}
+
+/// Maps the URL of a `SETUP` request to the index of a media description of the mount.
+fn find_track(mount: &Mount, base_url: &Uri, uri: &Uri) -> Option<usize> {
+    let path = uri.path().trim_end_matches('/');
+    let media_descriptions = mount.media_session().media_descriptions();
+
+    let index = media_descriptions.iter().position(|media_description| {
+        media_description
+            .control()
+            .and_then(|control| resolve_control(base_url, control).ok())
+            .is_some_and(|control| control.path().trim_end_matches('/') == path)
+    });
+    if index.is_none() && media_descriptions.len() == 1 {
+        return Some(0);
+    }
+    index
+}
+
+fn normalize_path(path: &str) -> String {
+    format!("/{}", path.trim_matches('/'))
+}
@@ -0,0 +1,120 @@
+use std::{
+    collections::hash_map::RandomState,
+    hash::{BuildHasher, Hasher},
+    net::SocketAddr,
+};
+
+use crate::rtsp_transport::RtspTransport;
+
+/// Session timeout in seconds announced in the `Session` header.
+pub const SESSION_TIMEOUT: u64 = 60;
+
+/// Represents the state of a session on the server side.
+///
+/// The states follow the server state machine of RFC 2326, appendix A.2.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SessionState {
+    #[default]
+    Init,
+    Ready,
+    Playing,
+}
+
+impl SessionState {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SessionState::Init => "Init",
+            SessionState::Ready => "Ready",
+            SessionState::Playing => "Playing",
+        }
+    }
+}
+
+/// Represents a track set up by a client.
+///
+/// # Fields
+///
+/// * `index` - The index of the media description in the mount.
+/// * `transport` - The transport confirmed to the client.
+/// * `rtp_target` - The address RTP packets are sent to, if the transport is UDP.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SessionTrack {
+    index: usize,
+    transport: RtspTransport,
+    rtp_target: Option<SocketAddr>,
+}
+
+impl SessionTrack {
+    pub fn new(index: usize, transport: RtspTransport, rtp_target: Option<SocketAddr>) -> Self {
+        Self { index, transport, rtp_target }
+    }
+
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    pub fn transport(&self) -> &RtspTransport {
+        &self.transport
+    }
+
+    pub fn rtp_target(&self) -> Option<SocketAddr> {
+        self.rtp_target
+    }
+}
+
+/// Represents the session of one client with one mount.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ServerSession {
+    id: String,
+    mount_path: String,
+    state: SessionState,
+    tracks: Vec<SessionTrack>,
+}
+
+impl ServerSession {
+    /// Creates a new `ServerSession` with a random identifier.
+    ///
+    /// # Arguments
+    ///
+    /// * `mount_path` - The path of the mount the session belongs to.
+    pub fn new(mount_path: String) -> Self {
+        let id = RandomState::new().build_hasher().finish();
+        Self {
+            id: format!("{:016X}", id),
+            mount_path,
+            state: SessionState::default(),
+            tracks: Vec::new(),
+        }
+    }
+
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    pub fn mount_path(&self) -> &str {
+        &self.mount_path
+    }
+
+    pub fn state(&self) -> SessionState {
+        self.state
+    }
+
+    pub fn tracks(&self) -> &[SessionTrack] {
+        &self.tracks
+    }
+
+    /// Returns the value of the `Session` header for responses.
+    pub fn header(&self) -> String {
+        format!("{};timeout={}", self.id, SESSION_TIMEOUT)
+    }
+
+    pub(crate) fn set_state(&mut self, state: SessionState) {
+        self.state = state;
+    }
+
+    /// Adds the track, replacing a previous setup of the same media description.
+    pub(crate) fn add_track(&mut self, track: SessionTrack) {
+        self.tracks.retain(|existing| existing.index != track.index);
+        self.tracks.push(track);
+    }
+}
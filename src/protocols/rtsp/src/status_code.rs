@@ -0,0 +1,225 @@
+/// Represents the status code of an RTSP response.
+///
+/// The `StatusCode` enum corresponds to the second token of the status line, as
+/// defined in RFC 2326, section 7.1.1. Codes that are not defined by the RFC are
+/// kept in the `Unknown` variant, so that they can be written back unchanged.
+///
+/// Example:
+///
+/// ```text
+/// RTSP/1.0 454 Session Not Found
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum StatusCode {
+    Continue,
+    Ok,
+    Created,
+    LowOnStorageSpace,
+    MultipleChoices,
+    MovedPermanently,
+    MovedTemporarily,
+    SeeOther,
+    NotModified,
+    UseProxy,
+    BadRequest,
+    Unauthorized,
+    PaymentRequired,
+    Forbidden,
+    NotFound,
+    MethodNotAllowed,
+    NotAcceptable,
+    ProxyAuthenticationRequired,
+    RequestTimeout,
+    Gone,
+    LengthRequired,
+    PreconditionFailed,
+    RequestEntityTooLarge,
+    RequestUriTooLarge,
+    UnsupportedMediaType,
+    ParameterNotUnderstood,
+    ConferenceNotFound,
+    NotEnoughBandwidth,
+    SessionNotFound,
+    MethodNotValidInThisState,
+    HeaderFieldNotValidForResource,
+    InvalidRange,
+    ParameterIsReadOnly,
+    AggregateOperationNotAllowed,
+    OnlyAggregateOperationAllowed,
+    UnsupportedTransport,
+    DestinationUnreachable,
+    InternalServerError,
+    NotImplemented,
+    BadGateway,
+    ServiceUnavailable,
+    GatewayTimeout,
+    RtspVersionNotSupported,
+    OptionNotSupported,
+    Unknown(u16),
+}
+
+impl StatusCode {
+    pub fn from_u16(code: u16) -> Self {
+        match code {
+            100 => StatusCode::Continue,
+            200 => StatusCode::Ok,
+            201 => StatusCode::Created,
+            250 => StatusCode::LowOnStorageSpace,
+            300 => StatusCode::MultipleChoices,
+            301 => StatusCode::MovedPermanently,
+            302 => StatusCode::MovedTemporarily,
+            303 => StatusCode::SeeOther,
+            304 => StatusCode::NotModified,
+            305 => StatusCode::UseProxy,
+            400 => StatusCode::BadRequest,
+            401 => StatusCode::Unauthorized,
+            402 => StatusCode::PaymentRequired,
+            403 => StatusCode::Forbidden,
+            404 => StatusCode::NotFound,
+            405 => StatusCode::MethodNotAllowed,
+            406 => StatusCode::NotAcceptable,
+            407 => StatusCode::ProxyAuthenticationRequired,
+            408 => StatusCode::RequestTimeout,
+            410 => StatusCode::Gone,
+            411 => StatusCode::LengthRequired,
+            412 => StatusCode::PreconditionFailed,
+            413 => StatusCode::RequestEntityTooLarge,
+            414 => StatusCode::RequestUriTooLarge,
+            415 => StatusCode::UnsupportedMediaType,
+            451 => StatusCode::ParameterNotUnderstood,
+            452 => StatusCode::ConferenceNotFound,
+            453 => StatusCode::NotEnoughBandwidth,
+            454 => StatusCode::SessionNotFound,
+            455 => StatusCode::MethodNotValidInThisState,
+            456 => StatusCode::HeaderFieldNotValidForResource,
+            457 => StatusCode::InvalidRange,
+            458 => StatusCode::ParameterIsReadOnly,
+            459 => StatusCode::AggregateOperationNotAllowed,
+            460 => StatusCode::OnlyAggregateOperationAllowed,
+            461 => StatusCode::UnsupportedTransport,
+            462 => StatusCode::DestinationUnreachable,
+            500 => StatusCode::InternalServerError,
+            501 => StatusCode::NotImplemented,
+            502 => StatusCode::BadGateway,
+            503 => StatusCode::ServiceUnavailable,
+            504 => StatusCode::GatewayTimeout,
+            505 => StatusCode::RtspVersionNotSupported,
+            551 => StatusCode::OptionNotSupported,
+            _ => StatusCode::Unknown(code),
+        }
+    }
+
+    pub fn as_u16(&self) -> u16 {
+        match self {
+            StatusCode::Continue => 100,
+            StatusCode::Ok => 200,
+            StatusCode::Created => 201,
+            StatusCode::LowOnStorageSpace => 250,
+            StatusCode::MultipleChoices => 300,
+            StatusCode::MovedPermanently => 301,
+            StatusCode::MovedTemporarily => 302,
+            StatusCode::SeeOther => 303,
+            StatusCode::NotModified => 304,
+            StatusCode::UseProxy => 305,
+            StatusCode::BadRequest => 400,
+            StatusCode::Unauthorized => 401,
+            StatusCode::PaymentRequired => 402,
+            StatusCode::Forbidden => 403,
+            StatusCode::NotFound => 404,
+            StatusCode::MethodNotAllowed => 405,
+            StatusCode::NotAcceptable => 406,
+            StatusCode::ProxyAuthenticationRequired => 407,
+            StatusCode::RequestTimeout => 408,
+            StatusCode::Gone => 410,
+            StatusCode::LengthRequired => 411,
+            StatusCode::PreconditionFailed => 412,
+            StatusCode::RequestEntityTooLarge => 413,
+            StatusCode::RequestUriTooLarge => 414,
+            StatusCode::UnsupportedMediaType => 415,
+            StatusCode::ParameterNotUnderstood => 451,
+            StatusCode::ConferenceNotFound => 452,
+            StatusCode::NotEnoughBandwidth => 453,
+            StatusCode::SessionNotFound => 454,
+            StatusCode::MethodNotValidInThisState => 455,
+            StatusCode::HeaderFieldNotValidForResource => 456,
+            StatusCode::InvalidRange => 457,
+            StatusCode::ParameterIsReadOnly => 458,
+            StatusCode::AggregateOperationNotAllowed => 459,
+            StatusCode::OnlyAggregateOperationAllowed => 460,
+            StatusCode::UnsupportedTransport => 461,
+            StatusCode::DestinationUnreachable => 462,
+            StatusCode::InternalServerError => 500,
+            StatusCode::NotImplemented => 501,
+            StatusCode::BadGateway => 502,
+            StatusCode::ServiceUnavailable => 503,
+            StatusCode::GatewayTimeout => 504,
+            StatusCode::RtspVersionNotSupported => 505,
+            StatusCode::OptionNotSupported => 551,
+            StatusCode::Unknown(code) => *code,
+        }
+    }
+
+    /// Returns the default reason phrase of the status code.
+    ///
+    /// # Returns
+    ///
+    /// The reason phrase recommended by RFC 2326, or an empty string for unknown codes.
+    pub fn reason_phrase(&self) -> &'static str {
+        match self {
+            StatusCode::Continue => "Continue",
+            StatusCode::Ok => "OK",
+            StatusCode::Created => "Created",
+            StatusCode::LowOnStorageSpace => "Low on Storage Space",
+            StatusCode::MultipleChoices => "Multiple Choices",
+            StatusCode::MovedPermanently => "Moved Permanently",
+            StatusCode::MovedTemporarily => "Moved Temporarily",
+            StatusCode::SeeOther => "See Other",
+            StatusCode::NotModified => "Not Modified",
+            StatusCode::UseProxy => "Use Proxy",
+            StatusCode::BadRequest => "Bad Request",
+            StatusCode::Unauthorized => "Unauthorized",
+            StatusCode::PaymentRequired => "Payment Required",
+            StatusCode::Forbidden => "Forbidden",
+            StatusCode::NotFound => "Not Found",
+            StatusCode::MethodNotAllowed => "Method Not Allowed",
+            StatusCode::NotAcceptable => "Not Acceptable",
+            StatusCode::ProxyAuthenticationRequired => "Proxy Authentication Required",
+            StatusCode::RequestTimeout => "Request Time-out",
+            StatusCode::Gone => "Gone",
+            StatusCode::LengthRequired => "Length Required",
+            StatusCode::PreconditionFailed => "Precondition Failed",
+            StatusCode::RequestEntityTooLarge => "Request Entity Too Large",
+            StatusCode::RequestUriTooLarge => "Request-URI Too Large",
+            StatusCode::UnsupportedMediaType => "Unsupported Media Type",
+            StatusCode::ParameterNotUnderstood => "Parameter Not Understood",
+            StatusCode::ConferenceNotFound => "Conference Not Found",
+            StatusCode::NotEnoughBandwidth => "Not Enough Bandwidth",
+            StatusCode::SessionNotFound => "Session Not Found",
+            StatusCode::MethodNotValidInThisState => "Method Not Valid in This State",
+            StatusCode::HeaderFieldNotValidForResource => "Header Field Not Valid for Resource",
+            StatusCode::InvalidRange => "Invalid Range",
+            StatusCode::ParameterIsReadOnly => "Parameter Is Read-Only",
+            StatusCode::AggregateOperationNotAllowed => "Aggregate operation not allowed",
+            StatusCode::OnlyAggregateOperationAllowed => "Only aggregate operation allowed",
+            StatusCode::UnsupportedTransport => "Unsupported transport",
+            StatusCode::DestinationUnreachable => "Destination unreachable",
+            StatusCode::InternalServerError => "Internal Server Error",
+            StatusCode::NotImplemented => "Not Implemented",
+            StatusCode::BadGateway => "Bad Gateway",
+            StatusCode::ServiceUnavailable => "Service Unavailable",
+            StatusCode::GatewayTimeout => "Gateway Time-out",
+            StatusCode::RtspVersionNotSupported => "RTSP Version not supported",
+            StatusCode::OptionNotSupported => "Option not supported",
+            StatusCode::Unknown(_) => "",
+        }
+    }
+
+    /// Indicates if the status code belongs to the `2xx` class.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the request was successfully received, understood and accepted.
+    pub fn is_success(&self) -> bool {
+        (200..300).contains(&self.as_u16())
+    }
+}
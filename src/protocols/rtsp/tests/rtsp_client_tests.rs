@@ -0,0 +1,236 @@
+use abstractions::parsing::payload_parser::PayloadParser;
+use rtsp::{
+    client::{client_state::ClientState, rtsp_client::{resolve_control, RtspClient}},
+    rtsp_decoder::RtspDecoder,
+    rtsp_error::RtspError,
+    rtsp_headers::{CONTENT_BASE, PUBLIC, RANGE, SESSION, TRANSPORT},
+    rtsp_message::RtspMessage,
+    rtsp_method::RtspMethod,
+    rtsp_request::RtspRequest,
+    rtsp_response::RtspResponse,
+    rtsp_transport::RtspTransport,
+    status_code::StatusCode,
+};
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::{TcpListener, TcpStream},
+    task::JoinHandle,
+};
+
+const SDP: &[u8] = b"v=0\r
+o=- 2890844526 2890842807 IN IP4 192.0.2.10\r
+s=SDP Seminar\r
+t=0 0\r
+m=audio 0 RTP/AVP 0\r
+a=rtpmap:0 PCMU/8000\r
+a=control:trackID=1\r
+m=audio 0 RTP/AVP 96\r
+a=rtpmap:96 opus/48000/2\r
+a=control:rtsp://192.0.2.10/stream/trackID=2\r
+";
+
+const AGGREGATE_SDP: &[u8] = b"v=0\r
+o=- 2890844526 2890842807 IN IP4 192.0.2.10\r
+s=SDP Seminar\r
+t=0 0\r
+a=control:rtsp://192.0.2.10/live\r
+m=audio 0 RTP/AVP 0\r
+a=rtpmap:0 PCMU/8000\r
+a=control:trackID=1\r
+";
+
+/// Answers requests like a camera would and returns the received requests once the client disconnects.
+async fn mock_server(listener: TcpListener, sdp: &'static [u8], pause_status: StatusCode) -> Vec<RtspRequest> {
+    let (mut stream, _) = listener.accept().await.unwrap();
+    let mut decoder = RtspDecoder::new();
+    let mut requests = Vec::new();
+    let mut buffer = [0u8; 1024];
+
+    loop {
+        let read = stream.read(&mut buffer).await.unwrap();
+        if read == 0 {
+            return requests;
+        }
+        decoder.extend(&buffer[..read]);
+        while let Some(RtspMessage::Request(request)) = decoder.decode().unwrap() {
+            let mut response = RtspResponse::new(StatusCode::Ok);
+            response.set_cseq(request.cseq().unwrap());
+            match request.method() {
+                RtspMethod::Options => response.headers_mut().insert(PUBLIC, "OPTIONS, DESCRIBE, SETUP, PLAY, PAUSE, TEARDOWN"),
+                RtspMethod::Describe => {
+                    response.headers_mut().insert(CONTENT_BASE, "rtsp://192.0.2.10/stream/");
+                    response.set_body("application/sdp", sdp.to_vec());
+                }
+                RtspMethod::Setup => {
+                    let mut transport = RtspTransport::parse(request.headers().get(TRANSPORT).unwrap().as_bytes()).unwrap();
+                    transport.set_server_port(Some((6970, 6971)));
+                    transport.set_ssrc(Some(0x2A3F5C10));
+                    response.headers_mut().insert(TRANSPORT, &transport.to_string());
+                    response.headers_mut().insert(SESSION, "12345678;timeout=60");
+                }
+                RtspMethod::Pause => response = RtspResponse::new(pause_status),
+                _ => response.headers_mut().insert(SESSION, "12345678"),
+            }
+            if response.cseq().is_none() {
+                response.set_cseq(request.cseq().unwrap());
+            }
+            stream.write_all(&response.to_bytes()).await.unwrap();
+            requests.push(request);
+        }
+    }
+}
+
+async fn start() -> (RtspClient<TcpStream>, JoinHandle<Vec<RtspRequest>>) {
+    start_with(SDP, StatusCode::MethodNotValidInThisState).await
+}
+
+async fn start_with(sdp: &'static [u8], pause_status: StatusCode) -> (RtspClient<TcpStream>, JoinHandle<Vec<RtspRequest>>) {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let address = listener.local_addr().unwrap();
+    let server = tokio::spawn(mock_server(listener, sdp, pause_status));
+    let url = format!("rtsp://{}/stream", address).parse().unwrap();
+    (RtspClient::connect(url).await.unwrap(), server)
+}
+
+#[tokio::test]
+async fn test_client_session() {
+    let (mut client, server) = start().await;
+
+    let tracks = client
+        .negotiate(|index, _| RtspTransport::udp(5000 + index as u16 * 2, 5001 + index as u16 * 2))
+        .await
+        .unwrap();
+
+    assert_eq!(client.state(), ClientState::Ready);
+    assert_eq!(client.session_id(), Some("12345678"));
+    assert_eq!(client.session_timeout(), Some(60));
+    assert_eq!(client.media_session().unwrap().media_descriptions().len(), 2);
+    assert_eq!(tracks.len(), 2);
+    assert_eq!(tracks[0].control().to_string(), "rtsp://192.0.2.10/stream/trackID=1");
+    assert_eq!(tracks[1].control().to_string(), "rtsp://192.0.2.10/stream/trackID=2");
+    assert_eq!(tracks[1].transport().client_port(), Some((5002, 5003)));
+    assert_eq!(tracks[1].transport().server_port(), Some((6970, 6971)));
+    assert_eq!(tracks[1].transport().ssrc(), Some(0x2A3F5C10));
+
+    client.play().await.unwrap();
+    assert_eq!(client.state(), ClientState::Playing);
+
+    let result = client.pause().await;
+    assert!(matches!(
+        result,
+        Err(RtspError::UnexpectedStatus { method: RtspMethod::Pause, status: StatusCode::MethodNotValidInThisState })
+    ));
+
+    client.teardown().await.unwrap();
+    assert_eq!(client.state(), ClientState::Init);
+    assert_eq!(client.session_id(), None);
+    drop(client);
+
+    let requests = server.await.unwrap();
+    let methods = requests.iter().map(|request| request.method().clone()).collect::<Vec<_>>();
+    assert_eq!(
+        methods,
+        vec![
+            RtspMethod::Options,
+            RtspMethod::Describe,
+            RtspMethod::Setup,
+            RtspMethod::Setup,
+            RtspMethod::Play,
+            RtspMethod::Pause,
+            RtspMethod::Teardown,
+        ]
+    );
+    let cseqs = requests.iter().map(|request| request.cseq().unwrap()).collect::<Vec<_>>();
+    assert_eq!(cseqs, vec![1, 2, 3, 4, 5, 6, 7]);
+    assert_eq!(requests[2].headers().get(SESSION), None);
+    assert_eq!(requests[3].headers().get(SESSION), Some("12345678"));
+    assert_eq!(requests[4].uri().to_string(), "rtsp://192.0.2.10/stream/");
+}
+
+#[tokio::test]
+async fn test_client_resumes_after_pause() {
+    let (mut client, server) = start_with(AGGREGATE_SDP, StatusCode::Ok).await;
+
+    client.negotiate(|_, _| RtspTransport::udp(5000, 5001)).await.unwrap();
+    client.play().await.unwrap();
+    client.pause().await.unwrap();
+    assert_eq!(client.state(), ClientState::Ready);
+    client.play().await.unwrap();
+    client.teardown().await.unwrap();
+    drop(client);
+
+    let requests = server.await.unwrap();
+    assert_eq!(requests[2].uri().to_string(), "rtsp://192.0.2.10/stream/trackID=1");
+    for request in &requests[3..] {
+        assert_eq!(request.uri().to_string(), "rtsp://192.0.2.10/live");
+    }
+    assert_eq!(requests[3].headers().get(RANGE), Some("npt=0.000-"));
+    assert_eq!(*requests[5].method(), RtspMethod::Play);
+    assert_eq!(requests[5].headers().get(RANGE), None);
+}
+
+#[tokio::test]
+async fn test_client_play_without_setup() {
+    let (mut client, _server) = start().await;
+
+    let result = client.play().await;
+    assert!(matches!(result, Err(RtspError::InvalidState { method: RtspMethod::Play, .. })));
+}
+
+#[test]
+fn test_resolve_control() {
+    let base = "rtsp://192.0.2.10/stream".parse().unwrap();
+
+    assert_eq!(resolve_control(&base, "*").unwrap().to_string(), "rtsp://192.0.2.10/stream");
+    assert_eq!(resolve_control(&base, "track1").unwrap().to_string(), "rtsp://192.0.2.10/stream/track1");
+    assert_eq!(
+        resolve_control(&base, "rtsp://192.0.2.11/other").unwrap().to_string(),
+        "rtsp://192.0.2.11/other"
+    );
+}
+
+#[test]
+fn test_resolve_control_with_query() {
+    let base = "rtsp://192.0.2.10/stream?channel=1&subtype=0".parse().unwrap();
+
+    assert_eq!(resolve_control(&base, "*").unwrap().to_string(), "rtsp://192.0.2.10/stream?channel=1&subtype=0");
+    assert_eq!(resolve_control(&base, "trackID=0").unwrap().to_string(), "rtsp://192.0.2.10/stream/trackID=0");
+}
+
+#[tokio::test]
+async fn test_client_rejects_server_requests() {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let address = listener.local_addr().unwrap();
+    let server = tokio::spawn(async move {
+        let (mut stream, _) = listener.accept().await.unwrap();
+        let mut decoder = RtspDecoder::new();
+        let mut buffer = [0u8; 1024];
+        loop {
+            let read = stream.read(&mut buffer).await.unwrap();
+            decoder.extend(&buffer[..read]);
+            if let Some(RtspMessage::Request(request)) = decoder.decode().unwrap() {
+                let mut server_request = RtspRequest::new(RtspMethod::SetParameter, "rtsp://192.0.2.10/stream".parse().unwrap());
+                server_request.set_cseq(9);
+                stream.write_all(&server_request.to_bytes()).await.unwrap();
+                let mut response = RtspResponse::new(StatusCode::Ok);
+                response.set_cseq(request.cseq().unwrap());
+                stream.write_all(&response.to_bytes()).await.unwrap();
+                break;
+            }
+        }
+        loop {
+            if let Some(RtspMessage::Response(response)) = decoder.decode().unwrap() {
+                return response;
+            }
+            let read = stream.read(&mut buffer).await.unwrap();
+            decoder.extend(&buffer[..read]);
+        }
+    });
+    let mut client = RtspClient::connect(format!("rtsp://{}/stream", address).parse().unwrap()).await.unwrap();
+
+    client.options().await.unwrap();
+
+    let response = server.await.unwrap();
+    assert_eq!(response.status(), StatusCode::NotImplemented);
+    assert_eq!(response.cseq(), Some(9));
+}
@@ -0,0 +1,108 @@
+use abstractions::parsing::payload_parser::PayloadParser;
+use rtsp::{
+    interleaved_frame::InterleavedFrame, rtsp_decoder::RtspDecoder, rtsp_error::RtspError, rtsp_frame::RtspFrame,
+    rtsp_message::RtspMessage, rtsp_method::RtspMethod,
+};
+
+#[test]
+fn test_parse_partial_incomplete() {
+    assert!(RtspMessage::parse_partial(b"RTSP/1.0 200 OK\r\nCSeq: 1\r\n").unwrap().is_none());
+    assert!(RtspMessage::parse_partial(b"RTSP/1.0 200 OK\r\nContent-Length: 4\r\n\r\nv=").unwrap().is_none());
+}
+
+#[test]
+fn test_parse_partial_leaves_tail() {
+    let data = b"\r\nRTSP/1.0 200 OK\r\nContent-Length: 3\r\n\r\nv=0OPTIONS";
+    let (message, consumed) = RtspMessage::parse_partial(data).unwrap().unwrap();
+
+    assert_eq!(message.body(), b"v=0");
+    assert_eq!(&data[consumed..], b"OPTIONS");
+}
+
+#[test]
+fn test_parse_invalid_content_length() {
+    assert!(RtspMessage::parse_partial(b"RTSP/1.0 200 OK\r\nContent-Length: abc\r\n\r\nv=0").is_err());
+    assert!(RtspMessage::parse_partial(b"RTSP/1.0 200 OK\r\nContent-Length: -1\r\n\r\n").is_err());
+    assert!(RtspMessage::parse(b"DESCRIBE rtsp://192.0.2.10/stream RTSP/1.0\r\nContent-Length: 3x\r\n\r\nv=0").is_err());
+}
+
+#[test]
+fn test_decoder_rejects_oversized_body() {
+    let mut decoder = RtspDecoder::new();
+    decoder.extend(b"RTSP/1.0 200 OK\r\nCSeq: 2\r\nContent-Length: 999999999\r\n\r\nv=0");
+    assert!(decoder.decode().is_err());
+}
+
+#[test]
+fn test_decoder_split_reads() {
+    let data = b"OPTIONS * RTSP/1.0\r\nCSeq: 1\r\n\r\nRTSP/1.0 200 OK\r\nCSeq: 2\r\nContent-Length: 5\r\n\r\nhello";
+    let mut decoder = RtspDecoder::new();
+    let mut messages = Vec::new();
+
+    for chunk in data.chunks(7) {
+        decoder.extend(chunk);
+        while let Some(message) = decoder.decode().unwrap() {
+            messages.push(message);
+        }
+    }
+
+    assert_eq!(messages.len(), 2);
+    match &messages[0] {
+        RtspMessage::Request(request) => assert_eq!(request.method(), &RtspMethod::Options),
+        RtspMessage::Response(_) => panic!("expected a request"),
+    }
+    assert_eq!(messages[1].cseq(), Some(2));
+    assert_eq!(messages[1].body(), b"hello");
+    assert!(decoder.remaining().is_empty());
+}
+
+#[test]
+fn test_decoder_invalid_message() {
+    let mut decoder = RtspDecoder::new();
+    decoder.extend(b"garbage\r\n\r\n");
+    assert!(decoder.decode().is_err());
+}
+
+#[test]
+fn test_decoder_interleaved_frames() {
+    let mut data = vec![b'$', 0, 0, 3, 0x80, 0x60, 0x00];
+    data.extend_from_slice(b"RTSP/1.0 200 OK\r\nCSeq: 5\r\n\r\n");
+    data.extend_from_slice(&[b'$', 1, 0, 2, 0xAB, 0xCD]);
+    let mut decoder = RtspDecoder::new();
+    let mut frames = Vec::new();
+
+    for chunk in data.chunks(3) {
+        decoder.extend(chunk);
+        while let Some(frame) = decoder.decode_frame().unwrap() {
+            frames.push(frame);
+        }
+    }
+
+    assert_eq!(
+        frames,
+        vec![
+            RtspFrame::Interleaved(InterleavedFrame::new(0, vec![0x80, 0x60, 0x00])),
+            RtspFrame::Message(RtspMessage::parse(b"RTSP/1.0 200 OK\r\nCSeq: 5\r\n\r\n").unwrap()),
+            RtspFrame::Interleaved(InterleavedFrame::new(1, vec![0xAB, 0xCD])),
+        ]
+    );
+    assert!(decoder.remaining().is_empty());
+}
+
+#[test]
+fn test_interleaved_frame_to_bytes() {
+    let frame = InterleavedFrame::new(2, vec![1, 2, 3]);
+    assert_eq!(frame.to_bytes().unwrap(), vec![b'$', 2, 0, 3, 1, 2, 3]);
+    assert_eq!(InterleavedFrame::parse_partial(&frame.to_bytes().unwrap()), Some((frame, 7)));
+    assert_eq!(InterleavedFrame::parse_partial(&[b'$', 2, 0, 3, 1]), None);
+}
+
+#[test]
+fn test_interleaved_frame_too_large() {
+    let mut buffer = Vec::new();
+    let result = InterleavedFrame::new(0, vec![0; 65536]).write_to(&mut buffer);
+
+    assert!(matches!(result, Err(RtspError::PacketTooLarge(65536))));
+    assert!(buffer.is_empty());
+    assert_eq!(InterleavedFrame::new(0, vec![0; 65535]).to_bytes().unwrap().len(), 65539);
+}
@@ -0,0 +1,29 @@
+use abstractions::parsing::payload_parser::PayloadParser;
+use rtsp::rtsp_headers::{RtspHeaders, CSEQ, SESSION};
+
+#[test]
+fn test_parse_headers() {
+    let headers = RtspHeaders::parse(b"CSeq: 4\r\nSession: 12345678;timeout=60\r\nContent-Base: rtsp://192.0.2.10/stream/\r\n").unwrap();
+
+    assert_eq!(headers.len(), 3);
+    assert_eq!(headers.cseq(), Some(4));
+    assert_eq!(headers.session_id(), Some("12345678"));
+    assert_eq!(headers.get("content-base"), Some("rtsp://192.0.2.10/stream/"));
+    assert_eq!(headers.get("Transport"), None);
+}
+
+#[test]
+fn test_headers_insert_and_remove() {
+    let mut headers = RtspHeaders::new();
+    headers.append(SESSION, "1");
+    headers.append("session", "2");
+    headers.append(CSEQ, "1");
+
+    headers.insert("SESSION", "3");
+    assert_eq!(headers.get_all(SESSION).collect::<Vec<_>>(), vec!["3"]);
+    assert_eq!(headers.to_string(), "Session: 3\r\nCSeq: 1\r\n");
+
+    assert!(headers.remove(SESSION));
+    assert!(!headers.remove(SESSION));
+    assert_eq!(headers.len(), 1);
+}
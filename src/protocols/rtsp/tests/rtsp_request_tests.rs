@@ -0,0 +1,57 @@
+use abstractions::parsing::payload_parser::PayloadParser;
+use rstest::rstest;
+use rtsp::{rtsp_method::RtspMethod, rtsp_request::RtspRequest, rtsp_version::RtspVersion};
+
+#[rstest]
+#[case(b"OPTIONS * RTSP/1.0\r\nCSeq: 1\r\n\r\n", RtspMethod::Options, "*", Some(1), b"")]
+#[case(
+    b"DESCRIBE rtsp://192.0.2.10/stream RTSP/1.0\r\nCSeq: 2\r\nAccept: application/sdp\r\n\r\n",
+    RtspMethod::Describe,
+    "rtsp://192.0.2.10/stream",
+    Some(2),
+    b""
+)]
+#[case(
+    b"SET_PARAMETER rtsp://192.0.2.10/stream RTSP/1.0\r\nCSeq: 7\r\nContent-Length: 12\r\n\r\nbarparam: 1\n",
+    RtspMethod::SetParameter,
+    "rtsp://192.0.2.10/stream",
+    Some(7),
+    b"barparam: 1\n"
+)]
+#[case(b"FOO rtsp://192.0.2.10 RTSP/1.0\r\n\r\n", RtspMethod::Unknown("FOO".to_string()), "rtsp://192.0.2.10/", None, b"")]
+fn test_parse_request(
+    #[case] data: &[u8],
+    #[case] method: RtspMethod,
+    #[case] uri: &str,
+    #[case] cseq: Option<u32>,
+    #[case] body: &[u8],
+) {
+    let request = RtspRequest::parse(data).unwrap();
+    assert_eq!(request.method(), &method);
+    assert_eq!(request.uri().to_string(), uri);
+    assert_eq!(request.version(), RtspVersion::V1_0);
+    assert_eq!(request.cseq(), cseq);
+    assert_eq!(request.body(), body);
+}
+
+#[rstest]
+#[case(b"invalid request")]
+#[case(b"OPTIONS * HTTP/1.1\r\nCSeq: 1\r\n\r\n")]
+#[case(b"OPTIONS * RTSP/1.0\r\nCSeq 1\r\n\r\n")]
+#[case(b"ANNOUNCE rtsp://192.0.2.10 RTSP/1.0\r\nContent-Length: 10\r\n\r\nshort")]
+fn test_parse_invalid_request(#[case] data: &[u8]) {
+    assert!(RtspRequest::parse(data).is_err());
+}
+
+#[test]
+fn test_request_to_bytes() {
+    let mut request = RtspRequest::new(RtspMethod::Announce, "rtsp://192.0.2.10/stream".parse().unwrap());
+    request.set_cseq(3);
+    request.set_body("application/sdp", b"v=0\r\n".to_vec());
+
+    assert_eq!(
+        request.to_bytes(),
+        b"ANNOUNCE rtsp://192.0.2.10/stream RTSP/1.0\r\nCSeq: 3\r\nContent-Type: application/sdp\r\nContent-Length: 5\r\n\r\nv=0\r\n"
+    );
+    assert_eq!(RtspRequest::parse(&request.to_bytes()).unwrap(), request);
+}
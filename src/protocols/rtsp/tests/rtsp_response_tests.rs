@@ -0,0 +1,49 @@
+use abstractions::parsing::payload_parser::PayloadParser;
+use rstest::rstest;
+use rtsp::{rtsp_headers::PUBLIC, rtsp_response::RtspResponse, status_code::StatusCode};
+
+#[rstest]
+#[case(b"RTSP/1.0 200 OK\r\nCSeq: 1\r\nPublic: OPTIONS, DESCRIBE\r\n\r\n", StatusCode::Ok, "OK", Some(1))]
+#[case(b"RTSP/1.0 454 Session Not Found\r\nCSeq: 5\r\n\r\n", StatusCode::SessionNotFound, "Session Not Found", Some(5))]
+#[case(b"RTSP/1.0 299\r\n\r\n", StatusCode::Unknown(299), "", None)]
+fn test_parse_response(
+    #[case] data: &[u8],
+    #[case] status: StatusCode,
+    #[case] reason_phrase: &str,
+    #[case] cseq: Option<u32>,
+) {
+    let response = RtspResponse::parse(data).unwrap();
+    assert_eq!(response.status(), status);
+    assert_eq!(response.reason_phrase(), reason_phrase);
+    assert_eq!(response.cseq(), cseq);
+}
+
+#[test]
+fn test_parse_response_with_body() {
+    let data = b"RTSP/1.0 200 OK\r\nCSeq: 2\r\nContent-Type: application/sdp\r\nContent-Length: 5\r\n\r\nv=0\r\n";
+    let response = RtspResponse::parse(data).unwrap();
+
+    assert_eq!(response.headers().content_length(), Some(5));
+    assert_eq!(response.body(), b"v=0\r\n");
+}
+
+#[test]
+fn test_response_to_bytes() {
+    let mut response = RtspResponse::new(StatusCode::Ok);
+    response.set_cseq(1);
+    response.headers_mut().insert(PUBLIC, "OPTIONS, DESCRIBE");
+
+    assert_eq!(response.to_bytes(), b"RTSP/1.0 200 OK\r\nCSeq: 1\r\nPublic: OPTIONS, DESCRIBE\r\n\r\n");
+    assert_eq!(RtspResponse::parse(&response.to_bytes()).unwrap(), response);
+}
+
+#[rstest]
+#[case(200, StatusCode::Ok, true)]
+#[case(461, StatusCode::UnsupportedTransport, false)]
+#[case(299, StatusCode::Unknown(299), true)]
+fn test_status_code(#[case] code: u16, #[case] expected: StatusCode, #[case] is_success: bool) {
+    let status = StatusCode::from_u16(code);
+    assert_eq!(status, expected);
+    assert_eq!(status.as_u16(), code);
+    assert_eq!(status.is_success(), is_success);
+}
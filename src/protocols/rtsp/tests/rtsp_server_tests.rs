@@ -0,0 +1,183 @@
+use std::time::Duration;
+
+use abstractions::parsing::payload_parser::PayloadParser;
+use rtp::{rtp_header::RtpHeader, rtp_packet::RtpPacket};
+use rtsp::{
+    client::{client_state::ClientState, interleaved_packet::InterleavedPacket, rtsp_client::RtspClient},
+    rtsp_error::RtspError,
+    rtsp_method::RtspMethod,
+    rtsp_request::RtspRequest,
+    rtsp_transport::RtspTransport,
+    server::{mount::Mount, rtsp_server::RtspServer},
+    status_code::StatusCode,
+};
+use tokio::{
+    net::{TcpListener, TcpStream, UdpSocket},
+    time::timeout,
+};
+
+const SDP: &[u8] = b"v=0\r
+o=- 2890844526 2890842807 IN IP4 127.0.0.1\r
+s=Live\r
+t=0 0\r
+m=audio 0 RTP/AVP 0\r
+a=rtpmap:0 PCMU/8000\r
+a=control:trackID=1\r
+m=audio 0 RTP/AVP 96\r
+a=rtpmap:96 opus/48000/2\r
+a=control:trackID=2\r
+";
+
+async fn start() -> (RtspServer, String) {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let address = listener.local_addr().unwrap();
+    let server = RtspServer::new();
+    tokio::spawn(server.clone().serve(listener));
+    (server, format!("rtsp://{}", address))
+}
+
+async fn play(url: &str) -> (RtspClient<TcpStream>, UdpSocket) {
+    let socket = UdpSocket::bind("127.0.0.1:0").await.unwrap();
+    let port = socket.local_addr().unwrap().port();
+    let mut client = RtspClient::connect(url.parse().unwrap()).await.unwrap();
+
+    let tracks = client
+        .negotiate(|index, _| {
+            let rtp_port = if index == 0 { port } else { 0 };
+            RtspTransport::udp(rtp_port, rtp_port + 1)
+        })
+        .await
+        .unwrap();
+    assert_eq!(tracks.len(), 2);
+    assert!(tracks[0].transport().server_port().is_some());
+
+    client.play().await.unwrap();
+    assert_eq!(client.state(), ClientState::Playing);
+    (client, socket)
+}
+
+#[tokio::test]
+async fn test_server_streams_to_clients() {
+    let (server, address) = start().await;
+    let mount = server.add_mount("/live", Mount::new(SDP).unwrap());
+    let url = format!("{}/live", address);
+
+    let (mut first, first_socket) = play(&url).await;
+    let (second, second_socket) = play(&url).await;
+    assert_eq!(mount.receiver_count(), 2);
+    assert_ne!(first.session_id(), second.session_id());
+
+    let payload = [0xD5u8; 160];
+    let packet = RtpPacket::new(RtpHeader::new(2, false, false, 0, true, 0, 1, 160, 0x2A3F5C10, &[]), &payload);
+    assert_eq!(mount.send(0, &packet), 2);
+
+    let mut buffer = [0u8; 1500];
+    for socket in [&first_socket, &second_socket] {
+        let read = timeout(Duration::from_secs(5), socket.recv(&mut buffer))
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(read, 172);
+        assert_eq!(&buffer[12..read], &payload);
+    }
+
+    first.pause().await.unwrap();
+    first.teardown().await.unwrap();
+    assert_eq!(first.state(), ClientState::Init);
+}
+
+#[tokio::test]
+async fn test_server_unknown_mount() {
+    let (server, address) = start().await;
+    server.add_mount("/live", Mount::new(SDP).unwrap());
+
+    let mut client = RtspClient::connect(format!("{}/other", address).parse().unwrap())
+        .await
+        .unwrap();
+    let result = client.describe().await;
+    assert!(matches!(
+        result,
+        Err(RtspError::UnexpectedStatus { method: RtspMethod::Describe, status: StatusCode::NotFound })
+    ));
+}
+
+#[tokio::test]
+async fn test_server_streams_interleaved() {
+    let (server, address) = start().await;
+    let mount = server.add_mount("/live", Mount::new(SDP).unwrap());
+
+    let mut client = RtspClient::connect(format!("{}/live", address).parse().unwrap())
+        .await
+        .unwrap();
+    let tracks = client
+        .negotiate(|index, _| RtspTransport::tcp(index as u8 * 2, index as u8 * 2 + 1))
+        .await
+        .unwrap();
+    assert_eq!(tracks[1].transport().interleaved(), Some((2, 3)));
+    client.play().await.unwrap();
+
+    let payload = [0x11u8; 20];
+    let packet = RtpPacket::new(RtpHeader::new(2, false, false, 0, false, 96, 7, 960, 0x0BADCAFE, &[]), &payload);
+    mount.send(1, &packet);
+
+    // The frame arrives on the connection before the response to the keep-alive.
+    let request = RtspRequest::new(RtspMethod::GetParameter, client.url().clone());
+    let response = client.send(request).await.unwrap();
+    assert_eq!(response.status(), StatusCode::Ok);
+
+    let frame = timeout(Duration::from_secs(5), client.read_frame()).await.unwrap().unwrap();
+    assert_eq!(frame.channel(), 2);
+    match client.route(&frame).unwrap() {
+        Some(InterleavedPacket::Rtp { media_index, packet }) => {
+            assert_eq!(media_index, 1);
+            assert_eq!(packet.header().sequence_number(), 7);
+            assert_eq!(packet.payload(), &payload);
+        }
+        packet => panic!("expected an RTP packet, got {:?}", packet),
+    }
+}
+
+#[tokio::test]
+async fn test_server_rejects_multicast_transport() {
+    let (server, address) = start().await;
+    server.add_mount("/live", Mount::new(SDP).unwrap());
+
+    let mut client = RtspClient::connect(format!("{}/live", address).parse().unwrap())
+        .await
+        .unwrap();
+    client.describe().await.unwrap();
+    let transport = RtspTransport::parse(b"RTP/AVP;multicast;port=5000-5001").unwrap();
+    let result = client.setup(0, transport).await;
+    assert!(matches!(
+        result,
+        Err(RtspError::UnexpectedStatus { method: RtspMethod::Setup, status: StatusCode::UnsupportedTransport })
+    ));
+}
+
+#[tokio::test]
+async fn test_server_streams_tracks_set_up_while_playing() {
+    let (server, address) = start().await;
+    let mount = server.add_mount("/live", Mount::new(SDP).unwrap());
+
+    let mut client = RtspClient::connect(format!("{}/live", address).parse().unwrap())
+        .await
+        .unwrap();
+    client.describe().await.unwrap();
+    client.setup(0, RtspTransport::tcp(0, 1)).await.unwrap();
+    client.play().await.unwrap();
+    client.setup(1, RtspTransport::tcp(2, 3)).await.unwrap();
+
+    let payload = [0x22u8; 20];
+    let packet = RtpPacket::new(RtpHeader::new(2, false, false, 0, false, 96, 8, 960, 0x0BADCAFE, &[]), &payload);
+    mount.send(1, &packet);
+
+    let frame = timeout(Duration::from_secs(5), client.read_frame()).await.unwrap().unwrap();
+    assert_eq!(frame.channel(), 2);
+    match client.route(&frame).unwrap() {
+        Some(InterleavedPacket::Rtp { media_index, packet }) => {
+            assert_eq!(media_index, 1);
+            assert_eq!(packet.payload(), &payload);
+        }
+        packet => panic!("expected an RTP packet, got {:?}", packet),
+    }
+}
@@ -0,0 +1,50 @@
+use abstractions::parsing::payload_parser::PayloadParser;
+use rstest::rstest;
+use rtsp::rtsp_transport::{LowerTransport, RtspTransport};
+
+#[rstest]
+#[case(
+    b"RTP/AVP;unicast;client_port=5000-5001;server_port=6256-6257;ssrc=2A3F5C10",
+    LowerTransport::Udp,
+    None,
+    Some((5000, 5001)),
+    Some((6256, 6257)),
+    Some(0x2A3F5C10)
+)]
+#[case(b"RTP/AVP/TCP;unicast;interleaved=2-3", LowerTransport::Tcp, Some((2, 3)), None, None, None)]
+#[case(b"RTP/AVP/TCP;interleaved=4, RTP/AVP;unicast;client_port=5000", LowerTransport::Tcp, Some((4, 5)), None, None, None)]
+fn test_parse_transport(
+    #[case] data: &[u8],
+    #[case] lower_transport: LowerTransport,
+    #[case] interleaved: Option<(u8, u8)>,
+    #[case] client_port: Option<(u16, u16)>,
+    #[case] server_port: Option<(u16, u16)>,
+    #[case] ssrc: Option<u32>,
+) {
+    let transport = RtspTransport::parse(data).unwrap();
+    assert_eq!(transport.lower_transport(), lower_transport);
+    assert_eq!(transport.interleaved(), interleaved);
+    assert_eq!(transport.client_port(), client_port);
+    assert_eq!(transport.server_port(), server_port);
+    assert_eq!(transport.ssrc(), ssrc);
+}
+
+#[rstest]
+#[case(b"RTP/SAVP;unicast")]
+#[case(b"RTP/AVP;client_port=abc")]
+fn test_parse_invalid_transport(#[case] data: &[u8]) {
+    assert!(RtspTransport::parse(data).is_err());
+}
+
+#[test]
+fn test_transport_to_string() {
+    let mut transport = RtspTransport::udp(5000, 5001);
+    transport.set_server_port(Some((6970, 6971)));
+    transport.set_ssrc(Some(0x2A3F5C10));
+    transport.set_mode(Some("PLAY".to_string()));
+
+    let value = transport.to_string();
+    assert_eq!(value, "RTP/AVP;unicast;client_port=5000-5001;server_port=6970-6971;ssrc=2A3F5C10;mode=\"PLAY\"");
+    assert_eq!(RtspTransport::parse(value.as_bytes()).unwrap(), transport);
+    assert_eq!(RtspTransport::tcp(0, 1).to_string(), "RTP/AVP/TCP;unicast;interleaved=0-1");
+}
@@ -1,3 +1,5 @@
+use std::fmt;
+
 use abstractions::{
     extensions::{
         array_extensions::ArrayExt, utf8_array_extensions::U8ArrayExt
@@ -30,7 +32,7 @@ use abstractions::{
 /// 
 /// * `index` - The bandwidth modifier, represented as a `String`.
 /// * `element` - The bandwidth value, represented as a `u32`.
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
 pub struct Bandwidth {
     index: String, 
     element: u32 
@@ -60,9 +62,10 @@ impl Bandwidth {
         self.element
     }
 }
-
-impl Default for Bandwidth {
-    fn default() -> Self {
-        Self { index: Default::default(), element: Default::default() }
+/// Writes the value of the `b=` field, e.g. `AS:128`.
+impl fmt::Display for Bandwidth {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", self.index, self.element)
     }
 }
+
@@ -51,6 +51,15 @@ impl DataTransferMode {
         }
     }
 
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DataTransferMode::Inactive => "inactive",
+            DataTransferMode::Receive => "recvonly",
+            DataTransferMode::Send => "sendonly",
+            DataTransferMode::SendReceive => "sendrecv",
+        }
+    }
+
     pub fn as_u8(self) -> u8 {
         self as u8
     }
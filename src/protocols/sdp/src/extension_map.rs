@@ -0,0 +1,108 @@
+use std::fmt;
+
+use abstractions::{
+    extensions::{array_extensions::ArrayExt, utf8_array_extensions::U8ArrayExt},
+    parsing::{parsing_error::ParsingError, payload_parser::PayloadParser, SLASH, TRIM_NEW_LINE, WHITESPACE},
+};
+
+use crate::data_transfer_mode::DataTransferMode;
+
+/// Represents an `a=extmap` attribute, which maps an RTP header extension URI to a local identifier.
+///
+/// According to RFC 8285, the `a=extmap` attribute has the following syntax:
+///
+/// ```text
+/// a=extmap:<value>["/"<direction>] <URI> <extensionattributes>
+/// ```
+///
+/// - `<value>`: The identifier used in the header extension elements, from 1 to 14 for the
+///   one-byte form and from 1 to 255 for the two-byte form.
+/// - `<direction>`: The direction the mapping applies to, `sendrecv` if omitted.
+/// - `<URI>`: The URI that identifies the extension.
+/// - `<extensionattributes>`: Parameters specific to the extension.
+///
+/// Example:
+///
+/// ```text
+/// a=extmap:1 urn:ietf:params:rtp-hdrext:toffset
+/// ```
+///
+/// # Fields
+///
+/// * `id` - The local identifier of the extension.
+/// * `direction` - Optional direction of the mapping.
+/// * `uri` - The URI of the extension.
+/// * `attributes` - Optional extension attributes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExtensionMap {
+    id: u8,
+    direction: Option<DataTransferMode>,
+    uri: String,
+    attributes: Option<String>,
+}
+
+impl PayloadParser for ExtensionMap {
+    fn parse(data: &[u8]) -> Result<Self, ParsingError> {
+        let data = data.trim(TRIM_NEW_LINE);
+        let (value, other) = data.separate(WHITESPACE).ok_or_else(|| ParsingError::from_bytes(data))?;
+
+        let (id, direction) = match value.separate(SLASH) {
+            Some((id, direction)) => {
+                let direction = DataTransferMode::from_bytes(direction).ok_or_else(|| ParsingError::from_bytes(data))?;
+                (id, Some(direction))
+            }
+            None => (value, None),
+        };
+        let id = id.utf8_to_number::<u8>()?;
+        if id == 0 {
+            return Err(ParsingError::from_bytes(data));
+        }
+
+        let (uri, attributes) = match other.separate(WHITESPACE) {
+            Some((uri, attributes)) => (uri, Some(attributes.utf8_to_str()?.to_string())),
+            None => (other, None),
+        };
+        if uri.is_empty() {
+            return Err(ParsingError::from_bytes(data));
+        }
+
+        Ok(Self::new(id, direction, uri.utf8_to_str()?.to_string(), attributes))
+    }
+}
+
+impl ExtensionMap {
+    pub fn new(id: u8, direction: Option<DataTransferMode>, uri: String, attributes: Option<String>) -> Self {
+        Self { id, direction, uri, attributes }
+    }
+
+    pub fn id(&self) -> u8 {
+        self.id
+    }
+
+    pub fn direction(&self) -> Option<DataTransferMode> {
+        self.direction
+    }
+
+    pub fn uri(&self) -> &str {
+        &self.uri
+    }
+
+    pub fn attributes(&self) -> Option<&str> {
+        self.attributes.as_deref()
+    }
+}
+
+/// Writes the value of the `a=extmap` attribute, e.g. `1/recvonly urn:ietf:params:rtp-hdrext:toffset`.
+impl fmt::Display for ExtensionMap {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.id)?;
+        if let Some(direction) = self.direction {
+            write!(f, "/{}", direction.as_str())?;
+        }
+        write!(f, " {}", self.uri)?;
+        if let Some(attributes) = &self.attributes {
+            write!(f, " {}", attributes)?;
+        }
+        Ok(())
+    }
+}
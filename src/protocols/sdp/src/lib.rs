@@ -28,13 +28,24 @@
 ///     Err(err) => println!("Failed to parse media session: {:?}", err),
 /// }
 /// ```
+///
+/// ## Building an SDP Message
+///
+/// [`media_session_builder::MediaSessionBuilder`], [`media_description_builder::MediaDescriptionBuilder`]
+/// and [`origin_builder::OriginBuilder`] construct the same types programmatically, and every type
+/// implements `Display` to write it back out as SDP text.
 pub mod media_session;
+pub mod media_session_builder;
 pub mod bandwidth;
 pub mod origin;
+pub mod origin_builder;
 pub mod media_attribute;
+pub mod extension_map;
 pub mod media_description;
+pub mod media_description_builder;
 pub mod data_transfer_mode;
 pub mod payload_type;
 pub mod time;
 pub mod sdp_port;
-pub mod transport_protocol;
\ No newline at end of file
+pub mod transport_protocol;
+pub mod sdp_error;
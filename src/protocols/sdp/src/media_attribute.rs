@@ -1,4 +1,14 @@
-#[derive(Debug, PartialEq)]
+use std::fmt;
+
+/// Represents an `a=` attribute that has no dedicated type.
+///
+/// According to RFC 4566, attributes have one of the following forms:
+///
+/// ```text
+/// a=<attribute>
+/// a=<attribute>:<value>
+/// ```
+#[derive(Debug, Clone, PartialEq)]
 pub struct UnknownMediaAttribute {
     name: String,
     value: Option<String>,
@@ -10,10 +20,21 @@ impl UnknownMediaAttribute {
         Self { name, value }
     }
     
-    fn name(&self) -> &String {
+    pub fn name(&self) -> &str {
         &self.name
     }
-    fn value(&self) -> &Option<String> {
-        &self.value
+
+    pub fn value(&self) -> Option<&str> {
+        self.value.as_deref()
+    }
+}
+
+/// Writes the value of the `a=` field, e.g. `range:npt=0-`.
+impl fmt::Display for UnknownMediaAttribute {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.value {
+            Some(value) => write!(f, "{}:{}", self.name, value),
+            None => f.write_str(&self.name),
+        }
     }
-}
\ No newline at end of file
+}
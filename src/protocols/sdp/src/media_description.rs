@@ -1,15 +1,25 @@
+use std::fmt;
+
 use abstractions::{
     extensions::{array_extensions::ArrayExt, utf8_array_extensions::U8ArrayExt}, net::connection_addresses::ConnectionAddresses, parsing::{
-        parsing_error::ParsingError, payload_parser::PayloadParser, NEW_LINE, SLASH, TRIM_NEW_LINE, WHITESPACE
+        parsing_error::ParsingError, payload_parser::PayloadParser, COLON, NEW_LINE, SLASH, TRIM_NEW_LINE, WHITESPACE
     }
 };
-use media::{codec::RTPMAP_KEY, codec_type::CodecType};
+use media::{codec::{FMTP_KEY, RTPMAP_KEY}, codec_type::CodecType};
 
 use crate::{
-    bandwidth::Bandwidth, data_transfer_mode::DataTransferMode, payload_type::PayloadType, sdp_port::SdpPort, transport_protocol::MediaTransportProtocol
+    bandwidth::Bandwidth, data_transfer_mode::DataTransferMode, extension_map::ExtensionMap, media_attribute::UnknownMediaAttribute, payload_type::PayloadType, sdp_port::SdpPort, transport_protocol::MediaTransportProtocol
 };
 
+const MEDIA_KEY: &[u8] = b"m=";
 const CONNECTION_KEY: &[u8] = b"c=";
+const BANDWIDTH_KEY: &[u8] = b"b=";
+const ATTRIBUTE_KEY: &[u8] = b"a=";
+const CONTROL_KEY: &[u8] = b"a=control:";
+pub(crate) const EXTENSION_MAP_KEY: &[u8] = b"extmap:";
+
+/// The media-level `b=` field, direction, header extension mappings and remaining attributes of a media description.
+type MediaAttributes = (Option<Bandwidth>, Option<DataTransferMode>, Vec<ExtensionMap>, Vec<UnknownMediaAttribute>);
 
 /// Represents a media description in SDP.
 ///
@@ -41,6 +51,10 @@ const CONNECTION_KEY: &[u8] = b"c=";
 /// * `payload_type` - The payload type for the media description.
 /// * `ports` - A list of ports used for the media description.
 /// * `transport_protocol` - The transport protocol used for the media description.
+/// * `control` - Optional `a=control` URL used to address the stream in RTSP requests.
+/// * `extension_maps` - The `a=extmap` attributes mapping RTP header extensions to identifiers.
+/// * `session_extension_maps` - The session-level `a=extmap` attributes that apply to the media description.
+/// * `attributes` - Other `a=` attributes of the media description, in their original order.
 #[derive(Debug, PartialEq)]
 pub struct MediaDescription {
     bandwidth: Option<Bandwidth>,
@@ -51,9 +65,14 @@ pub struct MediaDescription {
     port_count: usize,
     transport_protocol: MediaTransportProtocol,
     connection_addresses: Option<ConnectionAddresses>,
+    control: Option<String>,
+    extension_maps: Vec<ExtensionMap>,
+    session_extension_maps: Vec<ExtensionMap>,
+    attributes: Vec<UnknownMediaAttribute>,
 }
 
 impl MediaDescription {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         bandwidth: Option<Bandwidth>,
         codecs: Vec<CodecType>,
@@ -73,6 +92,10 @@ impl MediaDescription {
             port_count,
             transport_protocol,
             connection_addresses,
+            control: None,
+            extension_maps: Vec::new(),
+            session_extension_maps: Vec::new(),
+            attributes: Vec::new(),
         }
     }
 
@@ -147,6 +170,79 @@ impl MediaDescription {
     pub fn connection_addresses(&self) -> &Option<ConnectionAddresses> {
         &self.connection_addresses
     }
+
+    /// Returns the `a=control` attribute of the media description.
+    ///
+    /// # Returns
+    ///
+    /// An `Option` containing the absolute or relative control URL, or `None` if not present.
+    pub fn control(&self) -> Option<&str> {
+        self.control.as_deref()
+    }
+
+    /// Returns the `a=extmap` attributes of the media description.
+    ///
+    /// # Returns
+    ///
+    /// A slice containing the `ExtensionMap` instances.
+    pub fn extension_maps(&self) -> &[ExtensionMap] {
+        &self.extension_maps
+    }
+
+    /// Finds the identifier that an `a=extmap` attribute maps to an RTP header extension.
+    ///
+    /// A mapping of the media description takes precedence over a session-level one for the same extension.
+    ///
+    /// # Arguments
+    ///
+    /// * `uri` - The URI of the header extension, e.g. `urn:ietf:params:rtp-hdrext:toffset`.
+    ///
+    /// # Returns
+    ///
+    /// An `Option` containing the identifier of the header extension elements, or `None` if the extension is not mapped.
+    pub fn extension_id(&self, uri: &str) -> Option<u8> {
+        self.extension_maps
+            .iter()
+            .chain(&self.session_extension_maps)
+            .find(|extension_map| extension_map.uri() == uri)
+            .map(ExtensionMap::id)
+    }
+
+    /// Returns the `a=` attributes that have no dedicated field.
+    ///
+    /// # Returns
+    ///
+    /// A slice containing the `UnknownMediaAttribute` instances.
+    pub fn attributes(&self) -> &[UnknownMediaAttribute] {
+        &self.attributes
+    }
+
+    pub(crate) fn set_control(&mut self, control: Option<String>) {
+        self.control = control;
+    }
+
+    pub(crate) fn set_extension_maps(&mut self, extension_maps: Vec<ExtensionMap>) {
+        self.extension_maps = extension_maps;
+    }
+
+    /// Applies the session-level `a=extmap` attributes to the media description.
+    ///
+    /// Mappings whose identifier or extension is already mapped by the media description are ignored.
+    pub(crate) fn inherit_extension_maps(&mut self, session_extension_maps: &[ExtensionMap]) {
+        self.session_extension_maps = session_extension_maps
+            .iter()
+            .filter(|session_extension_map| {
+                !self.extension_maps.iter().any(|extension_map| {
+                    extension_map.id() == session_extension_map.id() || extension_map.uri() == session_extension_map.uri()
+                })
+            })
+            .cloned()
+            .collect();
+    }
+
+    pub(crate) fn set_attributes(&mut self, attributes: Vec<UnknownMediaAttribute>) {
+        self.attributes = attributes;
+    }
 }
 
 impl PayloadParser for MediaDescription {
@@ -155,6 +251,7 @@ impl PayloadParser for MediaDescription {
         Self: Sized,
     {
         let mut connection_address: Option<ConnectionAddresses> = None;
+        let data = data.strip_prefix(MEDIA_KEY).unwrap_or(data);
         let (top, bot) = data
             .separate_trimmed(NEW_LINE, TRIM_NEW_LINE)
             .ok_or_else(|| ParsingError::from_bytes(data))?;
@@ -186,6 +283,9 @@ impl PayloadParser for MediaDescription {
             return Err(ParsingError::from_bytes(data));
         }
 
+        let control = get_control(bot)?;
+        let (bandwidth, data_transfer_mode, extension_maps, attributes) = get_attributes(bot)?;
+
         let mut slice = bot;
         if let Some ((_, bot)) = slice.separate(CONNECTION_KEY) {
             let (connection, bot) = bot
@@ -196,34 +296,189 @@ impl PayloadParser for MediaDescription {
             slice = bot;
         }
 
-        let mut codecs: Vec<CodecType> = Vec::new();
-        let (_, bot) = slice
-            .separate_trimmed(RTPMAP_KEY, WHITESPACE)
-            .ok_or_else(|| ParsingError::from_bytes(data))?;
-        slice = bot;
-
-        while let Some((top, bot)) = slice.while_separate_trimmed(RTPMAP_KEY, TRIM_NEW_LINE) {
-            codecs.push(CodecType::parse(top)?);
+        let mut mapped_codecs: Vec<CodecType> = Vec::new();
+        if let Some((_, bot)) = slice.separate_trimmed(RTPMAP_KEY, WHITESPACE) {
             slice = bot;
+            while let Some((top, bot)) = slice.while_separate_trimmed(RTPMAP_KEY, TRIM_NEW_LINE) {
+                mapped_codecs.push(CodecType::parse(top)?);
+                slice = bot;
+            }
         }
-
-        if codecs.is_empty() || codecs.len() != codec_formats.len() {
-            return Err(ParsingError::from_bytes(data));
-        }
+        let codecs = get_codecs(&codec_formats, mapped_codecs).ok_or_else(|| ParsingError::from_bytes(data))?;
 
         Ok(MediaDescription {
-            bandwidth: None,
+            bandwidth,
             codecs,
-            data_transfer_mode: None,
+            data_transfer_mode,
             payload_type: r#type,
             ports,
             port_count,
             transport_protocol,
             connection_addresses: connection_address,
+            control,
+            extension_maps,
+            session_extension_maps: Vec::new(),
+            attributes,
         })
     }
 }
 
+/// Writes the `m=` line followed by the media-level fields of the description.
+///
+/// ```text
+/// m=<media> <port>[/<number of ports>] <proto> <fmt> ...
+/// c=<nettype> <addrtype> <connection-address>
+/// b=<bwtype>:<bandwidth>
+/// a=rtpmap:<payload type> <encoding name>/<clock rate>[/<encoding parameters>]
+/// a=fmtp:<format> <format specific parameters>
+/// a=control:<url>
+/// a=extmap:<value>["/"<direction>] <URI> <extensionattributes>
+/// a=<attribute>[:<value>]
+/// ```
+impl fmt::Display for MediaDescription {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let port = self.ports.first().map_or(0, |port| port.rtp_port());
+        write!(f, "m={} {}", self.payload_type.as_str(), port)?;
+        if self.ports.len() > 1 {
+            write!(f, "/{}", self.ports.len())?;
+        }
+        write!(f, " {}", self.transport_protocol.as_str())?;
+        for codec in &self.codecs {
+            write!(f, " {}", codec.format())?;
+        }
+        f.write_str("\r\n")?;
+
+        if let Some(connection_addresses) = &self.connection_addresses {
+            write!(f, "c={}\r\n", connection_addresses)?;
+        }
+        if let Some(bandwidth) = &self.bandwidth {
+            write!(f, "b={}\r\n", bandwidth)?;
+        }
+        for codec in &self.codecs {
+            write!(f, "{}", codec)?;
+        }
+        if let Some(control) = &self.control {
+            write!(f, "a=control:{}\r\n", control)?;
+        }
+        for extension_map in &self.extension_maps {
+            write!(f, "a=extmap:{}\r\n", extension_map)?;
+        }
+        for attribute in &self.attributes {
+            write!(f, "a={}\r\n", attribute)?;
+        }
+        if let Some(data_transfer_mode) = self.data_transfer_mode {
+            write!(f, "a={}\r\n", data_transfer_mode.as_str())?;
+        }
+        Ok(())
+    }
+}
+
+/// Finds the `a=control` attribute among the attribute lines of a media description.
+///
+/// # Arguments
+///
+/// * `data` - A byte slice containing the lines that follow the `m=` line.
+///
+/// # Returns
+///
+/// A `Result` containing the control URL if present, or a `ParsingError` if it is not valid UTF-8.
+fn get_control(data: &[u8]) -> Result<Option<String>, ParsingError> {
+    let Some((_, other)) = data.separate(CONTROL_KEY) else {
+        return Ok(None);
+    };
+    let (control, _) = other
+        .while_separate_trimmed(NEW_LINE, TRIM_NEW_LINE)
+        .ok_or_else(|| ParsingError::from_bytes(data))?;
+    Ok(Some(control.utf8_to_str()?.to_string()))
+}
+
+/// Collects the `b=` field, the direction, the `a=extmap` attributes and the other attributes of a media description.
+///
+/// The `a=rtpmap`, `a=fmtp` and `a=control` attributes are skipped as they are parsed separately.
+///
+/// # Arguments
+///
+/// * `data` - A byte slice containing the lines that follow the `m=` line.
+///
+/// # Returns
+///
+/// A `Result` containing the bandwidth, the direction, the header extension mappings and the remaining attributes,
+/// or a `ParsingError` if one of the lines is malformed.
+fn get_attributes(data: &[u8]) -> Result<MediaAttributes, ParsingError> {
+    let mut bandwidth = None;
+    let mut data_transfer_mode = None;
+    let mut extension_maps = Vec::new();
+    let mut attributes = Vec::new();
+
+    let mut slice = data;
+    while let Some((line, bot)) = slice.while_separate_trimmed(NEW_LINE, TRIM_NEW_LINE) {
+        slice = bot;
+        let line = line.trim(TRIM_NEW_LINE);
+        if let Some(value) = line.strip_prefix(BANDWIDTH_KEY) {
+            bandwidth = Some(Bandwidth::parse(value)?);
+            continue;
+        }
+        let Some(attribute) = line.strip_prefix(ATTRIBUTE_KEY) else {
+            continue;
+        };
+        if line.starts_with(RTPMAP_KEY) || line.starts_with(FMTP_KEY) || line.starts_with(CONTROL_KEY) {
+            continue;
+        }
+        if let Some(mode) = DataTransferMode::from_bytes(attribute) {
+            data_transfer_mode = Some(mode);
+        }
+        else if let Some(extension_map) = attribute.strip_prefix(EXTENSION_MAP_KEY).and_then(|value| ExtensionMap::parse(value).ok()) {
+            extension_maps.push(extension_map);
+        }
+        else {
+            attributes.push(parse_attribute(attribute)?);
+        }
+    }
+    Ok((bandwidth, data_transfer_mode, extension_maps, attributes))
+}
+
+/// Parses the value of an `a=` line into an attribute.
+///
+/// # Arguments
+///
+/// * `data` - A byte slice containing `<attribute>` or `<attribute>:<value>`.
+///
+/// # Returns
+///
+/// A `Result` containing the attribute, or a `ParsingError` if it is not valid UTF-8.
+pub(crate) fn parse_attribute(data: &[u8]) -> Result<UnknownMediaAttribute, ParsingError> {
+    match data.separate(COLON) {
+        Some((name, value)) => Ok(UnknownMediaAttribute::new(
+            name.utf8_to_str()?.to_string(),
+            Some(value.utf8_to_str()?.to_string()),
+        )),
+        None => Ok(UnknownMediaAttribute::new(data.utf8_to_str()?.to_string(), None)),
+    }
+}
+
+/// Orders the codecs as the formats of the `m=` line and resolves the formats without an `a=rtpmap` line.
+///
+/// # Arguments
+///
+/// * `codec_formats` - The formats of the `m=` line.
+/// * `mapped_codecs` - The codecs parsed from the `a=rtpmap` lines.
+///
+/// # Returns
+///
+/// An `Option` containing a codec for every format, or `None` if a dynamic format has no `a=rtpmap`
+/// line or an `a=rtpmap` line describes a format that is not listed.
+fn get_codecs(codec_formats: &[u16], mut mapped_codecs: Vec<CodecType>) -> Option<Vec<CodecType>> {
+    let mut codecs = Vec::with_capacity(codec_formats.len());
+    for &format in codec_formats {
+        let codec = match mapped_codecs.iter().position(|codec| codec.format() == format) {
+            Some(index) => mapped_codecs.remove(index),
+            None => CodecType::from_static_payload_type(format)?,
+        };
+        codecs.push(codec);
+    }
+    mapped_codecs.is_empty().then_some(codecs)
+}
+
 /// Parses the ports from a byte slice.
 ///
 /// # Arguments
@@ -232,20 +487,22 @@ impl PayloadParser for MediaDescription {
 ///
 /// # Returns
 ///
-/// A `Result` containing a vector of `SdpPort` instances if successful, or a `ParsingError` if the parsing fails.
+/// A `Result` containing a vector of `SdpPort` instances if successful, or a `ParsingError` if the parsing fails,
+/// the number of ports is zero or a port does not fit in 16 bits.
 fn get_ports(ports_block: &[u8]) -> Result<Vec<SdpPort>, ParsingError> {
-    let mut ports: Vec<SdpPort> = Vec::new();
-    if let Some((port, count)) = ports_block.separate(SLASH) {
-        let mut port = port.utf8_to_number::<u16>()?;
-        let count = count.utf8_to_number::<u8>()?;
-
-        for _ in 1..count {
-            ports.push(SdpPort::new(port, port + 1));
-            port += 2;
-        }
-    } else {
-        let rtp_port = ports_block.utf8_to_number::<u16>()?;
-        ports.push(SdpPort::new(rtp_port, rtp_port + 1));
+    let (port, count) = match ports_block.separate(SLASH) {
+        Some((port, count)) => (port.utf8_to_number::<u16>()?, count.utf8_to_number::<u8>()?),
+        None => (ports_block.utf8_to_number::<u16>()?, 1),
+    };
+    if count == 0 {
+        return Err(ParsingError::from_bytes(ports_block));
     }
-    Ok(ports)
+
+    (0..count as u16)
+        .map(|index| {
+            port.checked_add(index * 2)
+                .and_then(|rtp_port| Some(SdpPort::new(rtp_port, rtp_port.checked_add(1)?)))
+                .ok_or_else(|| ParsingError::from_bytes(ports_block))
+        })
+        .collect()
 }
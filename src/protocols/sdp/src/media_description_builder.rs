@@ -0,0 +1,161 @@
+use abstractions::net::connection_addresses::ConnectionAddresses;
+use media::codec_type::CodecType;
+
+use crate::{
+    bandwidth::Bandwidth, data_transfer_mode::DataTransferMode, extension_map::ExtensionMap, media_attribute::UnknownMediaAttribute,
+    media_description::MediaDescription, payload_type::PayloadType, sdp_error::SdpError, sdp_port::SdpPort,
+    transport_protocol::MediaTransportProtocol,
+};
+
+/// Builds a [`MediaDescription`] for an `m=` section.
+///
+/// The port defaults to `0`, which is what RTSP servers announce as the transport is
+/// negotiated with `SETUP`, and the transport protocol defaults to `RTP/AVP`.
+///
+/// # Example
+///
+/// ```rust
+/// use media::{codec_type::CodecType, unsupported_codec::UnsupportedCodec};
+/// use sdp::{media_description_builder::MediaDescriptionBuilder, payload_type::PayloadType};
+///
+/// let media_description = MediaDescriptionBuilder::new(PayloadType::Audio)
+///     .codec(CodecType::Unsupported(UnsupportedCodec::new("PCMU".to_string(), 0, 8000, None, None)))
+///     .control("trackID=1")
+///     .build()
+///     .unwrap();
+///
+/// assert_eq!(
+///     media_description.to_string(),
+///     "m=audio 0 RTP/AVP 0\r\na=rtpmap:0 PCMU/8000\r\na=control:trackID=1\r\n"
+/// );
+/// ```
+#[derive(Debug)]
+pub struct MediaDescriptionBuilder {
+    payload_type: PayloadType,
+    rtp_port: u16,
+    port_count: u16,
+    transport_protocol: MediaTransportProtocol,
+    codecs: Vec<CodecType>,
+    connection_addresses: Option<ConnectionAddresses>,
+    bandwidth: Option<Bandwidth>,
+    data_transfer_mode: Option<DataTransferMode>,
+    control: Option<String>,
+    extension_maps: Vec<ExtensionMap>,
+    attributes: Vec<UnknownMediaAttribute>,
+}
+
+impl MediaDescriptionBuilder {
+    /// Creates a new `MediaDescriptionBuilder` for the given media type.
+    pub fn new(payload_type: PayloadType) -> Self {
+        Self {
+            payload_type,
+            rtp_port: 0,
+            port_count: 1,
+            transport_protocol: MediaTransportProtocol::RtpAvp,
+            codecs: Vec::new(),
+            connection_addresses: None,
+            bandwidth: None,
+            data_transfer_mode: None,
+            control: None,
+            extension_maps: Vec::new(),
+            attributes: Vec::new(),
+        }
+    }
+
+    /// Sets the RTP port; the RTCP port is the next one.
+    pub fn port(self, rtp_port: u16) -> Self {
+        self.port_range(rtp_port, 1)
+    }
+
+    /// Sets `count` consecutive RTP/RTCP port pairs starting at `rtp_port`, written as `<port>/<count>`.
+    ///
+    /// Every port of the range has to fit into 16 bits, which `build` checks.
+    pub fn port_range(mut self, rtp_port: u16, count: u16) -> Self {
+        self.rtp_port = rtp_port;
+        self.port_count = count;
+        self
+    }
+
+    pub fn transport_protocol(mut self, transport_protocol: MediaTransportProtocol) -> Self {
+        self.transport_protocol = transport_protocol;
+        self
+    }
+
+    /// Adds a codec; its format is appended to the `m=` line.
+    pub fn codec(mut self, codec: CodecType) -> Self {
+        self.codecs.push(codec);
+        self
+    }
+
+    pub fn connection_addresses(mut self, connection_addresses: ConnectionAddresses) -> Self {
+        self.connection_addresses = Some(connection_addresses);
+        self
+    }
+
+    pub fn bandwidth(mut self, bandwidth: Bandwidth) -> Self {
+        self.bandwidth = Some(bandwidth);
+        self
+    }
+
+    pub fn data_transfer_mode(mut self, data_transfer_mode: DataTransferMode) -> Self {
+        self.data_transfer_mode = Some(data_transfer_mode);
+        self
+    }
+
+    /// Sets the `a=control` URL, absolute or relative to the session.
+    pub fn control(mut self, control: impl Into<String>) -> Self {
+        self.control = Some(control.into());
+        self
+    }
+
+    /// Adds an `a=extmap` attribute mapping an RTP header extension to an identifier.
+    pub fn extension_map(mut self, extension_map: ExtensionMap) -> Self {
+        self.extension_maps.push(extension_map);
+        self
+    }
+
+    /// Adds an `a=<name>[:<value>]` attribute.
+    pub fn attribute(mut self, name: impl Into<String>, value: Option<String>) -> Self {
+        self.attributes.push(UnknownMediaAttribute::new(name.into(), value));
+        self
+    }
+
+    /// Builds the media description.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the media description, or an `SdpError` if no codec or port was set
+    /// or if the port range goes beyond port 65535.
+    pub fn build(self) -> Result<MediaDescription, SdpError> {
+        if self.codecs.is_empty() {
+            return Err(SdpError::MissingField("codecs"));
+        }
+        if self.port_count == 0 {
+            return Err(SdpError::MissingField("ports"));
+        }
+
+        let ports = (0..self.port_count)
+            .map(|index| {
+                let rtp_port = index.checked_mul(2).and_then(|offset| self.rtp_port.checked_add(offset))?;
+                let rtcp_port = rtp_port.checked_add(1)?;
+                Some(SdpPort::new(rtp_port, rtcp_port))
+            })
+            .collect::<Option<Vec<_>>>()
+            .ok_or(SdpError::InvalidField("ports"))?;
+        let port_count = ports.len();
+        let mut media_description = MediaDescription::new(
+            self.bandwidth,
+            self.codecs,
+            self.data_transfer_mode,
+            self.payload_type,
+            ports,
+            port_count,
+            self.transport_protocol,
+            self.connection_addresses,
+        );
+        media_description.set_control(self.control);
+        media_description.set_extension_maps(self.extension_maps);
+        media_description.set_attributes(self.attributes);
+        Ok(media_description)
+    }
+}
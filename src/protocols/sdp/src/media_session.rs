@@ -1,7 +1,10 @@
+use std::fmt;
+
 use super::bandwidth::Bandwidth;
 use crate::{
-    data_transfer_mode::DataTransferMode, media_attribute::UnknownMediaAttribute,
-    media_description::MediaDescription, origin::Origin, time::timing::Timing,
+    data_transfer_mode::DataTransferMode, extension_map::ExtensionMap, media_attribute::UnknownMediaAttribute,
+    media_description::{parse_attribute, MediaDescription, EXTENSION_MAP_KEY}, origin::Origin,
+    time::{repeat_times::RepeatTimes, time_zones::TimeZones, timing::Timing},
 };
 
 use abstractions::{
@@ -24,10 +27,13 @@ const PHONE: &[u8] = b"p";
 const CONNECTION: &[u8] = b"c";
 const BANDWIDTH: &[u8] = b"b";
 const TIMING: &[u8] = b"t";
+const REPEAT_TIMES: &[u8] = b"r";
+const TIME_ZONES: &[u8] = b"z";
+const ENCRYPTION_KEY: &[u8] = b"k";
 const MEDIA_DESC: &[u8] = b"m";
 const ATTRIBUTE: &[u8] = b"a";
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Default, PartialEq)]
 pub struct MediaSession {
     /// v=  
     protocol_version: i32,
@@ -62,32 +68,20 @@ pub struct MediaSession {
     /// t=
     timing: Option<Timing>,
 
+    /// r=
+    repeat_times: Option<RepeatTimes>,
+
+    /// z=
+    time_zones: Option<TimeZones>,
+
     media_descriptions: Vec<MediaDescription>,
 
     media_attributes: Vec<UnknownMediaAttribute>,
 
-    data_transfer_mode: Option<DataTransferMode>,
-}
+    /// a=extmap
+    extension_maps: Vec<ExtensionMap>,
 
-impl Default for MediaSession {
-    fn default() -> Self {
-        Self {
-            protocol_version: Default::default(),
-            originator_of_session: Default::default(),
-            session_name: Default::default(),
-            media_title: None,
-            uri_of_description: None,
-            email_address: None,
-            phone_number: None,
-            connection_addresses: None,
-            encryption_key: None,
-            bandwidth: None,
-            timing: None,
-            media_descriptions: Default::default(),
-            media_attributes: Default::default(),
-            data_transfer_mode: None,
-        }
-    }
+    data_transfer_mode: Option<DataTransferMode>,
 }
 
 impl PayloadParser for MediaSession {
@@ -109,11 +103,15 @@ impl PayloadParser for MediaSession {
                     CONNECTION => {
                         session.set_connection_address(Some(ConnectionAddresses::parse(right)?))
                     }
-                    BANDWIDTH => session.set_bandwidth(Some(Bandwidth::parse(data)?)),
+                    BANDWIDTH => session.set_bandwidth(Some(Bandwidth::parse(right)?)),
                     TIMING => session.set_timing(Some(Timing::parse(right)?)),
+                    REPEAT_TIMES => session.set_repeat_times(Some(RepeatTimes::parse(right)?)),
+                    TIME_ZONES => session.set_time_zones(Some(TimeZones::parse(right)?)),
+                    ENCRYPTION_KEY => session.set_encryption_key(Some(right.utf8_to_str()?.to_string())),
                     MEDIA_DESC => {
                         session.set_media_attributes(media_attributes);
                         let mut media_descriptions: Vec<MediaDescription> = Vec::default();
+                        let extension_maps = std::mem::take(&mut session.extension_maps);
                         let separator = [MEDIA_DESC, EQUAL].concat();
                         (_, slice) = slice
                             .separate(separator.as_slice())
@@ -122,31 +120,27 @@ impl PayloadParser for MediaSession {
                         while let Some((top, bot)) =
                             slice.while_separate_trimmed(separator.as_slice(), WHITESPACE)
                         {
-                            let media_desc: MediaDescription = MediaDescription::parse(top)?;
+                            let mut media_desc: MediaDescription = MediaDescription::parse(top)?;
+                            media_desc.inherit_extension_maps(&extension_maps);
                             media_descriptions.push(media_desc);
                             slice = bot;
                         }
                         session.set_media_descriptions(media_descriptions);
+                        session.set_extension_maps(extension_maps);
                         break;
                     }
                     ATTRIBUTE => {
-                        if let Some((key, value)) = right.separate_trimmed(WHITESPACE, WHITESPACE) {
-                            let media_attribute = UnknownMediaAttribute::new(
-                                key.utf8_to_str()?.to_string(),
-                                Some(value.utf8_to_str()?.to_string()),
-                            );
-                            media_attributes.push(media_attribute);
-                        } 
-                        else if let Some(data_transfer_mode) = DataTransferMode::from_bytes(right) {
+                        if let Some(data_transfer_mode) = DataTransferMode::from_bytes(right) {
                             session.set_data_transfer_mode(Some(data_transfer_mode));
                         } 
+                        else if let Some(extension_map) = right.strip_prefix(EXTENSION_MAP_KEY).and_then(|value| ExtensionMap::parse(value).ok()) {
+                            session.extension_maps.push(extension_map);
+                        }
                         else {
-                            let media_attribute =
-                                UnknownMediaAttribute::new(right.utf8_to_str()?.to_string(), None);
-                            media_attributes.push(media_attribute);
+                            media_attributes.push(parse_attribute(right)?);
                         }
                     }
-                    _ => { continue; }
+                    _ => {}
                 }
             }
             slice = bot;
@@ -158,7 +152,87 @@ impl PayloadParser for MediaSession {
     }
 }
 
+/// Writes the session description with the fields in the order required by RFC 4566.
+///
+/// ```text
+/// v=  (protocol version)
+/// o=  (originator and session identifier)
+/// s=  (session name)
+/// i=* (session information)
+/// u=* (URI of description)
+/// e=* (email address)
+/// p=* (phone number)
+/// c=* (connection information)
+/// b=* (bandwidth information)
+/// t=  (time the session is active)
+/// r=* (repeat times)
+/// z=* (time zone adjustments)
+/// k=* (encryption key)
+/// a=* (session attributes)
+/// m=  (media descriptions)
+/// ```
+impl fmt::Display for MediaSession {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "v={}\r\n", self.protocol_version)?;
+        write!(f, "o={}\r\n", self.originator_of_session)?;
+        write!(f, "s={}\r\n", self.session_name)?;
+        if let Some(media_title) = &self.media_title {
+            write!(f, "i={}\r\n", media_title)?;
+        }
+        if let Some(uri) = &self.uri_of_description {
+            write!(f, "u={}\r\n", uri)?;
+        }
+        if let Some(email_address) = &self.email_address {
+            write!(f, "e={}\r\n", email_address)?;
+        }
+        if let Some(phone_number) = &self.phone_number {
+            write!(f, "p={}\r\n", phone_number)?;
+        }
+        if let Some(connection_addresses) = &self.connection_addresses {
+            write!(f, "c={}\r\n", connection_addresses)?;
+        }
+        if let Some(bandwidth) = &self.bandwidth {
+            write!(f, "b={}\r\n", bandwidth)?;
+        }
+        match &self.timing {
+            Some(timing) => write!(f, "t={}\r\n", timing)?,
+            None => f.write_str("t=0 0\r\n")?,
+        }
+        if let Some(repeat_times) = &self.repeat_times {
+            write!(f, "r={}\r\n", repeat_times)?;
+        }
+        if let Some(time_zones) = &self.time_zones {
+            write!(f, "z={}\r\n", time_zones)?;
+        }
+        if let Some(encryption_key) = &self.encryption_key {
+            write!(f, "k={}\r\n", encryption_key)?;
+        }
+        for attribute in &self.media_attributes {
+            write!(f, "a={}\r\n", attribute)?;
+        }
+        if let Some(data_transfer_mode) = self.data_transfer_mode {
+            write!(f, "a={}\r\n", data_transfer_mode.as_str())?;
+        }
+        for extension_map in &self.extension_maps {
+            write!(f, "a=extmap:{}\r\n", extension_map)?;
+        }
+        for media_description in &self.media_descriptions {
+            write!(f, "{}", media_description)?;
+        }
+        Ok(())
+    }
+}
+
 impl MediaSession {
+    /// Serializes the session description into the body of a `DESCRIBE` response.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        self.to_string().into_bytes()
+    }
+
+    pub fn protocol_version(&self) -> i32 {
+        self.protocol_version
+    }
+
     pub fn originator_of_session(&self) -> &Origin {
         &self.originator_of_session
     }
@@ -199,6 +273,14 @@ impl MediaSession {
         self.timing.as_ref()
     }
 
+    pub fn repeat_times(&self) -> Option<&RepeatTimes> {
+        self.repeat_times.as_ref()
+    }
+
+    pub fn time_zones(&self) -> Option<&TimeZones> {
+        self.time_zones.as_ref()
+    }
+
     pub fn media_descriptions(&self) -> &[MediaDescription] {
         &self.media_descriptions
     }
@@ -207,62 +289,87 @@ impl MediaSession {
         &self.media_attributes
     }
 
+    /// Returns the session-level `a=control` attribute, the aggregate control URL of the presentation.
+    pub fn control(&self) -> Option<&str> {
+        self.media_attributes
+            .iter()
+            .find(|attribute| attribute.name() == "control")
+            .and_then(|attribute| attribute.value())
+    }
+
+    /// Returns the session-level `a=extmap` attributes, which also apply to every media description.
+    pub fn extension_maps(&self) -> &[ExtensionMap] {
+        &self.extension_maps
+    }
+
     pub fn data_transfer_mode(&self) -> Option<DataTransferMode> {
         self.data_transfer_mode
     }
 
-    fn set_protocol_version(&mut self, protocol_version: i32) {
+    pub(crate) fn set_protocol_version(&mut self, protocol_version: i32) {
         self.protocol_version = protocol_version;
     }
 
-    fn set_originator_of_session(&mut self, originator_of_session: Origin) {
+    pub(crate) fn set_originator_of_session(&mut self, originator_of_session: Origin) {
         self.originator_of_session = originator_of_session;
     }
 
-    fn set_session_name(&mut self, session_name: String) {
+    pub(crate) fn set_session_name(&mut self, session_name: String) {
         self.session_name = session_name;
     }
 
-    fn set_media_title(&mut self, media_title: Option<String>) {
+    pub(crate) fn set_media_title(&mut self, media_title: Option<String>) {
         self.media_title = media_title;
     }
 
-    fn set_uri_of_description(&mut self, uri_of_description: Option<Uri>) {
+    pub(crate) fn set_uri_of_description(&mut self, uri_of_description: Option<Uri>) {
         self.uri_of_description = uri_of_description;
     }
 
-    fn set_email_address(&mut self, email_address: Option<String>) {
+    pub(crate) fn set_email_address(&mut self, email_address: Option<String>) {
         self.email_address = email_address;
     }
 
-    fn set_phone_number(&mut self, phone_number: Option<String>) {
+    pub(crate) fn set_phone_number(&mut self, phone_number: Option<String>) {
         self.phone_number = phone_number;
     }
 
-    fn set_connection_address(&mut self, connection_addresses: Option<ConnectionAddresses>) {
+    pub(crate) fn set_connection_address(&mut self, connection_addresses: Option<ConnectionAddresses>) {
         self.connection_addresses = connection_addresses;
     }
 
-    fn set_encryption_key(&mut self, encryption_key: Option<String>) {
+    pub(crate) fn set_encryption_key(&mut self, encryption_key: Option<String>) {
         self.encryption_key = encryption_key;
     }
 
-    fn set_bandwidth(&mut self, bandwidth: Option<Bandwidth>) {
+    pub(crate) fn set_bandwidth(&mut self, bandwidth: Option<Bandwidth>) {
         self.bandwidth = bandwidth;
     }
 
-    fn set_timing(&mut self, timing: Option<Timing>) {
+    pub(crate) fn set_timing(&mut self, timing: Option<Timing>) {
         self.timing = timing;
     }
 
-    fn set_media_descriptions(&mut self, media_descriptions: Vec<MediaDescription>) {
+    pub(crate) fn set_repeat_times(&mut self, repeat_times: Option<RepeatTimes>) {
+        self.repeat_times = repeat_times;
+    }
+
+    pub(crate) fn set_time_zones(&mut self, time_zones: Option<TimeZones>) {
+        self.time_zones = time_zones;
+    }
+
+    pub(crate) fn set_media_descriptions(&mut self, media_descriptions: Vec<MediaDescription>) {
         self.media_descriptions = media_descriptions;
     }
 
-    fn set_media_attributes(&mut self, media_attributes: Vec<UnknownMediaAttribute>) {
+    pub(crate) fn set_media_attributes(&mut self, media_attributes: Vec<UnknownMediaAttribute>) {
         self.media_attributes = media_attributes;
     }
 
+    pub(crate) fn set_extension_maps(&mut self, extension_maps: Vec<ExtensionMap>) {
+        self.extension_maps = extension_maps;
+    }
+
     pub fn set_data_transfer_mode(&mut self, data_transfer_mode: Option<DataTransferMode>) {
         self.data_transfer_mode = data_transfer_mode;
     }
@@ -275,5 +382,5 @@ impl MediaSession {
 }
 
 fn get_uri(data: &[u8]) -> Result<Uri, ParsingError> {
-    Ok(Uri::try_from(data).map_err(|_| ParsingError::from_bytes(data))?)
+    Uri::try_from(data).map_err(|_| ParsingError::from_bytes(data))
 }
@@ -0,0 +1,176 @@
+use abstractions::net::connection_addresses::ConnectionAddresses;
+use http::Uri;
+
+use crate::{
+    bandwidth::Bandwidth, data_transfer_mode::DataTransferMode, media_attribute::UnknownMediaAttribute,
+    media_description::MediaDescription, media_session::MediaSession, origin::Origin, sdp_error::SdpError,
+    time::{repeat_times::RepeatTimes, time_zones::TimeZones, timing::Timing},
+};
+
+/// Builds a [`MediaSession`] without formatting SDP text by hand.
+///
+/// `build` enforces the same invariants as parsing: an origin, a session name and
+/// at least one media description are required.
+///
+/// # Example
+///
+/// ```rust
+/// use media::{codec_type::CodecType, unsupported_codec::UnsupportedCodec};
+/// use sdp::{
+///     media_description_builder::MediaDescriptionBuilder, media_session_builder::MediaSessionBuilder,
+///     origin_builder::OriginBuilder, payload_type::PayloadType,
+/// };
+///
+/// let media_session = MediaSessionBuilder::new()
+///     .origin(OriginBuilder::new().session_id("1").network_address("192.0.2.10".parse().unwrap()).build().unwrap())
+///     .session_name("Camera")
+///     .media_description(
+///         MediaDescriptionBuilder::new(PayloadType::Audio)
+///             .codec(CodecType::Unsupported(UnsupportedCodec::new("PCMU".to_string(), 0, 8000, None, None)))
+///             .build()
+///             .unwrap(),
+///     )
+///     .build()
+///     .unwrap();
+///
+/// assert_eq!(media_session.session_name(), "Camera");
+/// ```
+#[derive(Debug, Default)]
+pub struct MediaSessionBuilder {
+    origin: Option<Origin>,
+    session_name: Option<String>,
+    media_title: Option<String>,
+    uri_of_description: Option<Uri>,
+    email_address: Option<String>,
+    phone_number: Option<String>,
+    connection_addresses: Option<ConnectionAddresses>,
+    bandwidth: Option<Bandwidth>,
+    timing: Option<Timing>,
+    repeat_times: Option<RepeatTimes>,
+    time_zones: Option<TimeZones>,
+    encryption_key: Option<String>,
+    media_attributes: Vec<UnknownMediaAttribute>,
+    data_transfer_mode: Option<DataTransferMode>,
+    media_descriptions: Vec<MediaDescription>,
+}
+
+impl MediaSessionBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn origin(mut self, origin: Origin) -> Self {
+        self.origin = Some(origin);
+        self
+    }
+
+    pub fn session_name(mut self, session_name: impl Into<String>) -> Self {
+        self.session_name = Some(session_name.into());
+        self
+    }
+
+    pub fn media_title(mut self, media_title: impl Into<String>) -> Self {
+        self.media_title = Some(media_title.into());
+        self
+    }
+
+    pub fn uri_of_description(mut self, uri_of_description: Uri) -> Self {
+        self.uri_of_description = Some(uri_of_description);
+        self
+    }
+
+    pub fn email_address(mut self, email_address: impl Into<String>) -> Self {
+        self.email_address = Some(email_address.into());
+        self
+    }
+
+    pub fn phone_number(mut self, phone_number: impl Into<String>) -> Self {
+        self.phone_number = Some(phone_number.into());
+        self
+    }
+
+    pub fn connection_addresses(mut self, connection_addresses: ConnectionAddresses) -> Self {
+        self.connection_addresses = Some(connection_addresses);
+        self
+    }
+
+    pub fn bandwidth(mut self, bandwidth: Bandwidth) -> Self {
+        self.bandwidth = Some(bandwidth);
+        self
+    }
+
+    /// Sets the `t=` field; an unbounded session (`t=0 0`) is written if not set.
+    pub fn timing(mut self, timing: Timing) -> Self {
+        self.timing = Some(timing);
+        self
+    }
+
+    pub fn repeat_times(mut self, repeat_times: RepeatTimes) -> Self {
+        self.repeat_times = Some(repeat_times);
+        self
+    }
+
+    pub fn time_zones(mut self, time_zones: TimeZones) -> Self {
+        self.time_zones = Some(time_zones);
+        self
+    }
+
+    pub fn encryption_key(mut self, encryption_key: impl Into<String>) -> Self {
+        self.encryption_key = Some(encryption_key.into());
+        self
+    }
+
+    /// Adds a session-level `a=<name>[:<value>]` attribute.
+    pub fn attribute(mut self, name: impl Into<String>, value: Option<String>) -> Self {
+        self.media_attributes.push(UnknownMediaAttribute::new(name.into(), value));
+        self
+    }
+
+    pub fn data_transfer_mode(mut self, data_transfer_mode: DataTransferMode) -> Self {
+        self.data_transfer_mode = Some(data_transfer_mode);
+        self
+    }
+
+    /// Adds a media description; the descriptions are written in the order they are added.
+    pub fn media_description(mut self, media_description: MediaDescription) -> Self {
+        self.media_descriptions.push(media_description);
+        self
+    }
+
+    /// Builds the session description.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the session, or an `SdpError` naming the first missing field.
+    pub fn build(self) -> Result<MediaSession, SdpError> {
+        let origin = self
+            .origin
+            .filter(|origin| *origin != Origin::default())
+            .ok_or(SdpError::MissingField("origin"))?;
+        let session_name = self
+            .session_name
+            .filter(|session_name| !session_name.is_empty())
+            .ok_or(SdpError::MissingField("session_name"))?;
+        if self.media_descriptions.is_empty() {
+            return Err(SdpError::MissingField("media_descriptions"));
+        }
+
+        let mut session = MediaSession::default();
+        session.set_originator_of_session(origin);
+        session.set_session_name(session_name);
+        session.set_media_title(self.media_title);
+        session.set_uri_of_description(self.uri_of_description);
+        session.set_email_address(self.email_address);
+        session.set_phone_number(self.phone_number);
+        session.set_connection_address(self.connection_addresses);
+        session.set_bandwidth(self.bandwidth);
+        session.set_timing(self.timing);
+        session.set_repeat_times(self.repeat_times);
+        session.set_time_zones(self.time_zones);
+        session.set_encryption_key(self.encryption_key);
+        session.set_media_attributes(self.media_attributes);
+        session.set_data_transfer_mode(self.data_transfer_mode);
+        session.set_media_descriptions(self.media_descriptions);
+        Ok(session)
+    }
+}
@@ -1,4 +1,5 @@
 use std::{
+    fmt,
     net::IpAddr, 
     str::FromStr
 };
@@ -114,6 +115,21 @@ impl PayloadParser for Origin {
     }
 }
 
+/// Writes the value of the `o=` field, e.g. `- 2890844526 2890842807 IN IP4 192.0.2.10`.
+impl fmt::Display for Origin {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} {} {} {} {} {}",
+            self.user_name,
+            self.session_id,
+            self.session_version,
+            self.network_type.as_str(),
+            self.address_type.as_str(),
+            self.network_address
+        )
+    }
+}
 
 impl Default for Origin {
     fn default() -> Self {
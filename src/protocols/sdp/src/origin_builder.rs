@@ -0,0 +1,87 @@
+use std::net::IpAddr;
+
+use abstractions::net::address_type::AddressType;
+
+use crate::{origin::Origin, sdp_error::SdpError};
+
+/// Username written when the originating host has no concept of user IDs.
+const ANONYMOUS_USER_NAME: &str = "-";
+
+/// Builds an [`Origin`] for the `o=` field.
+///
+/// The address type is derived from the network address. The session version defaults to
+/// the session identifier, as RFC 4566 recommends an NTP timestamp for both.
+///
+/// # Example
+///
+/// ```rust
+/// use sdp::origin_builder::OriginBuilder;
+///
+/// let origin = OriginBuilder::new()
+///     .session_id("2890844526")
+///     .network_address("192.0.2.10".parse().unwrap())
+///     .build()
+///     .unwrap();
+///
+/// assert_eq!(origin.to_string(), "- 2890844526 2890844526 IN IP4 192.0.2.10");
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct OriginBuilder {
+    user_name: Option<String>,
+    session_id: Option<String>,
+    session_version: Option<String>,
+    network_address: Option<IpAddr>,
+}
+
+impl OriginBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the username, `-` if not set.
+    pub fn user_name(mut self, user_name: impl Into<String>) -> Self {
+        self.user_name = Some(user_name.into());
+        self
+    }
+
+    pub fn session_id(mut self, session_id: impl Into<String>) -> Self {
+        self.session_id = Some(session_id.into());
+        self
+    }
+
+    pub fn session_version(mut self, session_version: impl Into<String>) -> Self {
+        self.session_version = Some(session_version.into());
+        self
+    }
+
+    pub fn network_address(mut self, network_address: IpAddr) -> Self {
+        self.network_address = Some(network_address);
+        self
+    }
+
+    /// Builds the origin.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the origin, or an `SdpError` if the session identifier
+    /// or the network address is missing.
+    pub fn build(self) -> Result<Origin, SdpError> {
+        let session_id = self
+            .session_id
+            .filter(|session_id| !session_id.is_empty())
+            .ok_or(SdpError::MissingField("session_id"))?;
+        let network_address = self.network_address.ok_or(SdpError::MissingField("network_address"))?;
+        let address_type = match network_address {
+            IpAddr::V4(_) => AddressType::Ipv4,
+            IpAddr::V6(_) => AddressType::Ipv6,
+        };
+
+        Ok(Origin::new(
+            self.user_name.unwrap_or_else(|| ANONYMOUS_USER_NAME.to_string()),
+            self.session_version.unwrap_or_else(|| session_id.clone()),
+            session_id,
+            address_type,
+            network_address,
+        ))
+    }
+}
@@ -47,6 +47,7 @@ impl PayloadType {
         }
     }
 
+    #[allow(clippy::should_implement_trait)]
     pub fn from_str(s: &str) -> Result<Self, ParsingError> {
         match s {
             "video" => Ok(PayloadType::Video),
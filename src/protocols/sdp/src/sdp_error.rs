@@ -0,0 +1,13 @@
+use thiserror::Error;
+
+/// Represents errors that can occur while building a session description.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum SdpError {
+    /// Represents a required field that was not set.
+    #[error("Missing field: {0}")]
+    MissingField(&'static str),
+
+    /// Represents a field whose value cannot be written.
+    #[error("Invalid field: {0}")]
+    InvalidField(&'static str),
+}
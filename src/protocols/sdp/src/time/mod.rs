@@ -1,2 +1,3 @@
 pub mod timing;
 pub mod repeat_times;
+pub mod time_zones;
@@ -1,3 +1,5 @@
+use std::fmt;
+
 use abstractions::{
     extensions::{array_extensions::ArrayExt, utf8_array_extensions::U8ArrayExt},
     parsing::{parsing_error::ParsingError, payload_parser::PayloadParser, WHITESPACE},
@@ -28,6 +30,7 @@ use chrono::Duration;
 ///
 /// This example indicates that the session repeats every week (604800 seconds),
 /// is active for one hour (3600 seconds), and has offsets of 0 and 25 hours (90000 seconds).
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct RepeatTimes {
     repeat_interval: Duration,
     active_duration: Duration,
@@ -98,7 +101,21 @@ impl RepeatTimes {
     }
 }
 
-fn parse_duration(data: &[u8]) -> Result<Duration, ParsingError> {
+/// Writes the value of the `r=` field in seconds, e.g. `604800 3600 0 90000`.
+impl fmt::Display for RepeatTimes {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} {} {} {}",
+            self.repeat_interval.num_seconds(),
+            self.active_duration.num_seconds(),
+            self.offset1.num_seconds(),
+            self.offset2.num_seconds()
+        )
+    }
+}
+
+pub(crate) fn parse_duration(data: &[u8]) -> Result<Duration, ParsingError> {
     let Some((unit, value)) = data.split_last() else {
         return Err(ParsingError::from_str("Data is empty."));
     };
@@ -106,12 +123,12 @@ fn parse_duration(data: &[u8]) -> Result<Duration, ParsingError> {
     if !has_unit_specifier {
         let value: i64 = data
             .utf8_to_number::<i64>()
-            .map_err(|e| ParsingError::Utf8Error(e))?;
+            .map_err(ParsingError::Utf8Error)?;
         return Ok(Duration::seconds(value));
     }
     let value: i64 = value
         .utf8_to_number::<i64>()
-        .map_err(|e| ParsingError::Utf8Error(e))?;
+        .map_err(ParsingError::Utf8Error)?;
     match unit {
         b'd' => Ok(Duration::days(value)),
         b'h' => Ok(Duration::hours(value)),
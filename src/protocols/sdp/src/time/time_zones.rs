@@ -0,0 +1,107 @@
+use std::fmt;
+
+use abstractions::{
+    extensions::{array_extensions::ArrayExt, utf8_array_extensions::U8ArrayExt},
+    parsing::{parsing_error::ParsingError, payload_parser::PayloadParser, WHITESPACE},
+};
+use chrono::Duration;
+
+use super::repeat_times::parse_duration;
+
+/// Represents a single adjustment of the `z=` field.
+///
+/// # Fields
+///
+/// * `adjustment_time` - The NTP time at which the adjustment takes effect.
+/// * `offset` - The offset applied to the base time from that moment on.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TimeZoneAdjustment {
+    adjustment_time: u64,
+    offset: Duration,
+}
+
+impl TimeZoneAdjustment {
+    pub fn new(adjustment_time: u64, offset: Duration) -> Self {
+        Self { adjustment_time, offset }
+    }
+
+    pub fn adjustment_time(&self) -> u64 {
+        self.adjustment_time
+    }
+
+    pub fn offset(&self) -> Duration {
+        self.offset
+    }
+}
+
+/// Represents the time zone adjustments field in an SDP message.
+///
+/// The `TimeZones` struct corresponds to the `z=` field in SDP, which lets repeated sessions
+/// follow daylight-saving changes.
+///
+/// According to RFC 4566, the `z=` field has the following syntax:
+///
+/// ```text
+/// z=<adjustment time> <offset> <adjustment time> <offset> ....
+/// ```
+///
+/// Example:
+///
+/// ```text
+/// z=2882844526 -1h 2898848070 0
+/// ```
+///
+/// This example moves the base time back by one hour at the first adjustment time and
+/// restores it at the second one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TimeZones {
+    adjustments: Vec<TimeZoneAdjustment>,
+}
+
+impl PayloadParser for TimeZones {
+    fn parse(data: &[u8]) -> Result<Self, ParsingError> {
+        let mut adjustments = Vec::new();
+        let mut slice = data;
+        while !slice.is_empty() {
+            let (adjustment_time, other) = slice
+                .separate_trimmed(WHITESPACE, WHITESPACE)
+                .ok_or_else(|| ParsingError::from_bytes(data))?;
+            let (offset, other) = other.separate_trimmed(WHITESPACE, WHITESPACE).unwrap_or((other, &[]));
+
+            adjustments.push(TimeZoneAdjustment::new(adjustment_time.utf8_to_number::<u64>()?, parse_duration(offset)?));
+            slice = other;
+        }
+        if adjustments.is_empty() {
+            return Err(ParsingError::from_bytes(data));
+        }
+        Ok(TimeZones::new(adjustments))
+    }
+}
+
+impl TimeZones {
+    /// Creates a new `TimeZones` instance.
+    ///
+    /// # Arguments
+    ///
+    /// * `adjustments` - The adjustments, in chronological order.
+    pub fn new(adjustments: Vec<TimeZoneAdjustment>) -> Self {
+        Self { adjustments }
+    }
+
+    pub fn adjustments(&self) -> &[TimeZoneAdjustment] {
+        &self.adjustments
+    }
+}
+
+/// Writes the value of the `z=` field with offsets in seconds, e.g. `2882844526 -3600 2898848070 0`.
+impl fmt::Display for TimeZones {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (index, adjustment) in self.adjustments.iter().enumerate() {
+            if index > 0 {
+                f.write_str(" ")?;
+            }
+            write!(f, "{} {}", adjustment.adjustment_time, adjustment.offset.num_seconds())?;
+        }
+        Ok(())
+    }
+}
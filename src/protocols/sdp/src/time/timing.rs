@@ -1,3 +1,5 @@
+use std::fmt;
+
 use abstractions::{
     extensions::{
         array_extensions::ArrayExt, utf8_array_extensions::U8ArrayExt
@@ -72,6 +74,16 @@ impl PayloadParser for Timing {
     }
 }
 
+/// Writes the value of the `t=` field as NTP seconds, e.g. `2873397496 2873404696`.
+///
+/// A session without a stop time is written with a stop time of `0`.
+impl fmt::Display for Timing {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let stop_time = self.stop_time.map_or(0, |stop_time| stop_time.timestamp() + SUBTRAHEND);
+        write!(f, "{} {}", self.start_time.timestamp() + SUBTRAHEND, stop_time)
+    }
+}
+
 impl Timing {
     pub fn new(start_time: DateTime<Utc>, stop_time: Option<DateTime<Utc>>) -> Self {
         Self { start_time, stop_time }
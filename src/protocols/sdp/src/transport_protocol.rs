@@ -8,6 +8,14 @@ pub enum MediaTransportProtocol {
 }
 
 impl MediaTransportProtocol {
+    pub fn as_str(&self) -> &str {
+        match self {
+            MediaTransportProtocol::RtpAvp => "RTP/AVP",
+            MediaTransportProtocol::RtpSavp => "RTP/SAVP",
+            MediaTransportProtocol::Unknknown(protocol) => protocol,
+        }
+    }
+
     pub fn from_bytes(bytes: &[u8]) -> Result<Self, ParsingError> {
         match bytes {
             b"RTP/AVP" => Ok(MediaTransportProtocol::RtpAvp),
@@ -0,0 +1,29 @@
+use abstractions::parsing::payload_parser::PayloadParser;
+use rstest::rstest;
+use sdp::{data_transfer_mode::DataTransferMode, extension_map::ExtensionMap};
+
+#[rstest]
+#[case(b"1 urn:ietf:params:rtp-hdrext:toffset", Ok(ExtensionMap::new(1, None, "urn:ietf:params:rtp-hdrext:toffset".to_string(), None)))]
+#[case(
+    b"2/recvonly urn:ietf:params:rtp-hdrext:ssrc-audio-level vad=on\r\n",
+    Ok(ExtensionMap::new(2, Some(DataTransferMode::Receive), "urn:ietf:params:rtp-hdrext:ssrc-audio-level".to_string(), Some("vad=on".to_string())))
+)]
+#[case(b"200 http://www.webrtc.org/experiments/rtp-hdrext/abs-send-time", Ok(ExtensionMap::new(200, None, "http://www.webrtc.org/experiments/rtp-hdrext/abs-send-time".to_string(), None)))]
+#[case(b"0 urn:ietf:params:rtp-hdrext:toffset", Err(()))]
+#[case(b"4096 urn:ietf:params:rtp-hdrext:toffset", Err(()))]
+#[case(b"1/upward urn:ietf:params:rtp-hdrext:toffset", Err(()))]
+#[case(b"1", Err(()))]
+fn test_parse_extension_map(#[case] input: &[u8], #[case] expected: Result<ExtensionMap, ()>) {
+    assert_eq!(ExtensionMap::parse(input).map_err(|_| ()), expected);
+}
+
+#[rstest]
+#[case(ExtensionMap::new(1, None, "urn:ietf:params:rtp-hdrext:toffset".to_string(), None), "1 urn:ietf:params:rtp-hdrext:toffset")]
+#[case(
+    ExtensionMap::new(2, Some(DataTransferMode::Send), "urn:ietf:params:rtp-hdrext:ssrc-audio-level".to_string(), Some("vad=on".to_string())),
+    "2/sendonly urn:ietf:params:rtp-hdrext:ssrc-audio-level vad=on"
+)]
+fn test_display_extension_map(#[case] extension_map: ExtensionMap, #[case] expected: &str) {
+    assert_eq!(extension_map.to_string(), expected);
+    assert_eq!(ExtensionMap::parse(expected.as_bytes()).unwrap(), extension_map);
+}
@@ -1,6 +1,11 @@
 use media::video::h264_codec::H264Codec;
 use media::video::h265_codec::H265Codec;
+use sdp::bandwidth::Bandwidth;
+use sdp::data_transfer_mode::DataTransferMode;
+use sdp::extension_map::ExtensionMap;
 use sdp::media_description::MediaDescription;
+use sdp::media_description_builder::MediaDescriptionBuilder;
+use sdp::sdp_error::SdpError;
 use sdp::payload_type::PayloadType;
 use sdp::transport_protocol::MediaTransportProtocol;
 use abstractions::parsing::payload_parser::PayloadParser;
@@ -61,4 +66,136 @@ use rstest::rstest;
 fn test_parse_media_description(#[case] sdp_message: &[u8], #[case] expected: Result<MediaDescription, ()>) {
     let result = MediaDescription::parse(sdp_message).map_err(|_| ());
     assert_eq!(result, expected);
-}
\ No newline at end of file
+}
+#[rstest]
+#[case(b"audio 0 RTP/AVP 96\r\na=control:trackID=1\r\na=rtpmap:96 opus/48000/2\r\n", Some("trackID=1"))]
+#[case(b"audio 0 RTP/AVP 96\r\na=rtpmap:96 opus/48000/2\r\na=control:rtsp://192.0.2.10/stream/trackID=2\r\n", Some("rtsp://192.0.2.10/stream/trackID=2"))]
+#[case(b"audio 0 RTP/AVP 96\r\na=rtpmap:96 opus/48000/2\r\n", None)]
+fn test_parse_media_description_control(#[case] sdp_message: &[u8], #[case] expected: Option<&str>) {
+    let result = MediaDescription::parse(sdp_message).unwrap();
+    assert_eq!(result.control(), expected);
+}
+
+#[test]
+fn test_parse_media_description_extension_maps() {
+    let media_description = MediaDescription::parse(
+        b"audio 0 RTP/AVP 96\r\n\
+          a=rtpmap:96 opus/48000/2\r\n\
+          a=extmap:1 urn:ietf:params:rtp-hdrext:toffset\r\n\
+          a=extmap:3/recvonly urn:ietf:params:rtp-hdrext:sdes:mid\r\n\
+          a=framerate:25\r\n",
+    )
+    .unwrap();
+
+    assert_eq!(
+        media_description.extension_maps(),
+        &[
+            ExtensionMap::new(1, None, "urn:ietf:params:rtp-hdrext:toffset".to_string(), None),
+            ExtensionMap::new(3, Some(DataTransferMode::Receive), "urn:ietf:params:rtp-hdrext:sdes:mid".to_string(), None),
+        ]
+    );
+    assert_eq!(media_description.extension_id("urn:ietf:params:rtp-hdrext:sdes:mid"), Some(3));
+    assert_eq!(media_description.extension_id("urn:ietf:params:rtp-hdrext:ntp-64"), None);
+    assert_eq!(media_description.attributes().len(), 1);
+    assert_eq!(media_description.codecs().len(), 1);
+}
+
+#[rstest]
+#[case(b"audio 65532/2 RTP/AVP 0\r\n", Ok(vec![(65532, 65533), (65534, 65535)]))]
+#[case(b"audio 65534/1 RTP/AVP 0\r\n", Ok(vec![(65534, 65535)]))]
+#[case(b"audio 65534/2 RTP/AVP 0\r\n", Err(()))]
+#[case(b"audio 65535 RTP/AVP 0\r\n", Err(()))]
+#[case(b"audio 5000/0 RTP/AVP 0\r\n", Err(()))]
+fn test_parse_media_description_ports(#[case] data: &[u8], #[case] expected: Result<Vec<(u16, u16)>, ()>) {
+    let ports = MediaDescription::parse(data).map_err(|_| ()).map(|media_description| {
+        media_description.ports().iter().map(|port| (port.rtp_port(), port.rtcp_port())).collect::<Vec<_>>()
+    });
+    assert_eq!(ports, expected);
+}
+
+#[test]
+fn test_parse_media_description_invalid_extension_map() {
+    let media_description = MediaDescription::parse(
+        b"audio 0 RTP/AVP 96\r\n\
+          a=rtpmap:96 opus/48000/2\r\n\
+          a=extmap:4096 urn:x\r\n\
+          a=extmap:2 urn:ietf:params:rtp-hdrext:ssrc-audio-level\r\n",
+    )
+    .unwrap();
+
+    assert_eq!(
+        media_description.extension_maps(),
+        &[ExtensionMap::new(2, None, "urn:ietf:params:rtp-hdrext:ssrc-audio-level".to_string(), None)]
+    );
+    assert_eq!(media_description.attributes().len(), 1);
+    assert_eq!(media_description.attributes()[0].to_string(), "extmap:4096 urn:x");
+}
+
+#[rstest]
+#[case(b"audio 0 RTP/AVP 0\r\n", Some(vec![("PCMU", 0, 8000)]))]
+#[case(b"audio 0 RTP/AVP 8 0 9\r\na=control:trackID=1\r\n", Some(vec![("PCMA", 8, 8000), ("PCMU", 0, 8000), ("G722", 9, 8000)]))]
+#[case(b"audio 0 RTP/AVP 96 0\r\na=rtpmap:0 PCMU/8000\r\na=rtpmap:96 opus/48000/2\r\n", Some(vec![("opus", 96, 48000), ("PCMU", 0, 8000)]))]
+#[case(b"video 0 RTP/AVP 26\r\n", Some(vec![("JPEG", 26, 90000)]))]
+#[case(b"audio 0 RTP/AVP 96\r\n", None)]
+#[case(b"audio 0 RTP/AVP 0\r\na=rtpmap:96 opus/48000/2\r\n", None)]
+fn test_parse_static_payload_types(#[case] sdp_message: &[u8], #[case] expected: Option<Vec<(&str, u16, u32)>>) {
+    let result = MediaDescription::parse(sdp_message).ok().map(|media_description| {
+        media_description
+            .codecs()
+            .iter()
+            .map(|codec| (codec.get_name().to_string(), codec.format(), codec.clock_rate()))
+            .collect::<Vec<_>>()
+    });
+    let expected = expected.map(|codecs| codecs.into_iter().map(|(name, format, clock_rate)| (name.to_string(), format, clock_rate)).collect());
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_media_description_builder() {
+    let media_description = MediaDescriptionBuilder::new(PayloadType::Video)
+        .port_range(51372, 2)
+        .codec(CodecType::H264(H264Codec::parse(90000, None, b"a=fmtp:96 packetization-mode=1; profile-level-id=42e01f; sprop-parameter-sets=Z0IAH5WoFAFuQA==,aM4G4g==").unwrap()))
+        .bandwidth(Bandwidth::new("AS".to_string(), 512))
+        .control("trackID=1")
+        .extension_map(ExtensionMap::new(1, None, "urn:ietf:params:rtp-hdrext:toffset".to_string(), None))
+        .attribute("framerate", Some("25".to_string()))
+        .data_transfer_mode(DataTransferMode::Send)
+        .build()
+        .unwrap();
+
+    assert_eq!(media_description.ports(), &[SdpPort::new(51372, 51373), SdpPort::new(51374, 51375)]);
+    assert_eq!(
+        media_description.to_string(),
+        "m=video 51372/2 RTP/AVP 96\r\n\
+         b=AS:512\r\n\
+         a=rtpmap:96 H264/90000\r\n\
+         a=fmtp:96 packetization-mode=1; profile-level-id=42e01f; sprop-parameter-sets=Z0IAH5WoFAFuQA==,aM4G4g==\r\n\
+         a=control:trackID=1\r\n\
+         a=extmap:1 urn:ietf:params:rtp-hdrext:toffset\r\n\
+         a=framerate:25\r\n\
+         a=sendonly\r\n"
+    );
+    assert_eq!(MediaDescription::parse(media_description.to_string().as_bytes()).unwrap(), media_description);
+}
+
+#[test]
+fn test_media_description_builder_without_codecs() {
+    let result = MediaDescriptionBuilder::new(PayloadType::Audio).build();
+    assert_eq!(result, Err(SdpError::MissingField("codecs")));
+}
+
+#[rstest]
+#[case(65532, 2, Ok(vec![(65532, 65533), (65534, 65535)]))]
+#[case(65534, 2, Err(SdpError::InvalidField("ports")))]
+#[case(65535, 1, Err(SdpError::InvalidField("ports")))]
+#[case(5000, 0, Err(SdpError::MissingField("ports")))]
+fn test_media_description_builder_port_range(#[case] rtp_port: u16, #[case] count: u16, #[case] expected: Result<Vec<(u16, u16)>, SdpError>) {
+    let result = MediaDescriptionBuilder::new(PayloadType::Audio)
+        .port_range(rtp_port, count)
+        .codec(CodecType::parse(b"0 PCMU/8000").unwrap())
+        .build();
+    let ports = result.map(|media_description| {
+        media_description.ports().iter().map(|port| (port.rtp_port(), port.rtcp_port())).collect::<Vec<_>>()
+    });
+    assert_eq!(ports, expected);
+}
@@ -1,4 +1,9 @@
-use sdp::media_session::MediaSession;
+use media::{codec_type::CodecType, unsupported_codec::UnsupportedCodec};
+use sdp::{
+    media_description::MediaDescription, media_description_builder::MediaDescriptionBuilder,
+    media_session::MediaSession, media_session_builder::MediaSessionBuilder, origin_builder::OriginBuilder,
+    payload_type::PayloadType, sdp_error::SdpError,
+};
 use rstest::rstest;
 use abstractions::parsing::payload_parser::PayloadParser;
 
@@ -59,4 +64,89 @@ fn test_parse_sdp(#[case] sdp_message: &[u8], #[case] is_valid: bool) {
     } else {
         assert!(result.is_err());
     }
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_parse_session_extension_maps() {
+    let media_session = MediaSession::parse(
+        b"v=0\r\n\
+          o=- 2890844526 2890842807 IN IP4 192.0.2.10\r\n\
+          s=Camera\r\n\
+          t=0 0\r\n\
+          a=extmap:1 urn:ietf:params:rtp-hdrext:toffset\r\n\
+          a=extmap:2 urn:ietf:params:rtp-hdrext:ssrc-audio-level\r\n\
+          a=extmap:4096 urn:x\r\n\
+          m=audio 0 RTP/AVP 96\r\n\
+          a=rtpmap:96 opus/48000/2\r\n\
+          a=extmap:3 urn:ietf:params:rtp-hdrext:ssrc-audio-level\r\n\
+          m=video 0 RTP/AVP 26\r\n\
+          a=control:trackID=2\r\n",
+    )
+    .unwrap();
+
+    assert_eq!(media_session.extension_maps().len(), 2);
+    assert_eq!(media_session.media_attributes().len(), 1);
+    let audio = &media_session.media_descriptions()[0];
+    assert_eq!(audio.extension_id("urn:ietf:params:rtp-hdrext:toffset"), Some(1));
+    assert_eq!(audio.extension_id("urn:ietf:params:rtp-hdrext:ssrc-audio-level"), Some(3));
+    let video = &media_session.media_descriptions()[1];
+    assert_eq!(video.extension_id("urn:ietf:params:rtp-hdrext:ssrc-audio-level"), Some(2));
+    assert_eq!(video.extension_maps(), &[]);
+
+    let reparsed = MediaSession::parse(&media_session.to_bytes()).unwrap();
+    assert_eq!(reparsed, media_session);
+}
+
+fn audio_description() -> MediaDescription {
+    MediaDescriptionBuilder::new(PayloadType::Audio)
+        .codec(CodecType::Unsupported(UnsupportedCodec::new("speex".to_string(), 96, 16000, None, None)))
+        .control("trackID=1")
+        .build()
+        .unwrap()
+}
+
+#[test]
+fn test_media_session_builder() {
+    let media_session = MediaSessionBuilder::new()
+        .origin(OriginBuilder::new().session_id("2890844526").network_address("192.0.2.10".parse().unwrap()).build().unwrap())
+        .session_name("Camera")
+        .media_title("Front door")
+        .attribute("range", Some("npt=0-".to_string()))
+        .media_description(audio_description())
+        .build()
+        .unwrap();
+
+    assert_eq!(
+        media_session.to_string(),
+        "v=0\r\n\
+         o=- 2890844526 2890844526 IN IP4 192.0.2.10\r\n\
+         s=Camera\r\n\
+         i=Front door\r\n\
+         t=0 0\r\n\
+         a=range:npt=0-\r\n\
+         m=audio 0 RTP/AVP 96\r\n\
+         a=rtpmap:96 speex/16000\r\n\
+         a=control:trackID=1\r\n"
+    );
+    let reparsed = MediaSession::parse(&media_session.to_bytes()).unwrap();
+    assert_eq!(reparsed.media_descriptions(), media_session.media_descriptions());
+    assert_eq!(reparsed.media_attributes(), media_session.media_attributes());
+}
+
+#[rstest]
+#[case(MediaSessionBuilder::new().session_name("Camera").media_description(audio_description()), SdpError::MissingField("origin"))]
+#[case(
+    MediaSessionBuilder::new()
+        .origin(OriginBuilder::new().session_id("1").network_address("192.0.2.10".parse().unwrap()).build().unwrap())
+        .media_description(audio_description()),
+    SdpError::MissingField("session_name")
+)]
+#[case(
+    MediaSessionBuilder::new()
+        .origin(OriginBuilder::new().session_id("1").network_address("192.0.2.10".parse().unwrap()).build().unwrap())
+        .session_name("Camera"),
+    SdpError::MissingField("media_descriptions")
+)]
+fn test_media_session_builder_missing_field(#[case] builder: MediaSessionBuilder, #[case] expected: SdpError) {
+    assert_eq!(builder.build(), Err(expected));
+}
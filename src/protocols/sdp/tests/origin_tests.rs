@@ -1,6 +1,6 @@
 use std::net::IpAddr;
 use rstest::rstest;
-use sdp::origin::Origin;
+use sdp::{origin::Origin, origin_builder::OriginBuilder, sdp_error::SdpError};
 use abstractions::net::{address_type::AddressType, network_type::NetworkType};
 
 #[rstest]
@@ -43,4 +43,30 @@ fn test_origin_methods() {
     assert_eq!(*origin.network_type(), NetworkType::Internet);
     assert_eq!(*origin.address_type(), AddressType::Ipv4);
     assert_eq!(origin.network_address(), "192.0.2.10".parse::<IpAddr>().unwrap());
-}
\ No newline at end of file
+}
+#[rstest]
+#[case("192.0.2.10", AddressType::Ipv4)]
+#[case("2001:db8::1", AddressType::Ipv6)]
+fn test_origin_builder(#[case] address: &str, #[case] address_type: AddressType) {
+    let origin = OriginBuilder::new()
+        .user_name("jdoe")
+        .session_id("2890844526")
+        .network_address(address.parse().unwrap())
+        .build()
+        .unwrap();
+
+    assert_eq!(origin, Origin::new(
+        "jdoe".to_string(),
+        "2890844526".to_string(),
+        "2890844526".to_string(),
+        address_type,
+        address.parse().unwrap(),
+    ));
+}
+
+#[rstest]
+#[case(OriginBuilder::new().network_address("192.0.2.10".parse().unwrap()), SdpError::MissingField("session_id"))]
+#[case(OriginBuilder::new().session_id("1"), SdpError::MissingField("network_address"))]
+fn test_origin_builder_missing_field(#[case] builder: OriginBuilder, #[case] expected: SdpError) {
+    assert_eq!(builder.build(), Err(expected));
+}
@@ -0,0 +1,127 @@
+use abstractions::parsing::payload_parser::PayloadParser;
+use rstest::rstest;
+use sdp::{media_description::MediaDescription, media_session::MediaSession};
+
+#[rstest]
+#[case(
+    b"v=0\r
+      o=- 2890844526 2890842807 IN IP4 192.0.2.10\r
+      s=SDP Seminar\r
+      c=IN IP4 224.2.17.12/127\r
+      t=2873397496 2873404696\r
+      m=audio 49170 RTP/AVP 0 96\r
+      a=rtpmap:0 PCMU/8000\r
+      a=rtpmap:96 opus/48000/2\r"
+)]
+#[case(
+    b"v=0\r
+      o=- 2890844526 2890842807 IN IP4 192.0.2.10\r
+      s=SDP Seminar\r
+      c=IN IP4 224.2.17.12/127\r
+      t=2873397496 2873404696\r
+      m=video 51372 RTP/AVP 97 98\r
+      a=rtpmap:97 H264/90000\r
+      a=fmtp:97 packetization-mode=1; profile-level-id=42e01f; sprop-parameter-sets=Z0IAH5WoFAFuQA==,aM4G4g==\r
+      a=rtpmap:98 H265/90000\r
+      a=fmtp:98 profile-id=1; sprop-vps=Z0IAH5WoFAFuQA==; sprop-sps=Z0IAH5WoFAFuQA==; sprop-pps=aM4G4g==\r"
+)]
+#[case(
+    b"v=0\r
+      o=- 2890844526 2890842807 IN IP4 192.0.2.10\r
+      s=SDP Seminar\r
+      c=IN IP4 224.2.17.12/127\r
+      t=2873397496 2873404696\r
+      m=audio 49170 RTP/AVP 97\r
+      a=rtpmap:97 MPEG4-GENERIC/48000/2\r
+      a=fmtp:97 streamtype=5; profile-level-id=15; mode=AAC-hbr; config=1190; sizeLength=13; indexLength=3; indexDeltaLength=3; profile=1;\r"
+)]
+#[case(
+    b"v=0\r
+      o=jdoe 2890844526 2890842807 IN IP4 10.47.16.5\r
+      s=SDP Seminar\r
+      i=A Seminar on the session description protocol\r
+      u=http://www.example.com/seminars/sdp.pdf\r
+      e=j.doe@example.com (Jane Doe)\r
+      p=+1 617 555-6011\r
+      c=IN IP4 224.2.17.12/127\r
+      b=AS:256\r
+      t=2873397496 2873404696\r
+      r=7d 1h 0 25h\r
+      z=2882844526 -1h 2898848070 0\r
+      k=prompt\r
+      a=tool:example\r
+      a=recvonly\r
+      m=audio 49170/2 RTP/AVP 0\r
+      b=AS:64\r
+      a=rtpmap:0 PCMU/8000\r
+      a=control:trackID=1\r
+      a=ptime:20\r
+      a=sendonly\r
+      m=video 51372 RTP/AVP 99\r
+      a=rtpmap:99 h263-1998/90000\r
+      a=fmtp:99 profile=0; level=10\r
+      a=control:trackID=2\r"
+)]
+fn test_media_session_round_trip(#[case] sdp_message: &[u8]) {
+    let parsed = MediaSession::parse(sdp_message).unwrap();
+    let serialized = parsed.to_string();
+    let reparsed = MediaSession::parse(serialized.as_bytes()).unwrap();
+
+    assert_eq!(reparsed, parsed);
+    assert_eq!(reparsed.to_string(), serialized);
+}
+
+#[rstest]
+#[case(
+    b"video 51372 RTP/AVP 97 98\r
+      c=IN IP4 192.168.1.1/1/2\r
+      a=rtpmap:97 H264/90000\r
+      a=fmtp:97 packetization-mode=1; profile-level-id=42e01f; sprop-parameter-sets=Z0IAH5WoFAFuQA==,aM4G4g==\r\n
+      a=rtpmap:98 H265/90000\r
+      a=fmtp:98 profile-id=1; sprop-vps=Z0IAH5WoFAFuQA==; sprop-sps=Z0IAH5WoFAFuQA==; sprop-pps=aM4G4g==\r\n"
+)]
+#[case(
+    b"audio 49170 RTP/AVP 97\r
+      a=rtpmap:97 MPEG4-GENERIC/48000/2\r
+      a=fmtp:97 streamtype=5; profile-level-id=15; mode=AAC-hbr; config=1190; sizeLength=13; indexLength=3; indexDeltaLength=3; profile=1;\r"
+)]
+#[case(b"audio 0 RTP/AVP 96\r\na=control:trackID=1\r\na=rtpmap:96 opus/48000/2\r\n")]
+#[case(b"audio 0 RTP/AVP 96\r\na=rtpmap:96 opus/48000/2\r\na=control:rtsp://192.0.2.10/stream/trackID=2\r\n")]
+fn test_media_description_round_trip(#[case] sdp_message: &[u8]) {
+    let parsed = MediaDescription::parse(sdp_message).unwrap();
+    let serialized = parsed.to_string();
+    let reparsed = MediaDescription::parse(serialized.as_bytes()).unwrap();
+
+    assert_eq!(reparsed, parsed);
+    assert_eq!(reparsed.to_string(), serialized);
+}
+
+#[test]
+fn test_media_session_canonical_order() {
+    let sdp_message = b"v=0\r
+        o=- 2890844526 2890842807 IN IP4 192.0.2.10\r
+        s=SDP Seminar\r
+        t=2873397496 0\r
+        a=range:npt=0-\r
+        c=IN IP4 224.2.17.12/127\r
+        b=AS:128\r
+        m=audio 49170 RTP/AVP 96\r
+        a=control:trackID=1\r
+        a=rtpmap:96 opus/48000/2\r";
+
+    let media_session = MediaSession::parse(sdp_message).unwrap();
+
+    assert_eq!(
+        media_session.to_string(),
+        "v=0\r\n\
+         o=- 2890844526 2890842807 IN IP4 192.0.2.10\r\n\
+         s=SDP Seminar\r\n\
+         c=IN IP4 224.2.17.12/127\r\n\
+         b=AS:128\r\n\
+         t=2873397496 0\r\n\
+         a=range:npt=0-\r\n\
+         m=audio 49170 RTP/AVP 96\r\n\
+         a=rtpmap:96 opus/48000/2\r\n\
+         a=control:trackID=1\r\n"
+    );
+}
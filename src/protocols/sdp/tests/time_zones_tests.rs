@@ -0,0 +1,48 @@
+use abstractions::parsing::payload_parser::PayloadParser;
+use chrono::Duration;
+use rstest::rstest;
+use sdp::{
+    media_session::MediaSession,
+    time::time_zones::{TimeZoneAdjustment, TimeZones},
+};
+
+#[rstest]
+#[case(b"2882844526 -1h", Ok(vec![TimeZoneAdjustment::new(2882844526, Duration::hours(-1))]))]
+#[case(
+    b"2882844526 -1h 2898848070 0",
+    Ok(vec![TimeZoneAdjustment::new(2882844526, Duration::hours(-1)), TimeZoneAdjustment::new(2898848070, Duration::seconds(0))])
+)]
+#[case(b"2882844526 1800", Ok(vec![TimeZoneAdjustment::new(2882844526, Duration::minutes(30))]))]
+#[case(b"2882844526", Err(()))]
+#[case(b"2882844526 -1x", Err(()))]
+#[case(b"", Err(()))]
+fn test_parse_time_zones(#[case] input: &[u8], #[case] expected: Result<Vec<TimeZoneAdjustment>, ()>) {
+    let result = TimeZones::parse(input).map(|time_zones| time_zones.adjustments().to_vec()).map_err(|_| ());
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_write_time_zones() {
+    let time_zones = TimeZones::parse(b"2882844526 -1h 2898848070 0").unwrap();
+
+    assert_eq!(time_zones.to_string(), "2882844526 -3600 2898848070 0");
+}
+
+#[test]
+fn test_media_session_time_zones() {
+    let media_session = MediaSession::parse(
+        b"v=0\r\n\
+          o=- 2890844526 2890842807 IN IP4 192.0.2.10\r\n\
+          s=Camera\r\n\
+          t=2873397496 2873404696\r\n\
+          r=7d 1h 0 25h\r\n\
+          z=2882844526 -1h\r\n\
+          m=audio 0 RTP/AVP 0\r\n\
+          a=control:trackID=1\r\n",
+    )
+    .unwrap();
+
+    assert_eq!(media_session.time_zones(), Some(&TimeZones::new(vec![TimeZoneAdjustment::new(2882844526, Duration::hours(-1))])));
+    assert!(media_session.to_string().contains("r=604800 3600 0 90000\r\nz=2882844526 -3600\r\n"));
+}
@@ -0,0 +1,11 @@
+/// Represents the framing used on a stream transport.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Framing {
+    /// RFC 1006 TPKT headers with version 3.
+    Tpkt,
+    /// RTSP interleaved headers starting with `$`.
+    #[default]
+    Interleaved,
+    /// RFC 4571 16-bit length prefixes.
+    LengthPrefixed,
+}
@@ -0,0 +1,78 @@
+use abstractions::extensions::cast_error::CastError;
+use byteorder::{BigEndian, ByteOrder};
+
+/// Size of the length prefix in bytes.
+pub const LENGTH_PREFIX_SIZE: usize = 2;
+
+/// Represents an RTP or RTCP packet framed for a connection-oriented transport.
+///
+/// According to RFC 4571, section 2, every packet is preceded by its length as a 16-bit
+/// unsigned integer in network byte order:
+///
+/// ```text
+///  0                   1                   2                   3
+///  0 1 2 3 4 5 6 7 8 9 0 1 2 3 4 5 6 7 8 9 0 1 2 3 4 5 6 7 8 9 0 1
+/// ---------------------------------------------------------------
+/// |             LENGTH            |  RTP or RTCP packet ...       |
+/// ---------------------------------------------------------------
+/// ```
+#[derive(Debug, PartialEq, Eq)]
+pub struct LengthPrefixedFrame<'a> {
+    payload: &'a [u8],
+}
+
+impl<'a> TryFrom<&'a [u8]> for LengthPrefixedFrame<'a> {
+    type Error = CastError;
+
+    fn try_from(value: &'a [u8]) -> Result<Self, Self::Error> {
+        if value.len() < LENGTH_PREFIX_SIZE {
+            return Err(CastError::from_str("Buffer too short to contain length prefix"));
+        }
+        let length = BigEndian::read_u16(&value[..LENGTH_PREFIX_SIZE]) as usize;
+        let payload = value
+            .get(LENGTH_PREFIX_SIZE..LENGTH_PREFIX_SIZE + length)
+            .ok_or_else(|| CastError::from_str("Buffer too short to contain framed packet"))?;
+        Ok(Self { payload })
+    }
+}
+
+impl<'a> LengthPrefixedFrame<'a> {
+    /// Creates a new `LengthPrefixedFrame`.
+    ///
+    /// # Arguments
+    ///
+    /// * `payload` - The packet, at most 65535 bytes long.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the frame, or a `CastError` if the payload is too long.
+    pub fn new(payload: &'a [u8]) -> Result<Self, CastError> {
+        if payload.len() > u16::MAX as usize {
+            return Err(CastError::from_str("Payload too long for a framed packet"));
+        }
+        Ok(Self { payload })
+    }
+
+    pub fn payload(&self) -> &[u8] {
+        self.payload
+    }
+
+    /// Writes the frame to a buffer.
+    ///
+    /// # Arguments
+    ///
+    /// * `buffer` - The buffer to write the frame to, at least `byte_size()` bytes long.
+    ///
+    /// # Returns
+    ///
+    /// The length of the written data.
+    pub fn write(&self, buffer: &mut [u8]) -> usize {
+        BigEndian::write_u16(&mut buffer[..LENGTH_PREFIX_SIZE], self.payload.len() as u16);
+        buffer[LENGTH_PREFIX_SIZE..self.byte_size()].copy_from_slice(self.payload);
+        self.byte_size()
+    }
+
+    pub fn byte_size(&self) -> usize {
+        LENGTH_PREFIX_SIZE + self.payload.len()
+    }
+}
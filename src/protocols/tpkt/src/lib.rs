@@ -1,14 +1,29 @@
-pub fn add(left: usize, right: usize) -> usize {
-    left + right
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn it_works() {
-        let result = add(2, 2);
-        assert_eq!(result, 4);
-    }
-}
+/// TPKT module for framing packets on stream transports.
+///
+/// This module provides the 4-byte TPKT header of RFC 1006 and its RTSP interleaved variant
+/// (RFC 2326, section 10.12), the 2-byte length prefix of RFC 4571 for RTP and RTCP over
+/// connection-oriented transports, and a streaming decoder that yields complete frames from
+/// partial reads.
+///
+/// # Examples
+///
+/// ## Decoding interleaved frames
+///
+/// ```rust
+/// use tpkt::{framing::Framing, tpkt_decoder::TpktDecoder, tpkt_frame::TpktFrame};
+///
+/// let mut decoder = TpktDecoder::new(Framing::Interleaved);
+/// decoder.extend(&[b'$', 1, 0x00, 0x03, 0xAA]);
+/// assert!(decoder.decode().unwrap().is_none());
+///
+/// decoder.extend(&[0xBB, 0xCC]);
+/// let data = decoder.decode().unwrap().unwrap();
+/// let frame = TpktFrame::try_from(&data[..]).unwrap();
+/// assert_eq!(frame.header().channel(), 1);
+/// assert_eq!(frame.payload(), &[0xAA, 0xBB, 0xCC]);
+/// ```
+pub mod tpkt_payload_header;
+pub mod tpkt_frame;
+pub mod length_prefixed_frame;
+pub mod framing;
+pub mod tpkt_decoder;
@@ -0,0 +1,91 @@
+use abstractions::extensions::cast_error::CastError;
+use byteorder::{BigEndian, ByteOrder};
+
+use crate::{
+    framing::Framing,
+    length_prefixed_frame::LENGTH_PREFIX_SIZE,
+    tpkt_payload_header::{TpktPayloadHeader, INTERLEAVED_VERSION, TPKT_HEADER_SIZE, TPKT_VERSION},
+};
+
+/// Accumulates bytes read from a stream transport and yields complete frames.
+///
+/// A single read may contain a fraction of a frame or several frames at once; the decoder
+/// keeps the unconsumed tail between calls. Each decoded frame includes its header, so it
+/// can be parsed with `TpktFrame::try_from` or `LengthPrefixedFrame::try_from`.
+#[derive(Debug, Default)]
+pub struct TpktDecoder {
+    framing: Framing,
+    buffer: Vec<u8>,
+}
+
+impl TpktDecoder {
+    pub fn new(framing: Framing) -> Self {
+        Self {
+            framing,
+            buffer: Vec::new(),
+        }
+    }
+
+    pub fn framing(&self) -> Framing {
+        self.framing
+    }
+
+    /// Appends received bytes to the internal buffer.
+    pub fn extend(&mut self, data: &[u8]) {
+        self.buffer.extend_from_slice(data);
+    }
+
+    /// Takes the next complete frame out of the buffer.
+    ///
+    /// # Returns
+    ///
+    /// `Ok(None)` if the buffer does not hold a complete frame yet, or a `CastError` if the
+    /// buffered bytes do not start with a header of the configured framing.
+    pub fn decode(&mut self) -> Result<Option<Vec<u8>>, CastError> {
+        let Some(length) = self.frame_length()? else {
+            return Ok(None);
+        };
+        if self.buffer.len() < length {
+            return Ok(None);
+        }
+        Ok(Some(self.buffer.drain(..length).collect()))
+    }
+
+    /// Returns the bytes that have not been decoded yet.
+    pub fn remaining(&self) -> &[u8] {
+        &self.buffer
+    }
+
+    pub fn clear(&mut self) {
+        self.buffer.clear();
+    }
+
+    /// Returns the length of the frame at the start of the buffer, header included.
+    fn frame_length(&self) -> Result<Option<usize>, CastError> {
+        match self.framing {
+            Framing::LengthPrefixed => {
+                if self.buffer.len() < LENGTH_PREFIX_SIZE {
+                    return Ok(None);
+                }
+                Ok(Some(LENGTH_PREFIX_SIZE + BigEndian::read_u16(&self.buffer) as usize))
+            }
+            Framing::Tpkt | Framing::Interleaved => {
+                let expected = match self.framing {
+                    Framing::Tpkt => TPKT_VERSION,
+                    _ => INTERLEAVED_VERSION,
+                };
+                match self.buffer.first() {
+                    None => return Ok(None),
+                    Some(version) if *version != expected => {
+                        return Err(CastError::from_str("Invalid TPKT version"));
+                    }
+                    _ => {}
+                }
+                if self.buffer.len() < TPKT_HEADER_SIZE {
+                    return Ok(None);
+                }
+                Ok(Some(TpktPayloadHeader::try_from(&self.buffer[..])?.packet_length()))
+            }
+        }
+    }
+}
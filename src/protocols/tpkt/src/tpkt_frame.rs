@@ -0,0 +1,88 @@
+use abstractions::extensions::cast_error::CastError;
+
+use crate::tpkt_payload_header::{TpktPayloadHeader, TPKT_HEADER_SIZE};
+
+/// Represents a packet framed with a TPKT or RTSP interleaved header.
+#[derive(Debug, PartialEq, Eq)]
+pub struct TpktFrame<'a> {
+    header: TpktPayloadHeader,
+    payload: &'a [u8],
+}
+
+impl<'a> TryFrom<&'a [u8]> for TpktFrame<'a> {
+    type Error = CastError;
+
+    fn try_from(value: &'a [u8]) -> Result<Self, Self::Error> {
+        let header = TpktPayloadHeader::try_from(value)?;
+        let payload = value
+            .get(TPKT_HEADER_SIZE..header.packet_length())
+            .ok_or_else(|| CastError::from_str("Buffer too short to contain TPKT payload"))?;
+        Ok(Self { header, payload })
+    }
+}
+
+impl<'a> TpktFrame<'a> {
+    /// Creates a new RTSP interleaved `TpktFrame`.
+    ///
+    /// # Arguments
+    ///
+    /// * `channel` - The interleaved channel.
+    /// * `payload` - The packet, at most 65535 bytes long.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the frame, or a `CastError` if the payload is too long.
+    pub fn interleaved(channel: u8, payload: &'a [u8]) -> Result<Self, CastError> {
+        let length = u16::try_from(payload.len())
+            .map_err(|_| CastError::from_str("Payload too long for a TPKT frame"))?;
+        Ok(Self {
+            header: TpktPayloadHeader::interleaved(channel, length),
+            payload,
+        })
+    }
+
+    /// Creates a new RFC 1006 `TpktFrame`.
+    ///
+    /// # Arguments
+    ///
+    /// * `payload` - The packet, at most 65531 bytes long.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the frame, or a `CastError` if the payload is too long.
+    pub fn tpkt(payload: &'a [u8]) -> Result<Self, CastError> {
+        let length = u16::try_from(payload.len())
+            .map_err(|_| CastError::from_str("Payload too long for a TPKT frame"))?;
+        Ok(Self {
+            header: TpktPayloadHeader::tpkt(length)?,
+            payload,
+        })
+    }
+
+    pub fn header(&self) -> &TpktPayloadHeader {
+        &self.header
+    }
+
+    pub fn payload(&self) -> &[u8] {
+        self.payload
+    }
+
+    /// Writes the frame to a buffer.
+    ///
+    /// # Arguments
+    ///
+    /// * `buffer` - The buffer to write the frame to, at least `byte_size()` bytes long.
+    ///
+    /// # Returns
+    ///
+    /// The length of the written data.
+    pub fn write(&self, buffer: &mut [u8]) -> usize {
+        let header_len = self.header.write(buffer);
+        buffer[header_len..header_len + self.payload.len()].copy_from_slice(self.payload);
+        header_len + self.payload.len()
+    }
+
+    pub fn byte_size(&self) -> usize {
+        TPKT_HEADER_SIZE + self.payload.len()
+    }
+}
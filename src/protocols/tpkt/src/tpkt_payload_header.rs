@@ -1,8 +1,147 @@
-#[derive(Clone, Copy)]
-#[repr(packed)]
+use abstractions::extensions::cast_error::CastError;
+use byteorder::{BigEndian, ByteOrder};
+
+/// Size of the TPKT header in bytes.
+pub const TPKT_HEADER_SIZE: usize = 4;
+/// Version byte of an RFC 1006 TPKT header.
+pub const TPKT_VERSION: u8 = 3;
+/// Version byte of an RTSP interleaved header, the `$` sign.
+pub const INTERLEAVED_VERSION: u8 = b'$';
+
+/// Represents the 4-byte header that frames a packet on a stream transport.
+///
+/// According to RFC 1006, section 6, a TPKT header has the following layout, where the
+/// length covers the header itself:
+///
+/// ```text
+///  0                   1                   2                   3
+///  0 1 2 3 4 5 6 7 8 9 0 1 2 3 4 5 6 7 8 9 0 1 2 3 4 5 6 7 8 9 0 1
+/// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+/// |  vrsn (= 3)   |   reserved    |         packet length         |
+/// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+/// ```
+///
+/// RTSP reuses the layout for interleaved data (RFC 2326, section 10.12), with a `$` in
+/// place of the version, the channel in place of the reserved byte and a length that
+/// covers the payload only:
+///
+/// ```text
+/// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+/// |   '$' (0x24)  |    channel    |        payload length         |
+/// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+/// ```
+///
+/// `payload_length` always refers to the bytes following the header, whichever variant is used.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct TpktPayloadHeader {
     version: u8,
-    pre_reserved: u8,
-    header: u8,
-    packet_length: u8
-}
\ No newline at end of file
+    channel: u8,
+    payload_length: u16,
+}
+
+impl TryFrom<&[u8]> for TpktPayloadHeader {
+    type Error = CastError;
+
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        if value.len() < TPKT_HEADER_SIZE {
+            return Err(CastError::from_str("Buffer too short to contain TPKT header"));
+        }
+
+        let version = value[0];
+        let channel = value[1];
+        let length = BigEndian::read_u16(&value[2..4]);
+        let payload_length = match version {
+            INTERLEAVED_VERSION => length,
+            TPKT_VERSION => length
+                .checked_sub(TPKT_HEADER_SIZE as u16)
+                .ok_or_else(|| CastError::from_str("TPKT length shorter than its header"))?,
+            _ => return Err(CastError::from_str("Invalid TPKT version")),
+        };
+
+        Ok(Self {
+            version,
+            channel,
+            payload_length,
+        })
+    }
+}
+
+impl TpktPayloadHeader {
+    /// Creates a new RFC 1006 `TpktPayloadHeader`.
+    ///
+    /// # Arguments
+    ///
+    /// * `payload_length` - The length of the payload, at most 65531 bytes.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the header, or a `CastError` if the packet length would not fit
+    /// into 16 bits with the header included.
+    pub fn tpkt(payload_length: u16) -> Result<Self, CastError> {
+        if payload_length > u16::MAX - TPKT_HEADER_SIZE as u16 {
+            return Err(CastError::from_str("Payload too long for a TPKT frame"));
+        }
+        Ok(Self {
+            version: TPKT_VERSION,
+            channel: 0,
+            payload_length,
+        })
+    }
+
+    /// Creates a new RTSP interleaved `TpktPayloadHeader`.
+    ///
+    /// # Arguments
+    ///
+    /// * `channel` - The interleaved channel negotiated with the `Transport` header.
+    /// * `payload_length` - The length of the payload.
+    pub fn interleaved(channel: u8, payload_length: u16) -> Self {
+        Self {
+            version: INTERLEAVED_VERSION,
+            channel,
+            payload_length,
+        }
+    }
+
+    pub fn version(&self) -> u8 {
+        self.version
+    }
+
+    /// Returns the interleaved channel, or the reserved byte of an RFC 1006 header.
+    pub fn channel(&self) -> u8 {
+        self.channel
+    }
+
+    pub fn payload_length(&self) -> u16 {
+        self.payload_length
+    }
+
+    pub fn is_interleaved(&self) -> bool {
+        self.version == INTERLEAVED_VERSION
+    }
+
+    /// Returns the size of the header and the payload together.
+    pub fn packet_length(&self) -> usize {
+        TPKT_HEADER_SIZE + self.payload_length as usize
+    }
+
+    /// Writes the header to a buffer.
+    ///
+    /// # Arguments
+    ///
+    /// * `buffer` - The buffer to write the header to, at least 4 bytes long.
+    ///
+    /// # Returns
+    ///
+    /// The length of the written data.
+    pub fn write(&self, buffer: &mut [u8]) -> usize {
+        let length = if self.is_interleaved() {
+            self.payload_length
+        } else {
+            self.payload_length + TPKT_HEADER_SIZE as u16
+        };
+        buffer[0] = self.version;
+        buffer[1] = self.channel;
+        BigEndian::write_u16(&mut buffer[2..4], length);
+        TPKT_HEADER_SIZE
+    }
+}
@@ -0,0 +1,32 @@
+use rstest::rstest;
+use tpkt::{framing::Framing, tpkt_decoder::TpktDecoder};
+
+#[rstest]
+#[case(Framing::Interleaved, &[0x24, 0x00, 0x00, 0x02, 0xAA, 0xBB, 0x24, 0x01, 0x00, 0x01, 0xCC], 4)]
+#[case(Framing::Tpkt, &[0x03, 0x00, 0x00, 0x06, 0xAA, 0xBB, 0x03, 0x00, 0x00, 0x05, 0xCC], 4)]
+#[case(Framing::LengthPrefixed, &[0x00, 0x02, 0xAA, 0xBB, 0x00, 0x01, 0xCC], 2)]
+fn test_decoder_split_reads(#[case] framing: Framing, #[case] data: &[u8], #[case] header_size: usize) {
+    for chunk_size in 1..data.len() {
+        let mut decoder = TpktDecoder::new(framing);
+        let mut frames = Vec::new();
+
+        for chunk in data.chunks(chunk_size) {
+            decoder.extend(chunk);
+            while let Some(frame) = decoder.decode().unwrap() {
+                frames.push(frame);
+            }
+        }
+
+        assert_eq!(frames.len(), 2);
+        assert_eq!(&frames[0][header_size..], &[0xAA, 0xBB]);
+        assert_eq!(&frames[1][header_size..], &[0xCC]);
+        assert!(decoder.remaining().is_empty());
+    }
+}
+
+#[test]
+fn test_decoder_invalid_version() {
+    let mut decoder = TpktDecoder::new(Framing::Interleaved);
+    decoder.extend(b"RTSP/1.0 200 OK\r\n");
+    assert!(decoder.decode().is_err());
+}
@@ -0,0 +1,71 @@
+use rstest::rstest;
+use tpkt::{
+    length_prefixed_frame::LengthPrefixedFrame,
+    tpkt_frame::TpktFrame,
+    tpkt_payload_header::{TpktPayloadHeader, INTERLEAVED_VERSION, TPKT_VERSION},
+};
+
+#[rstest]
+#[case(&[0x24, 0x01, 0x00, 0x05], INTERLEAVED_VERSION, 1, 5)]
+#[case(&[0x03, 0x00, 0x00, 0x09], TPKT_VERSION, 0, 5)]
+#[case(&[0x24, 0xFF, 0xFF, 0xFF], INTERLEAVED_VERSION, 255, 65535)]
+fn test_tpkt_payload_header_try_from(
+    #[case] data: &[u8],
+    #[case] version: u8,
+    #[case] channel: u8,
+    #[case] payload_length: u16,
+) {
+    let header = TpktPayloadHeader::try_from(data).unwrap();
+
+    assert_eq!(header.version(), version);
+    assert_eq!(header.channel(), channel);
+    assert_eq!(header.payload_length(), payload_length);
+
+    let mut buffer = [0u8; 4];
+    assert_eq!(header.write(&mut buffer), 4);
+    assert_eq!(&buffer, data);
+}
+
+#[rstest]
+#[case(&[0x24, 0x01, 0x00])]
+#[case(&[0x03, 0x00, 0x00, 0x02])]
+#[case(&[0x80, 0x60, 0x00, 0x01])]
+fn test_tpkt_payload_header_invalid(#[case] data: &[u8]) {
+    assert!(TpktPayloadHeader::try_from(data).is_err());
+}
+
+#[rstest]
+#[case(65531, true)]
+#[case(65532, false)]
+#[case(u16::MAX, false)]
+fn test_tpkt_payload_header_length(#[case] payload_length: u16, #[case] valid: bool) {
+    assert_eq!(TpktPayloadHeader::tpkt(payload_length).is_ok(), valid);
+    assert_eq!(TpktFrame::tpkt(&vec![0u8; payload_length as usize]).is_ok(), valid);
+}
+
+#[test]
+fn test_tpkt_frame() {
+    let frame = TpktFrame::interleaved(2, &[1, 2, 3]).unwrap();
+    let mut buffer = vec![0u8; frame.byte_size()];
+
+    assert_eq!(frame.write(&mut buffer), 7);
+    assert_eq!(buffer, vec![0x24, 0x02, 0x00, 0x03, 1, 2, 3]);
+    assert_eq!(TpktFrame::try_from(&buffer[..]).unwrap(), frame);
+    assert!(TpktFrame::try_from(&buffer[..6]).is_err());
+
+    let frame = TpktFrame::tpkt(&[1, 2, 3]).unwrap();
+    frame.write(&mut buffer);
+    assert_eq!(buffer, vec![0x03, 0x00, 0x00, 0x07, 1, 2, 3]);
+}
+
+#[test]
+fn test_length_prefixed_frame() {
+    let frame = LengthPrefixedFrame::new(&[0x80, 0x60, 0x00]).unwrap();
+    let mut buffer = vec![0u8; frame.byte_size()];
+
+    assert_eq!(frame.write(&mut buffer), 5);
+    assert_eq!(buffer, vec![0x00, 0x03, 0x80, 0x60, 0x00]);
+    assert_eq!(LengthPrefixedFrame::try_from(&buffer[..]).unwrap().payload(), &[0x80, 0x60, 0x00]);
+    assert!(LengthPrefixedFrame::try_from(&buffer[..4]).is_err());
+    assert!(LengthPrefixedFrame::new(&vec![0u8; 65536]).is_err());
+}